@@ -0,0 +1,4 @@
+debugger;
+new Foo();
+if (NaN === foo) {
+}
@@ -0,0 +1,2 @@
+export { reexported } from "./deep";
+export * from "./star";
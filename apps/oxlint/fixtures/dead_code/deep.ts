@@ -0,0 +1,2 @@
+export const reexported = 1;
+export const deepUnused = 2;
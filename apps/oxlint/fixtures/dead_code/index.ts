@@ -0,0 +1,8 @@
+import { used } from "./lib";
+import * as ns from "./ns";
+import { reexported, starred } from "./barrel";
+
+export const api = used + ns.a + reexported + starred;
+
+// Followed, `lazy.ts` is reachable and all of its exports are used.
+export const load = () => import("./lazy");
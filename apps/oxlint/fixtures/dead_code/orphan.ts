@@ -0,0 +1 @@
+export const orphan = 1;
@@ -0,0 +1,2 @@
+export const starred = 1;
+export const starUnused = 2;
@@ -0,0 +1,9 @@
+// eslint-disable-next-line no-debugger
+debugger;
+// eslint-disable-next-line no-console, no-debugger
+debugger;
+// eslint-disable-next-line no-debugger
+foo();
+// eslint-disable-next-line prettier/prettier
+foo();
+foo(); // eslint-disable-line
@@ -0,0 +1,5 @@
+async function f() {
+  await await await 1;
+}
+
+const a = [...[...[...[b]]]];
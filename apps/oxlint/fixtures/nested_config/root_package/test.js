@@ -0,0 +1,3 @@
+debugger;
+console.log();
+if (a == b) {}
@@ -0,0 +1,3 @@
+import self from "./self.js";
+
+export default self;
@@ -0,0 +1,2 @@
+if (a > 5 && a < 3) {
+}
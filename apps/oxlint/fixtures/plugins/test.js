@@ -0,0 +1,4 @@
+fit("foo", () => {
+  if (foo instanceof Array) {
+  }
+});
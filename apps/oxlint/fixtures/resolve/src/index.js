@@ -0,0 +1,5 @@
+import pkg from "pkg";
+import { foo } from "./foo";
+import missing from "./missing";
+
+export { pkg, foo, missing };
@@ -0,0 +1,12 @@
+async function fetchData(): Promise<number> {
+  return 1;
+}
+
+fetchData();
+
+export async function main(count: number) {
+  const value = await count;
+  if (fetchData()) {
+    return value;
+  }
+}
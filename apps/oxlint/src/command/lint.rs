@@ -1,7 +1,7 @@
 use std::{path::PathBuf, str::FromStr};
 
-use bpaf::Bpaf;
-use oxc_linter::AllowWarnDeny;
+use bpaf::{construct, Bpaf, Parser};
+use oxc_linter::{AllowWarnDeny, FixKind};
 
 use super::{
     expand_glob,
@@ -24,6 +24,9 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub fix_options: FixOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
@@ -37,6 +40,18 @@ pub struct LintCommand {
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print the configuration file given by `-c` after merging its `extends`
+    #[bpaf(switch, hide_usage)]
+    pub print_config: bool,
+
+    /// Explain the severity and options of a rule for the file given as PATH,
+    /// and whether they come from the command line, a configuration file, or its `overrides`
+    #[bpaf(argument("NAME"), hide_usage)]
+    pub explain_rule: Option<String>,
+
+    #[bpaf(external)]
+    pub dead_code_options: DeadCodeOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -65,9 +80,20 @@ pub struct BasicOptions {
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
+    /// Do not look up `.oxlintrc.json` files in the directories of the linted files.
+    /// By default, the nearest `.oxlintrc.json` between the current working directory and each file
+    /// is merged with the ones of its parent directories, until one with `"root": true`.
+    #[bpaf(switch, hide_usage)]
+    pub no_config_lookup: bool,
+
     /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
+
+    /// Run the type-aware rules (e.g. `typescript/no-floating-promises`),
+    /// same as `"typeAware": true` in the configuration
+    #[bpaf(switch, hide_usage)]
+    pub type_aware: bool,
 }
 
 // This is formatted according to
@@ -122,6 +148,43 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+    /// Apply the suggested fixes too, which may not be what you intended
+    #[bpaf(switch, hide_usage)]
+    pub fix_suggestions: bool,
+    /// Apply the dangerous fixes too, which may change the behavior of the code
+    #[bpaf(switch, hide_usage)]
+    pub fix_dangerously: bool,
+}
+
+impl FixOptions {
+    /// Kinds of fixes to apply, every flag implies `--fix`.
+    pub fn fix_kind(&self) -> FixKind {
+        let mut kind = FixKind::None;
+        if self.fix || self.fix_suggestions || self.fix_dangerously {
+            kind |= FixKind::SafeFix;
+        }
+        if self.fix_suggestions {
+            kind |= FixKind::Suggestion;
+        }
+        if self.fix_dangerously {
+            kind |= FixKind::DangerousFix;
+        }
+        kind
+    }
+}
+
+/// Cache
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint the files changed since the previous run with `--cache`,
+    /// and report the results of the unchanged files from the cache.
+    /// Not used with `--fix`
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file, defaults to `.oxlintcache`
+    #[bpaf(argument("PATH"), fallback(PathBuf::from(".oxlintcache")), hide_usage)]
+    pub cache_location: PathBuf,
 }
 
 /// Handle Warnings
@@ -139,6 +202,11 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Report directive comments like `// oxlint-disable-line`,
+    /// when no errors would have been reported on that line anyway
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
 }
 
 /// Output
@@ -147,6 +215,11 @@ pub struct OutputOptions {
     /// Use a specific output format (default, json, unix, checkstyle, github)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Print the time spent by each rule, and by parsing and building the semantic model,
+    /// similar to ESLint's `TIMING=1`. Included in the output of `--format json`
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -174,50 +247,198 @@ impl FromStr for OutputFormat {
     }
 }
 
+/// Dead Code
+#[derive(Debug, Clone, Bpaf)]
+pub struct DeadCodeOptions {
+    /// Report the exports never imported from the entry points given by `--entry`,
+    /// and the files unreachable from them, instead of linting. Enables the import plugin.
+    /// The `import` and `export` declarations, and the `import()` calls with a string literal
+    /// are followed, the files only loaded with `require()` are unreachable unless they are
+    /// entry points
+    #[bpaf(switch, hide_usage)]
+    pub dead_code: bool,
+
+    /// Glob of the entry points for `--dead-code`, matched against the paths relative to the
+    /// current working directory, e.g. `--entry 'src/index.ts' --entry 'src/**/*.test.ts'`
+    #[bpaf(argument("GLOB"), many, hide_usage)]
+    pub entry: Vec<String>,
+}
+
 /// Enable Plugins
+///
+/// Plugins not given here are configured by the `plugins` of the configuration file,
+/// each plugin can be turned on with `--<name>-plugin` and off with `--disable-<name>-plugin`.
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Clone, Bpaf)]
 pub struct EnablePlugins {
-    /// Disable react plugin, which is turned on by default
-    #[bpaf(long("disable-react-plugin"), flag(false, true), hide_usage)]
-    pub react_plugin: bool,
+    #[bpaf(external)]
+    pub react_plugin: Option<bool>,
 
-    /// Disable unicorn plugin, which is turned on by default
-    #[bpaf(long("disable-unicorn-plugin"), flag(false, true), hide_usage)]
-    pub unicorn_plugin: bool,
+    #[bpaf(external)]
+    pub unicorn_plugin: Option<bool>,
 
-    /// Disable oxc unique rules, which is turned on by default
-    #[bpaf(long("disable-oxc-plugin"), flag(false, true), hide_usage)]
-    pub oxc_plugin: bool,
+    #[bpaf(external)]
+    pub oxc_plugin: Option<bool>,
 
-    /// Disable TypeScript plugin, which is turned on by default
-    #[bpaf(long("disable-typescript-plugin"), flag(false, true), hide_usage)]
-    pub typescript_plugin: bool,
+    #[bpaf(external)]
+    pub typescript_plugin: Option<bool>,
 
-    /// Enable the experimental import plugin and detect ESM problems.
-    /// It is recommended to use along side with the `--tsconfig` option.
-    #[bpaf(switch, hide_usage)]
-    pub import_plugin: bool,
+    #[bpaf(external)]
+    pub import_plugin: Option<bool>,
 
-    /// Enable the experimental jsdoc plugin and detect JSDoc problems
-    #[bpaf(switch, hide_usage)]
-    pub jsdoc_plugin: bool,
+    #[bpaf(external)]
+    pub jsdoc_plugin: Option<bool>,
 
-    /// Enable the Jest plugin and detect test problems
-    #[bpaf(switch, hide_usage)]
-    pub jest_plugin: bool,
+    #[bpaf(external)]
+    pub jest_plugin: Option<bool>,
 
-    /// Enable the JSX-a11y plugin and detect accessibility problems
-    #[bpaf(switch, hide_usage)]
-    pub jsx_a11y_plugin: bool,
+    #[bpaf(external)]
+    pub jsx_a11y_plugin: Option<bool>,
 
-    /// Enable the Next.js plugin and detect Next.js problems
-    #[bpaf(switch, hide_usage)]
-    pub nextjs_plugin: bool,
+    #[bpaf(external)]
+    pub nextjs_plugin: Option<bool>,
 
-    /// Enable the React performance plugin and detect rendering performance problems
-    #[bpaf(switch, hide_usage)]
-    pub react_perf_plugin: bool,
+    #[bpaf(external)]
+    pub react_perf_plugin: Option<bool>,
+
+    #[bpaf(external)]
+    pub promise_plugin: Option<bool>,
+
+    #[bpaf(external)]
+    pub node_plugin: Option<bool>,
+
+    #[bpaf(external)]
+    pub vitest_plugin: Option<bool>,
+}
+
+/// `Some(true)` for the enable flag, `Some(false)` for the disable flag,
+/// `None` to leave the plugin to the configuration file.
+fn plugin_flag(
+    enable: &'static str,
+    enable_help: &'static str,
+    disable: &'static str,
+    disable_help: &'static str,
+) -> impl Parser<Option<bool>> {
+    let enable = bpaf::long(enable).help(enable_help).req_flag(true);
+    let disable = bpaf::long(disable).help(disable_help).req_flag(false);
+    construct!([enable, disable]).optional().hide_usage()
+}
+
+fn react_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "react-plugin",
+        "Enable react plugin when the `plugins` of the configuration file leave it out",
+        "disable-react-plugin",
+        "Disable react plugin, which is turned on by default",
+    )
+}
+
+fn unicorn_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "unicorn-plugin",
+        "Enable unicorn plugin when the `plugins` of the configuration file leave it out",
+        "disable-unicorn-plugin",
+        "Disable unicorn plugin, which is turned on by default",
+    )
+}
+
+fn oxc_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "oxc-plugin",
+        "Enable oxc unique rules when the `plugins` of the configuration file leave it out",
+        "disable-oxc-plugin",
+        "Disable oxc unique rules, which is turned on by default",
+    )
+}
+
+fn typescript_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "typescript-plugin",
+        "Enable TypeScript plugin when the `plugins` of the configuration file leave it out",
+        "disable-typescript-plugin",
+        "Disable TypeScript plugin, which is turned on by default",
+    )
+}
+
+fn import_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "import-plugin",
+        "Enable the experimental import plugin and detect ESM problems. It is recommended to use along side with the `--tsconfig` option.",
+        "disable-import-plugin",
+        "Disable the import plugin",
+    )
+}
+
+fn jsdoc_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "jsdoc-plugin",
+        "Enable the experimental jsdoc plugin and detect JSDoc problems",
+        "disable-jsdoc-plugin",
+        "Disable the jsdoc plugin",
+    )
+}
+
+fn jest_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "jest-plugin",
+        "Enable the Jest plugin and detect test problems",
+        "disable-jest-plugin",
+        "Disable the Jest plugin",
+    )
+}
+
+fn jsx_a11y_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "jsx-a11y-plugin",
+        "Enable the JSX-a11y plugin and detect accessibility problems",
+        "disable-jsx-a11y-plugin",
+        "Disable the JSX-a11y plugin",
+    )
+}
+
+fn nextjs_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "nextjs-plugin",
+        "Enable the Next.js plugin and detect Next.js problems",
+        "disable-nextjs-plugin",
+        "Disable the Next.js plugin",
+    )
+}
+
+fn react_perf_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "react-perf-plugin",
+        "Enable the React performance plugin and detect rendering performance problems",
+        "disable-react-perf-plugin",
+        "Disable the React performance plugin",
+    )
+}
+
+fn promise_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "promise-plugin",
+        "Enable the promise plugin and detect promise usage problems",
+        "disable-promise-plugin",
+        "Disable the promise plugin",
+    )
+}
+
+fn node_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "node-plugin",
+        "Enable the Node.js plugin and detect Node.js problems",
+        "disable-node-plugin",
+        "Disable the Node.js plugin",
+    )
+}
+
+fn vitest_plugin() -> impl Parser<Option<bool>> {
+    plugin_flag(
+        "vitest-plugin",
+        "Enable the Vitest plugin and detect test problems, the Jest rules are enabled as well",
+        "disable-vitest-plugin",
+        "Disable the Vitest plugin",
+    )
 }
 
 #[cfg(test)]
@@ -254,7 +475,7 @@ mod lint_options {
     use std::fs::File;
     use std::path::PathBuf;
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixKind};
 
     use super::{lint_command, LintCommand, OutputFormat};
 
@@ -331,6 +552,17 @@ mod lint_options {
     fn fix() {
         let options = get_lint_options("--fix test.js");
         assert!(options.fix_options.fix);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+    }
+
+    #[test]
+    fn fix_kinds() {
+        let options = get_lint_options("test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::None);
+        let options = get_lint_options("--fix-suggestions test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix | FixKind::Suggestion);
+        let options = get_lint_options("--fix --fix-dangerously test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix | FixKind::DangerousFix);
     }
 
     #[test]
@@ -352,9 +584,17 @@ mod lint_options {
     fn format() {
         let options = get_lint_options("-f json");
         assert_eq!(options.output_options.format, OutputFormat::Json);
+        assert!(!options.output_options.timing);
         assert!(options.paths.is_empty());
     }
 
+    #[test]
+    fn timing() {
+        let options = get_lint_options("--timing -f json");
+        assert!(options.output_options.timing);
+        assert_eq!(options.output_options.format, OutputFormat::Json);
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -369,4 +609,41 @@ mod lint_options {
         let options = get_lint_options("--rules");
         assert!(options.list_rules);
     }
+
+    #[test]
+    fn no_config_lookup() {
+        let options = get_lint_options(".");
+        assert!(!options.basic_options.no_config_lookup);
+        let options = get_lint_options("--no-config-lookup .");
+        assert!(options.basic_options.no_config_lookup);
+    }
+
+    #[test]
+    fn type_aware() {
+        let options = get_lint_options(".");
+        assert!(!options.basic_options.type_aware);
+        let options = get_lint_options("--type-aware .");
+        assert!(options.basic_options.type_aware);
+    }
+
+    #[test]
+    fn explain_rule() {
+        let options = get_lint_options("--explain-rule no-debugger src/index.js");
+        assert_eq!(options.explain_rule, Some("no-debugger".to_string()));
+        assert_eq!(options.paths, vec![PathBuf::from("src/index.js")]);
+    }
+
+    #[test]
+    fn dead_code() {
+        let options = get_lint_options("--dead-code --entry src/index.ts --entry bin/*.ts .");
+        assert!(options.dead_code_options.dead_code);
+        assert_eq!(options.dead_code_options.entry, vec!["src/index.ts", "bin/*.ts"]);
+    }
+
+    #[test]
+    fn print_config() {
+        let options = get_lint_options("-c oxlintrc.json --print-config");
+        assert!(options.print_config);
+        assert_eq!(options.basic_options.config, Some(PathBuf::from("oxlintrc.json")));
+    }
 }
@@ -9,7 +9,7 @@ pub use self::{
     lint::{lint_command, LintCommand, OutputFormat, OutputOptions, WarningOptions},
 };
 
-const VERSION: &str = match option_env!("OXC_VERSION") {
+pub(crate) const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
@@ -1,14 +1,22 @@
 use ignore::gitignore::Gitignore;
-use std::{env, io::BufWriter, time::Instant};
+use std::{
+    env,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
 
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    partial_loader::LINT_PARTIAL_LOADER_EXT, LintCache, LintOptions, LintService,
+    LintServiceOptions, Linter, OxlintConfig, OxlintGlobs,
 };
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
-    command::{LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    command::{LintCommand, OutputFormat, OutputOptions, WarningOptions, VERSION},
+    result::DeadCodeResult,
     walk::{Extensions, Walk},
     CliRunResult, LintResult, MiscOptions, Runner,
 };
@@ -31,6 +39,10 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if self.options.print_config {
+            return Self::print_config(self.options.basic_options.config.as_deref());
+        }
+
         let LintCommand {
             paths,
             filter,
@@ -38,9 +50,12 @@ impl Runner for LintRunner {
             warning_options,
             ignore_options,
             fix_options,
+            cache_options,
             enable_plugins,
             output_options,
             misc_options,
+            explain_rule,
+            dead_code_options,
             ..
         } = self.options;
 
@@ -48,6 +63,49 @@ impl Runner for LintRunner {
         let provided_path_count = paths.len();
         let now = Instant::now();
 
+        let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        let lint_options = LintOptions::default()
+            .with_filter(filter)
+            .with_config_path(basic_options.config)
+            .with_config_lookup_root((!basic_options.no_config_lookup).then(|| cwd.to_path_buf()))
+            .with_fix(fix_options.fix_kind())
+            .with_report_unused_directives(warning_options.report_unused_disable_directives)
+            .with_timing(output_options.timing)
+            .with_type_aware(basic_options.type_aware)
+            .with_react_plugin(enable_plugins.react_plugin)
+            .with_unicorn_plugin(enable_plugins.unicorn_plugin)
+            .with_typescript_plugin(enable_plugins.typescript_plugin)
+            .with_oxc_plugin(enable_plugins.oxc_plugin)
+            .with_import_plugin(if dead_code_options.dead_code {
+                Some(true)
+            } else {
+                enable_plugins.import_plugin
+            })
+            .with_jsdoc_plugin(enable_plugins.jsdoc_plugin)
+            .with_jest_plugin(enable_plugins.jest_plugin)
+            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
+            .with_nextjs_plugin(enable_plugins.nextjs_plugin)
+            .with_react_perf_plugin(enable_plugins.react_perf_plugin)
+            .with_promise_plugin(enable_plugins.promise_plugin)
+            .with_node_plugin(enable_plugins.node_plugin)
+            .with_vitest_plugin(enable_plugins.vitest_plugin);
+
+        let linter = match Linter::from_options(lint_options) {
+            Ok(lint_service) => lint_service,
+            Err(diagnostic) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to parse configuration file.\n{err}"),
+                };
+            }
+        };
+
+        if let Some(rule) = explain_rule {
+            return Self::explain_rule(&linter, &rule, &paths);
+        }
+
         // The ignore crate whitelists explicit paths, but priority
         // should be given to the ignore file. Many users lint
         // automatically and pass a list of changed files explicitly.
@@ -89,34 +147,6 @@ impl Runner for LintRunner {
 
         let number_of_files = paths.len();
 
-        let cwd = std::env::current_dir().unwrap().into_boxed_path();
-        let lint_options = LintOptions::default()
-            .with_filter(filter)
-            .with_config_path(basic_options.config)
-            .with_fix(fix_options.fix)
-            .with_react_plugin(enable_plugins.react_plugin)
-            .with_unicorn_plugin(enable_plugins.unicorn_plugin)
-            .with_typescript_plugin(enable_plugins.typescript_plugin)
-            .with_oxc_plugin(enable_plugins.oxc_plugin)
-            .with_import_plugin(enable_plugins.import_plugin)
-            .with_jsdoc_plugin(enable_plugins.jsdoc_plugin)
-            .with_jest_plugin(enable_plugins.jest_plugin)
-            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
-            .with_nextjs_plugin(enable_plugins.nextjs_plugin)
-            .with_react_perf_plugin(enable_plugins.react_perf_plugin);
-
-        let linter = match Linter::from_options(lint_options) {
-            Ok(lint_service) => lint_service,
-            Err(diagnostic) => {
-                let handler = GraphicalReportHandler::new();
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                return CliRunResult::InvalidOptions {
-                    message: "Failed to parse configuration file.\n{err}".to_string(),
-                };
-            }
-        };
-
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
             if !path.is_file() {
@@ -127,10 +157,30 @@ impl Runner for LintRunner {
             }
         }
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        let cache =
+            cache_options.cache.then(|| LintCache::new(cache_options.cache_location, VERSION));
+        let options = LintServiceOptions { cwd, paths, tsconfig, cache };
+        if dead_code_options.dead_code {
+            return Self::dead_code(
+                linter,
+                options,
+                &dead_code_options.entry,
+                output_options.format,
+                misc_options.silent,
+                now,
+                number_of_files,
+            );
+        }
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
+        let timings = lint_service.linter().timings().cloned();
+        if let (Some(timings), OutputFormat::Json) = (&timings, output_options.format) {
+            let timings = Arc::clone(timings);
+            diagnostic_service.set_json_reporter_with_fields(Box::new(move || {
+                vec![("timing".to_string(), timings.to_json().to_string())]
+            }));
+        }
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -142,20 +192,130 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        if let Some(timings) = timings.filter(|_| output_options.format != OutputFormat::Json) {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            write!(stdout, "\n{timings}").unwrap();
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
             number_of_files,
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
+            number_of_fixes: lint_service.number_of_fixes(),
+            number_of_remaining_fixes: lint_service.number_of_remaining_fixes(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
             print_summary: matches!(output_options.format, OutputFormat::Default),
+            config_warnings: lint_service.linter().config_warnings(),
         })
     }
 }
 
 impl LintRunner {
+    fn explain_rule(linter: &Linter, rule: &str, paths: &[PathBuf]) -> CliRunResult {
+        let [path] = paths else {
+            return CliRunResult::InvalidOptions {
+                message: "`--explain-rule` requires a single file.".to_string(),
+            };
+        };
+        match linter.explain_rule(rule, path) {
+            Ok(explanation) => {
+                let mut stdout = BufWriter::new(std::io::stdout());
+                write!(stdout, "{explanation}").unwrap();
+                CliRunResult::None
+            }
+            Err(diagnostic) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, &diagnostic).unwrap();
+                CliRunResult::InvalidOptions { message: err }
+            }
+        }
+    }
+
+    fn dead_code(
+        linter: Linter,
+        options: LintServiceOptions,
+        entries: &[String],
+        format: OutputFormat,
+        silent: bool,
+        now: Instant,
+        number_of_files: usize,
+    ) -> CliRunResult {
+        if entries.is_empty() {
+            return CliRunResult::InvalidOptions {
+                message: "`--dead-code` requires the entry points given by `--entry`.".to_string(),
+            };
+        }
+        let entries = match OxlintGlobs::new(entries) {
+            Ok(entries) => entries,
+            Err(err) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Invalid `--entry` glob: {err}"),
+                };
+            }
+        };
+
+        let lint_service =
+            LintService::module_graph(linter, LintServiceOptions { cache: None, ..options });
+        // Only the parse errors are reported, they would break the JSON output.
+        let mut diagnostic_service =
+            DiagnosticService::default().with_silent(silent || format == OutputFormat::Json);
+        rayon::spawn({
+            let tx_error = diagnostic_service.sender().clone();
+            let lint_service = lint_service.clone();
+            move || {
+                lint_service.run(&tx_error);
+            }
+        });
+        diagnostic_service.run();
+
+        let report = lint_service.dead_code(&entries);
+        if !silent {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            if format == OutputFormat::Json {
+                writeln!(stdout, "{}", serde_json::to_string_pretty(&report).unwrap()).unwrap();
+            } else {
+                write!(stdout, "{report}").unwrap();
+            }
+            stdout.flush().unwrap();
+        }
+
+        CliRunResult::DeadCodeResult(DeadCodeResult {
+            duration: now.elapsed(),
+            number_of_files,
+            number_of_unused_exports: report.unused_exports.len(),
+            number_of_unreachable_files: report.unreachable_files.len(),
+            print_summary: format == OutputFormat::Default,
+        })
+    }
+
+    fn print_config(config: Option<&Path>) -> CliRunResult {
+        let Some(config) = config else {
+            return CliRunResult::InvalidOptions {
+                message: "`--print-config` requires a configuration file given by `-c`."
+                    .to_string(),
+            };
+        };
+        match OxlintConfig::resolve_json(config) {
+            Ok(json) => {
+                let mut stdout = BufWriter::new(std::io::stdout());
+                writeln!(stdout, "{}", serde_json::to_string_pretty(&json).unwrap()).unwrap();
+                CliRunResult::None
+            }
+            Err(diagnostic) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, &diagnostic).unwrap();
+                CliRunResult::InvalidOptions {
+                    message: format!("Failed to parse configuration file.\n{err}"),
+                }
+            }
+        }
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -179,6 +339,8 @@ impl LintRunner {
 
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use std::{env, fs};
+
     use super::LintRunner;
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
@@ -414,6 +576,86 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn unknown_rules() {
+        let args =
+            &["-c", "fixtures/unknown_rules/eslintrc.json", "fixtures/unknown_rules/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 1);
+        assert_eq!(
+            result.config_warnings,
+            [
+                "fixtures/unknown_rules/eslintrc.json:3:5: Unknown rule `indent` is ignored",
+                "fixtures/unknown_rules/eslintrc.json:4:5: \
+                 Unknown plugin `prettier` in rule `prettier/prettier` is ignored",
+            ]
+        );
+    }
+
+    #[test]
+    fn eslintrc_extends() {
+        let args = &["-c", "fixtures/extends/eslintrc.json", "fixtures/extends/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 2);
+    }
+
+    #[test]
+    fn nested_config() {
+        let args = &["fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 5);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn nested_config_import_plugin() {
+        // The import plugin is only enabled by `sub/.oxlintrc.json`.
+        let args = &["fixtures/nested_import"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn nested_config_no_lookup() {
+        let args = &["--no-config-lookup", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn explain_rule() {
+        let args = &["--explain-rule", "no-debugger", "fixtures/linter/debugger.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::None));
+
+        let message = test_invalid_options(&["--explain-rule", "no-such-rule", "fixtures/linter"]);
+        assert!(message.contains("Rule `no-such-rule` not found."));
+        let message = test_invalid_options(&["--explain-rule", "no-debugger"]);
+        assert!(message.contains("requires a single file"));
+    }
+
+    #[test]
+    fn print_config_without_config() {
+        assert!(test_invalid_options(&["--print-config"]).contains("requires a configuration file"));
+    }
+
+    #[test]
+    fn timing() {
+        let args = &["--timing", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn typescript_eslint() {
         let args = &[
@@ -441,6 +683,313 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn plugins() {
+        let args = &["-c", "fixtures/plugins/eslintrc.json", "fixtures/plugins/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 2);
+    }
+
+    #[test]
+    fn plugins_disabled_by_flag() {
+        let args = &[
+            "-c",
+            "fixtures/plugins/eslintrc.json",
+            "--disable-unicorn-plugin",
+            "fixtures/plugins/test.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn plugins_of_config_disabled_by_flag() {
+        let args = &[
+            "-c",
+            "fixtures/plugins/eslintrc.json",
+            "--disable-jest-plugin",
+            "fixtures/plugins/test.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn plugins_left_out_of_config_enabled_by_flag() {
+        let args = &[
+            "-c",
+            "fixtures/plugins/eslintrc.json",
+            "-D",
+            "const-comparisons",
+            "fixtures/plugins/oxc.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args = &[
+            "-c",
+            "fixtures/plugins/eslintrc.json",
+            "--oxc-plugin",
+            "-D",
+            "const-comparisons",
+            "fixtures/plugins/oxc.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn categories() {
+        let args = &["-c", "fixtures/categories/eslintrc.json", "fixtures/categories/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn cache() {
+        let cache_location = env::temp_dir().join("oxlint_test_cache.json");
+        let _ = fs::remove_file(&cache_location);
+        let cache_location = cache_location.to_string_lossy().to_string();
+        let args = &["--cache", "--cache-location", &cache_location, "fixtures/linter"];
+        for _ in 0..2 {
+            let result = test(args);
+            assert_eq!(result.number_of_files, 2);
+            assert_eq!(result.number_of_warnings, 2);
+            assert_eq!(result.number_of_errors, 0);
+        }
+        assert!(fs::read_to_string(&cache_location).unwrap().contains("debugger.js"));
+        fs::remove_file(&cache_location).unwrap();
+    }
+
+    #[test]
+    fn cache_type_aware() {
+        let cache_location = env::temp_dir().join("oxlint_test_cache_type_aware.json");
+        let _ = fs::remove_file(&cache_location);
+        let cache_location = cache_location.to_string_lossy().to_string();
+        let args = &[
+            "--cache",
+            "--cache-location",
+            &cache_location,
+            "-W",
+            "correctness",
+            "-W",
+            "no-misused-promises",
+            "fixtures/type_aware/promises.ts",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &[
+            "--cache",
+            "--cache-location",
+            &cache_location,
+            "--type-aware",
+            "-W",
+            "correctness",
+            "-W",
+            "no-misused-promises",
+            "fixtures/type_aware/promises.ts",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 3);
+        fs::remove_file(&cache_location).unwrap();
+    }
+
+    #[test]
+    fn cache_report_unused_disable_directives() {
+        let cache_location = env::temp_dir().join("oxlint_test_cache_unused_directives.json");
+        let _ = fs::remove_file(&cache_location);
+        let cache_location = cache_location.to_string_lossy().to_string();
+        let args = &[
+            "--cache",
+            "--cache-location",
+            &cache_location,
+            "fixtures/disable_directives/unused.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &[
+            "--cache",
+            "--cache-location",
+            &cache_location,
+            "--report-unused-disable-directives",
+            "fixtures/disable_directives/unused.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        fs::remove_file(&cache_location).unwrap();
+    }
+
+    #[test]
+    fn cache_unresolved() {
+        let dir = env::temp_dir().join("oxlint_test_cache_unresolved");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("index.js"), "import missing from './missing';\nexport { missing };\n")
+            .unwrap();
+        let cache_location = dir.join("cache.json").to_string_lossy().to_string();
+        let file = dir.join("index.js").to_string_lossy().to_string();
+        let args = &[
+            "--cache",
+            "--cache-location",
+            &cache_location,
+            "--import-plugin",
+            "-A",
+            "all",
+            "-W",
+            "no-unresolved",
+            &file,
+        ];
+        for _ in 0..2 {
+            assert_eq!(test(args).number_of_warnings, 1);
+        }
+        fs::write(dir.join("missing.js"), "export default 1;\n").unwrap();
+        assert_eq!(test(args).number_of_warnings, 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fix_nested() {
+        let dir = env::temp_dir().join("oxlint_test_fix_nested");
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("nested.js");
+        fs::copy("fixtures/fix/nested.js", &file).unwrap();
+        let file_arg = file.to_string_lossy().to_string();
+        let args = &[
+            "--fix-dangerously",
+            "-A",
+            "all",
+            "-D",
+            "no-unnecessary-await",
+            "-D",
+            "no-useless-spread",
+            &file_arg,
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_fixes, 6);
+        assert_eq!(result.number_of_remaining_fixes, 0);
+        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "async function f() {\n  1;\n}\n\nconst a = [b];\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fix_safe_only() {
+        let dir = env::temp_dir().join("oxlint_test_fix_safe_only");
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("nested.js");
+        fs::copy("fixtures/fix/nested.js", &file).unwrap();
+        let file_arg = file.to_string_lossy().to_string();
+        let args = &[
+            "--fix",
+            "-A",
+            "all",
+            "-D",
+            "no-unnecessary-await",
+            "-D",
+            "no-useless-spread",
+            &file_arg,
+        ];
+        let result = test(args);
+        // `no-useless-spread` only provides dangerous fixes
+        assert_eq!(result.number_of_fixes, 3);
+        assert_eq!(result.number_of_remaining_fixes, 0);
+        assert_eq!(result.number_of_errors, 3);
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "async function f() {\n  1;\n}\n\nconst a = [...[...[...[b]]]];\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let args = &["fixtures/disable_directives/unused.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &["--report-unused-disable-directives", "fixtures/disable_directives/unused.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args = &[
+            "-c",
+            "fixtures/disable_directives/config.json",
+            "fixtures/disable_directives/unused.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn fix_unused_disable_directives() {
+        let dir = env::temp_dir().join("oxlint_test_fix_unused_disable_directives");
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("unused.js");
+        fs::copy("fixtures/disable_directives/unused.js", &file).unwrap();
+        let file_arg = file.to_string_lossy().to_string();
+        let args = &["--fix", "--report-unused-disable-directives", &file_arg];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        // `no-console` is not enabled and `prettier/prettier` is not an oxlint rule
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "// eslint-disable-next-line no-debugger\ndebugger;\n\
+             // eslint-disable-next-line no-console, no-debugger\ndebugger;\n\
+             foo();\n\
+             // eslint-disable-next-line prettier/prettier\nfoo();\n\
+             foo();\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn type_aware() {
+        let args =
+            &["-W", "correctness", "-W", "no-misused-promises", "fixtures/type_aware/promises.ts"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &[
+            "--type-aware",
+            "-W",
+            "correctness",
+            "-W",
+            "no-misused-promises",
+            "fixtures/type_aware/promises.ts",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args = &[
+            "-c",
+            "fixtures/type_aware/config.json",
+            "-W",
+            "correctness",
+            "-W",
+            "no-misused-promises",
+            "fixtures/type_aware/promises.ts",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 3);
+    }
+
     #[test]
     fn lint_vue_file() {
         let args = &["fixtures/vue/debugger.vue"];
@@ -486,4 +1035,50 @@ mod test {
         assert!(test_invalid_options(&["--tsconfig", "oxc/tsconfig.json"])
             .contains("oxc/tsconfig.json\" does not exist, Please provide a valid tsconfig file."));
     }
+
+    #[test]
+    fn dead_code() {
+        let args = &["--silent", "--dead-code", "--entry", "index.ts", "fixtures/dead_code"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        match LintRunner::new(options).run() {
+            CliRunResult::DeadCodeResult(result) => {
+                assert_eq!(result.number_of_files, 8);
+                assert_eq!(result.number_of_unused_exports, 4);
+                // `orphan.ts`, but not `lazy.ts` only loaded by `import()`
+                assert_eq!(result.number_of_unreachable_files, 1);
+            }
+            other => panic!("{other:?}"),
+        }
+
+        let message = test_invalid_options(&["--dead-code", "fixtures/dead_code"]);
+        assert!(message.contains("requires the entry points"));
+    }
+
+    #[test]
+    fn test_tsconfig_paths() {
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-W",
+            "no-unresolved",
+            "fixtures/tsconfig_paths/index.ts",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-W",
+            "no-unresolved",
+            "--tsconfig",
+            "fixtures/tsconfig_paths/tsconfig.json",
+            "fixtures/tsconfig_paths/index.ts",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
 }
@@ -11,6 +11,7 @@ pub enum CliRunResult {
     PathNotFound { paths: Vec<PathBuf> },
     LintResult(LintResult),
     FormatResult(FormatResult),
+    DeadCodeResult(DeadCodeResult),
     TypeCheckResult { duration: Duration, number_of_diagnostics: usize },
 }
 
@@ -21,9 +22,24 @@ pub struct LintResult {
     pub number_of_files: usize,
     pub number_of_warnings: usize,
     pub number_of_errors: usize,
+    /// Number of fixes applied by `--fix`.
+    pub number_of_fixes: usize,
+    /// Number of fixes left by `--fix`, because they overlap with other fixes.
+    pub number_of_remaining_fixes: usize,
     pub max_warnings_exceeded: bool,
     pub deny_warnings: bool,
     pub print_summary: bool,
+    /// Unknown plugins and rules of the configuration files, see `Linter::config_warnings`.
+    pub config_warnings: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct DeadCodeResult {
+    pub duration: Duration,
+    pub number_of_files: usize,
+    pub number_of_unused_exports: usize,
+    pub number_of_unreachable_files: usize,
+    pub print_summary: bool,
 }
 
 #[derive(Debug)]
@@ -51,9 +67,12 @@ impl Termination for CliRunResult {
                 number_of_files,
                 number_of_warnings,
                 number_of_errors,
+                number_of_fixes,
+                number_of_remaining_fixes,
                 max_warnings_exceeded,
                 deny_warnings,
                 print_summary,
+                config_warnings,
             }) => {
                 if print_summary {
                     let threads = rayon::current_num_threads();
@@ -63,12 +82,24 @@ impl Termination for CliRunResult {
                         println!();
                     }
 
+                    for warning in &config_warnings {
+                        println!("Warning: {warning}");
+                    }
+
                     let time = Self::get_execution_time(&duration);
                     let s = if number_of_files == 1 { "" } else { "s" };
                     println!(
                         "Finished in {time} on {number_of_files} file{s} with {number_of_rules} rules using {threads} threads."
                     );
 
+                    if number_of_fixes > 0 || number_of_remaining_fixes > 0 {
+                        println!(
+                            "Applied {number_of_fixes} fix{}, {number_of_remaining_fixes} fix{} remaining.",
+                            if number_of_fixes == 1 { "" } else { "es" },
+                            if number_of_remaining_fixes == 1 { "" } else { "es" }
+                        );
+                    }
+
                     if max_warnings_exceeded {
                         println!(
                             "Exceeded maximum number of warnings. Found {number_of_warnings}."
@@ -96,6 +127,31 @@ impl Termination for CliRunResult {
                 );
                 ExitCode::from(0)
             }
+            Self::DeadCodeResult(DeadCodeResult {
+                duration,
+                number_of_files,
+                number_of_unused_exports,
+                number_of_unreachable_files,
+                print_summary,
+            }) => {
+                if print_summary {
+                    let threads = rayon::current_num_threads();
+                    if number_of_unused_exports + number_of_unreachable_files > 0 {
+                        println!();
+                    }
+                    let time = Self::get_execution_time(&duration);
+                    let s = if number_of_files == 1 { "" } else { "s" };
+                    println!(
+                        "Finished in {time} on {number_of_files} file{s} using {threads} threads."
+                    );
+                    println!(
+                        "Found {number_of_unused_exports} unused export{} and {number_of_unreachable_files} unreachable file{}.",
+                        if number_of_unused_exports == 1 { "" } else { "s" },
+                        if number_of_unreachable_files == 1 { "" } else { "s" }
+                    );
+                }
+                ExitCode::from(u8::from(number_of_unused_exports + number_of_unreachable_files > 0))
+            }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
                 let time = Self::get_execution_time(&duration);
                 println!("Finished in {time}.");
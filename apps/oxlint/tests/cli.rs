@@ -0,0 +1,100 @@
+use std::{env, fs, path::Path, process::Command};
+
+/// Run `oxlint` in `dir` in a separate process, unlike the tests in `src` which share
+/// the working directory, and the `package.json` files read by the rules.
+fn oxlint(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_oxlint"))
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(dir))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_resolve_from_subdirectory() {
+    // `pkg` is installed in `fixtures/resolve/node_modules`, above the working directory.
+    let args = &["--import-plugin", "-A", "all", "-W", "no-unresolved"];
+    for path in ["index.js", "."] {
+        let stdout =
+            oxlint(Path::new("fixtures/resolve/src"), &[args.as_slice(), &[path]].concat());
+        assert!(stdout.contains("Found 1 warning and 0 errors."), "{stdout}");
+        assert!(stdout.contains("'./missing'"), "{stdout}");
+    }
+}
+
+#[test]
+fn test_cache_package_json() {
+    let dir = env::temp_dir().join("oxlint_test_cache_package_json");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::create_dir_all(dir.join("node_modules/foo")).unwrap();
+    fs::write(dir.join("node_modules/foo/index.js"), "export default 1;\n").unwrap();
+    fs::write(dir.join("src/index.js"), "import foo from 'foo';\nexport { foo };\n").unwrap();
+    fs::write(dir.join("package.json"), r#"{ "dependencies": {} }"#).unwrap();
+    let args = &[
+        "--cache",
+        "--cache-location",
+        "cache.json",
+        "--import-plugin",
+        "-A",
+        "all",
+        "-W",
+        "no-extraneous-dependencies",
+        "src/index.js",
+    ];
+    for _ in 0..2 {
+        assert!(oxlint(&dir, args).contains("Found 1 warning and 0 errors."));
+    }
+    // changed `package.json`
+    fs::write(dir.join("package.json"), r#"{ "dependencies": { "foo": "1.0.0" } }"#).unwrap();
+    assert!(oxlint(&dir, args).contains("Found 0 warnings and 0 errors."));
+    // created `package.json`, closer to the file
+    fs::write(dir.join("src/package.json"), r#"{ "dependencies": {} }"#).unwrap();
+    assert!(oxlint(&dir, args).contains("Found 1 warning and 0 errors."));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_dead_code_current_directory() {
+    for args in [["--entry", "index.ts", "."], ["--entry", "./index.ts", "./"]] {
+        let args = [["--dead-code", "--format", "json"].as_slice(), &args].concat();
+        let stdout = oxlint(Path::new("fixtures/dead_code"), &args);
+        let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        // `lazy.ts` is only loaded by `import()`, which is followed.
+        assert_eq!(report["unreachableFiles"], serde_json::json!(["orphan.ts"]));
+        let paths = report["unusedExports"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|export| export["path"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["deep.ts", "lib.ts", "lib.ts", "star.ts"]);
+    }
+}
+
+#[test]
+fn test_cache_engines() {
+    let dir = env::temp_dir().join("oxlint_test_cache_engines");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("index.js"), "var a = b ?? c;\n").unwrap();
+    fs::write(dir.join("package.json"), r#"{ "engines": { "node": ">=12" } }"#).unwrap();
+    let args = &[
+        "--cache",
+        "--cache-location",
+        "cache.json",
+        "--node-plugin",
+        "-A",
+        "all",
+        "-W",
+        "no-unsupported-features-es-syntax",
+        "index.js",
+    ];
+    for _ in 0..2 {
+        assert!(oxlint(&dir, args).contains("Found 1 warning and 0 errors."));
+    }
+    fs::write(dir.join("package.json"), r#"{ "engines": { "node": ">=18" } }"#).unwrap();
+    assert!(oxlint(&dir, args).contains("Found 0 warnings and 0 errors."));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -5,16 +5,47 @@ use crate::ast::*;
 
 macro_rules! ast_kinds {
     { $($ident:ident($type:ty),)* } => (
-        #[derive(Debug, Clone, Copy)]
+        /// Type of an [`AstKind`], without the node
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum AstType {
             $($ident,)*
         }
 
+        impl AstType {
+            /// Number of AST types, `ty as usize` is below it.
+            pub const COUNT: usize = [$(stringify!($ident)),*].len();
+
+            /// Name of the [`AstKind`] variant of this type, e.g. `CallExpression`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$ident => stringify!($ident),)*
+                }
+            }
+        }
+
         /// Untyped AST Node Kind
         #[derive(Debug, Clone, Copy)]
         pub enum AstKind<'a> {
             $($ident($type),)*
         }
+
+        impl<'a> AstKind<'a> {
+            pub fn ty(&self) -> AstType {
+                match self {
+                    $(Self::$ident(_) => AstType::$ident,)*
+                }
+            }
+        }
+
+        /// Serialize the node, as it is serialized inside of the [`Program`](crate::ast::Program).
+        #[cfg(feature = "serialize")]
+        impl<'a> serde::Serialize for AstKind<'a> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    $(Self::$ident(node) => node.serialize(serializer),)*
+                }
+            }
+        }
     )
 }
 
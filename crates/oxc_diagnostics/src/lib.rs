@@ -14,6 +14,7 @@ use std::{
 pub use crate::{
     graphic_reporter::GraphicalReportHandler,
     graphical_theme::GraphicalTheme,
+    reporter::JsonFields,
     service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
 };
 
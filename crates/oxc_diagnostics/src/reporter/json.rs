@@ -4,16 +4,27 @@ use crate::Error;
 
 use super::DiagnosticReporter;
 
+/// Fields printed along with the diagnostics, as `(name, JSON text)` pairs.
+pub type JsonFields = Box<dyn FnOnce() -> Vec<(String, String)>>;
+
 #[derive(Default)]
 pub struct JsonReporter {
     diagnostics: Vec<Error>,
+    /// Print `{ "diagnostics": [...], ...fields }` instead of the array of diagnostics.
+    fields: Option<JsonFields>,
+}
+
+impl JsonReporter {
+    pub fn with_fields(fields: JsonFields) -> Self {
+        Self { diagnostics: vec![], fields: Some(fields) }
+    }
 }
 
 impl DiagnosticReporter for JsonReporter {
     // NOTE: this output does not conform to eslint json format yet
     // https://eslint.org/docs/latest/use/formatters/#json
     fn finish(&mut self) {
-        format_json(&mut self.diagnostics);
+        format_json(&mut self.diagnostics, self.fields.take());
     }
 
     fn render_diagnostics(&mut self, _s: &[u8]) {}
@@ -26,7 +37,7 @@ impl DiagnosticReporter for JsonReporter {
 
 /// <https://github.com/fregante/eslint-formatters/tree/main/packages/eslint-formatter-json>
 #[allow(clippy::print_stdout)]
-fn format_json(diagnostics: &mut Vec<Error>) {
+fn format_json(diagnostics: &mut Vec<Error>, fields: Option<JsonFields>) {
     let handler = JSONReportHandler::new();
     let messages = diagnostics
         .drain(..)
@@ -37,5 +48,14 @@ fn format_json(diagnostics: &mut Vec<Error>) {
         })
         .collect::<Vec<_>>()
         .join(",\n");
-    println!("[\n{messages}\n]");
+    let Some(fields) = fields else {
+        println!("[\n{messages}\n]");
+        return;
+    };
+    let fields = fields()
+        .into_iter()
+        .map(|(name, value)| format!("\"{name}\": {value}"))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    println!("{{\n\"diagnostics\": [\n{messages}\n],\n{fields}\n}}");
 }
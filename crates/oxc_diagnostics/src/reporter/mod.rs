@@ -5,8 +5,11 @@ mod json;
 mod unix;
 
 pub use self::{
-    checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    checkstyle::CheckstyleReporter,
+    github::GithubReporter,
+    graphical::GraphicalReporter,
+    json::{JsonFields, JsonReporter},
+    unix::UnixReporter,
 };
 
 use std::io::{BufWriter, Stdout};
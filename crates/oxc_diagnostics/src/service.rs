@@ -6,8 +6,8 @@ use std::{
 
 use crate::{
     reporter::{
-        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonFields,
+        JsonReporter, UnixReporter,
     },
     Error, NamedSource, OxcDiagnostic, Severity,
 };
@@ -60,6 +60,12 @@ impl DiagnosticService {
         self.reporter = Box::<JsonReporter>::default();
     }
 
+    /// Print the diagnostics as JSON in an object `{ "diagnostics": [...], ...fields }`.
+    /// `fields` is called once all diagnostics are received.
+    pub fn set_json_reporter_with_fields(&mut self, fields: JsonFields) {
+        self.reporter = Box::new(JsonReporter::with_fields(fields));
+    }
+
     pub fn set_unix_reporter(&mut self) {
         self.reporter = Box::<UnixReporter>::default();
     }
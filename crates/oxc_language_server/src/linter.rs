@@ -13,14 +13,14 @@ use oxc_linter::{
         AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
         LINT_PARTIAL_LOADER_EXT,
     },
-    LintContext, Linter,
+    FixKind, LintContext, Linter,
 };
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use ropey::Rope;
 use tower_lsp::lsp_types::{
-    self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
+    self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, TextEdit, Url,
 };
 
 #[derive(Debug)]
@@ -148,8 +148,9 @@ struct ErrorReport {
 
 #[derive(Debug, Clone)]
 pub struct FixedContent {
-    pub code: String,
-    pub range: Range,
+    /// Edits applied all together.
+    pub edits: Vec<TextEdit>,
+    pub kind: FixKind,
 }
 
 pub struct IsolatedLintHandler {
@@ -313,20 +314,28 @@ impl IsolatedLintHandler {
             let reports = result
                 .into_iter()
                 .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(
-                                f.span.start as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                            end: offset_to_position(
-                                f.span.end as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                        },
+                    let fix_kind = msg.fix_kind;
+                    let fixed_content = msg.fix.map(|fix| FixedContent {
+                        edits: fix
+                            .edits()
+                            .iter()
+                            .map(|f| TextEdit {
+                                new_text: f.content.to_string(),
+                                range: Range {
+                                    start: offset_to_position(
+                                        f.span.start as usize + start,
+                                        javascript_source_text,
+                                    )
+                                    .unwrap_or_default(),
+                                    end: offset_to_position(
+                                        f.span.end as usize + start,
+                                        javascript_source_text,
+                                    )
+                                    .unwrap_or_default(),
+                                },
+                            })
+                            .collect(),
+                        kind: fix_kind,
                     });
 
                     ErrorReport { error: Error::from(msg.error), fixed_content }
@@ -387,7 +396,7 @@ pub struct ServerLinter {
 
 impl ServerLinter {
     pub fn new() -> Self {
-        let linter = Linter::default().with_fix(true);
+        let linter = Linter::default().with_fix(FixKind::all());
         Self { linter: Arc::new(linter) }
     }
 
@@ -3,8 +3,8 @@ mod linter;
 use crate::linter::{DiagnosticReport, ServerLinter};
 use globset::Glob;
 use ignore::gitignore::Gitignore;
-use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use log::{debug, error, info, warn};
+use oxc_linter::{FixKind, LintOptions, Linter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -21,7 +21,7 @@ use tower_lsp::lsp_types::{
     DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
     DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
     InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    TextDocumentSyncKind, Url, WorkDoneProgressOptions, WorkspaceEdit,
     WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
@@ -235,37 +235,48 @@ impl LanguageServer for Backend {
         let uri = params.text_document.uri;
 
         if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
+            // One code action per fix, the suggestions and dangerous fixes are never preferred.
+            let code_actions = value
                 .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
-                        || "Fix this problem".into(),
-                        |s| format!("Fix this {s} problem"),
-                    );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
+                .filter(|r| r.diagnostic.range == params.range)
+                .filter_map(|report| {
+                    let fixed_content = report.fixed_content.clone()?;
+                    let problem = report.diagnostic.message.split(':').next();
+                    let title = if fixed_content.kind == FixKind::Suggestion {
+                        problem.map_or_else(
+                            || "Apply the suggestion for this problem".into(),
+                            |s| format!("Apply the suggestion for this {s} problem"),
+                        )
+                    } else if fixed_content.kind == FixKind::DangerousFix {
+                        problem.map_or_else(
+                            || "Fix this problem (may change behavior)".into(),
+                            |s| format!("Fix this {s} problem (may change behavior)"),
+                        )
+                    } else {
+                        problem.map_or_else(
+                            || "Fix this problem".into(),
+                            |s| format!("Fix this {s} problem"),
+                        )
+                    };
+
+                    Some(CodeActionOrCommand::CodeAction(CodeAction {
+                        title,
+                        kind: Some(CodeActionKind::QUICKFIX),
+                        is_preferred: Some(fixed_content.kind == FixKind::SafeFix),
+                        edit: Some(WorkspaceEdit {
+                            changes: Some(HashMap::from([(uri.clone(), fixed_content.edits)])),
+                            ..WorkspaceEdit::default()
+                        }),
+                        disabled: None,
+                        data: None,
+                        diagnostics: None,
+                        command: None,
+                    }))
+                })
+                .collect::<Vec<_>>();
+
+            if !code_actions.is_empty() {
+                return Ok(Some(code_actions));
             }
         }
 
@@ -341,13 +352,19 @@ impl Backend {
             config_path = Some(config);
         }
         if let Some(config_path) = config_path {
-            let mut linter = self.server_linter.write().await;
-            *linter = ServerLinter::new_with_linter(
-                Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
-                )
-                .expect("should have initialized linter with new options"),
-            );
+            let linter = match Linter::from_options(
+                LintOptions::default().with_fix(FixKind::all()).with_config_path(Some(config_path)),
+            ) {
+                Ok(linter) => linter,
+                Err(err) => {
+                    error!("Failed to parse the configuration file: {err}");
+                    return;
+                }
+            };
+            for warning in linter.config_warnings() {
+                warn!("{warning}");
+            }
+            *self.server_linter.write().await = ServerLinter::new_with_linter(linter);
         }
     }
 
@@ -0,0 +1,56 @@
+module.exports = {
+  meta: { name: 'eslint-plugin-local' },
+  rules: {
+    'no-foo': {
+      meta: {
+        fixable: 'code',
+        messages: { foo: 'Unexpected identifier `{{ name }}`.' },
+      },
+      create(context) {
+        return {
+          Identifier(node) {
+            if (node.name === 'foo') {
+              context.report({
+                node,
+                messageId: 'foo',
+                data: { name: node.name },
+                fix: (fixer) => fixer.replaceText(node, 'bar'),
+              });
+            }
+          },
+        };
+      },
+    },
+    'max-literals': {
+      create(context) {
+        const max = context.options[0]?.max ?? 1;
+        let count = 0;
+        return {
+          Literal() {
+            count += 1;
+          },
+          'Program:exit'(node) {
+            if (count > max) {
+              context.report({ node, message: `Too many literals (${count}).` });
+            }
+          },
+        };
+      },
+    },
+    'bad-ranges': {
+      meta: { fixable: 'code' },
+      create(context) {
+        return {
+          Program(node) {
+            context.report({
+              node,
+              message: 'Fix past the end.',
+              fix: (fixer) => fixer.replaceTextRange([0, 1000], ''),
+            });
+            context.report({ node: { range: [2, 1000] }, message: 'Report past the end.' });
+          },
+        };
+      },
+    },
+  },
+};
@@ -0,0 +1,319 @@
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::{DashMap, DashSet};
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+
+/// Rules reporting a module depending on the modules importing it,
+/// their results are invalidated by a change to any of the linted files.
+pub const DEPENDENTS_RULES: [&str; 1] = ["no-unused-modules"];
+
+/// Lint results of the previous runs, stored in a file.
+///
+/// The results of a file are reused when the file, the version of oxlint,
+/// and the rules and configuration used to lint the file are unchanged.
+/// With the import plugin enabled, the modules imported by the file directly or indirectly must be
+/// unchanged too, and the module specifiers which could not be resolved must still not resolve.
+/// The files read by the rules, see [`crate::rule::Rule::read_files`], must be unchanged,
+/// and all the linted files must be unchanged when one of [`DEPENDENTS_RULES`] is enabled.
+pub struct LintCache {
+    location: PathBuf,
+    version: String,
+    /// Entries read from `location`.
+    previous: FxHashMap<PathBuf, CacheEntry>,
+    /// Entries of the files linted by this run.
+    current: DashMap<PathBuf, CacheEntry>,
+    /// Content hashes of the files read by this run, `None` if a file cannot be read.
+    content_hashes: DashMap<PathBuf, Option<u64>>,
+    /// Files whose results have been reported by this run, from the cache or by linting them.
+    reported: DashSet<PathBuf>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    entries: FxHashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub content_hash: u64,
+    /// See `ResolvedConfig::hash`.
+    pub config_hash: u64,
+    /// Hash of all the linted files, when one of [`DEPENDENTS_RULES`] is enabled.
+    pub project_hash: Option<u64>,
+    /// Files imported by the file directly or indirectly, and files read by the rules,
+    /// with their content hash, `None` for a file which does not exist.
+    pub dependencies: Vec<(PathBuf, Option<u64>)>,
+    /// Module specifiers which cannot be resolved, with the path of the module importing them.
+    /// The results are invalidated when one of them can be resolved, e.g. once the file is created.
+    pub unresolved: Vec<(PathBuf, String)>,
+    /// Diagnostics of each source of the file, see [`crate::partial_loader::PartialLoader`].
+    pub diagnostics: Vec<Vec<CachedDiagnostic>>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CachedDiagnostic {
+    message: String,
+    help: Option<String>,
+    severity: CachedSeverity,
+    labels: Option<Vec<CachedLabel>>,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+impl LintCache {
+    /// Read the cache at `location`, discarding it when it is written by another `version` of oxlint.
+    pub fn new(location: PathBuf, version: &str) -> Self {
+        let previous = fs::read_to_string(&location)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|file| file.version == version)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            location,
+            version: version.to_string(),
+            previous,
+            current: DashMap::default(),
+            content_hashes: DashMap::default(),
+            reported: DashSet::default(),
+        }
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Write the entries of this run, and the previous entries of the files not linted by this run.
+    ///
+    /// # Errors
+    ///
+    /// * The cache file cannot be written
+    pub fn save(&self) -> io::Result<()> {
+        let mut entries = self.previous.clone();
+        entries
+            .extend(self.current.iter().map(|entry| (entry.key().clone(), entry.value().clone())));
+        let file = CacheFile { version: self.version.clone(), entries };
+        fs::write(&self.location, serde_json::to_string(&file)?)
+    }
+
+    /// Record the content hash of the file at `path`, read by the caller.
+    pub(crate) fn insert_content(&self, path: &Path, source_text: &str) -> u64 {
+        let hash = hash_bytes(source_text.as_bytes());
+        self.content_hashes.insert(path.to_path_buf(), Some(hash));
+        hash
+    }
+
+    /// Content hash of the file at `path`, the file is read once per run.
+    pub(crate) fn content_hash(&self, path: &Path) -> Option<u64> {
+        if let Some(hash) = self.content_hashes.get(path) {
+            return *hash;
+        }
+        let hash = fs::read(path).ok().map(|bytes| hash_bytes(&bytes));
+        self.content_hashes.insert(path.to_path_buf(), hash);
+        hash
+    }
+
+    /// The previous entry of `path`, if it is still valid.
+    /// `is_resolved` tells whether a module specifier can be resolved from the module at a path.
+    pub(crate) fn get(
+        &self,
+        path: &Path,
+        content_hash: u64,
+        config_hash: u64,
+        project_hash: impl FnOnce() -> u64,
+        is_resolved: impl Fn(&Path, &str) -> bool,
+    ) -> Option<&CacheEntry> {
+        let entry = self.previous.get(path)?;
+        let is_valid = entry.content_hash == content_hash
+            && entry.config_hash == config_hash
+            && entry
+                .dependencies
+                .iter()
+                .all(|(dependency, hash)| self.content_hash(dependency) == *hash)
+            && !entry.unresolved.iter().any(|(path, specifier)| is_resolved(path, specifier))
+            && entry.project_hash.map_or(true, |hash| hash == project_hash());
+        is_valid.then_some(entry)
+    }
+
+    pub(crate) fn insert(&self, path: &Path, entry: CacheEntry) {
+        self.current.insert(path.to_path_buf(), entry);
+    }
+
+    /// Returns `false` if the results of `path` have already been reported by this run.
+    pub(crate) fn claim(&self, path: &Path) -> bool {
+        self.reported.insert(path.to_path_buf())
+    }
+}
+
+pub(crate) fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl From<&OxcDiagnostic> for CachedDiagnostic {
+    fn from(diagnostic: &OxcDiagnostic) -> Self {
+        let severity = match diagnostic.severity {
+            Severity::Advice => CachedSeverity::Advice,
+            Severity::Warning => CachedSeverity::Warning,
+            Severity::Error => CachedSeverity::Error,
+        };
+        let labels = diagnostic.labels.as_ref().map(|labels| {
+            labels
+                .iter()
+                .map(|label| CachedLabel {
+                    label: label.label().map(ToString::to_string),
+                    offset: label.offset(),
+                    len: label.len(),
+                    primary: label.primary(),
+                })
+                .collect()
+        });
+        Self {
+            message: diagnostic.message.clone(),
+            help: diagnostic.help.clone(),
+            severity,
+            labels,
+        }
+    }
+}
+
+impl From<&CachedDiagnostic> for OxcDiagnostic {
+    fn from(cached: &CachedDiagnostic) -> Self {
+        let severity = match cached.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        };
+        let mut diagnostic = Self::error(cached.message.clone()).with_severity(severity);
+        if let Some(help) = &cached.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        if let Some(labels) = &cached.labels {
+            diagnostic = diagnostic.with_labels(labels.iter().map(|label| {
+                let span = (label.offset, label.len);
+                if label.primary {
+                    LabeledSpan::new_primary_with_span(label.label.clone(), span)
+                } else {
+                    LabeledSpan::new_with_span(label.label.clone(), span)
+                }
+            }));
+        }
+        diagnostic
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+    use oxc_span::Span;
+
+    use super::{CacheEntry, CachedDiagnostic, LintCache};
+
+    #[test]
+    fn test_diagnostic_round_trip() {
+        let diagnostic = OxcDiagnostic::warn("message").with_help("help").with_labels([
+            Span::new(1, 3).into(),
+            LabeledSpan::new_primary_with_span(Some("label".into()), Span::new(5, 8)),
+        ]);
+        let cached = CachedDiagnostic::from(&diagnostic);
+        let restored = OxcDiagnostic::from(&cached);
+        assert_eq!(restored.message, "message");
+        assert_eq!(restored.help.as_deref(), Some("help"));
+        assert_eq!(restored.severity, Severity::Warning);
+        assert_eq!(restored.labels, diagnostic.labels);
+    }
+
+    #[test]
+    fn test_save_and_get() {
+        let location = env::temp_dir().join("oxc_linter_test_save_and_get.json");
+        let path = Path::new("index.js");
+        let cache = LintCache::new(location.clone(), "1.0.0");
+        let content_hash = cache.insert_content(path, "debugger;");
+        cache.insert(
+            path,
+            CacheEntry {
+                content_hash,
+                config_hash: 1,
+                project_hash: None,
+                dependencies: vec![],
+                unresolved: vec![],
+                diagnostics: vec![vec![]],
+            },
+        );
+        cache.save().unwrap();
+
+        let cache = LintCache::new(location.clone(), "1.0.0");
+        assert!(cache.get(path, content_hash, 1, || 0, |_, _| false).is_some());
+        // changed file
+        assert!(cache.get(path, content_hash + 1, 1, || 0, |_, _| false).is_none());
+        // changed configuration
+        assert!(cache.get(path, content_hash, 2, || 0, |_, _| false).is_none());
+        // another version
+        let cache = LintCache::new(location.clone(), "1.0.1");
+        assert!(cache.get(path, content_hash, 1, || 0, |_, _| false).is_none());
+
+        fs::remove_file(location).unwrap();
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let location = env::temp_dir().join("oxc_linter_test_dependencies.json");
+        let path = Path::new("index.js");
+        let dependency = env::current_dir().unwrap().join("fixtures/import/bar.js");
+        let cache = LintCache::new(location, "1.0.0");
+        let dependency_hash = cache.content_hash(&dependency);
+        assert!(dependency_hash.is_some());
+        let missing = env::current_dir().unwrap().join("fixtures/import/missing.js");
+        let entry = |hash: Option<u64>, missing_hash| CacheEntry {
+            content_hash: 0,
+            config_hash: 0,
+            project_hash: Some(42),
+            dependencies: vec![(dependency.clone(), hash), (missing.clone(), missing_hash)],
+            unresolved: vec![(path.to_path_buf(), "./missing".to_string())],
+            diagnostics: vec![],
+        };
+        let mut cache = cache;
+        cache.previous.insert(path.to_path_buf(), entry(dependency_hash, None));
+        assert!(cache.get(path, 0, 0, || 42, |_, _| false).is_some());
+        // changed project
+        assert!(cache.get(path, 0, 0, || 43, |_, _| false).is_none());
+        // resolved module
+        assert!(cache.get(path, 0, 0, || 42, |_, specifier| specifier == "./missing").is_none());
+        // changed dependency
+        cache
+            .previous
+            .insert(path.to_path_buf(), entry(dependency_hash.map(|hash| hash + 1), None));
+        assert!(cache.get(path, 0, 0, || 42, |_, _| false).is_none());
+        // created dependency, recorded when it did not exist
+        cache.previous.insert(path.to_path_buf(), entry(None, None));
+        assert!(cache.get(path, 0, 0, || 42, |_, _| false).is_none());
+        // deleted dependency
+        cache.previous.insert(path.to_path_buf(), entry(dependency_hash, Some(0)));
+        assert!(cache.get(path, 0, 0, || 42, |_, _| false).is_none());
+    }
+}
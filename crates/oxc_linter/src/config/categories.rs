@@ -0,0 +1,160 @@
+use std::{borrow::Cow, fmt};
+
+use rustc_hash::FxHashSet;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{
+    de::{self, Deserializer, Visitor},
+    Deserialize,
+};
+
+use crate::{rules::RuleEnum, AllowWarnDeny, RuleCategory, RuleWithSeverity};
+
+// TS type is `Partial<Record<Category, SeverityConf>>`
+//   - type SeverityConf = 0 | 1 | 2 | "off" | "warn" | "error";
+#[derive(Debug, Clone, Default)]
+pub struct OxlintCategories(Vec<(RuleCategory, AllowWarnDeny)>);
+
+impl OxlintCategories {
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &(RuleCategory, AllowWarnDeny)> {
+        self.0.iter()
+    }
+
+    /// Enable the rules of each category with its severity, or disable them with `"off"`.
+    pub(crate) fn override_rules(
+        &self,
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) {
+        for (category, severity) in &self.0 {
+            if severity.is_allow() {
+                rules_for_override.retain(|rule| rule.category() != *category);
+                continue;
+            }
+            for rule in all_rules.iter().filter(|rule| rule.category() == *category) {
+                rules_for_override.replace(RuleWithSeverity::new(rule.clone(), *severity));
+            }
+        }
+    }
+}
+
+impl JsonSchema for OxlintCategories {
+    fn schema_name() -> String {
+        "OxlintCategories".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("OxlintCategories")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        #[allow(unused)]
+        #[derive(Debug, Clone, JsonSchema)]
+        #[serde(untagged)]
+        enum DummySeverity {
+            #[schemars(range(min = 0, max = 2.0))]
+            Number(usize),
+            String(String),
+        }
+
+        #[allow(unused)]
+        #[derive(Debug, Clone, JsonSchema)]
+        struct DummyCategories {
+            correctness: Option<DummySeverity>,
+            suspicious: Option<DummySeverity>,
+            pedantic: Option<DummySeverity>,
+            perf: Option<DummySeverity>,
+            style: Option<DummySeverity>,
+            restriction: Option<DummySeverity>,
+            nursery: Option<DummySeverity>,
+        }
+
+        gen.subschema_for::<DummyCategories>()
+    }
+}
+
+impl<'de> Deserialize<'de> for OxlintCategories {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OxlintCategoriesVisitor;
+
+        impl<'de> Visitor<'de> for OxlintCategoriesVisitor {
+            type Value = OxlintCategories;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("Partial<Record<Category, SeverityConf>>")
+            }
+
+            fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+            where
+                M: de::MapAccess<'de>,
+            {
+                let mut categories = vec![];
+                while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
+                    let category = RuleCategory::from(&key).ok_or_else(|| {
+                        de::Error::custom(format!(
+                            "Unknown category {key:?}, expected one of \"correctness\", \"suspicious\", \"pedantic\", \"perf\", \"style\", \"restriction\" or \"nursery\""
+                        ))
+                    })?;
+                    let severity = AllowWarnDeny::try_from(&value).map_err(de::Error::custom)?;
+                    categories.push((category, severity));
+                }
+
+                Ok(OxlintCategories(categories))
+            }
+        }
+
+        deserializer.deserialize_any(OxlintCategoriesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashSet;
+    use serde::Deserialize;
+
+    use super::OxlintCategories;
+    use crate::{rules::RULES, AllowWarnDeny, RuleCategory, RuleWithSeverity};
+
+    #[test]
+    fn test_parse_categories() {
+        let categories = OxlintCategories::deserialize(&serde_json::json!({
+            "correctness": "error", "perf": 1, "nursery": "off"
+        }))
+        .unwrap();
+        assert_eq!(
+            categories.0,
+            vec![
+                (RuleCategory::Correctness, AllowWarnDeny::Deny),
+                (RuleCategory::Perf, AllowWarnDeny::Warn),
+                (RuleCategory::Nursery, AllowWarnDeny::Allow),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_categories_invalid() {
+        assert!(OxlintCategories::deserialize(&serde_json::json!({ "all": "error" })).is_err());
+        assert!(OxlintCategories::deserialize(&serde_json::json!({ "perf": "on" })).is_err());
+    }
+
+    #[test]
+    fn test_override_rules() {
+        let categories = OxlintCategories::deserialize(&serde_json::json!({
+            "correctness": "off", "perf": "error"
+        }))
+        .unwrap();
+        let mut rules = RULES
+            .iter()
+            .filter(|rule| rule.category() == RuleCategory::Correctness)
+            .map(|rule| RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn))
+            .collect::<FxHashSet<_>>();
+        categories.override_rules(&mut rules, &RULES);
+        assert!(!rules.is_empty());
+        assert!(rules
+            .iter()
+            .all(|rule| rule.category() == RuleCategory::Perf
+                && rule.severity == AllowWarnDeny::Deny));
+    }
+}
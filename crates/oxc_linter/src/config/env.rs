@@ -19,6 +19,11 @@ impl OxlintEnv {
         // Filter out false values
         self.0.iter().filter(|(_, v)| **v).map(|(k, _)| k.as_str())
     }
+
+    /// Merge `other` into `self`, entries in `other` take precedence.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.0.extend(other.0.iter().map(|(k, v)| (k.clone(), *v)));
+    }
 }
 
 impl Default for OxlintEnv {
@@ -0,0 +1,305 @@
+//! Resolution of the `extends` field.
+//!
+//! Configurations are merged at the JSON level, before deserializing into [`OxlintConfig`]:
+//!
+//! * `extends` are merged in order, and the extending configuration is merged last.
+//! * `rules` entries replace the entry of the same name.
+//!   A severity-only entry (e.g. `"warn"`) keeps the options of the replaced entry.
+//! * `overrides` are appended.
+//! * Other objects (`env`, `globals`, `settings`) are merged recursively.
+//!
+//! The `extends` of the resolved configuration lists the direct `extends` of the root file.
+
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::OxcDiagnostic;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde_json::{Map, Value};
+
+use super::{validate_rules, OxlintConfig};
+use crate::{rules::RULES, RuleCategory};
+
+const PRESET_PREFIX: &str = "oxlint:";
+
+/// Names of the built-in presets, without the `oxlint:` prefix.
+pub const PRESETS: [&str; 3] = ["recommended", "typescript-strict", "all"];
+
+/// Resolve the configuration at `path`,
+/// pushing the warnings about the unknown rules of the configurations to `warnings`.
+pub fn resolve(path: &Path, warnings: &mut Vec<String>) -> Result<Value, OxcDiagnostic> {
+    resolve_file(path, &mut vec![], warnings).map(Value::Object)
+}
+
+fn resolve_file(
+    path: &Path,
+    stack: &mut Vec<PathBuf>,
+    warnings: &mut Vec<String>,
+) -> Result<Map<String, Value>, OxcDiagnostic> {
+    let canonical_path = path.canonicalize().map_err(|e| {
+        OxcDiagnostic::error(format!("Failed to find config {path:?} with error {e:?}"))
+    })?;
+    if stack.contains(&canonical_path) {
+        return Err(OxcDiagnostic::error(format!(
+            "Config {path:?} extends itself, the chain is {stack:?}"
+        )));
+    }
+
+    let (Value::Object(mut json), source) = OxlintConfig::read_json(path)? else {
+        return Err(OxcDiagnostic::error(format!(
+            "Failed to parse config {path:?}, expected an object"
+        )));
+    };
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    resolve_js_plugin_paths(&mut json, canonical_path.parent().unwrap_or(dir));
+    let extends = take_extends(&mut json).map_err(|err| {
+        OxcDiagnostic::error(format!("Failed to parse `extends` of config {path:?}.\n{err}"))
+    })?;
+
+    let mut resolved = Map::new();
+    if let Some(extends) = &extends {
+        stack.push(canonical_path);
+        for name in extends {
+            let base = if let Some(preset) = name.strip_prefix(PRESET_PREFIX) {
+                preset_rules(preset)
+                    .map(|rules| Map::from_iter([("rules".to_string(), rules)]))
+                    .ok_or_else(|| {
+                        OxcDiagnostic::error(format!(
+                            "Unknown preset {name:?} in config {path:?}, expected one of {}",
+                            PRESETS.map(|p| format!("\"{PRESET_PREFIX}{p}\"")).join(", ")
+                        ))
+                    })?
+            } else {
+                resolve_file(&dir.join(name), stack, warnings)?
+            };
+            merge(&mut resolved, base);
+        }
+        stack.pop();
+    }
+
+    // Rules of the JS plugins can be configured by the configurations extending the one declaring the plugin.
+    let js_plugins = [&resolved, &json]
+        .into_iter()
+        .filter_map(|json| json.get("jsPlugins").and_then(Value::as_object))
+        .flat_map(|plugins| plugins.keys().map(String::as_str))
+        .collect::<Vec<_>>();
+    warnings.extend(validate_rules(path, &source, &json, &js_plugins)?);
+
+    let Some(extends) = extends else {
+        return Ok(json);
+    };
+    merge(&mut resolved, json);
+    resolved.insert(
+        "extends".to_string(),
+        Value::Array(extends.into_iter().map(Value::String).collect()),
+    );
+    Ok(resolved)
+}
+
+/// Make the relative paths in `jsPlugins` relative to `dir`, the directory of the configuration file.
+/// Package names are left as is.
+fn resolve_js_plugin_paths(json: &mut Map<String, Value>, dir: &Path) {
+    let Some(Value::Object(plugins)) = json.get_mut("jsPlugins") else {
+        return;
+    };
+    for specifier in plugins.values_mut() {
+        if let Value::String(path) = specifier {
+            if path.starts_with("./") || path.starts_with("../") {
+                *path = dir.join(&*path).to_string_lossy().into_owned();
+            }
+        }
+    }
+}
+
+pub fn extends_schema(gen: &mut SchemaGenerator) -> Schema {
+    #[allow(unused)]
+    #[derive(Debug, Clone, JsonSchema)]
+    #[serde(untagged)]
+    enum DummyExtends {
+        One(String),
+        Many(Vec<String>),
+    }
+    gen.subschema_for::<DummyExtends>()
+}
+
+/// Remove `extends` from `json`, accepting both a string and an array of strings.
+fn take_extends(json: &mut Map<String, Value>) -> Result<Option<Vec<String>>, String> {
+    let Some(extends) = json.remove("extends") else {
+        return Ok(None);
+    };
+    match extends {
+        Value::String(name) => Ok(Some(vec![name])),
+        Value::Array(names) => names
+            .into_iter()
+            .map(|name| match name {
+                Value::String(name) => Ok(name),
+                value => Err(format!("Expected a string, but got {value}")),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some),
+        value => Err(format!("Expected a string or an array of strings, but got {value}")),
+    }
+}
+
+/// Merge `other` into `base`, see the module documentation for the semantics.
+pub fn merge(base: &mut Map<String, Value>, other: Map<String, Value>) {
+    for (key, value) in other {
+        match (key.as_str(), base.get_mut(&key), value) {
+            ("rules", Some(Value::Object(base_rules)), Value::Object(rules)) => {
+                merge_rules(base_rules, rules);
+            }
+            ("overrides", Some(Value::Array(base_overrides)), Value::Array(overrides)) => {
+                base_overrides.extend(overrides);
+            }
+            (key_name, Some(base_value), value)
+                if key_name != "rules" && key_name != "overrides" =>
+            {
+                merge_value(base_value, value);
+            }
+            (_, _, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn merge_value(base: &mut Value, other: Value) {
+    match (&mut *base, other) {
+        (Value::Object(base), Value::Object(other)) => {
+            for (key, value) in other {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_value(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (_, other) => *base = other,
+    }
+}
+
+fn merge_rules(base: &mut Map<String, Value>, other: Map<String, Value>) {
+    for (name, value) in other {
+        match (base.get_mut(&name), value) {
+            // e.g. `["error", { "allow": ["~"] }]` followed by `"warn"`
+            (Some(Value::Array(base_value)), severity @ (Value::String(_) | Value::Number(_)))
+                if !base_value.is_empty() =>
+            {
+                base_value[0] = severity;
+            }
+            (_, value) => {
+                base.insert(name, value);
+            }
+        }
+    }
+}
+
+/// Build the `rules` of a built-in preset.
+fn preset_rules(name: &str) -> Option<Value> {
+    if !PRESETS.contains(&name) {
+        return None;
+    }
+    let rules = RULES.iter().filter(|rule| match name {
+        "recommended" => rule.category() == RuleCategory::Correctness,
+        "typescript-strict" => {
+            rule.plugin_name() == "typescript" && rule.category() != RuleCategory::Nursery
+        }
+        "all" => rule.category() != RuleCategory::Nursery,
+        _ => false,
+    });
+    let rules = rules
+        .map(|rule| {
+            let key = match rule.plugin_name() {
+                "eslint" => rule.name().to_string(),
+                plugin_name => format!("{plugin_name}/{}", rule.name()),
+            };
+            (key, Value::String("error".to_string()))
+        })
+        .collect::<Map<_, _>>();
+    Some(Value::Object(rules))
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use serde_json::json;
+
+    use super::{merge, preset_rules, resolve};
+
+    #[test]
+    fn test_merge() {
+        let mut base = json!({
+            "rules": { "eqeqeq": ["error", "always"], "no-console": "off", "no-debugger": "warn" },
+            "env": { "browser": true },
+            "settings": { "next": { "rootDir": "app" } },
+            "overrides": [{ "files": "*.test.ts" }]
+        });
+        let base = base.as_object_mut().unwrap();
+        merge(
+            base,
+            json!({
+                "rules": { "eqeqeq": "warn", "no-console": ["error", { "allow": ["warn"] }] },
+                "env": { "node": true },
+                "settings": { "jsx-a11y": { "polymorphicPropName": "role" } },
+                "overrides": [{ "files": "scripts/**" }]
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        );
+        assert_eq!(
+            serde_json::Value::Object(base.clone()),
+            json!({
+                "rules": {
+                    "eqeqeq": ["warn", "always"],
+                    "no-console": ["error", { "allow": ["warn"] }],
+                    "no-debugger": "warn"
+                },
+                "env": { "browser": true, "node": true },
+                "settings": {
+                    "next": { "rootDir": "app" },
+                    "jsx-a11y": { "polymorphicPropName": "role" }
+                },
+                "overrides": [{ "files": "*.test.ts" }, { "files": "scripts/**" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_presets() {
+        let recommended = preset_rules("recommended").unwrap();
+        assert_eq!(recommended["no-debugger"], json!("error"));
+        let typescript_strict = preset_rules("typescript-strict").unwrap();
+        assert!(typescript_strict
+            .as_object()
+            .unwrap()
+            .keys()
+            .all(|key| key.starts_with("typescript/")));
+        assert!(preset_rules("unknown").is_none());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/.oxlintrc.json");
+        let json = resolve(&fixture_path, &mut vec![]).unwrap();
+        assert_eq!(json["extends"], json!(["oxlint:recommended", "./base.json"]));
+        // from `oxlint:recommended`
+        assert_eq!(json["rules"]["no-debugger"], json!("error"));
+        // from `./base.json`, severity replaced by `.oxlintrc.json`
+        assert_eq!(json["rules"]["eqeqeq"], json!(["warn", "always"]));
+        assert_eq!(json["env"], json!({ "browser": true, "node": true }));
+    }
+
+    #[test]
+    fn test_resolve_cycle() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/cycle_a.json");
+        assert!(resolve(&fixture_path, &mut vec![]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_unknown_preset() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/unknown_preset.json");
+        assert!(resolve(&fixture_path, &mut vec![]).is_err());
+    }
+}
@@ -5,11 +5,11 @@ use rustc_hash::FxHashMap;
 
 /// Add or remove global variables.
 // <https://eslint.org/docs/v8.x/use/configure/language-options#using-configuration-files-1>
-#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
 pub struct OxlintGlobals(FxHashMap<String, GlobalValue>);
 
 // TODO: support deprecated `false`
-#[derive(Debug, Eq, PartialEq, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum GlobalValue {
     Readonly,
@@ -21,4 +21,9 @@ impl OxlintGlobals {
     pub fn is_enabled(&self, name: &str) -> bool {
         self.0.get(name).is_some_and(|value| *value != GlobalValue::Off)
     }
+
+    /// Merge `other` into `self`, entries in `other` take precedence.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.0.extend(other.0.iter().map(|(k, v)| (k.clone(), *v)));
+    }
 }
@@ -1,20 +1,37 @@
+mod categories;
 mod env;
+mod extends;
 mod globals;
+mod nested;
+mod overrides;
+mod plugins;
 mod rules;
 mod settings;
 
-use std::path::Path;
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
 
 use oxc_diagnostics::OxcDiagnostic;
 use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::{rules::RuleEnum, AllowWarnDeny, RuleWithSeverity};
+use crate::{js_plugins::JsRule, rules::RuleEnum, RuleWithSeverity};
+
+pub(crate) use self::rules::{parse_rule_key, validate_rules};
 
 pub use self::{
-    env::OxlintEnv, globals::OxlintGlobals, rules::OxlintRules,
-    settings::jsdoc::JSDocPluginSettings, settings::OxlintSettings,
+    categories::OxlintCategories,
+    env::OxlintEnv,
+    globals::OxlintGlobals,
+    nested::NestedConfigs,
+    overrides::{OxlintGlobs, OxlintOverrides},
+    plugins::{OxlintPlugins, DEFAULT_PLUGINS},
+    rules::OxlintRules,
+    settings::jsdoc::JSDocPluginSettings,
+    settings::OxlintSettings,
 };
 
 /// Oxlint Configuration File
@@ -35,6 +52,12 @@ pub use self::{
 ///
 /// ```json
 /// {
+///   "extends": ["oxlint:recommended", "./base.json"],
+///   "plugins": ["react", "typescript", "import"],
+///   "categories": {
+///       "correctness": "error",
+///       "perf": "warn"
+///   },
 ///   "env": {
 ///       "browser": true
 ///   },
@@ -45,24 +68,108 @@ pub use self::{
 ///   },
 ///   "rules": {
 ///       "eqeqeq": "warn"
-///   }
+///   },
+///   "overrides": [
+///     {
+///       "files": ["scripts/**"],
+///       "rules": { "no-console": "off" }
+///     }
+///   ]
 ///  }
 /// ```
-#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct OxlintConfig {
+    /// Configurations to extend from, merged in order before this one.
+    ///
+    /// Either paths relative to this file, or built-in presets:
+    /// `oxlint:recommended`, `oxlint:typescript-strict` and `oxlint:all`.
+    #[schemars(schema_with = "extends::extends_schema")]
+    pub(crate) extends: Vec<String>,
+    /// Stop looking up `.oxlintrc.json` files in the parent directories.
+    pub(crate) root: bool,
+    /// Plugins to enable, replacing the default set of plugins:
+    /// `react`, `unicorn`, `typescript` and `oxc`.
+    ///
+    /// The command line flags (e.g. `--import-plugin`, `--disable-react-plugin`) take precedence.
+    pub(crate) plugins: Option<OxlintPlugins>,
+    /// Enable or disable the rules of whole categories, e.g. `{ "correctness": "error" }`.
+    ///
+    /// Applied after the command line filters (`-A`, `-W`, `-D`) and before the `rules` entries.
+    pub(crate) categories: OxlintCategories,
     /// See [Oxlint Rules](./rules)
     pub(crate) rules: OxlintRules,
+    /// Report the disable directives (e.g. `// eslint-disable-line no-console`)
+    /// which disable no diagnostic, same as `--report-unused-disable-directives`.
+    #[serde(rename = "reportUnusedDisableDirectives")]
+    pub(crate) report_unused_disable_directives: bool,
+    /// Enable the type-aware rules (e.g. `typescript/no-floating-promises`), same as `--type-aware`.
+    #[serde(rename = "typeAware")]
+    pub(crate) type_aware: bool,
+    /// ESLint plugins written in JavaScript, run with `node`, keyed by the plugin name used in `rules`,
+    /// e.g. `{ "my-company": "./eslint-plugin-my-company.js" }`.
+    ///
+    /// Relative paths are relative to this file, other values are package names.
+    #[serde(rename = "jsPlugins")]
+    pub(crate) js_plugins: BTreeMap<String, String>,
     pub(crate) settings: OxlintSettings,
     pub(crate) env: OxlintEnv,
     pub(crate) globals: OxlintGlobals,
+    pub(crate) overrides: OxlintOverrides,
+    /// Unknown plugins and rules of the configuration file and of its `extends`, which are ignored.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub(crate) warnings: Vec<String>,
 }
 
 impl OxlintConfig {
     /// # Errors
     ///
     /// * Parse Failure
+    /// * A configuration in `extends` cannot be found, or extends itself
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let mut warnings = vec![];
+        let json = extends::resolve(path, &mut warnings)?;
+
+        let mut config = Self::deserialize(&json).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+        })?;
+
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().map_or_else(|_| path.to_path_buf(), |cwd| cwd.join(path))
+        };
+        if let Some(dir) = path.parent() {
+            let dir =
+                dir.components().filter(|c| !matches!(c, Component::CurDir)).collect::<PathBuf>();
+            config.overrides.set_base_dir(&dir);
+        }
+        config.warnings = warnings;
+
+        Ok(config)
+    }
+
+    /// Unknown plugins and rules of the configuration, with their location.
+    /// They are ignored, e.g. the rules of ESLint not implemented by oxlint.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Read the configuration file at `path` and merge the configurations listed in its `extends` into it.
+    ///
+    /// The unknown plugins and rules are left in the result, see [`OxlintConfig::warnings`].
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    /// * A configuration in `extends` cannot be found, or extends itself
+    pub fn resolve_json(path: &Path) -> Result<serde_json::Value, OxcDiagnostic> {
+        extends::resolve(path, &mut vec![])
+    }
+
+    /// Read the configuration file at `path`, returning its JSON value and its source without comments.
+    fn read_json(path: &Path) -> Result<(serde_json::Value, String), OxcDiagnostic> {
         let mut string = std::fs::read_to_string(path).map_err(|e| {
             OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
         })?;
@@ -87,83 +194,79 @@ impl OxlintConfig {
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
-        })?;
-
-        Ok(config)
+        Ok((json, string))
     }
 
-    #[allow(clippy::option_if_let_else)]
     pub fn override_rules(
         &self,
         rules_for_override: &mut FxHashSet<RuleWithSeverity>,
         all_rules: &[RuleEnum],
     ) {
-        use itertools::Itertools;
-        let mut rules_to_replace: Vec<RuleWithSeverity> = vec![];
-        let mut rules_to_remove: Vec<RuleWithSeverity> = vec![];
-
-        // Rules can have the same name but different plugin names
-        let lookup = self.rules.iter().into_group_map_by(|r| r.rule_name.as_str());
-
-        for (name, rule_configs) in &lookup {
-            match rule_configs.len() {
-                0 => unreachable!(),
-                1 => {
-                    let rule_config = &rule_configs[0];
-                    let rule_name = &rule_config.rule_name;
-                    let plugin_name = &rule_config.plugin_name;
-                    let severity = rule_config.severity;
-                    match severity {
-                        AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
-                            if let Some(rule) = all_rules
-                                .iter()
-                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                            {
-                                let config = rule_config.config.clone().unwrap_or_default();
-                                let rule = rule.read_json(config);
-                                rules_to_replace.push(RuleWithSeverity::new(rule, severity));
-                            }
-                        }
-                        AllowWarnDeny::Allow => {
-                            if let Some(rule) = rules_for_override
-                                .iter()
-                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                            {
-                                let rule = rule.clone();
-                                rules_to_remove.push(rule);
-                            }
-                        }
-                    }
-                }
-                _ => {
-                    // For overlapping rule names, use the "error" one
-                    // "no-loss-of-precision": "off",
-                    // "@typescript-eslint/no-loss-of-precision": "error"
-                    if let Some(rule_config) =
-                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
-                    {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            let config = rule_config.config.clone().unwrap_or_default();
-                            rules_to_replace
-                                .push(RuleWithSeverity::new(rule.read_json(config), rule.severity));
-                        }
-                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            rules_to_remove.push(rule.clone());
-                        }
-                    }
-                }
+        self.categories.override_rules(rules_for_override, all_rules);
+        self.rules.override_rules(rules_for_override, all_rules);
+    }
+
+    /// Rules of the `jsPlugins` enabled for the files matched by the `overrides` at `indices`.
+    ///
+    /// An entry without options keeps the options of the previous entry of the same rule.
+    pub(crate) fn js_rules(&self, indices: &[usize]) -> Vec<JsRule> {
+        let mut js_rules: Vec<JsRule> = vec![];
+        let overrides = indices.iter().filter_map(|i| self.overrides.get(*i));
+        let entries = std::iter::once(&self.rules)
+            .chain(overrides.map(|r#override| &r#override.rules))
+            .flat_map(|rules| rules.iter());
+        for entry in entries {
+            let Some(specifier) = self.js_plugins.get(&entry.plugin_name) else { continue };
+            let previous = js_rules
+                .iter()
+                .position(|rule| rule.plugin == entry.plugin_name && rule.rule == entry.rule_name)
+                .map(|index| js_rules.remove(index));
+            if entry.severity.is_allow() {
+                continue;
             }
+            let options = entry
+                .config
+                .clone()
+                .or_else(|| previous.map(|rule| rule.options))
+                .unwrap_or_else(|| serde_json::Value::Array(vec![]));
+            js_rules.push(JsRule {
+                plugin: entry.plugin_name.clone(),
+                specifier: specifier.clone(),
+                rule: entry.rule_name.clone(),
+                severity: entry.severity,
+                options,
+            });
         }
+        js_rules
+    }
 
-        for rule in rules_to_remove {
-            rules_for_override.remove(&rule);
-        }
-        for rule in rules_to_replace {
-            rules_for_override.replace(rule);
+    /// Indices of the `overrides` which apply to `path`.
+    pub fn matching_overrides(&self, path: &Path) -> Vec<usize> {
+        self.overrides.matching_indices(path)
+    }
+
+    /// Apply the `overrides` at `indices` in order,
+    /// returning the configuration used for the files matched by them.
+    pub(crate) fn apply_overrides(
+        &self,
+        indices: &[usize],
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) -> Self {
+        let mut config = self.clone();
+        for r#override in indices.iter().filter_map(|i| self.overrides.get(*i)) {
+            r#override.rules.override_rules(rules_for_override, all_rules);
+            if let Some(env) = &r#override.env {
+                config.env.merge(env);
+            }
+            if let Some(globals) = &r#override.globals {
+                config.globals.merge(globals);
+            }
+            if let Some(settings) = &r#override.settings {
+                config.settings = settings.clone();
+            }
         }
+        config
     }
 }
 
@@ -180,6 +283,37 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_from_file_invalid_rules() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/invalid_rules.json");
+        let err = OxlintConfig::from_file(&fixture_path).unwrap_err().to_string();
+        let path = fixture_path.display();
+        assert!(err.contains(&format!("{path}:5:5: Invalid options for rule `no-console`")));
+        assert!(err.contains(&format!(
+            "{path}:7:5: Invalid options for rule `no-restricted-syntax`: \
+            Invalid selector `CallExpression[callee`: expected `]` or an operator at offset 21"
+        )));
+        // unknown plugins and rules are warnings
+        assert!(!err.contains("foo/bar"));
+        assert!(!err.contains("no-unknown-rule"));
+        assert!(!err.contains("no-debugger"));
+    }
+
+    #[test]
+    fn test_from_file_unknown_rules() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/unknown_rules.json");
+        let config = OxlintConfig::from_file(&fixture_path).unwrap();
+        let path = fixture_path.display();
+        assert_eq!(
+            config.warnings(),
+            [
+                format!("{path}:3:5: Unknown plugin `foo` in rule `foo/bar` is ignored"),
+                format!("{path}:4:5: Unknown rule `indent` is ignored"),
+                format!("{path}:10:18: Unknown rule `no-unknown-rule` is ignored"),
+            ]
+        );
+    }
+
     #[test]
     fn test_deserialize() {
         let config = OxlintConfig::deserialize(&serde_json::json!({
@@ -212,7 +346,7 @@ mod test {
         }));
         assert!(config.is_ok());
 
-        let OxlintConfig { rules, settings, env, globals } = config.unwrap();
+        let OxlintConfig { rules, settings, env, globals, .. } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
@@ -0,0 +1,239 @@
+//! Lookup of `.oxlintrc.json` files in the directories of the linted files.
+//!
+//! The configuration of a file is merged from the configuration files found in its directory
+//! and every parent directory up to the root (usually the current working directory),
+//! the nearest one taking precedence. The lookup stops at a configuration file with `"root": true`.
+//! A configuration given explicitly (`-c`) is merged below all of them.
+
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::OxcDiagnostic;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::{extends, OxlintConfig, OxlintOverrides};
+
+const CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// Configuration merged from the configuration files of a directory and its parents.
+#[derive(Debug, Clone)]
+pub struct NestedConfig {
+    /// Directory of the nearest configuration file.
+    pub dir: PathBuf,
+    /// Merged configuration, without `overrides`.
+    json: Map<String, Value>,
+    /// `overrides` are kept apart because their globs are relative to their own configuration file.
+    overrides: OxlintOverrides,
+    /// See [`OxlintConfig::warnings`].
+    warnings: Vec<String>,
+}
+
+impl NestedConfig {
+    fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let mut warnings = vec![];
+        let Value::Object(mut json) = extends::resolve(path, &mut warnings)? else {
+            return Err(OxcDiagnostic::error(format!(
+                "Failed to parse config {path:?}, expected an object"
+            )));
+        };
+        let dir = path.parent().map(normalize).unwrap_or_default();
+        let mut overrides = match json.remove("overrides") {
+            Some(overrides) => OxlintOverrides::deserialize(&overrides).map_err(|err| {
+                OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {err:?}"))
+            })?,
+            None => OxlintOverrides::default(),
+        };
+        overrides.set_base_dir(&dir);
+        Ok(Self { dir, json, overrides, warnings })
+    }
+
+    /// Path of the nearest configuration file.
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(CONFIG_FILE_NAME)
+    }
+
+    fn is_root(&self) -> bool {
+        self.json.get("root").is_some_and(|root| root == &Value::Bool(true))
+    }
+
+    /// Merge `other` on top of `self`.
+    fn merge(&self, other: Self) -> Self {
+        let mut json = self.json.clone();
+        extends::merge(&mut json, other.json);
+        let mut overrides = self.overrides.clone();
+        overrides.extend(other.overrides);
+        let mut warnings = self.warnings.clone();
+        warnings.extend(other.warnings);
+        Self { dir: other.dir, json, overrides, warnings }
+    }
+
+    /// # Errors
+    ///
+    /// * The merged configuration is invalid
+    pub fn to_config(&self) -> Result<OxlintConfig, OxcDiagnostic> {
+        let mut config =
+            OxlintConfig::deserialize(&Value::Object(self.json.clone())).map_err(|err| {
+                OxcDiagnostic::error(format!(
+                    "Failed to parse config in {:?} with error {err:?}",
+                    self.dir
+                ))
+            })?;
+        config.overrides = self.overrides.clone();
+        config.warnings.clone_from(&self.warnings);
+        Ok(config)
+    }
+}
+
+/// Configuration files found in the directories of the linted files.
+pub struct NestedConfigs {
+    /// Configuration files are not looked up above this directory.
+    root: PathBuf,
+    /// The configuration given explicitly, merged below the ones found.
+    base: Option<(PathBuf, NestedConfig)>,
+    /// Keyed by directory, `None` when no configuration file applies to it.
+    dirs: DashMap<PathBuf, Result<Option<Arc<NestedConfig>>, OxcDiagnostic>>,
+}
+
+impl NestedConfigs {
+    /// # Errors
+    ///
+    /// * The configuration at `config_path` is invalid
+    pub fn new(root: &Path, config_path: Option<&Path>) -> Result<Self, OxcDiagnostic> {
+        let root = normalize(root);
+        let base = config_path
+            .map(|path| {
+                let path = absolute(&root, path);
+                NestedConfig::from_file(&path).map(|config| (path, config))
+            })
+            .transpose()?;
+        Ok(Self { root, base, dirs: DashMap::default() })
+    }
+
+    /// The configuration applying to `path`,
+    /// `None` if no configuration file is found between `path` and the root.
+    ///
+    /// # Errors
+    ///
+    /// * A configuration file is invalid
+    pub fn lookup(&self, path: &Path) -> Result<Option<Arc<NestedConfig>>, OxcDiagnostic> {
+        let path = absolute(&self.root, path);
+        match path.parent() {
+            Some(dir) if dir.starts_with(&self.root) => self.lookup_dir(dir),
+            _ => Ok(None),
+        }
+    }
+
+    fn lookup_dir(&self, dir: &Path) -> Result<Option<Arc<NestedConfig>>, OxcDiagnostic> {
+        if let Some(result) = self.dirs.get(dir) {
+            return result.value().clone();
+        }
+        let result = self.lookup_dir_uncached(dir);
+        self.dirs.insert(dir.to_path_buf(), result.clone());
+        result
+    }
+
+    fn lookup_dir_uncached(&self, dir: &Path) -> Result<Option<Arc<NestedConfig>>, OxcDiagnostic> {
+        let path = dir.join(CONFIG_FILE_NAME);
+        let own = if path.is_file()
+            && self.base.as_ref().map_or(true, |(base_path, _)| !is_same_file(base_path, &path))
+        {
+            Some(NestedConfig::from_file(&path)?)
+        } else {
+            None
+        };
+
+        let parent = match dir.parent() {
+            Some(parent)
+                if dir != self.root
+                    && parent.starts_with(&self.root)
+                    && !own.as_ref().is_some_and(NestedConfig::is_root) =>
+            {
+                self.lookup_dir(parent)?
+            }
+            _ => None,
+        };
+
+        let Some(own) = own else {
+            return Ok(parent);
+        };
+        let merged = match (parent, &self.base) {
+            (Some(parent), _) => parent.merge(own),
+            (None, Some((_, base))) => base.merge(own),
+            (None, None) => own,
+        };
+        Ok(Some(Arc::new(merged)))
+    }
+}
+
+fn absolute(root: &Path, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        normalize(path)
+    } else {
+        normalize(&root.join(path))
+    }
+}
+
+/// Remove `.` components.
+fn normalize(path: &Path) -> PathBuf {
+    path.components().filter(|c| !matches!(c, Component::CurDir)).collect()
+}
+
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    a == b || a.canonicalize().ok().is_some_and(|a| b.canonicalize().ok().is_some_and(|b| a == b))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use super::NestedConfigs;
+
+    #[test]
+    fn test_lookup() {
+        let root = env::current_dir().unwrap().join("fixtures/nested");
+        let configs = NestedConfigs::new(&root, None).unwrap();
+
+        let config = configs.lookup(Path::new("index.js")).unwrap().unwrap();
+        assert_eq!(config.dir, root);
+        let config = config.to_config().unwrap();
+        assert!(config.rules.iter().any(|r| r.rule_name == "no-debugger"));
+
+        // merged with the root configuration
+        let config = configs.lookup(Path::new("./packages/a/src/index.js")).unwrap().unwrap();
+        assert_eq!(config.dir, root.join("packages/a"));
+        let config = config.to_config().unwrap();
+        assert!(config.rules.iter().any(|r| r.rule_name == "no-debugger"));
+        assert!(config.rules.iter().any(|r| r.rule_name == "no-console"));
+
+        // `"root": true` stops the lookup
+        let config = configs.lookup(&root.join("packages/b/index.js")).unwrap().unwrap();
+        let config = config.to_config().unwrap();
+        assert!(!config.rules.iter().any(|r| r.rule_name == "no-debugger"));
+        assert!(config.rules.iter().any(|r| r.rule_name == "eqeqeq"));
+
+        // outside of the root
+        assert!(configs.lookup(Path::new("/index.js")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_lookup_without_root_config() {
+        let root = env::current_dir().unwrap().join("fixtures/nested/packages");
+        let configs = NestedConfigs::new(&root, None).unwrap();
+        assert!(configs.lookup(Path::new("index.js")).unwrap().is_none());
+        assert!(configs.lookup(Path::new("a/index.js")).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_lookup_with_base() {
+        let root = env::current_dir().unwrap().join("fixtures/nested/packages");
+        let base = Path::new("../base.json");
+        let configs = NestedConfigs::new(&root, Some(base)).unwrap();
+        let config = configs.lookup(Path::new("a/index.js")).unwrap().unwrap().to_config().unwrap();
+        assert!(config.rules.iter().any(|r| r.rule_name == "no-var"));
+        assert!(config.rules.iter().any(|r| r.rule_name == "no-console"));
+    }
+}
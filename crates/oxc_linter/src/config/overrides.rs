@@ -0,0 +1,226 @@
+use std::{
+    borrow::Cow,
+    path::{Component, Path, PathBuf},
+};
+
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, Deserialize, Deserializer};
+
+use super::{OxlintEnv, OxlintGlobals, OxlintRules, OxlintSettings};
+
+/// Apply a different set of rules, environments, globals and settings to a subset of files.
+///
+/// Overrides are applied in order, later overrides take precedence over earlier ones.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct OxlintOverrides(Vec<OxlintOverride>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OxlintOverride {
+    /// Glob patterns of the files this override applies to.
+    ///
+    /// Patterns are relative to the directory of the configuration file.
+    /// A pattern without a `/` is matched against the file name only.
+    pub files: OxlintGlobs,
+
+    /// Glob patterns of the files excluded from this override.
+    #[serde(default)]
+    pub excluded_files: OxlintGlobs,
+
+    /// Rules merged on top of the top-level `rules`.
+    #[serde(default)]
+    pub rules: OxlintRules,
+
+    /// Environments merged on top of the top-level `env`.
+    pub env: Option<OxlintEnv>,
+
+    /// Globals merged on top of the top-level `globals`.
+    pub globals: Option<OxlintGlobals>,
+
+    /// Settings replacing the top-level `settings`.
+    pub settings: Option<OxlintSettings>,
+
+    /// Directory of the configuration file declaring this override.
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+}
+
+impl OxlintOverrides {
+    /// Indices of the overrides matching `path`, in declaration order.
+    ///
+    /// `path` is made relative to the directory of the configuration file declaring each override.
+    pub fn matching_indices(&self, path: &Path) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| {
+                let path = normalize_path(path, o.base_dir.as_deref());
+                o.files.is_match(&path) && !o.excluded_files.is_match(&path)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Set the directory of the configuration file for the overrides which do not have one yet.
+    pub fn set_base_dir(&mut self, dir: &Path) {
+        for r#override in self.0.iter_mut().filter(|o| o.base_dir.is_none()) {
+            r#override.base_dir = Some(dir.to_path_buf());
+        }
+    }
+
+    pub fn extend(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+
+    pub fn get(&self, index: usize) -> Option<&OxlintOverride> {
+        self.0.get(index)
+    }
+}
+
+/// Strip `base_dir` and `.` components so that globs can be matched against a relative path.
+fn normalize_path(path: &Path, base_dir: Option<&Path>) -> PathBuf {
+    let path = base_dir.and_then(|base| path.strip_prefix(base).ok()).unwrap_or(path);
+    path.components().filter(|c| !matches!(c, Component::CurDir)).collect()
+}
+
+/// A list of glob patterns, written as a single string or an array of strings.
+#[derive(Debug, Clone)]
+pub struct OxlintGlobs {
+    /// Patterns without a path separator, matched against the file name.
+    file_name_globs: GlobSet,
+    /// Patterns with a path separator, matched against the relative path.
+    path_globs: GlobSet,
+}
+
+impl Default for OxlintGlobs {
+    fn default() -> Self {
+        Self { file_name_globs: GlobSet::empty(), path_globs: GlobSet::empty() }
+    }
+}
+
+impl OxlintGlobs {
+    /// # Errors
+    ///
+    /// * Invalid glob pattern
+    pub fn new(patterns: &[String]) -> Result<Self, globset::Error> {
+        let mut file_name_globs = GlobSetBuilder::new();
+        let mut path_globs = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = pattern.trim_start_matches("./");
+            if glob.contains('/') {
+                path_globs.add(Self::build_glob(glob)?);
+            } else {
+                file_name_globs.add(Self::build_glob(glob)?);
+            }
+        }
+        Ok(Self { file_name_globs: file_name_globs.build()?, path_globs: path_globs.build()? })
+    }
+
+    fn build_glob(pattern: &str) -> Result<Glob, globset::Error> {
+        GlobBuilder::new(pattern).literal_separator(true).build()
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        self.path_globs.is_match(path)
+            || path.file_name().is_some_and(|name| self.file_name_globs.is_match(name))
+    }
+}
+
+impl<'de> Deserialize<'de> for OxlintGlobs {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        let patterns = match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(pattern) => vec![pattern],
+            OneOrMany::Many(patterns) => patterns,
+        };
+        Self::new(&patterns).map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for OxlintGlobs {
+    fn schema_name() -> String {
+        "OxlintGlobs".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("OxlintGlobs")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        #[allow(unused)]
+        #[derive(Debug, Clone, JsonSchema)]
+        #[serde(untagged)]
+        enum DummyGlobs {
+            One(String),
+            Many(Vec<String>),
+        }
+        gen.subschema_for::<DummyGlobs>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::OxlintOverrides;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_parse_overrides() {
+        let overrides = OxlintOverrides::deserialize(&serde_json::json!([
+            { "files": "*.test.ts", "rules": { "no-console": "off" } },
+            {
+                "files": ["scripts/**", "tools/*.js"],
+                "excludedFiles": "scripts/release.js",
+                "env": { "node": true },
+                "globals": { "foo": "readonly" }
+            }
+        ]))
+        .unwrap();
+        let first = overrides.get(0).unwrap();
+        assert!(first.files.is_match(Path::new("foo.test.ts")));
+        assert_eq!(first.rules.len(), 1);
+        assert!(first.env.is_none());
+        let second = overrides.get(1).unwrap();
+        assert!(second.files.is_match(Path::new("tools/build.js")));
+        assert!(second.excluded_files.is_match(Path::new("scripts/release.js")));
+        assert!(second.env.as_ref().is_some_and(|env| env.iter().any(|e| e == "node")));
+        assert!(second.globals.as_ref().is_some_and(|globals| globals.is_enabled("foo")));
+    }
+
+    #[test]
+    fn test_parse_overrides_invalid_glob() {
+        let overrides = OxlintOverrides::deserialize(&serde_json::json!([{ "files": "a/**{" }]));
+        assert!(overrides.is_err());
+    }
+
+    #[test]
+    fn test_matching_indices() {
+        let overrides = OxlintOverrides::deserialize(&serde_json::json!([
+            { "files": ["*.test.ts"] },
+            { "files": ["scripts/**"], "excludedFiles": ["scripts/release.js"] },
+            { "files": ["src/*.js"] }
+        ]))
+        .unwrap();
+        let mut overrides = overrides;
+        overrides.set_base_dir(Path::new("/repo"));
+        let matching = |path: &str| overrides.matching_indices(Path::new(path));
+        assert_eq!(matching("/repo/src/foo.test.ts"), vec![0]);
+        assert_eq!(matching("./foo.test.ts"), vec![0]);
+        assert_eq!(matching("/repo/scripts/build/index.js"), vec![1]);
+        assert_eq!(matching("/repo/scripts/release.js"), Vec::<usize>::new());
+        assert_eq!(matching("/repo/src/index.js"), vec![2]);
+        assert_eq!(matching("/repo/src/nested/index.js"), Vec::<usize>::new());
+        assert_eq!(matching("src/index.js"), vec![2]);
+    }
+}
@@ -0,0 +1,79 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// Plugins enabled by default, when neither the configuration nor the command line lists them.
+pub const DEFAULT_PLUGINS: [&str; 4] = ["react", "unicorn", "typescript", "oxc"];
+
+/// Plugins to enable.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct OxlintPlugins(Vec<OxlintPlugin>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum OxlintPlugin {
+    React,
+    Unicorn,
+    Typescript,
+    Oxc,
+    Import,
+    Jsdoc,
+    Jest,
+    #[serde(alias = "jsx_a11y")]
+    JsxA11y,
+    Nextjs,
+    #[serde(alias = "react_perf")]
+    ReactPerf,
+    Promise,
+    Node,
+    Vitest,
+}
+
+impl OxlintPlugin {
+    /// Name of the plugin, as returned by `RuleEnum::plugin_name`.
+    pub fn plugin_name(self) -> &'static str {
+        match self {
+            Self::React => "react",
+            Self::Unicorn => "unicorn",
+            Self::Typescript => "typescript",
+            Self::Oxc => "oxc",
+            Self::Import => "import",
+            Self::Jsdoc => "jsdoc",
+            Self::Jest => "jest",
+            Self::JsxA11y => "jsx_a11y",
+            Self::Nextjs => "nextjs",
+            Self::ReactPerf => "react_perf",
+            Self::Promise => "promise",
+            Self::Node => "node",
+            Self::Vitest => "vitest",
+        }
+    }
+}
+
+impl OxlintPlugins {
+    pub fn contains(&self, plugin_name: &str) -> bool {
+        self.0.iter().any(|plugin| plugin.plugin_name() == plugin_name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::OxlintPlugins;
+
+    #[test]
+    fn test_parse_plugins() {
+        let plugins =
+            OxlintPlugins::deserialize(&serde_json::json!(["import", "jsx-a11y", "react_perf"]))
+                .unwrap();
+        assert!(plugins.contains("import"));
+        assert!(plugins.contains("jsx_a11y"));
+        assert!(plugins.contains("react_perf"));
+        assert!(!plugins.contains("react"));
+    }
+
+    #[test]
+    fn test_parse_plugins_unknown() {
+        assert!(OxlintPlugins::deserialize(&serde_json::json!(["eslint-plugin-foo"])).is_err());
+    }
+}
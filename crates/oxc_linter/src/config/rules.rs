@@ -1,14 +1,24 @@
-use std::{borrow::Cow, fmt, ops::Deref};
+use std::{borrow::Cow, fmt, ops::Deref, path::Path};
 
 use oxc_diagnostics::{Error, OxcDiagnostic};
-use rustc_hash::FxHashMap;
-use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use rustc_hash::FxHashSet;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{
+        ArrayValidation, InstanceType, ObjectValidation, Schema, SchemaObject, SingleOrVec,
+        SubschemaValidation,
+    },
+    JsonSchema,
+};
 use serde::{
     de::{self, Deserializer, Visitor},
     Deserialize,
 };
 
-use crate::AllowWarnDeny;
+use crate::{
+    rules::{RuleEnum, RULES},
+    AllowWarnDeny, RuleWithSeverity,
+};
 
 // TS type is `Record<string, RuleConf>`
 //   - type SeverityConf = 0 | 1 | 2 | "off" | "warn" | "error";
@@ -34,6 +44,8 @@ impl JsonSchema for OxlintRules {
         Cow::Borrowed("OxlintRules")
     }
 
+    /// Rules with options are described with the schema of their options,
+    /// other rules with the generic `DummyRule`.
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
         #[allow(unused)]
         #[derive(Debug, Clone, JsonSchema)]
@@ -44,7 +56,62 @@ impl JsonSchema for OxlintRules {
             String(String),
             Array(Vec<serde_json::Value>),
         }
-        gen.subschema_for::<FxHashMap<String, DummyRule>>()
+
+        #[allow(unused)]
+        #[derive(Debug, Clone, JsonSchema)]
+        #[serde(untagged)]
+        enum DummyRuleSeverity {
+            #[schemars(range(min = 0, max = 2.0))]
+            Number(usize),
+            String(String),
+        }
+
+        let severity = gen.subschema_for::<DummyRuleSeverity>();
+        let mut properties = schemars::Map::new();
+        for rule in RULES.iter() {
+            let Some(options) = rule.config_schema(gen) else { continue };
+            let with_options = SchemaObject {
+                instance_type: Some(InstanceType::Array.into()),
+                array: Some(Box::new(if rule.repeated_config() {
+                    ArrayValidation {
+                        items: Some(SingleOrVec::Vec(vec![severity.clone()])),
+                        additional_items: Some(Box::new(options)),
+                        min_items: Some(1),
+                        ..ArrayValidation::default()
+                    }
+                } else {
+                    ArrayValidation {
+                        items: Some(SingleOrVec::Vec(vec![severity.clone(), options])),
+                        min_items: Some(1),
+                        ..ArrayValidation::default()
+                    }
+                })),
+                ..SchemaObject::default()
+            };
+            let rule_schema = SchemaObject {
+                subschemas: Some(Box::new(SubschemaValidation {
+                    any_of: Some(vec![severity.clone(), with_options.into()]),
+                    ..SubschemaValidation::default()
+                })),
+                ..SchemaObject::default()
+            };
+            let key = match rule.plugin_name() {
+                "eslint" => rule.name().to_string(),
+                plugin_name => format!("{plugin_name}/{}", rule.name()),
+            };
+            properties.insert(key, rule_schema.into());
+        }
+
+        SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(ObjectValidation {
+                properties,
+                additional_properties: Some(Box::new(gen.subschema_for::<DummyRule>())),
+                ..ObjectValidation::default()
+            })),
+            ..SchemaObject::default()
+        }
+        .into()
     }
 }
 
@@ -85,7 +152,8 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+/// Split a rule key, e.g. `@typescript-eslint/no-explicit-any`, into its oxlint plugin name and rule name.
+pub(crate) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
@@ -100,6 +168,16 @@ fn parse_rule_key(name: &str) -> (String, String) {
         "react-hooks" => ("react", rule_name),
         // For backwards compatibility, deepscan rules reside in the oxc plugin.
         "deepscan" => ("oxc", rule_name),
+        // e.g. "n/no-unsupported-features/es-syntax" is "no-unsupported-features-es-syntax"
+        "n" | "node" => return ("node".to_string(), rule_name.replace('/', "-")),
+        // The jest rules check Vitest tests as well, e.g. "vitest/no-focused-tests".
+        "vitest"
+            if !RULES
+                .iter()
+                .any(|rule| rule.plugin_name() == "vitest" && rule.name() == rule_name) =>
+        {
+            ("jest", rule_name)
+        }
         _ => (plugin_name, rule_name),
     };
 
@@ -145,6 +223,80 @@ fn parse_rule_value(
     }
 }
 
+impl OxlintRules {
+    #[allow(clippy::option_if_let_else)]
+    pub(crate) fn override_rules(
+        &self,
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) {
+        use itertools::Itertools;
+        let mut rules_to_replace: Vec<RuleWithSeverity> = vec![];
+        let mut rules_to_remove: Vec<RuleWithSeverity> = vec![];
+
+        // Rules can have the same name but different plugin names
+        let lookup = self.iter().into_group_map_by(|r| r.rule_name.as_str());
+
+        for (name, rule_configs) in &lookup {
+            match rule_configs.len() {
+                0 => unreachable!(),
+                1 => {
+                    let rule_config = &rule_configs[0];
+                    let rule_name = &rule_config.rule_name;
+                    let plugin_name = &rule_config.plugin_name;
+                    let severity = rule_config.severity;
+                    match severity {
+                        AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
+                            if let Some(rule) = all_rules
+                                .iter()
+                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                            {
+                                let config = rule_config.config.clone().unwrap_or_default();
+                                let rule = rule.read_json(config);
+                                rules_to_replace.push(RuleWithSeverity::new(rule, severity));
+                            }
+                        }
+                        AllowWarnDeny::Allow => {
+                            if let Some(rule) = rules_for_override
+                                .iter()
+                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                            {
+                                let rule = rule.clone();
+                                rules_to_remove.push(rule);
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    // For overlapping rule names, use the "error" one
+                    // "no-loss-of-precision": "off",
+                    // "@typescript-eslint/no-loss-of-precision": "error"
+                    if let Some(rule_config) =
+                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
+                    {
+                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            let config = rule_config.config.clone().unwrap_or_default();
+                            rules_to_replace
+                                .push(RuleWithSeverity::new(rule.read_json(config), rule.severity));
+                        }
+                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
+                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            rules_to_remove.push(rule.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for rule in rules_to_remove {
+            rules_for_override.remove(&rule);
+        }
+        for rule in rules_to_replace {
+            rules_for_override.replace(rule);
+        }
+    }
+}
+
 impl Deref for OxlintRules {
     type Target = Vec<ESLintRule>;
 
@@ -153,6 +305,125 @@ impl Deref for OxlintRules {
     }
 }
 
+/// Check the `rules` and `overrides[].rules` of the configuration file at `path`,
+/// returning the warnings about the unknown plugins and rules, which are ignored.
+///
+/// Malformed options of the rules of oxlint are errors, reported together.
+/// Each problem has its location in `source`, the configuration file without comments.
+/// The rules of `js_plugins` are checked when the plugins are loaded.
+pub(crate) fn validate_rules(
+    path: &Path,
+    source: &str,
+    json: &serde_json::Map<String, serde_json::Value>,
+    js_plugins: &[&str],
+) -> Result<Vec<String>, OxcDiagnostic> {
+    let mut problems = vec![];
+    let mut warnings = vec![];
+    let location = |start: Option<usize>, key: &str| {
+        let offset = start.and_then(|start| find_key(source, start, key)).unwrap_or(0);
+        let (line, column) = line_column(source, offset);
+        format!("{}:{line}:{column}", path.display())
+    };
+    if let Some(serde_json::Value::Object(plugins)) = json.get("jsPlugins") {
+        let start = find_key(source, 0, "jsPlugins");
+        for name in plugins.keys() {
+            if RULES.iter().any(|rule| rule.plugin_name() == name) {
+                problems.push(format!(
+                    "{}: JS plugin `{name}` has the name of a built-in plugin",
+                    location(start, name)
+                ));
+            }
+        }
+    }
+    let mut validate = |rules: Option<&serde_json::Value>, start: Option<usize>| {
+        let Some(serde_json::Value::Object(rules)) = rules else { return };
+        for (key, value) in rules {
+            match validate_rule(key, value, js_plugins) {
+                Ok(None) => {}
+                Ok(Some(warning)) => warnings.push(format!("{}: {warning}", location(start, key))),
+                Err(problem) => problems.push(format!("{}: {problem}", location(start, key))),
+            }
+        }
+    };
+
+    validate(json.get("rules"), find_key(source, 0, "rules"));
+    if let Some(serde_json::Value::Array(overrides)) = json.get("overrides") {
+        let mut start = find_key(source, 0, "overrides");
+        for r#override in overrides {
+            start = start.and_then(|start| find_key(source, start, "rules"));
+            validate(r#override.get("rules"), start);
+            start = start.map(|start| start + 1);
+        }
+    }
+
+    if problems.is_empty() {
+        return Ok(warnings);
+    }
+    Err(OxcDiagnostic::error(format!("Invalid rules in config {path:?}\n{}", problems.join("\n"))))
+}
+
+/// `Ok(Some(warning))` for an unknown plugin or rule, e.g. a rule of ESLint not implemented by oxlint,
+/// `Err` for malformed options.
+fn validate_rule(
+    key: &str,
+    value: &serde_json::Value,
+    js_plugins: &[&str],
+) -> Result<Option<String>, String> {
+    let (plugin_name, rule_name) = parse_rule_key(key);
+    if js_plugins.contains(&plugin_name.as_str()) {
+        return parse_rule_value(value)
+            .map(|_| None)
+            .map_err(|err| format!("Invalid value for rule `{key}`: {err}"));
+    }
+    if !RULES.iter().any(|rule| rule.plugin_name() == plugin_name) {
+        return Ok(Some(format!("Unknown plugin `{plugin_name}` in rule `{key}` is ignored")));
+    }
+    // typescript-eslint extension rules, e.g. `@typescript-eslint/no-loss-of-precision`,
+    // are implemented by the eslint rule of the same name.
+    let rule = RULES
+        .iter()
+        .find(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+        .or_else(|| {
+            (plugin_name == "typescript").then(|| {
+                RULES.iter().find(|rule| rule.plugin_name() == "eslint" && rule.name() == rule_name)
+            })?
+        });
+    let Some(rule) = rule else {
+        return Ok(Some(format!("Unknown rule `{key}` is ignored")));
+    };
+    let config = match parse_rule_value(value) {
+        Ok((_, config)) => config.unwrap_or_default(),
+        Err(err) => return Err(format!("Invalid value for rule `{key}`: {err}")),
+    };
+    rule.validate_config(&config)
+        .map(|()| None)
+        .map_err(|err| format!("Invalid options for rule `{key}`: {err}"))
+}
+
+/// Offset of the first `"key":` in `source` at or after `start`.
+fn find_key(source: &str, start: usize, key: &str) -> Option<usize> {
+    let quoted = format!("\"{key}\"");
+    let mut from = start;
+    while let Some(index) = source.get(from..)?.find(&quoted) {
+        let offset = from + index;
+        let rest = &source[offset + quoted.len()..];
+        if rest.trim_start().starts_with(':') {
+            return Some(offset);
+        }
+        from = offset + quoted.len();
+    }
+    None
+}
+
+/// One-based line and column of `offset` in `source`.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column =
+        before.rfind('\n').map_or(before, |index| &before[index + 1..]).chars().count() + 1;
+    (line, column)
+}
+
 fn failed_to_parse_rule_value(value: &str, err: &str) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Failed to rule value {value:?} with error {err:?}"))
 }
@@ -169,6 +440,9 @@ mod test {
             "foo/no-unused-vars": [1],
             "dummy": ["error", "arg1", "args2"],
             "@next/next/noop": 2,
+            "n/no-unsupported-features/es-syntax": "warn",
+            "vitest/no-focused-tests": "error",
+            "vitest/no-conditional-tests": "error",
         }))
         .unwrap();
         let mut rules = rules.iter();
@@ -196,6 +470,19 @@ mod test {
         assert_eq!(r4.plugin_name, "nextjs");
         assert!(r4.severity.is_warn_deny());
         assert!(r4.config.is_none());
+
+        let r5 = rules.next().unwrap();
+        assert_eq!(r5.rule_name, "no-unsupported-features-es-syntax");
+        assert_eq!(r5.plugin_name, "node");
+        assert!(r5.severity.is_warn_deny());
+
+        let r6 = rules.next().unwrap();
+        assert_eq!(r6.rule_name, "no-focused-tests");
+        assert_eq!(r6.plugin_name, "jest");
+
+        let r7 = rules.next().unwrap();
+        assert_eq!(r7.rule_name, "no-conditional-tests");
+        assert_eq!(r7.plugin_name, "vitest");
     }
 
     #[test]
@@ -7,8 +7,9 @@ use oxc_syntax::module_record::ModuleRecord;
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message, RuleFixer},
+    fixer::{CompositeFix, FixKind, Message, RuleFixer},
     javascript_globals::GLOBALS,
+    type_info::TypeInfoProvider,
     AllowWarnDeny, OxlintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
 };
 
@@ -20,13 +21,16 @@ pub struct LintContext<'a> {
 
     disable_directives: Rc<DisableDirectives<'a>>,
 
-    /// Whether or not to apply code fixes during linting.
-    fix: bool,
+    /// Kinds of code fixes to provide during linting.
+    fix: FixKind,
 
     file_path: Rc<Path>,
 
     eslint_config: Arc<OxlintConfig>,
 
+    /// Set when type-aware linting is enabled.
+    type_info: Option<Arc<dyn TypeInfoProvider>>,
+
     // states
     current_rule_name: &'static str,
 
@@ -42,16 +46,17 @@ impl<'a> LintContext<'a> {
             semantic,
             diagnostics: RefCell::new(vec![]),
             disable_directives: Rc::new(disable_directives),
-            fix: false,
+            fix: FixKind::None,
             file_path: file_path.into(),
             eslint_config: Arc::new(OxlintConfig::default()),
+            type_info: None,
             current_rule_name: "",
             severity: Severity::Warning,
         }
     }
 
     #[must_use]
-    pub fn with_fix(mut self, fix: bool) -> Self {
+    pub fn with_fix(mut self, fix: FixKind) -> Self {
         self.fix = fix;
         self
     }
@@ -62,6 +67,12 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_type_info(mut self, type_info: Arc<dyn TypeInfoProvider>) -> Self {
+        self.type_info = Some(type_info);
+        self
+    }
+
     #[must_use]
     pub fn with_rule_name(mut self, name: &'static str) -> Self {
         self.current_rule_name = name;
@@ -101,6 +112,11 @@ impl<'a> LintContext<'a> {
         &self.file_path
     }
 
+    /// Type information for the type-aware rules, `None` unless type-aware linting is enabled.
+    pub fn type_info(&self) -> Option<&dyn TypeInfoProvider> {
+        self.type_info.as_deref()
+    }
+
     pub fn settings(&self) -> &OxlintSettings {
         &self.eslint_config.settings
     }
@@ -147,15 +163,46 @@ impl<'a> LintContext<'a> {
         self.add_diagnostic(Message::new(diagnostic, None));
     }
 
-    /// Report a lint rule violation and provide an automatic fix.
-    pub fn diagnostic_with_fix<F: FnOnce(RuleFixer<'_, 'a>) -> Fix<'a>>(
-        &self,
-        diagnostic: OxcDiagnostic,
-        fix: F,
-    ) {
-        if self.fix {
+    /// Report a lint rule violation and provide an automatic fix, which keeps the behavior of the code.
+    ///
+    /// The fix is either a single [`Fix`](crate::fixer::Fix), or several edits applied all together,
+    /// see [`RuleFixer::replace_many`].
+    pub fn diagnostic_with_fix<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        self.diagnostic_with_fix_kind(diagnostic, FixKind::SafeFix, fix);
+    }
+
+    /// Report a lint rule violation and suggest a fix, which may not be what the author intended.
+    pub fn diagnostic_with_suggestion<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        self.diagnostic_with_fix_kind(diagnostic, FixKind::Suggestion, fix);
+    }
+
+    /// Report a lint rule violation and provide a fix, which may change the behavior of the code.
+    pub fn diagnostic_with_dangerous_fix<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        self.diagnostic_with_fix_kind(diagnostic, FixKind::DangerousFix, fix);
+    }
+
+    fn diagnostic_with_fix_kind<C, F>(&self, diagnostic: OxcDiagnostic, fix_kind: FixKind, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        if self.fix.contains(fix_kind) {
             let fixer = RuleFixer::new(self);
-            self.add_diagnostic(Message::new(diagnostic, Some(fix(fixer))));
+            self.add_diagnostic(
+                Message::new(diagnostic, Some(fix(fixer).into())).with_fix_kind(fix_kind),
+            );
         } else {
             self.diagnostic(diagnostic);
         }
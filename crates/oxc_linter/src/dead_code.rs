@@ -0,0 +1,346 @@
+//! Exports never imported and files never reached from the entry points of a project,
+//! see [`LintService::dead_code`](crate::LintService::dead_code).
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use oxc_ast::{
+    ast::{Expression, ImportExpression, Program},
+    visit::walk::walk_import_expression,
+    Visit,
+};
+use oxc_span::{Atom, CompactStr, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+
+use crate::{config::OxlintGlobs, utils::normalize_path};
+
+/// Result of [`LintService::dead_code`](crate::LintService::dead_code),
+/// with the paths relative to the current working directory.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadCodeReport {
+    /// Exports of the files reachable from the entry points, other than the entry points,
+    /// which are never imported, directly or through re-exports.
+    pub unused_exports: Vec<UnusedExport>,
+    /// Files which are not imported, directly or indirectly, by the entry points.
+    /// The `import` and `export` declarations are followed, and the `import()` calls with a
+    /// string literal specifier, but not `require()`.
+    pub unreachable_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnusedExport {
+    pub path: PathBuf,
+    /// Exported name, `default` for the default export.
+    pub name: String,
+    /// 1-based line of the exported name.
+    pub line: usize,
+    /// 1-based column of the exported name.
+    pub column: usize,
+}
+
+impl DeadCodeReport {
+    pub fn is_empty(&self) -> bool {
+        self.unused_exports.is_empty() && self.unreachable_files.is_empty()
+    }
+
+    /// `records` are the module records of the module graph, `paths` are the linted files,
+    /// the only ones reported.
+    pub(crate) fn new(
+        cwd: &Path,
+        paths: &FxHashSet<Box<Path>>,
+        records: Vec<Arc<ModuleRecord>>,
+        entries: &OxlintGlobs,
+    ) -> Self {
+        let graph = ModuleGraph::new(records);
+
+        let mut project_files = paths
+            .iter()
+            .filter(|path| !is_declaration_file(path))
+            .filter_map(|path| {
+                let id = canonicalize(path);
+                graph.records.contains_key(&id).then(|| {
+                    let relative = normalize_path(path.strip_prefix(cwd).unwrap_or(path));
+                    (id, relative)
+                })
+            })
+            .collect::<Vec<_>>();
+        project_files.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+
+        let entry_files = project_files
+            .iter()
+            .filter(|(_, relative)| entries.is_match(relative))
+            .map(|(id, _)| id.clone())
+            .collect::<FxHashSet<_>>();
+
+        let mut reachable = entry_files.clone();
+        let mut stack =
+            entry_files.iter().map(|id| Arc::clone(&graph.records[id])).collect::<Vec<_>>();
+        while let Some(record) = stack.pop() {
+            for loaded in
+                record.loaded_modules.iter().chain(record.dynamically_loaded_modules.iter())
+            {
+                if reachable.insert(canonicalize(&loaded.resolved_absolute_path)) {
+                    stack.push(Arc::clone(loaded.value()));
+                }
+            }
+        }
+
+        let usages = graph.usages(&entry_files, &reachable);
+
+        let mut report = Self::default();
+        for (id, relative) in &project_files {
+            if !reachable.contains(id) {
+                report.unreachable_files.push(relative.clone());
+                continue;
+            }
+            if entry_files.contains(id) {
+                continue;
+            }
+            let usage = usages.get(id);
+            let mut unused = graph.records[id]
+                .local_export_entries
+                .iter()
+                .chain(&graph.records[id].indirect_export_entries)
+                .filter_map(|entry| match &entry.export_name {
+                    ExportExportName::Name(name) => Some((name.name().clone(), name.span())),
+                    ExportExportName::Default(span) => Some((CompactStr::new("default"), *span)),
+                    ExportExportName::Null => None,
+                })
+                .filter(|(name, _)| !usage.is_some_and(|usage| usage.contains(name)))
+                .collect::<Vec<_>>();
+            if unused.is_empty() {
+                continue;
+            }
+            unused.sort_unstable_by_key(|(_, span)| span.start);
+            let source_text = fs::read_to_string(id).unwrap_or_default();
+            for (name, span) in unused {
+                let (line, column) = line_column(&source_text, span);
+                report.unused_exports.push(UnusedExport {
+                    path: relative.clone(),
+                    name: name.to_string(),
+                    line,
+                    column,
+                });
+            }
+        }
+        report
+    }
+}
+
+impl fmt::Display for DeadCodeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for export in &self.unused_exports {
+            writeln!(
+                f,
+                "{}:{}:{}: `{}` is exported but never imported",
+                export.path.display(),
+                export.line,
+                export.column,
+                export.name
+            )?;
+        }
+        for path in &self.unreachable_files {
+            writeln!(f, "{}: file is not reachable from the entry points", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Module records keyed by canonicalized path, the same file may have been processed both
+/// with the path given on the command line and with its resolved path.
+struct ModuleGraph {
+    records: FxHashMap<PathBuf, Arc<ModuleRecord>>,
+}
+
+/// Exports of a module which are imported.
+#[derive(Debug, Default)]
+struct Usage {
+    all: bool,
+    all_but_default: bool,
+    names: FxHashSet<CompactStr>,
+}
+
+impl Usage {
+    fn contains(&self, name: &str) -> bool {
+        self.all || (self.all_but_default && name != "default") || self.names.contains(name)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Imported {
+    Name(CompactStr),
+    /// `import * as ns from 'mod'`, `export * as ns from 'mod'`, `import('mod')`, or an entry
+    /// point.
+    All,
+    /// `export * from 'mod'` when all the exports of the re-exporting module are imported.
+    AllButDefault,
+}
+
+impl ModuleGraph {
+    fn new(records: Vec<Arc<ModuleRecord>>) -> Self {
+        let records = records
+            .into_iter()
+            .map(|record| (canonicalize(&record.resolved_absolute_path), record))
+            .collect();
+        Self { records }
+    }
+
+    /// Path of the module imported with `specifier` by `record`.
+    fn resolve(record: &ModuleRecord, specifier: &str) -> Option<PathBuf> {
+        record
+            .loaded_modules
+            .get(specifier)
+            .map(|loaded| canonicalize(&loaded.value().resolved_absolute_path))
+    }
+
+    /// The exports imported by the `reachable` modules, following the re-exports.
+    fn usages(
+        &self,
+        entries: &FxHashSet<PathBuf>,
+        reachable: &FxHashSet<PathBuf>,
+    ) -> FxHashMap<PathBuf, Usage> {
+        let mut usages = FxHashMap::<PathBuf, Usage>::default();
+        let mut stack = entries.iter().map(|id| (id.clone(), Imported::All)).collect::<Vec<_>>();
+        for record in reachable.iter().filter_map(|id| self.records.get(id)) {
+            for entry in &record.import_entries {
+                let Some(target) = Self::resolve(record, entry.module_request.name()) else {
+                    continue;
+                };
+                let imported = match &entry.import_name {
+                    ImportImportName::Name(name) => Imported::Name(name.name().clone()),
+                    ImportImportName::Default(_) => Imported::Name(CompactStr::new("default")),
+                    ImportImportName::NamespaceObject => Imported::All,
+                };
+                stack.push((target, imported));
+            }
+            // `import()` resolves to the namespace object.
+            for loaded in &record.dynamically_loaded_modules {
+                stack.push((canonicalize(&loaded.resolved_absolute_path), Imported::All));
+            }
+        }
+
+        while let Some((id, imported)) = stack.pop() {
+            let usage = usages.entry(id.clone()).or_default();
+            let is_new = match &imported {
+                Imported::Name(name) => !usage.contains(name) && usage.names.insert(name.clone()),
+                Imported::All => !std::mem::replace(&mut usage.all, true),
+                Imported::AllButDefault => {
+                    !usage.all && !std::mem::replace(&mut usage.all_but_default, true)
+                }
+            };
+            if !is_new {
+                continue;
+            }
+            let Some(record) = self.records.get(&id) else {
+                continue;
+            };
+
+            // Names of the other modules re-exported under the imported names.
+            let mut is_reexported = false;
+            for entry in &record.indirect_export_entries {
+                let export_name = match &entry.export_name {
+                    ExportExportName::Name(name) => name.name().as_str(),
+                    ExportExportName::Default(_) => "default",
+                    ExportExportName::Null => continue,
+                };
+                let is_imported = match &imported {
+                    Imported::Name(name) => name.as_str() == export_name,
+                    Imported::All => true,
+                    Imported::AllButDefault => export_name != "default",
+                };
+                if !is_imported {
+                    continue;
+                }
+                is_reexported = true;
+                let Some(target) = entry
+                    .module_request
+                    .as_ref()
+                    .and_then(|request| Self::resolve(record, request.name()))
+                else {
+                    continue;
+                };
+                match &entry.import_name {
+                    ExportImportName::Name(name) => {
+                        stack.push((target, Imported::Name(name.name().clone())));
+                    }
+                    ExportImportName::All => stack.push((target, Imported::All)),
+                    ExportImportName::AllButDefault | ExportImportName::Null => {}
+                }
+            }
+
+            // Names which are not exported by the module itself may come from `export *`.
+            let star_imported = match &imported {
+                Imported::Name(name) => {
+                    let is_local = record.local_export_entries.iter().any(|entry| {
+                        matches!(&entry.export_name, ExportExportName::Name(n) if n.name() == name)
+                    });
+                    if is_local || is_reexported || name.as_str() == "default" {
+                        continue;
+                    }
+                    imported
+                }
+                Imported::All | Imported::AllButDefault => Imported::AllButDefault,
+            };
+            for entry in &record.star_export_entries {
+                if let Some(target) = entry
+                    .module_request
+                    .as_ref()
+                    .and_then(|request| Self::resolve(record, request.name()))
+                {
+                    stack.push((target, star_imported.clone()));
+                }
+            }
+        }
+        usages
+    }
+}
+
+/// Specifiers of the `import()` calls with a string literal, e.g. `./lazy` for
+/// `import('./lazy')`, which are followed when building the module graph.
+pub(crate) fn dynamic_imports<'a>(program: &Program<'a>) -> Vec<Atom<'a>> {
+    let mut finder = DynamicImportFinder::default();
+    finder.visit_program(program);
+    finder.specifiers
+}
+
+#[derive(Default)]
+struct DynamicImportFinder<'a> {
+    specifiers: Vec<Atom<'a>>,
+}
+
+impl<'a> Visit<'a> for DynamicImportFinder<'a> {
+    fn visit_import_expression(&mut self, expr: &ImportExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &expr.source {
+            self.specifiers.push(lit.value.clone());
+        }
+        walk_import_expression(self, expr);
+    }
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn is_declaration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| [".d.ts", ".d.mts", ".d.cts"].iter().any(|ext| name.ends_with(ext)))
+}
+
+/// 1-based line and column of the start of `span`.
+fn line_column(source_text: &str, span: Span) -> (usize, usize) {
+    let start = (span.start as usize).min(source_text.len());
+    let before = &source_text[..start];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rfind('\n').map_or(before, |i| &before[i + 1..]).chars().count() + 1;
+    (line, column)
+}
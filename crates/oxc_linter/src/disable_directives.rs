@@ -1,12 +1,17 @@
-use oxc_ast::Trivias;
+use std::cell::RefCell;
+
+use oxc_ast::{CommentKind, Trivias};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum DisabledRule<'a> {
-    All,
-    Single(&'a str),
+    /// All rules, disabled by the directive at `comment`
+    All { comment: Span },
+    /// A rule, disabled by its name at `name_span` in a directive
+    Single { rule_name: &'a str, name_span: Span },
 }
 
 /// A comment which disables one or more specific rules
@@ -17,6 +22,38 @@ pub struct DisableRuleComment<'a> {
     pub rules: Vec<&'a str>,
 }
 
+/// A disable directive, reported when it disables no diagnostic.
+struct Directive<'a> {
+    /// Span of the comment
+    span: Span,
+    /// Span to delete to remove the comment, see [`DisableDirectivesBuilder::removal_span`]
+    removal_span: Span,
+    /// Rules disabled by the comment with their span, empty if the comment disables all rules
+    rules: Vec<(&'a str, Span)>,
+}
+
+/// A directive, or a rule name in a directive, which disabled no diagnostic.
+pub struct UnusedDirective<'a> {
+    /// Span of the comment, or of the rule name
+    pub span: Span,
+    /// `None` if the whole directive is unused
+    pub rule_name: Option<&'a str>,
+    /// Span to delete to remove the directive, or the rule name with its separating comma
+    pub removal_span: Span,
+}
+
+impl<'a> UnusedDirective<'a> {
+    pub fn diagnostic(&self) -> OxcDiagnostic {
+        let message = match self.rule_name {
+            Some(rule_name) => {
+                format!("Unused disable directive (no problems were reported from `{rule_name}`).")
+            }
+            None => "Unused disable directive (no problems were reported).".to_string(),
+        };
+        OxcDiagnostic::warn(message).with_labels([self.span.into()])
+    }
+}
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
@@ -24,17 +61,31 @@ pub struct DisableDirectives<'a> {
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// All disable directives, in source order
+    directives: Vec<Directive<'a>>,
+    /// The disabled rules which disabled a diagnostic, see [`DisableDirectives::contains`]
+    used: RefCell<FxHashSet<DisabledRule<'a>>>,
 }
 
 impl<'a> DisableDirectives<'a> {
-    pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
+    /// Whether `rule_name` is disabled at `start`,
+    /// marking the directives disabling it as used.
+    pub fn contains(&self, rule_name: &str, start: u32) -> bool {
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let matched = match interval.val {
+                DisabledRule::All { .. } => true,
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                DisabledRule::Single { rule_name: name, .. } => name.contains(rule_name),
+            };
+            if matched {
+                self.used.borrow_mut().insert(interval.val);
+                contains = true;
+            }
+        }
+        contains
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -44,6 +95,55 @@ impl<'a> DisableDirectives<'a> {
     pub fn disable_rule_comments(&self) -> &Vec<DisableRuleComment<'a>> {
         &self.disable_rule_comments
     }
+
+    /// The directives, and the rule names in directives, which disabled no diagnostic so far.
+    ///
+    /// Only the rule names for which `is_enabled` returns `true` are reported, the others may be
+    /// rules of another linter, e.g. ESLint running alongside oxlint.
+    /// A directive whose rule names are all enabled and unused is reported as a whole.
+    pub fn unused_directives<F: Fn(&str) -> bool>(
+        &self,
+        is_enabled: F,
+    ) -> Vec<UnusedDirective<'a>> {
+        let used = self.used.borrow();
+        let mut unused = vec![];
+        for directive in &self.directives {
+            let whole = UnusedDirective {
+                span: directive.span,
+                rule_name: None,
+                removal_span: directive.removal_span,
+            };
+            if directive.rules.is_empty() {
+                if !used.contains(&DisabledRule::All { comment: directive.span }) {
+                    unused.push(whole);
+                }
+                continue;
+            }
+
+            let is_unused = |(rule_name, name_span): &(&'a str, Span)| {
+                is_enabled(rule_name)
+                    && !used.contains(&DisabledRule::Single { rule_name, name_span: *name_span })
+            };
+            if directive.rules.iter().all(is_unused) {
+                unused.push(whole);
+                continue;
+            }
+            for (i, rule) in directive.rules.iter().enumerate() {
+                if !is_unused(rule) {
+                    continue;
+                }
+                let (rule_name, span) = *rule;
+                // Remove the comma before the rule name, or after it for the first rule name
+                let removal_span = if i == 0 {
+                    Span::new(span.start, directive.rules[1].1.start)
+                } else {
+                    Span::new(directive.rules[i - 1].1.end, span.end)
+                };
+                unused.push(UnusedDirective { span, rule_name: Some(rule_name), removal_span });
+            }
+        }
+        unused
+    }
 }
 
 pub struct DisableDirectivesBuilder<'a> {
@@ -51,14 +151,16 @@ pub struct DisableDirectivesBuilder<'a> {
     trivias: Trivias,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable` or `oxlint-disable`
-    disable_all_start: Option<u32>,
+    /// Start of `eslint-disable` or `oxlint-disable`, with the span of the comment
+    disable_all_start: Option<(u32, Span)>,
     /// Start of `eslint-disable` or `oxlint-disable` rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    disable_start_map: FxHashMap<&'a str, (u32, DisabledRule<'a>)>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// All disable directives
+    directives: Vec<Directive<'a>>,
 }
 
 impl<'a> DisableDirectivesBuilder<'a> {
@@ -71,6 +173,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
             disable_rule_comments: vec![],
+            directives: vec![],
         }
     }
 
@@ -80,6 +183,8 @@ impl<'a> DisableDirectivesBuilder<'a> {
             intervals: self.intervals,
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
+            directives: self.directives,
+            used: RefCell::default(),
         }
     }
 
@@ -87,13 +192,40 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.intervals.insert(Interval { start, stop, val });
     }
 
+    fn add_directive(&mut self, kind: CommentKind, span: Span, rules: &[(&'a str, Span)]) {
+        let removal_span = self.removal_span(kind, span);
+        let rules = rules.iter().filter(|(rule_name, _)| !rule_name.is_empty()).copied().collect();
+        self.directives.push(Directive { span, removal_span, rules });
+    }
+
+    /// The span of the comment at `span` with its delimiters and the whitespace before it,
+    /// or of its whole line if the comment is alone on its line.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn removal_span(&self, kind: CommentKind, span: Span) -> Span {
+        let start = span.start as usize - 2;
+        let end = if kind.is_single_line() { span.end as usize } else { span.end as usize + 2 };
+        let before = self.source_text[..start].trim_end_matches([' ', '\t']);
+        let after = &self.source_text[end..];
+        let rest_of_line = after.trim_start_matches([' ', '\t']);
+        let newline_len = if rest_of_line.starts_with("\r\n") {
+            2
+        } else {
+            usize::from(rest_of_line.starts_with('\n'))
+        };
+        let alone_on_line = (before.is_empty() || before.ends_with('\n'))
+            && (newline_len > 0 || rest_of_line.is_empty());
+        let end =
+            if alone_on_line { end + after.len() - rest_of_line.len() + newline_len } else { end };
+        Span::new(before.len() as u32, end as u32)
+    }
+
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
     fn build_impl(&mut self) {
         let source_len = self.source_text.len() as u32;
         // This algorithm iterates through the comments and builds all intervals
         // for matching disable and enable pairs.
         // Wrongly ordered matching pairs are not taken into consideration.
-        for (_, span) in self.trivias.clone().comments() {
+        for (kind, span) in self.trivias.clone().comments() {
             let text = span.source_text(self.source_text);
             let text = text.trim_start();
 
@@ -103,9 +235,10 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 // `eslint-disable`
                 if text.trim().is_empty() {
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some((span.end, span));
                     }
                     self.disable_all_comments.push(span);
+                    self.add_directive(kind, span, &[]);
                     continue;
                 }
 
@@ -117,15 +250,22 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        self.add_interval(span.end, stop, DisabledRule::All { comment: span });
                         self.disable_all_comments.push(span);
+                        self.add_directive(kind, span, &[]);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
-                            rules.push(rule_name);
+                        Self::get_rule_names(text, span.end, |rule_name, name_span| {
+                            self.add_interval(
+                                span.end,
+                                stop,
+                                DisabledRule::Single { rule_name, name_span },
+                            );
+                            rules.push((rule_name, name_span));
                         });
+                        self.add_directive(kind, span, &rules);
+                        let rules = rules.into_iter().map(|(rule_name, _)| rule_name).collect();
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
                     }
                     continue;
@@ -142,15 +282,22 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        self.add_interval(start, stop, DisabledRule::All { comment: span });
                         self.disable_all_comments.push(span);
+                        self.add_directive(kind, span, &[]);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
-                            rules.push(rule_name);
+                        Self::get_rule_names(text, span.end, |rule_name, name_span| {
+                            self.add_interval(
+                                start,
+                                stop,
+                                DisabledRule::Single { rule_name, name_span },
+                            );
+                            rules.push((rule_name, name_span));
                         });
+                        self.add_directive(kind, span, &rules);
+                        let rules = rules.into_iter().map(|(rule_name, _)| rule_name).collect();
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
                     }
                     continue;
@@ -158,10 +305,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                 // `eslint-disable rule-name1, rule-name2`
                 let mut rules = vec![];
-                Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
-                    rules.push(rule_name);
+                Self::get_rule_names(text, span.end, |rule_name, name_span| {
+                    self.disable_start_map
+                        .entry(rule_name)
+                        .or_insert((span.end, DisabledRule::Single { rule_name, name_span }));
+                    rules.push((rule_name, name_span));
                 });
+                self.add_directive(kind, span, &rules);
+                let rules = rules.into_iter().map(|(rule_name, _)| rule_name).collect();
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
 
                 continue;
@@ -172,14 +323,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, comment)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, DisabledRule::All { comment });
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
-                    Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                    Self::get_rule_names(text, span.end, |rule_name, _| {
+                        if let Some((start, val)) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(start, span.start, val);
                         }
                     });
                 }
@@ -188,20 +339,28 @@ impl<'a> DisableDirectivesBuilder<'a> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, comment)) = self.disable_all_start {
+            self.add_interval(start, source_len, DisabledRule::All { comment });
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (_, (start, val)) in disable_start_map {
+            self.add_interval(start, source_len, val);
         }
     }
 
-    fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
+    /// Call `cb` with each rule name in `text` and its span, `text` ends at `end` in the source text.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn get_rule_names<F: FnMut(&'a str, Span)>(text: &'a str, end: u32, mut cb: F) {
+        let mut offset = end - text.len() as u32;
         if let Some(text) = text.split_terminator("--").next() {
-            text.split(',').map(str::trim).for_each(cb);
+            for part in text.split(',') {
+                let start = offset + (part.len() - part.trim_start().len()) as u32;
+                let rule_name = part.trim();
+                cb(rule_name, Span::new(start, start + rule_name.len() as u32));
+                offset += part.len() as u32 + 1;
+            }
         }
     }
 }
@@ -389,3 +548,48 @@ fn test() {
         Tester::new("no-debugger", pass, fail).test();
     }
 }
+
+#[test]
+fn test_unused_directives() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "
+// eslint-disable-next-line no-debugger
+debugger;
+debugger; // oxlint-disable-line no-console, no-debugger
+foo(); /* eslint-disable-line */
+/* eslint-disable no-console, no-alert */
+// eslint-disable-next-line prettier/prettier
+foo();
+";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let directives = DisableDirectivesBuilder::new(source_text, ret.trivias).build();
+    for (i, _) in source_text.match_indices("debugger;") {
+        #[allow(clippy::cast_possible_truncation)]
+        let start = i as u32;
+        assert!(directives.contains("no-debugger", start));
+    }
+
+    let unused = directives
+        .unused_directives(|rule_name| ["no-debugger", "no-console"].contains(&rule_name))
+        .into_iter()
+        .map(|unused| {
+            (
+                unused.span.source_text(source_text),
+                unused.rule_name,
+                unused.removal_span.source_text(source_text),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unused,
+        vec![
+            ("no-console", Some("no-console"), "no-console, "),
+            (" eslint-disable-line ", None, " /* eslint-disable-line */"),
+            ("no-console", Some("no-console"), "no-console, "),
+        ]
+    );
+}
@@ -0,0 +1,222 @@
+//! Explain why a rule is turned on or off for a file, see [`Linter::explain_rule`].
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{
+    config::parse_rule_key, rules::RULES, AllowWarnDeny, LintOptions, Linter, RuleCategory,
+};
+
+/// Effective severity and options of a rule for a file,
+/// with every setting which applied to the rule.
+#[derive(Debug)]
+pub struct RuleExplanation {
+    /// `plugin/rule-name`
+    pub rule_name: String,
+    pub path: PathBuf,
+    /// Whether the plugin of the rule is enabled, a rule of a disabled plugin is never turned on.
+    pub plugin_enabled: bool,
+    /// Effective severity, [`AllowWarnDeny::Allow`] when the rule is turned off.
+    pub severity: AllowWarnDeny,
+    /// The rule with its options as parsed by `Rule::from_configuration`, `None` when the rule is turned off.
+    pub options: Option<String>,
+    /// Settings which applied to the rule, in order of application. The last one takes precedence.
+    pub settings: Vec<RuleSetting>,
+}
+
+#[derive(Debug)]
+pub struct RuleSetting {
+    pub severity: AllowWarnDeny,
+    pub source: RuleSettingSource,
+}
+
+#[derive(Debug)]
+pub enum RuleSettingSource {
+    /// A `-A`, `-W` or `-D` filter, or the default `-W correctness` when no filter is given.
+    CommandLine { filter: String, default: bool },
+    /// An entry of the `categories` of a configuration file.
+    ConfigCategory { config: Option<PathBuf>, category: RuleCategory },
+    /// An entry of the `rules` of a configuration file.
+    ConfigRule { config: Option<PathBuf>, options: Option<serde_json::Value> },
+    /// An entry of the `rules` of the `overrides` at `index` of a configuration file.
+    Override { config: Option<PathBuf>, index: usize, options: Option<serde_json::Value> },
+}
+
+impl Linter {
+    /// Explain the effective severity and options of the rule `name` for the file at `path`,
+    /// and where each setting applying to it comes from.
+    ///
+    /// `name` is either a rule name (`no-debugger`), or a rule name prefixed with its plugin
+    /// (`eslint/no-debugger`, `@typescript-eslint/no-explicit-any`).
+    ///
+    /// # Errors
+    ///
+    /// * The rule does not exist
+    /// * A configuration file applying to `path` is invalid
+    pub fn explain_rule(&self, name: &str, path: &Path) -> Result<RuleExplanation, OxcDiagnostic> {
+        let (plugin_name, rule_name) = parse_rule_key(name);
+        let rule = RULES
+            .iter()
+            .find(|rule| {
+                rule.name() == rule_name
+                    && (!name.contains('/') || rule.plugin_name() == plugin_name)
+            })
+            .ok_or_else(|| OxcDiagnostic::error(format!("Rule `{name}` not found.")))?;
+
+        let state = self.resolve_state(path)?;
+        let config = state.config();
+        let config_path = &state.path;
+        let mut settings = vec![];
+
+        let is_default_filter = self.options.filter == LintOptions::default().filter;
+        for (severity, filter) in &self.options.filter {
+            let applies = match RuleCategory::from(filter) {
+                Some(category) => rule.category() == category,
+                None if filter == "all" => {
+                    severity.is_allow() || rule.category() != RuleCategory::Nursery
+                }
+                None => filter == rule.name(),
+            };
+            if applies {
+                let source = RuleSettingSource::CommandLine {
+                    filter: format!("{} {filter}", filter_flag(*severity)),
+                    default: is_default_filter,
+                };
+                settings.push(RuleSetting { severity: *severity, source });
+            }
+        }
+
+        for (category, severity) in config.categories.iter() {
+            if rule.category() == *category {
+                let source = RuleSettingSource::ConfigCategory {
+                    config: config_path.clone(),
+                    category: *category,
+                };
+                settings.push(RuleSetting { severity: *severity, source });
+            }
+        }
+
+        let matches_rule = |plugin_name: &str, rule_name: &str| {
+            rule_name == rule.name() && plugin_name == rule.plugin_name()
+        };
+        for entry in config.rules.iter() {
+            if matches_rule(&entry.plugin_name, &entry.rule_name) {
+                let source = RuleSettingSource::ConfigRule {
+                    config: config_path.clone(),
+                    options: entry.config.clone(),
+                };
+                settings.push(RuleSetting { severity: entry.severity, source });
+            }
+        }
+
+        for index in config.matching_overrides(path) {
+            let Some(r#override) = config.overrides.get(index) else { continue };
+            for entry in r#override.rules.iter() {
+                if matches_rule(&entry.plugin_name, &entry.rule_name) {
+                    let source = RuleSettingSource::Override {
+                        config: config_path.clone(),
+                        index,
+                        options: entry.config.clone(),
+                    };
+                    settings.push(RuleSetting { severity: entry.severity, source });
+                }
+            }
+        }
+
+        let resolved = state.resolve(path, &self.options);
+        let enabled = resolved
+            .rules()
+            .iter()
+            .find(|r| r.name() == rule.name() && r.plugin_name() == rule.plugin_name());
+
+        Ok(RuleExplanation {
+            rule_name: format!("{}/{}", rule.plugin_name(), rule.name()),
+            path: path.to_path_buf(),
+            plugin_enabled: self
+                .options
+                .is_plugin_enabled(rule.plugin_name(), config.plugins.as_ref()),
+            severity: enabled.map_or(AllowWarnDeny::Allow, |rule| rule.severity),
+            options: enabled.map(|rule| format!("{:#?}", rule.rule)),
+            settings,
+        })
+    }
+}
+
+fn filter_flag(severity: AllowWarnDeny) -> &'static str {
+    match severity {
+        AllowWarnDeny::Allow => "-A",
+        AllowWarnDeny::Warn => "-W",
+        AllowWarnDeny::Deny => "-D",
+    }
+}
+
+fn severity_name(severity: AllowWarnDeny) -> &'static str {
+    match severity {
+        AllowWarnDeny::Allow => "off",
+        AllowWarnDeny::Warn => "warn",
+        AllowWarnDeny::Deny => "error",
+    }
+}
+
+fn config_name(config: Option<&PathBuf>) -> String {
+    config.map_or_else(|| "the configuration".to_string(), |path| format!("{}", path.display()))
+}
+
+impl fmt::Display for RuleSettingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CommandLine { filter, default: true } => {
+                write!(f, "`{filter}` (default when no filter is given on the command line)")
+            }
+            Self::CommandLine { filter, default: false } => {
+                write!(f, "`{filter}` on the command line")
+            }
+            Self::ConfigCategory { config, category } => {
+                let category = format!("{category}").to_lowercase();
+                write!(f, "`categories.{category}` in {}", config_name(config.as_ref()))
+            }
+            Self::ConfigRule { config, options } => {
+                write!(f, "`rules` in {}", config_name(config.as_ref()))?;
+                write_options(f, options.as_ref())
+            }
+            Self::Override { config, index, options } => {
+                write!(f, "`overrides[{index}].rules` in {}", config_name(config.as_ref()))?;
+                write_options(f, options.as_ref())
+            }
+        }
+    }
+}
+
+fn write_options(f: &mut fmt::Formatter<'_>, options: Option<&serde_json::Value>) -> fmt::Result {
+    match options {
+        Some(options) => write!(f, " with options {options}"),
+        None => Ok(()),
+    }
+}
+
+impl fmt::Display for RuleExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rule: {}", self.rule_name)?;
+        writeln!(f, "File: {}", self.path.display())?;
+        writeln!(f, "Severity: {}", severity_name(self.severity))?;
+        if !self.plugin_enabled {
+            writeln!(f, "The plugin of this rule is not enabled.")?;
+        }
+        if self.settings.is_empty() {
+            writeln!(f, "No setting applies to this rule.")?;
+        } else {
+            writeln!(f, "Settings, in order of application (the last one takes precedence):")?;
+            for setting in &self.settings {
+                writeln!(f, "  {:<5} {}", severity_name(setting.severity), setting.source)?;
+            }
+        }
+        if let Some(options) = &self.options {
+            writeln!(f, "Options: {options}")?;
+        }
+        Ok(())
+    }
+}
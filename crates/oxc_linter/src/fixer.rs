@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use bitflags::bitflags;
 use oxc_ast::Trivias;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::OxcDiagnostic;
@@ -7,6 +8,22 @@ use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
 
+bitflags! {
+    /// Kinds of fixes provided by a rule, see [`crate::RuleMeta::FIX`].
+    ///
+    /// Also the kinds of fixes to apply, see [`crate::LintOptions::fix`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct FixKind: u8 {
+        const None = 0;
+        /// Keeps the behavior of the code, applied by `--fix`.
+        const SafeFix = 1 << 0;
+        /// May not be what the author intended, applied by `--fix-suggestions`.
+        const Suggestion = 1 << 1;
+        /// May change the behavior of the code, applied by `--fix-dangerously`.
+        const DangerousFix = 1 << 2;
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
@@ -23,6 +40,52 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// The edits of a fix, applied all together or not at all.
+#[derive(Debug, Clone)]
+pub enum CompositeFix<'a> {
+    Single(Fix<'a>),
+    /// Disjoint edits, sorted by their span.
+    Multiple(Vec<Fix<'a>>),
+}
+
+impl<'a> From<Fix<'a>> for CompositeFix<'a> {
+    fn from(fix: Fix<'a>) -> Self {
+        Self::Single(fix)
+    }
+}
+
+impl<'a> From<Vec<Fix<'a>>> for CompositeFix<'a> {
+    fn from(mut fixes: Vec<Fix<'a>>) -> Self {
+        fixes.sort_by_key(|fix| (fix.span.start, fix.span.end));
+        Self::Multiple(fixes)
+    }
+}
+
+impl<'a> CompositeFix<'a> {
+    pub fn edits(&self) -> &[Fix<'a>] {
+        match self {
+            Self::Single(fix) => std::slice::from_ref(fix),
+            Self::Multiple(fixes) => fixes,
+        }
+    }
+
+    /// The span from the start of the first edit to the end of the last edit.
+    pub fn span(&self) -> Span {
+        let edits = self.edits();
+        match (edits.first(), edits.last()) {
+            (Some(first), Some(last)) => Span::new(first.span.start, last.span.end),
+            _ => Span::default(),
+        }
+    }
+
+    /// Whether every edit has a valid span, and no edits overlap.
+    fn is_valid(&self) -> bool {
+        let edits = self.edits();
+        edits.iter().all(|edit| edit.span.start <= edit.span.end)
+            && edits.windows(2).all(|pair| pair[0].span.end <= pair[1].span.start)
+    }
+}
+
 /// Inspired by ESLint's [`RuleFixer`].
 ///
 /// [`RuleFixer`]: https://github.com/eslint/eslint/blob/main/lib/linter/rule-fixer.js
@@ -63,14 +126,58 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         Fix::new(replacement, target)
     }
 
+    /// Replace several disjoint spans at once, e.g. a call site and the import it needs.
+    #[allow(clippy::unused_self)]
+    pub fn replace_many<S, I>(self, replacements: I) -> CompositeFix<'a>
+    where
+        S: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = (Span, S)>,
+    {
+        replacements
+            .into_iter()
+            .map(|(target, replacement)| Fix::new(replacement, target))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Insert `text` before the given [`Span`] or AST node.
+    pub fn insert_text_before<T: GetSpan, S: Into<Cow<'a, str>>>(
+        self,
+        target: &T,
+        text: S,
+    ) -> Fix<'a> {
+        self.insert_text_before_range(target.span(), text)
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn insert_text_before_range<S: Into<Cow<'a, str>>>(self, span: Span, text: S) -> Fix<'a> {
+        Fix::new(text, Span::new(span.start, span.start))
+    }
+
+    /// Insert `text` after the given [`Span`] or AST node.
+    pub fn insert_text_after<T: GetSpan, S: Into<Cow<'a, str>>>(
+        self,
+        target: &T,
+        text: S,
+    ) -> Fix<'a> {
+        self.insert_text_after_range(target.span(), text)
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn insert_text_after_range<S: Into<Cow<'a, str>>>(self, span: Span, text: S) -> Fix<'a> {
+        Fix::new(text, Span::new(span.end, span.end))
+    }
+
     #[allow(clippy::unused_self)]
     pub fn codegen(self) -> Codegen<'a, false> {
         Codegen::<false>::new("", "", Trivias::default(), CodegenOptions::default())
     }
 }
 
+/// Maximum number of passes of `--fix`, same as ESLint.
+pub const MAX_FIX_PASSES: usize = 10;
+
 pub struct FixResult<'a> {
-    #[allow(unused)]
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message<'a>>,
@@ -81,13 +188,15 @@ pub struct Message<'a> {
     pub error: OxcDiagnostic,
     pub start: u32,
     pub end: u32,
-    pub fix: Option<Fix<'a>>,
+    pub fix: Option<CompositeFix<'a>>,
+    /// Kind of `fix`.
+    pub fix_kind: FixKind,
     fixed: bool,
 }
 
 impl<'a> Message<'a> {
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
-    pub fn new(error: OxcDiagnostic, fix: Option<Fix<'a>>) -> Self {
+    pub fn new(error: OxcDiagnostic, fix: Option<CompositeFix<'a>>) -> Self {
         let (start, end) = if let Some(labels) = &error.labels {
             let start = labels
                 .iter()
@@ -101,7 +210,13 @@ impl<'a> Message<'a> {
         } else {
             (0, 0)
         };
-        Self { error, start, end, fix, fixed: false }
+        Self { error, start, end, fix, fix_kind: FixKind::SafeFix, fixed: false }
+    }
+
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixKind) -> Self {
+        self.fix_kind = fix_kind;
+        self
     }
 
     pub fn start(&self) -> u32 {
@@ -136,31 +251,43 @@ impl<'a> Fixer<'a> {
             };
         }
 
-        self.messages.sort_by_key(|m| m.fix.as_ref().unwrap_or(&Fix::default()).span);
-        let mut fixed = false;
-        let mut output = String::with_capacity(source_text.len());
-        let mut last_pos: i64 = -1;
-        self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
-            let start = span.start;
-            let end = span.end;
-            if start > end {
-                return;
+        self.messages.sort_by_key(|m| m.fix.as_ref().map_or(Span::default(), CompositeFix::span));
+        // Apply a fix only if none of its edits overlaps or touches an edit of the applied fixes.
+        let mut applied_spans: Vec<Span> = vec![];
+        for m in &mut self.messages {
+            let Some(fix) = &m.fix else { continue };
+            if !fix.is_valid() {
+                continue;
             }
-            if i64::from(start) <= last_pos {
-                return;
+            let overlaps = fix.edits().iter().any(|edit| {
+                applied_spans
+                    .iter()
+                    .any(|applied| edit.span.start <= applied.end && applied.start <= edit.span.end)
+            });
+            if overlaps {
+                continue;
             }
-
+            applied_spans.extend(fix.edits().iter().map(|edit| edit.span));
             m.fixed = true;
-            fixed = true;
-            let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-            output.push_str(&source_text[offset..start as usize]);
-            output.push_str(content);
-            last_pos = i64::from(end);
-        });
+        }
 
-        let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-        output.push_str(&source_text[offset..]);
+        let mut edits = self
+            .messages
+            .iter()
+            .filter(|m| m.fixed)
+            .filter_map(|m| m.fix.as_ref())
+            .flat_map(CompositeFix::edits)
+            .collect::<Vec<_>>();
+        edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+        let fixed = !edits.is_empty();
+        let mut output = String::with_capacity(source_text.len());
+        let mut last_pos = 0;
+        for Fix { content, span } in edits {
+            output.push_str(&source_text[last_pos..span.start as usize]);
+            output.push_str(content);
+            last_pos = span.end as usize;
+        }
+        output.push_str(&source_text[last_pos..]);
 
         let mut messages = self.messages.into_iter().filter(|m| !m.fixed).collect::<Vec<_>>();
         messages.sort_by_key(|m| (m.start, m.end));
@@ -175,7 +302,7 @@ mod test {
     use oxc_diagnostics::OxcDiagnostic;
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{CompositeFix, Fix, FixResult, Fixer, Message};
 
     fn insert_at_end() -> OxcDiagnostic {
         OxcDiagnostic::warn("End")
@@ -246,7 +373,7 @@ mod test {
     }
 
     fn create_message(error: OxcDiagnostic, fix: Option<Fix>) -> Message {
-        Message::new(error, fix)
+        Message::new(error, fix.map(CompositeFix::from))
     }
 
     #[test]
@@ -436,6 +563,47 @@ mod test {
         assert!(!result.fixed);
     }
 
+    #[test]
+    fn apply_composite_fix() {
+        let fix = CompositeFix::from(vec![REPLACE_NUM, REPLACE_VAR]);
+        let result = get_fix_result(vec![Message::new(replace_var(), Some(fix))]);
+        assert_eq!(result.fixed_code, "let answer = 5 * 7;");
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
+    #[test]
+    fn skip_composite_fix_when_one_edit_overlaps() {
+        let fix = CompositeFix::from(vec![REPLACE_VAR, REPLACE_NUM]);
+        let result = get_fix_result(vec![
+            create_message(remove_middle(Span::default()), Some(REMOVE_START)),
+            Message::new(replace_var(), Some(fix)),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.replace("var ", ""));
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].error.to_string(), "let");
+        assert!(result.fixed);
+    }
+
+    #[test]
+    fn apply_fix_between_the_edits_of_a_composite_fix() {
+        let fix = CompositeFix::from(vec![REPLACE_VAR, REPLACE_NUM]);
+        let result = get_fix_result(vec![
+            Message::new(replace_var(), Some(fix)),
+            create_message(replace_id(), Some(REPLACE_ID)),
+        ]);
+        assert_eq!(result.fixed_code, "let foo = 5 * 7;");
+        assert_eq!(result.messages.len(), 0);
+    }
+
+    #[test]
+    fn ignore_composite_fix_with_overlapping_edits() {
+        let fix = CompositeFix::from(vec![REPLACE_ID, REMOVE_MIDDLE]);
+        let result = get_fix_result(vec![Message::new(replace_id(), Some(fix))]);
+        assert_eq!(result.fixed_code, TEST_CODE);
+        assert!(!result.fixed);
+    }
+
     #[test]
     fn sort_no_fix_messages_correctly() {
         let result = get_fix_result(vec![
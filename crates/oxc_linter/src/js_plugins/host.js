@@ -0,0 +1,486 @@
+// Host of the rules of the JS plugins, run by `node` and driven by `js_plugins/mod.rs`.
+//
+// Reads one JSON request per line on stdin and writes one JSON response per line on stdout:
+//   { path, source, ast, comments, rules: [{ name, plugin, specifier, rule, options }] }
+//   => { reports: [{ rule, message, start, end, fix, suggestion }], errors: [string] }
+// Offsets in requests and responses are UTF-8 byte offsets, as in oxc spans,
+// and are converted from and to the UTF-16 offsets seen by the rules.
+'use strict';
+
+const { createRequire } = require('node:module');
+const path = require('node:path');
+const readline = require('node:readline');
+const { pathToFileURL } = require('node:url');
+
+// stdout is reserved for the responses.
+const writeResponse = process.stdout.write.bind(process.stdout);
+console.log = console.info = console.debug = console.error;
+
+/** @type {Map<string, Promise<any>>} plugin modules by specifier */
+const plugins = new Map();
+
+function loadPlugin(specifier) {
+  let plugin = plugins.get(specifier);
+  if (!plugin) {
+    plugin = (async () => {
+      const require = createRequire(path.join(process.cwd(), 'noop.js'));
+      const resolved = require.resolve(specifier);
+      const module = await import(pathToFileURL(resolved).href);
+      return module.default ?? module;
+    })();
+    plugins.set(specifier, plugin);
+  }
+  return plugin;
+}
+
+/** Largest `u32`, never a valid offset. */
+const INVALID_OFFSET = 0xffffffff;
+
+/** Conversions between the UTF-8 offsets of oxc and the UTF-16 offsets of JS strings. */
+class Offsets {
+  constructor(source) {
+    const bytes = Buffer.byteLength(source);
+    this.bytes = bytes;
+    this.length = source.length;
+    this.identity = bytes === source.length;
+    if (this.identity) return;
+    this.toUtf16 = new Uint32Array(bytes + 1);
+    this.toUtf8 = new Uint32Array(source.length + 1);
+    let utf8 = 0;
+    for (let utf16 = 0; utf16 < source.length; utf16++) {
+      const code = source.charCodeAt(utf16);
+      this.toUtf8[utf16] = utf8;
+      let width;
+      if (code < 0x80) width = 1;
+      else if (code < 0x800) width = 2;
+      else if (code >= 0xd800 && code <= 0xdbff) width = 4;
+      else if (code >= 0xdc00 && code <= 0xdfff) width = 0; // second half of a surrogate pair
+      else width = 3;
+      for (let i = 0; i < width; i++) this.toUtf16[utf8 + i] = utf16;
+      utf8 += width;
+    }
+    this.toUtf16[utf8] = source.length;
+    this.toUtf8[source.length] = utf8;
+  }
+
+  utf16(offset) {
+    return this.identity ? offset : this.toUtf16[offset];
+  }
+
+  /** Offsets outside of the source stay outside of it, for oxc to reject the report or the fix. */
+  utf8(offset) {
+    if (!Number.isInteger(offset) || offset < 0) return INVALID_OFFSET;
+    if (offset > this.length) return Math.min(this.bytes + (offset - this.length), INVALID_OFFSET);
+    return this.identity ? offset : this.toUtf8[offset];
+  }
+}
+
+class SourceCode {
+  constructor(text, ast, comments) {
+    this.text = text;
+    this.ast = ast;
+    this.comments = comments;
+    this.lines = text.split(/\r\n|[\r\n\u2028\u2029]/u);
+    this.lineStarts = [0];
+    for (const match of text.matchAll(/\r\n|[\r\n\u2028\u2029]/gu)) {
+      this.lineStarts.push(match.index + match[0].length);
+    }
+  }
+
+  getText(node, beforeCount = 0, afterCount = 0) {
+    if (!node) return this.text;
+    return this.text.slice(Math.max(node.range[0] - beforeCount, 0), node.range[1] + afterCount);
+  }
+
+  getLines() {
+    return this.lines;
+  }
+
+  getAllComments() {
+    return this.comments;
+  }
+
+  getLocFromIndex(index) {
+    let low = 0;
+    let high = this.lineStarts.length - 1;
+    while (low < high) {
+      const mid = (low + high + 1) >> 1;
+      if (this.lineStarts[mid] <= index) low = mid;
+      else high = mid - 1;
+    }
+    return { line: low + 1, column: index - this.lineStarts[low] };
+  }
+
+  getIndexFromLoc(loc) {
+    return this.lineStarts[loc.line - 1] + loc.column;
+  }
+
+  getAncestors(node) {
+    const ancestors = [];
+    for (let parent = node.parent; parent; parent = parent.parent) ancestors.unshift(parent);
+    return ancestors;
+  }
+}
+
+const LITERALS = new Set([
+  'BooleanLiteral',
+  'NullLiteral',
+  'NumericLiteral',
+  'StringLiteral',
+  'BigIntLiteral',
+  'RegExpLiteral',
+]);
+const MEMBER_EXPRESSIONS = new Set([
+  'StaticMemberExpression',
+  'ComputedMemberExpression',
+  'PrivateFieldExpression',
+]);
+const SKIPPED_KEYS = new Set(['type', 'start', 'end', 'range', 'loc', 'parent', 'scopeId', 'scope_id']);
+
+function isNode(value) {
+  return value !== null && typeof value === 'object' && typeof value.type === 'string';
+}
+
+function directiveStatement(directive) {
+  return {
+    type: 'ExpressionStatement',
+    start: directive.start,
+    end: directive.end,
+    expression: directive.expression,
+    directive: directive.directive,
+  };
+}
+
+/** Rewrite the nodes of oxc which differ from ESTree, returning the node to use in place of `node`. */
+function toEstree(node, context) {
+  if (LITERALS.has(node.type)) {
+    const { offsets, source } = context;
+    const raw = node.raw ?? source.slice(offsets.utf16(node.start), offsets.utf16(node.end));
+    const literal = { type: 'Literal', start: node.start, end: node.end, value: node.value, raw };
+    if (node.type === 'NullLiteral') literal.value = null;
+    if (node.type === 'BigIntLiteral') {
+      literal.bigint = raw.slice(0, -1).replaceAll('_', '');
+      literal.value = BigInt(literal.bigint);
+    }
+    if (node.type === 'RegExpLiteral') {
+      literal.regex = node.regex;
+      try {
+        literal.value = new RegExp(node.regex.pattern, node.regex.flags);
+      } catch {
+        literal.value = null;
+      }
+    }
+    return literal;
+  }
+  if (MEMBER_EXPRESSIONS.has(node.type)) {
+    return {
+      type: 'MemberExpression',
+      start: node.start,
+      end: node.end,
+      object: node.object,
+      property: node.property ?? node.expression ?? node.field,
+      computed: node.type === 'ComputedMemberExpression',
+      optional: node.optional,
+    };
+  }
+  switch (node.type) {
+    case 'ParenthesizedExpression':
+      return node.expression;
+    case 'Program':
+      node.body = [...node.directives.map(directiveStatement), ...node.body];
+      delete node.directives;
+      node.sourceType = node.sourceType?.moduleKind === 'script' ? 'script' : 'module';
+      node.comments = [];
+      return node;
+    case 'FunctionBody':
+      return {
+        type: 'BlockStatement',
+        start: node.start,
+        end: node.end,
+        body: [...node.directives.map(directiveStatement), ...node.statements],
+      };
+    case 'FormalParameters':
+      return node.items.map((item) => (item.type === 'FormalParameter' ? item.pattern : item));
+    case 'CatchParameter':
+      return node.pattern;
+    case 'ObjectProperty':
+      node.type = 'Property';
+      delete node.init;
+      return node;
+    case 'BindingProperty':
+    case 'AssignmentTargetPropertyProperty':
+      return {
+        type: 'Property',
+        start: node.start,
+        end: node.end,
+        key: node.key ?? node.name,
+        value: node.value ?? node.binding,
+        kind: 'init',
+        method: false,
+        shorthand: node.shorthand ?? false,
+        computed: node.computed ?? false,
+      };
+    case 'AssignmentTargetPropertyIdentifier': {
+      const value = node.init
+        ? { type: 'AssignmentPattern', start: node.start, end: node.end, left: node.binding, right: node.init }
+        : node.binding;
+      return {
+        type: 'Property',
+        start: node.start,
+        end: node.end,
+        key: node.binding,
+        value,
+        kind: 'init',
+        method: false,
+        shorthand: true,
+        computed: false,
+      };
+    }
+    case 'ArrayAssignmentTarget':
+      node.type = 'ArrayPattern';
+      return node;
+    case 'ObjectAssignmentTarget':
+      node.type = 'ObjectPattern';
+      return node;
+    case 'AssignmentTargetWithDefault':
+      return { type: 'AssignmentPattern', start: node.start, end: node.end, left: node.binding, right: node.init };
+    case 'ArrowFunctionExpression':
+      if (node.expression && node.body.type === 'FunctionBody') {
+        node.body = node.body.statements[0].expression;
+      }
+      return node;
+    case 'FunctionDeclaration':
+    case 'FunctionExpression':
+      node.expression = false;
+      return node;
+    default:
+      return node;
+  }
+}
+
+/** Convert `node` and its children to ESTree in place, with their `range`, `loc` and `parent`. */
+function prepare(node, parent, context) {
+  for (let next = toEstree(node, context); next !== node; next = toEstree(node, context)) {
+    node = next;
+    if (!isNode(node)) break;
+  }
+  if (Array.isArray(node)) {
+    return node.map((item) => prepare(item, parent, context));
+  }
+  // shorthand properties use the same identifier as their key and value
+  if (!isNode(node) || context.prepared.has(node)) return node;
+  context.prepared.add(node);
+  for (const key of Object.keys(node)) {
+    if (SKIPPED_KEYS.has(key)) continue;
+    const value = node[key];
+    if (Array.isArray(value)) {
+      node[key] = value.flatMap((item) => {
+        if (!isNode(item)) return [item];
+        const prepared = prepare(item, node, context);
+        return Array.isArray(prepared) ? prepared : [prepared];
+      });
+    } else if (isNode(value)) {
+      node[key] = prepare(value, node, context);
+    }
+  }
+  node.start = context.offsets.utf16(node.start);
+  node.end = context.offsets.utf16(node.end);
+  node.range = [node.start, node.end];
+  Object.defineProperty(node, 'parent', { value: parent, writable: true, enumerable: false });
+  Object.defineProperty(node, 'loc', {
+    enumerable: false,
+    get() {
+      return {
+        start: context.sourceCode.getLocFromIndex(this.start),
+        end: context.sourceCode.getLocFromIndex(this.end),
+      };
+    },
+  });
+  return node;
+}
+
+/** Names of the node types a visitor key listens to, `null` for selectors which are not supported. */
+function parseSelector(key) {
+  const types = key.split(',').map((type) => type.trim());
+  return types.every((type) => /^(\*|[A-Za-z]+)(:exit)?$/.test(type)) ? types : null;
+}
+
+const FIXER = {
+  insertTextAfter: (node, text) => FIXER.insertTextAfterRange(node.range, text),
+  insertTextAfterRange: (range, text) => ({ range: [range[1], range[1]], text }),
+  insertTextBefore: (node, text) => FIXER.insertTextBeforeRange(node.range, text),
+  insertTextBeforeRange: (range, text) => ({ range: [range[0], range[0]], text }),
+  remove: (node) => FIXER.removeRange(node.range),
+  removeRange: (range) => ({ range, text: '' }),
+  replaceText: (node, text) => FIXER.replaceTextRange(node.range, text),
+  replaceTextRange: (range, text) => ({ range, text }),
+};
+
+function collectFix(fix, offsets) {
+  if (typeof fix !== 'function') return null;
+  const result = fix(FIXER);
+  if (!result) return null;
+  const fixes = typeof result[Symbol.iterator] === 'function' ? [...result] : [result];
+  const edits = fixes
+    .filter(Boolean)
+    .map(({ range, text }) => ({ start: offsets.utf8(range[0]), end: offsets.utf8(range[1]), text }));
+  return edits.length > 0 ? edits : null;
+}
+
+function interpolate(message, data) {
+  if (!data) return message;
+  return message.replace(/\{\{\s*([^{}]+?)\s*\}\}/g, (text, key) => (key in data ? String(data[key]) : text));
+}
+
+function createContext(setting, ruleModule, context, reports) {
+  const meta = ruleModule.meta ?? {};
+  const messageOf = (descriptor) => {
+    if (descriptor.messageId !== undefined) {
+      const message = meta.messages?.[descriptor.messageId];
+      if (message === undefined) throw new Error(`Unknown messageId \`${descriptor.messageId}\``);
+      return interpolate(message, descriptor.data);
+    }
+    return interpolate(descriptor.message ?? descriptor.desc ?? '', descriptor.data);
+  };
+  const { sourceCode, offsets } = context;
+  return {
+    id: setting.name,
+    options: setting.options,
+    settings: {},
+    languageOptions: {},
+    parserOptions: {},
+    filename: context.path,
+    physicalFilename: context.path,
+    cwd: process.cwd(),
+    sourceCode,
+    getSourceCode: () => sourceCode,
+    getFilename: () => context.path,
+    getPhysicalFilename: () => context.path,
+    getCwd: () => process.cwd(),
+    getAncestors: () => sourceCode.getAncestors(context.currentNode),
+    report(descriptor, ...legacy) {
+      if (isNode(descriptor)) {
+        descriptor = { node: descriptor, message: legacy[0], data: legacy[1] };
+      }
+      let start;
+      let end;
+      if (descriptor.loc) {
+        const loc = descriptor.loc.start ? descriptor.loc : { start: descriptor.loc, end: descriptor.loc };
+        start = sourceCode.getIndexFromLoc(loc.start);
+        end = sourceCode.getIndexFromLoc(loc.end);
+      } else {
+        [start, end] = descriptor.node.range;
+      }
+      if (descriptor.fix && !meta.fixable) {
+        throw new Error('Fixable rules must set the `meta.fixable` property to "code" or "whitespace".');
+      }
+      const suggestion = (descriptor.suggest ?? []).find((suggest) => suggest.fix);
+      reports.push({
+        rule: setting.name,
+        message: messageOf(descriptor),
+        start: offsets.utf8(start),
+        end: offsets.utf8(end),
+        fix: collectFix(descriptor.fix, offsets),
+        suggestion: suggestion ? collectFix(suggestion.fix, offsets) : null,
+      });
+    },
+  };
+}
+
+async function lint(request) {
+  const reports = [];
+  const errors = [];
+  const offsets = new Offsets(request.source);
+  const comments = request.comments.map((comment) => {
+    const start = offsets.utf16(comment.start);
+    const end = offsets.utf16(comment.end);
+    return { type: comment.type, value: comment.value, start, end, range: [start, end] };
+  });
+  const context = {
+    source: request.source,
+    path: request.path,
+    offsets,
+    prepared: new WeakSet(),
+    currentNode: null,
+  };
+  context.sourceCode = new SourceCode(request.source, null, comments);
+  const ast = prepare(request.ast, null, context);
+  ast.comments = comments;
+  context.sourceCode.ast = ast;
+
+  /** @type {Map<string, Array<[string, Function]>>} listeners by node type, with their rule */
+  const listeners = new Map();
+  const listen = (key, name, listener) => {
+    if (!listeners.has(key)) listeners.set(key, []);
+    listeners.get(key).push([name, listener]);
+  };
+  for (const setting of request.rules) {
+    try {
+      const plugin = await loadPlugin(setting.specifier);
+      const ruleModule = plugin.rules?.[setting.rule];
+      if (!ruleModule) {
+        errors.push(`Rule \`${setting.rule}\` not found in JS plugin \`${setting.plugin}\` (${setting.specifier})`);
+        continue;
+      }
+      const create = typeof ruleModule === 'function' ? ruleModule : ruleModule.create;
+      const visitor = create(createContext(setting, ruleModule, context, reports)) ?? {};
+      for (const [key, listener] of Object.entries(visitor)) {
+        if (typeof listener !== 'function' || key.startsWith('onCodePath')) continue;
+        const types = parseSelector(key);
+        if (!types) {
+          errors.push(`Unsupported selector \`${key}\` in rule \`${setting.name}\`, only node types are supported`);
+          continue;
+        }
+        types.forEach((type) => listen(type, setting.name, listener));
+      }
+    } catch (error) {
+      errors.push(`Failed to run rule \`${setting.name}\` of JS plugin \`${setting.plugin}\`: ${error?.stack ?? error}`);
+    }
+  }
+
+  const failed = new Set();
+  const emit = (key, node) => {
+    for (const [name, listener] of listeners.get(key) ?? []) {
+      if (failed.has(name)) continue;
+      try {
+        listener(node);
+      } catch (error) {
+        failed.add(name);
+        errors.push(`Failed to run rule \`${name}\`: ${error?.stack ?? error}`);
+      }
+    }
+  };
+  const walk = (node) => {
+    context.currentNode = node;
+    emit('*', node);
+    emit(node.type, node);
+    for (const key of Object.keys(node)) {
+      if (SKIPPED_KEYS.has(key)) continue;
+      const value = node[key];
+      if (Array.isArray(value)) {
+        value.forEach((item) => isNode(item) && walk(item));
+      } else if (isNode(value)) {
+        walk(value);
+      }
+    }
+    context.currentNode = node;
+    emit(`${node.type}:exit`, node);
+    emit('*:exit', node);
+  };
+  if (listeners.size > 0) walk(ast);
+
+  return { reports: reports.filter((report) => !failed.has(report.rule)), errors };
+}
+
+let queue = Promise.resolve();
+readline.createInterface({ input: process.stdin }).on('line', (line) => {
+  queue = queue.then(async () => {
+    let response;
+    try {
+      response = await lint(JSON.parse(line));
+    } catch (error) {
+      response = { reports: [], errors: [`JS plugin host: ${error?.stack ?? error}`] };
+    }
+    writeResponse(`${JSON.stringify(response)}\n`);
+  });
+});
@@ -0,0 +1,274 @@
+//! Rules of the ESLint plugins listed in the `jsPlugins` of the configuration.
+//!
+//! The rules run in `node` processes running [`HOST`], one file at a time per process.
+//! Each process receives the ESTree shape of the AST, produced by the `serialize` feature of `oxc_ast`,
+//! and returns the reports of the rules, which become diagnostics and fixes.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::Mutex,
+};
+
+use oxc_ast::{AstKind, CommentKind};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::AstNode;
+use oxc_span::Span;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    fixer::{CompositeFix, Fix, FixKind, Message},
+    AllowWarnDeny, LintContext,
+};
+
+/// Script run by each `node` process, see its header for the protocol.
+const HOST: &str = include_str!("host.js");
+
+/// A rule of a JS plugin enabled for a file.
+#[derive(Debug, Clone)]
+pub(crate) struct JsRule {
+    /// Name of the plugin in `jsPlugins`.
+    pub plugin: String,
+    /// Path or package name of the plugin module.
+    pub specifier: String,
+    pub rule: String,
+    pub severity: AllowWarnDeny,
+    /// Options following the severity in the configuration, `[]` if none.
+    pub options: serde_json::Value,
+}
+
+impl JsRule {
+    /// `plugin/rule`, as in the configuration and in disable directives.
+    pub fn name(&self) -> String {
+        format!("{}/{}", self.plugin, self.rule)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    reports: Vec<Report>,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Report {
+    rule: String,
+    message: String,
+    start: u32,
+    end: u32,
+    fix: Option<Vec<Edit>>,
+    suggestion: Option<Vec<Edit>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Edit {
+    start: u32,
+    end: u32,
+    text: String,
+}
+
+struct HostProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl HostProcess {
+    fn spawn() -> std::io::Result<Self> {
+        let mut child = Command::new("node")
+            .args(["-e", HOST])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        Ok(Self { child, stdin, stdout })
+    }
+
+    fn request(&mut self, request: &str) -> std::io::Result<String> {
+        self.stdin.write_all(request.as_bytes())?;
+        self.stdin.write_all(b"\n")?;
+        self.stdin.flush()?;
+        let mut response = String::new();
+        if self.stdout.read_line(&mut response)? == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "the process exited unexpectedly",
+            ));
+        }
+        Ok(response)
+    }
+}
+
+impl Drop for HostProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// The `node` processes running the rules of the JS plugins,
+/// started when a file first needs them and reused for the next files.
+#[derive(Default)]
+pub struct JsPluginHost {
+    /// Idle processes, one is taken from here or started for each file.
+    processes: Mutex<Vec<HostProcess>>,
+}
+
+impl JsPluginHost {
+    /// Run `rules` on the file of `ctx`, returning their diagnostics and the errors of the plugins.
+    pub(crate) fn run<'a>(
+        &self,
+        ctx: &LintContext<'a>,
+        rules: &[JsRule],
+        fix: FixKind,
+    ) -> Vec<Message<'a>> {
+        let process = self.processes.lock().unwrap().pop().map_or_else(HostProcess::spawn, Ok);
+        let mut process = match process {
+            Ok(process) => process,
+            Err(err) => {
+                return vec![error_message(format!(
+                    "Failed to start `node` to run the rules of the JS plugins: {err}"
+                ))];
+            }
+        };
+        let response = match process.request(&Self::request(ctx, rules)) {
+            Ok(response) => response,
+            // The process is dropped, a new one is started for the next file.
+            Err(err) => {
+                return vec![error_message(format!("Failed to run the JS plugins: {err}"))];
+            }
+        };
+        self.processes.lock().unwrap().push(process);
+
+        let response = match serde_json::from_str::<Response>(&response) {
+            Ok(response) => response,
+            Err(err) => {
+                return vec![error_message(format!("Invalid response of the JS plugins: {err}"))];
+            }
+        };
+        let source_text = ctx.source_text();
+        let mut messages = response.errors.into_iter().map(error_message).collect::<Vec<_>>();
+        for report in response.reports {
+            let Some(rule) = rules.iter().find(|rule| rule.name() == report.rule) else {
+                continue;
+            };
+            if !is_valid_range(source_text, report.start, report.end) {
+                messages.push(invalid_range_message(rule, "report", report.start, report.end));
+                continue;
+            }
+            if ctx.disable_directives().contains(&report.rule, report.start) {
+                continue;
+            }
+            Self::push_message(&mut messages, source_text, rule, report, fix);
+        }
+        messages
+    }
+
+    fn request(ctx: &LintContext, rules: &[JsRule]) -> String {
+        let Some(AstKind::Program(program)) = ctx.nodes().root_node().map(AstNode::kind) else {
+            unreachable!("the root node is the program");
+        };
+        let comments = ctx
+            .semantic()
+            .trivias()
+            .comments()
+            .map(|(kind, span)| {
+                // the spans of comments exclude their delimiters
+                let (r#type, start, end) = match kind {
+                    CommentKind::SingleLine => ("Line", span.start - 2, span.end),
+                    CommentKind::MultiLine => ("Block", span.start - 2, span.end + 2),
+                };
+                json!({ "type": r#type, "value": ctx.source_range(span), "start": start, "end": end })
+            })
+            .collect::<Vec<_>>();
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                json!({
+                    "name": rule.name(),
+                    "plugin": rule.plugin,
+                    "specifier": rule.specifier,
+                    "rule": rule.rule,
+                    "options": rule.options,
+                })
+            })
+            .collect::<Vec<_>>();
+        // The AST is serialized on its own, without going through `serde_json::Value`.
+        format!(
+            r#"{{"path":{},"source":{},"comments":{},"rules":{},"ast":{}}}"#,
+            json!(ctx.file_path().to_string_lossy()),
+            json!(ctx.source_text()),
+            json!(comments),
+            json!(rules),
+            program.to_json()
+        )
+    }
+
+    /// Push the message of `report`, whose range is valid, with its fix or suggestion if their
+    /// edits are valid, or with an error message instead of them otherwise.
+    fn push_message(
+        messages: &mut Vec<Message<'_>>,
+        source_text: &str,
+        rule: &JsRule,
+        report: Report,
+        fix: FixKind,
+    ) {
+        let span = Span::new(report.start, report.end);
+        let diagnostic =
+            OxcDiagnostic::warn(format!("{}({}): {}", rule.plugin, rule.rule, report.message))
+                .with_labels([span.into()])
+                .with_severity(Severity::from(rule.severity));
+        let (edits, fix_kind) = match (report.fix, report.suggestion) {
+            (Some(edits_of_fix), _) if fix.contains(FixKind::SafeFix) => {
+                (edits_of_fix, FixKind::SafeFix)
+            }
+            (_, Some(edits_of_suggestion)) if fix.contains(FixKind::Suggestion) => {
+                (edits_of_suggestion, FixKind::Suggestion)
+            }
+            _ => {
+                messages.push(Message::new(diagnostic, None));
+                return;
+            }
+        };
+        if let Some(edit) =
+            edits.iter().find(|edit| !is_valid_range(source_text, edit.start, edit.end))
+        {
+            let kind = if fix_kind == FixKind::Suggestion { "suggestion" } else { "fix" };
+            messages.push(invalid_range_message(rule, kind, edit.start, edit.end));
+            messages.push(Message::new(diagnostic, None));
+            return;
+        }
+        let fixes = edits
+            .into_iter()
+            .map(|edit| Fix::new(edit.text, Span::new(edit.start, edit.end)))
+            .collect::<Vec<_>>();
+        let message = Message::new(diagnostic, Some(CompositeFix::from(fixes)));
+        messages.push(if fix_kind == FixKind::Suggestion {
+            message.with_fix_kind(FixKind::Suggestion)
+        } else {
+            message
+        });
+    }
+}
+
+fn error_message<'a>(message: String) -> Message<'a> {
+    Message::new(OxcDiagnostic::error(message), None)
+}
+
+/// Whether `start..end` is in `source_text` and does not split a character.
+fn is_valid_range(source_text: &str, start: u32, end: u32) -> bool {
+    start <= end
+        && source_text.is_char_boundary(start as usize)
+        && source_text.is_char_boundary(end as usize)
+}
+
+fn invalid_range_message<'a>(rule: &JsRule, kind: &str, start: u32, end: u32) -> Message<'a> {
+    error_message(format!(
+        "Ignored a {kind} of the JS plugin rule `{}` with the invalid range {start}..{end}, \
+        outside of the file or inside a character",
+        rule.name()
+    ))
+}
@@ -4,37 +4,61 @@
 mod tester;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
+mod dead_code;
 mod disable_directives;
+mod explain;
 mod fixer;
 mod globals;
 mod javascript_globals;
+mod js_plugins;
 mod options;
 mod rule;
 mod rules;
+mod selector;
 mod service;
+mod timing;
+mod type_info;
 mod utils;
 
 pub mod partial_loader;
 pub mod table;
 
-use std::{io::Write, rc::Rc, sync::Arc};
+use std::{
+    hash::{Hash, Hasher},
+    io::Write,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use oxc_diagnostics::Error;
-use oxc_semantic::AstNode;
+use dashmap::DashMap;
+use oxc_ast::AstType;
+use oxc_diagnostics::{Error, OxcDiagnostic};
+use oxc_semantic::{AstNode, Semantic};
+use rustc_hash::{FxHashSet, FxHasher};
 
 pub use crate::{
-    config::OxlintConfig,
+    cache::LintCache,
+    config::{OxlintConfig, OxlintGlobs},
     context::LintContext,
+    dead_code::{DeadCodeReport, UnusedExport},
+    explain::{RuleExplanation, RuleSetting, RuleSettingSource},
+    fixer::FixKind,
     options::{AllowWarnDeny, LintOptions},
     rule::{RuleCategory, RuleMeta, RuleWithSeverity},
     service::{LintService, LintServiceOptions},
+    timing::{FileTiming, LintTimings, RuleTiming},
+    type_info::{SemanticTypeInfo, Thenable, TypeInfoProvider},
 };
 use crate::{
-    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    config::{NestedConfigs, OxlintEnv, OxlintGlobals, OxlintSettings},
     fixer::Fix,
     fixer::{Fixer, Message},
+    js_plugins::{JsPluginHost, JsRule},
     rules::RuleEnum,
     table::RuleTable,
 };
@@ -50,10 +74,136 @@ fn size_asserts() {
     assert_eq_size!(RuleEnum, [u8; 16]);
 }
 
-pub struct Linter {
+#[test]
+fn rule_enum_names() {
+    // Only the rules named like a rule declared before them in another plugin are prefixed.
+    let unicorn = RuleEnum::NoProcessExit(rules::NoProcessExit);
+    let node = RuleEnum::NodeNoProcessExit(rules::NodeNoProcessExit);
+    assert_eq!((unicorn.plugin_name(), unicorn.name()), ("unicorn", "no-process-exit"));
+    assert_eq!((node.plugin_name(), node.name()), ("node", "no-process-exit"));
+}
+
+/// Rules and configuration used to lint a file.
+pub(crate) struct ResolvedConfig {
     rules: Vec<RuleWithSeverity>,
+    config: Arc<OxlintConfig>,
+    /// Hash of the rules with their severity and options, of the configuration,
+    /// and of the [`LintOptions`] changing the results. Part of the key of the results in the [`LintCache`].
+    pub(crate) hash: u64,
+    /// Indices in `rules` of the rules to run on the nodes of each [`AstType`], indexed by `AstType as usize`.
+    /// Rules without [`RuleMeta::AST_TYPES`] run on every node.
+    dispatch: Box<[Vec<usize>]>,
+    /// Rules of the `jsPlugins` of the configuration.
+    js_rules: Vec<JsRule>,
+}
+
+impl ResolvedConfig {
+    /// `overrides` are the indices of the `overrides` of `config` matching the files using it.
+    fn new(
+        rules: Vec<RuleWithSeverity>,
+        config: Arc<OxlintConfig>,
+        overrides: &[usize],
+        options: &LintOptions,
+    ) -> Self {
+        let mut hasher = FxHasher::default();
+        for rule in &rules {
+            format!("{:?} {:?}", rule.severity, rule.rule).hash(&mut hasher);
+        }
+        format!("{config:?}").hash(&mut hasher);
+        (options.report_unused_directives, options.type_aware).hash(&mut hasher);
+        let js_rules = config.js_rules(overrides);
+        // The results of the JS plugins change with their code.
+        for specifier in js_rules.iter().map(|rule| &rule.specifier).collect::<FxHashSet<_>>() {
+            std::fs::read(specifier).ok().hash(&mut hasher);
+        }
+
+        let mut dispatch = vec![vec![]; AstType::COUNT].into_boxed_slice();
+        for (index, rule) in rules.iter().enumerate() {
+            match rule.ast_types() {
+                Some(ast_types) => {
+                    for ty in ast_types {
+                        dispatch[*ty as usize].push(index);
+                    }
+                }
+                None => dispatch.iter_mut().for_each(|indices| indices.push(index)),
+            }
+        }
+
+        Self { rules, config, hash: hasher.finish(), dispatch, js_rules }
+    }
+
+    pub(crate) fn rules(&self) -> &[RuleWithSeverity] {
+        &self.rules
+    }
+}
+
+/// Rules and configuration derived from one configuration,
+/// and from each combination of its `overrides` when a file matches them.
+struct ConfigState {
+    base: Arc<ResolvedConfig>,
+    /// Configuration file the configuration was read from, the nearest one for nested configurations.
+    path: Option<PathBuf>,
+    /// Keyed by the indices of the matching overrides.
+    overrides_cache: DashMap<Vec<usize>, Arc<ResolvedConfig>>,
+}
+
+impl ConfigState {
+    fn new(
+        rules: Vec<RuleWithSeverity>,
+        config: OxlintConfig,
+        path: Option<PathBuf>,
+        options: &LintOptions,
+    ) -> Self {
+        Self {
+            base: Arc::new(ResolvedConfig::new(rules, Arc::new(config), &[], options)),
+            path,
+            overrides_cache: DashMap::default(),
+        }
+    }
+
+    fn rules(&self) -> &[RuleWithSeverity] {
+        &self.base.rules
+    }
+
+    fn config(&self) -> &OxlintConfig {
+        &self.base.config
+    }
+
+    /// Rules and configuration for `path`, after applying the matching `overrides`.
+    fn resolve(&self, path: &Path, options: &LintOptions) -> Arc<ResolvedConfig> {
+        let indices = self.config().matching_overrides(path);
+        if indices.is_empty() {
+            return Arc::clone(&self.base);
+        }
+        if let Some(resolved) = self.overrides_cache.get(&indices) {
+            return Arc::clone(resolved.value());
+        }
+        let all_rules = options.get_filtered_rules(self.config().plugins.as_ref());
+        let mut rules = self.rules().iter().cloned().collect::<FxHashSet<_>>();
+        let config = self.config().apply_overrides(&indices, &mut rules, &all_rules);
+        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        // for stable diagnostics output ordering
+        rules.sort_unstable_by_key(|rule| rule.id());
+        let resolved = Arc::new(ResolvedConfig::new(rules, Arc::new(config), &indices, options));
+        self.overrides_cache.insert(indices, Arc::clone(&resolved));
+        resolved
+    }
+}
+
+pub struct Linter {
     options: LintOptions,
-    eslint_config: Arc<OxlintConfig>,
+    /// Rules and configuration given by `options`.
+    state: Arc<ConfigState>,
+    /// `.oxlintrc.json` files found in the directories of the linted files.
+    nested_configs: Option<NestedConfigs>,
+    /// Keyed by the directory of the nearest `.oxlintrc.json` file.
+    nested_states: DashMap<PathBuf, Arc<ConfigState>>,
+    /// Collected when [`LintOptions::timing`] is enabled.
+    timings: Option<Arc<LintTimings>>,
+    /// Given to the rules when type-aware linting is enabled.
+    type_info: Arc<dyn TypeInfoProvider>,
+    /// Runs the rules of the `jsPlugins` of the configurations.
+    js_plugins: JsPluginHost,
 }
 
 impl Default for Linter {
@@ -68,25 +218,58 @@ impl Linter {
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Error> {
         let (rules, eslint_config) = options.derive_rules_and_config()?;
-        Ok(Self { rules, options, eslint_config: Arc::new(eslint_config) })
+        let nested_configs = options
+            .config_lookup_root
+            .as_ref()
+            .map(|root| NestedConfigs::new(root, options.config_path.as_deref()))
+            .transpose()?;
+        let state =
+            Arc::new(ConfigState::new(rules, eslint_config, options.config_path.clone(), &options));
+        let timings = options.timing.then(|| Arc::new(LintTimings::default()));
+        Ok(Self {
+            options,
+            state,
+            nested_configs,
+            nested_states: DashMap::default(),
+            timings,
+            type_info: Arc::new(SemanticTypeInfo),
+            js_plugins: JsPluginHost::default(),
+        })
     }
 
     #[cfg(test)]
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleWithSeverity>) -> Self {
-        self.rules = rules;
+        self.state = Arc::new(ConfigState::new(
+            rules,
+            self.state.config().clone(),
+            self.state.path.clone(),
+            &self.options,
+        ));
         self
     }
 
     #[must_use]
     pub fn with_eslint_config(mut self, eslint_config: OxlintConfig) -> Self {
-        self.eslint_config = Arc::new(eslint_config);
+        self.state = Arc::new(ConfigState::new(
+            self.state.rules().to_vec(),
+            eslint_config,
+            self.state.path.clone(),
+            &self.options,
+        ));
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.options.fix = kind;
         self
     }
 
+    /// Replace the default [`SemanticTypeInfo`] of the type-aware rules.
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.options.fix = yes;
+    pub fn with_type_info_provider(mut self, type_info: Arc<dyn TypeInfoProvider>) -> Self {
+        self.type_info = type_info;
         self
     }
 
@@ -94,39 +277,184 @@ impl Linter {
         &self.options
     }
 
+    /// Time spent linting so far, `None` unless [`LintOptions::timing`] is enabled.
+    pub fn timings(&self) -> Option<&Arc<LintTimings>> {
+        self.timings.as_ref()
+    }
+
+    /// Whether the import plugin is enabled by the options, the configuration or one of the
+    /// nested configurations read so far, in which case the imported modules are resolved
+    /// before linting. The `overrides` do not enable plugins.
+    pub fn import_plugin_enabled(&self) -> bool {
+        std::iter::once(Arc::clone(&self.state))
+            .chain(self.nested_states.iter().map(|state| Arc::clone(state.value())))
+            .any(|state| self.options.is_plugin_enabled("import", state.config().plugins.as_ref()))
+    }
+
+    /// Read the nested configuration files of `paths` ahead of linting them,
+    /// for [`Self::import_plugin_enabled`]. The invalid ones are reported when linting.
+    pub fn read_nested_configs<'a, I>(&self, paths: I)
+    where
+        I: IntoIterator<Item = &'a Path>,
+    {
+        if self.nested_configs.is_none() {
+            return;
+        }
+        for path in paths {
+            let _ = self.resolve_state(path);
+        }
+    }
+
+    /// Unknown plugins and rules of the configuration files read so far, which are ignored.
+    /// The nested configuration files are read while linting the files in their directory.
+    pub fn config_warnings(&self) -> Vec<String> {
+        let mut warnings: Vec<String> = vec![];
+        let mut nested = self
+            .nested_states
+            .iter()
+            .map(|state| (state.key().clone(), Arc::clone(state.value())))
+            .collect::<Vec<_>>();
+        nested.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let nested = nested.into_iter().map(|(_, state)| state);
+        for state in std::iter::once(Arc::clone(&self.state)).chain(nested) {
+            for warning in state.config().warnings() {
+                if !warnings.contains(warning) {
+                    warnings.push(warning.clone());
+                }
+            }
+        }
+        warnings
+    }
+
     pub fn number_of_rules(&self) -> usize {
-        self.rules.len()
+        self.state.rules().len()
+    }
+
+    /// Rules and configuration for `path`, from the nearest `.oxlintrc.json` file if any.
+    pub(crate) fn resolve_config(&self, path: &Path) -> Result<Arc<ResolvedConfig>, OxcDiagnostic> {
+        Ok(self.resolve_state(path)?.resolve(path, &self.options))
+    }
+
+    /// Rules and configuration of the nearest `.oxlintrc.json` file of `path` if any,
+    /// before applying the `overrides` matching `path`.
+    fn resolve_state(&self, path: &Path) -> Result<Arc<ConfigState>, OxcDiagnostic> {
+        let Some(nested_config) =
+            self.nested_configs.as_ref().map(|configs| configs.lookup(path)).transpose()?.flatten()
+        else {
+            return Ok(Arc::clone(&self.state));
+        };
+        if let Some(state) = self.nested_states.get(&nested_config.dir) {
+            return Ok(Arc::clone(state.value()));
+        }
+        let config = nested_config.to_config()?;
+        let rules = self.options.derive_rules(Some(&config));
+        let state =
+            Arc::new(ConfigState::new(rules, config, Some(nested_config.path()), &self.options));
+        self.nested_states.insert(nested_config.dir.clone(), Arc::clone(&state));
+        Ok(state)
     }
 
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
 
-        let ctx = ctx.with_fix(self.options.fix).with_eslint_config(&self.eslint_config);
-        let rules = self
-            .rules
+        let resolved = match self.resolve_config(ctx.file_path()) {
+            Ok(resolved) => resolved,
+            Err(error) => return vec![Message::new(error, None)],
+        };
+        let (rules, eslint_config) = (&resolved.rules, &resolved.config);
+
+        let mut ctx = ctx.with_fix(self.options.fix).with_eslint_config(eslint_config);
+        if self.options.type_aware || eslint_config.type_aware {
+            ctx = ctx.with_type_info(Arc::clone(&self.type_info));
+        }
+        let rules = rules
             .iter()
             .map(|rule| {
                 (rule, ctx.clone().with_rule_name(rule.name()).with_severity(rule.severity))
             })
             .collect::<Vec<_>>();
 
-        for (rule, ctx) in &rules {
+        if let Some(timings) = &self.timings {
+            Self::run_rules_timed(&semantic, &rules, &resolved.dispatch, timings);
+        } else {
+            for (rule, ctx) in &rules {
+                rule.run_once(ctx);
+            }
+
+            for symbol in semantic.symbols().iter() {
+                for (rule, ctx) in &rules {
+                    rule.run_on_symbol(symbol, ctx);
+                }
+            }
+
+            for node in semantic.nodes().iter() {
+                for &index in &resolved.dispatch[node.kind().ty() as usize] {
+                    let (rule, ctx) = &rules[index];
+                    rule.run(node, ctx);
+                }
+            }
+        }
+
+        let mut messages =
+            rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>();
+
+        if !resolved.js_rules.is_empty() {
+            messages.extend(self.js_plugins.run(&ctx, &resolved.js_rules, self.options.fix));
+        }
+
+        if self.options.report_unused_directives || eslint_config.report_unused_disable_directives {
+            let fix = self.options.fix.contains(FixKind::SafeFix);
+            // Rule names of other linters, and of rules not enabled for this file, are left alone.
+            let is_enabled = |name: &str| {
+                let rule_name = name.rsplit_once('/').map_or(name, |(_, rule_name)| rule_name);
+                resolved.rules.iter().any(|rule| rule.name() == rule_name)
+                    || resolved.js_rules.iter().any(|rule| rule.name() == name)
+            };
+            messages.extend(
+                ctx.disable_directives().unused_directives(is_enabled).into_iter().map(|unused| {
+                    let fix = fix.then(|| Fix::delete(unused.removal_span).into());
+                    Message::new(unused.diagnostic(), fix)
+                }),
+            );
+        }
+
+        messages
+    }
+
+    /// Same as the loops of [`Linter::run`], measuring the time spent by each rule.
+    /// Kept separate so that the untimed loops pay nothing for it.
+    fn run_rules_timed<'a>(
+        semantic: &Semantic<'a>,
+        rules: &[(&RuleWithSeverity, LintContext<'a>)],
+        dispatch: &[Vec<usize>],
+        timings: &LintTimings,
+    ) {
+        let mut times = vec![Duration::ZERO; rules.len()];
+
+        for ((rule, ctx), time) in rules.iter().zip(&mut times) {
+            let start = Instant::now();
             rule.run_once(ctx);
+            *time += start.elapsed();
         }
 
         for symbol in semantic.symbols().iter() {
-            for (rule, ctx) in &rules {
+            for ((rule, ctx), time) in rules.iter().zip(&mut times) {
+                let start = Instant::now();
                 rule.run_on_symbol(symbol, ctx);
+                *time += start.elapsed();
             }
         }
 
         for node in semantic.nodes().iter() {
-            for (rule, ctx) in &rules {
+            for &index in &dispatch[node.kind().ty() as usize] {
+                let (rule, ctx) = &rules[index];
+                let start = Instant::now();
                 rule.run(node, ctx);
+                times[index] += start.elapsed();
             }
         }
 
-        rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>()
+        timings.add_rules(rules.iter().zip(times).map(|((rule, _), time)| (rule.id(), time)));
     }
 
     /// # Panics
@@ -142,7 +470,37 @@ impl Linter {
 
 #[cfg(test)]
 mod test {
-    use super::{Linter, OxlintConfig};
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use serde::Deserialize;
+
+    use super::{LintContext, LintOptions, Linter, OxlintConfig};
+
+    fn run_linter(linter: &Linter, path: &str, source_text: &str) -> usize {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(path).unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let ctx = LintContext::new(Path::new(path).into(), Rc::new(semantic));
+        linter.run(ctx).len()
+    }
+
+    #[test]
+    fn timing() {
+        let linter = Linter::from_options(LintOptions::default().with_timing(true)).unwrap();
+        assert_eq!(run_linter(&linter, "test.js", "debugger;"), 1);
+        let timings = linter.timings().unwrap();
+        let rules = timings.rules();
+        assert_eq!(rules.len(), linter.number_of_rules());
+        assert!(rules.iter().any(|rule| rule.rule_name == "eslint/no-debugger"));
+        assert!(rules.windows(2).all(|pair| pair[0].time >= pair[1].time));
+        assert!(Linter::default().timings().is_none());
+    }
 
     #[test]
     fn print_rules() {
@@ -151,6 +509,136 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn overrides() {
+        let config = OxlintConfig::deserialize(&serde_json::json!({
+            "rules": { "no-debugger": "error" },
+            "overrides": [
+                { "files": ["*.test.js"], "rules": { "no-debugger": "off" } },
+                { "files": ["scripts/**"], "rules": { "no-console": "error" } }
+            ]
+        }))
+        .unwrap();
+        let linter = Linter::default().with_eslint_config(config);
+        let source_text = "debugger; console.log()";
+        assert_eq!(run_linter(&linter, "src/foo.js", source_text), 1);
+        assert_eq!(run_linter(&linter, "src/foo.test.js", source_text), 0);
+        assert_eq!(run_linter(&linter, "scripts/foo.js", source_text), 2);
+        assert_eq!(run_linter(&linter, "scripts/foo.test.js", source_text), 1);
+    }
+
+    #[test]
+    fn js_plugins() {
+        use super::{FixKind, Fixer};
+
+        /// Messages with their severity and offsets, and the code fixed by the safe fixes.
+        fn lint(linter: &Linter, path: &str, source_text: &str) -> (Vec<String>, String) {
+            let allocator = Allocator::default();
+            let source_type = SourceType::from_path(path).unwrap();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let semantic = SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build(program)
+                .semantic;
+            let ctx = LintContext::new(Path::new(path).into(), Rc::new(semantic));
+            let messages = linter.run(ctx);
+            let summary = messages
+                .iter()
+                .map(|m| format!("{:?} {}..{} {}", m.error.severity, m.start, m.end, m.error))
+                .collect();
+            (summary, Fixer::new(source_text, messages).fix().fixed_code.into_owned())
+        }
+
+        let options = LintOptions::default()
+            .with_config_path(Some("fixtures/js_plugins/config.json".into()))
+            .with_fix(FixKind::SafeFix);
+        let linter = Linter::from_options(options).unwrap();
+
+        let (messages, fixed) = lint(&linter, "src/foo.js", "let foo = [1, 2, 3];");
+        assert_eq!(
+            messages,
+            [
+                "Error 4..7 local(no-foo): Unexpected identifier `foo`.",
+                "Warning 0..20 local(max-literals): Too many literals (3).",
+            ]
+        );
+        assert_eq!(fixed, "let bar = [1, 2, 3];");
+
+        // offsets are in bytes, not in UTF-16 code units
+        let (messages, _) = lint(&linter, "src/foo.js", "/* é😀 */ foo;");
+        assert_eq!(messages, ["Error 13..16 local(no-foo): Unexpected identifier `foo`."]);
+
+        let (messages, _) =
+            lint(&linter, "src/foo.js", "// eslint-disable-next-line local/no-foo\nfoo;");
+        assert!(messages.is_empty());
+
+        let (messages, _) = lint(&linter, "src/foo.test.js", "foo(1, 2, 3);");
+        assert_eq!(messages, ["Error 0..13 local(max-literals): Too many literals (3)."]);
+
+        let options = LintOptions::default()
+            .with_config_path(Some("fixtures/js_plugins/unknown_rule.json".into()));
+        let linter = Linter::from_options(options).unwrap();
+        let (messages, _) = lint(&linter, "src/foo.js", "foo;");
+        assert_eq!(messages.len(), 1);
+        assert!(
+            messages[0].contains("Rule `no-bar` not found in JS plugin `local`"),
+            "{messages:?}"
+        );
+
+        // reports and fixes past the end of the file are ignored with an error
+        let options = LintOptions::default()
+            .with_config_path(Some("fixtures/js_plugins/bad_ranges.json".into()))
+            .with_fix(FixKind::SafeFix);
+        let linter = Linter::from_options(options).unwrap();
+        for source_text in ["foo;", "é;"] {
+            let (messages, fixed) = lint(&linter, "src/foo.js", source_text);
+            let end = source_text.len();
+            assert_eq!(messages.len(), 3, "{messages:?}");
+            assert!(messages[0].starts_with("Error 0..0 Ignored a fix of "), "{messages:?}");
+            assert_eq!(messages[1], format!("Error 0..{end} local(bad-ranges): Fix past the end."));
+            assert!(messages[2].starts_with("Error 0..0 Ignored a report of "), "{messages:?}");
+            assert_eq!(fixed, source_text);
+        }
+    }
+
+    #[test]
+    fn explain_rule() {
+        use super::{AllowWarnDeny, RuleSettingSource};
+
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, "no-debugger".into())])
+            .with_config_path(Some("fixtures/explain_rule.json".into()));
+        let linter = Linter::from_options(options).unwrap();
+
+        let explanation = linter.explain_rule("no-debugger", Path::new("src/foo.js")).unwrap();
+        assert_eq!(explanation.rule_name, "eslint/no-debugger");
+        assert_eq!(explanation.severity, AllowWarnDeny::Deny);
+        assert_eq!(explanation.settings.len(), 2);
+        assert!(matches!(
+            &explanation.settings[0].source,
+            RuleSettingSource::CommandLine { filter, default: false } if filter == "-D no-debugger"
+        ));
+        assert!(matches!(explanation.settings[1].source, RuleSettingSource::ConfigCategory { .. }));
+
+        let explanation = linter.explain_rule("no-debugger", Path::new("src/foo.test.js")).unwrap();
+        assert_eq!(explanation.severity, AllowWarnDeny::Allow);
+        assert!(explanation.options.is_none());
+        assert!(matches!(
+            explanation.settings[2].source,
+            RuleSettingSource::Override { index: 0, .. }
+        ));
+
+        let explanation = linter.explain_rule("eslint/eqeqeq", Path::new("src/foo.js")).unwrap();
+        assert_eq!(explanation.severity, AllowWarnDeny::Warn);
+        assert!(explanation.options.as_ref().is_some_and(|options| options.contains("Smart")));
+        assert!(explanation
+            .to_string()
+            .contains("`rules` in fixtures/explain_rule.json with options [\"smart\"]"));
+
+        assert!(linter.explain_rule("no-such-rule", Path::new("src/foo.js")).is_err());
+    }
+
     #[test]
     fn test_schema_json() {
         use project_root::get_project_root;
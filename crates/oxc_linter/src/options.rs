@@ -5,7 +5,12 @@ use serde_json::{Number, Value};
 
 use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
 
-use crate::{config::OxlintConfig, rules::RULES, RuleCategory, RuleEnum, RuleWithSeverity};
+use crate::{
+    config::{OxlintConfig, OxlintPlugins, DEFAULT_PLUGINS},
+    fixer::FixKind,
+    rules::RULES,
+    RuleCategory, RuleEnum, RuleWithSeverity,
+};
 
 #[derive(Debug)]
 pub struct LintOptions {
@@ -13,18 +18,33 @@ pub struct LintOptions {
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
-    pub fix: bool,
-
-    pub react_plugin: bool,
-    pub unicorn_plugin: bool,
-    pub typescript_plugin: bool,
-    pub oxc_plugin: bool,
-    pub import_plugin: bool,
-    pub jsdoc_plugin: bool,
-    pub jest_plugin: bool,
-    pub jsx_a11y_plugin: bool,
-    pub nextjs_plugin: bool,
-    pub react_perf_plugin: bool,
+    /// Look up `.oxlintrc.json` files in the directories between this directory and each linted file.
+    pub config_lookup_root: Option<PathBuf>,
+    /// Kinds of fixes to apply, nothing is fixed with [`FixKind::None`].
+    pub fix: FixKind,
+    /// Report the disable directives which disable no diagnostic,
+    /// in addition to the `reportUnusedDisableDirectives` of the configuration.
+    pub report_unused_directives: bool,
+    /// Collect the time spent by each rule, and parsing and building the semantic model of each file.
+    pub timing: bool,
+    /// Run the type-aware rules, in addition to the `typeAware` of the configuration.
+    pub type_aware: bool,
+
+    /// Plugins enabled or disabled explicitly, taking precedence over the `plugins` of the configuration.
+    /// `None` defers to the configuration, then to [`DEFAULT_PLUGINS`].
+    pub react_plugin: Option<bool>,
+    pub unicorn_plugin: Option<bool>,
+    pub typescript_plugin: Option<bool>,
+    pub oxc_plugin: Option<bool>,
+    pub import_plugin: Option<bool>,
+    pub jsdoc_plugin: Option<bool>,
+    pub jest_plugin: Option<bool>,
+    pub jsx_a11y_plugin: Option<bool>,
+    pub nextjs_plugin: Option<bool>,
+    pub react_perf_plugin: Option<bool>,
+    pub promise_plugin: Option<bool>,
+    pub node_plugin: Option<bool>,
+    pub vitest_plugin: Option<bool>,
 }
 
 impl Default for LintOptions {
@@ -32,17 +52,24 @@ impl Default for LintOptions {
         Self {
             filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
-            fix: false,
-            react_plugin: true,
-            unicorn_plugin: true,
-            typescript_plugin: true,
-            oxc_plugin: true,
-            import_plugin: false,
-            jsdoc_plugin: false,
-            jest_plugin: false,
-            jsx_a11y_plugin: false,
-            nextjs_plugin: false,
-            react_perf_plugin: false,
+            config_lookup_root: None,
+            fix: FixKind::None,
+            report_unused_directives: false,
+            timing: false,
+            type_aware: false,
+            react_plugin: None,
+            unicorn_plugin: None,
+            typescript_plugin: None,
+            oxc_plugin: None,
+            import_plugin: None,
+            jsdoc_plugin: None,
+            jest_plugin: None,
+            jsx_a11y_plugin: None,
+            nextjs_plugin: None,
+            react_perf_plugin: None,
+            promise_plugin: None,
+            node_plugin: None,
+            vitest_plugin: None,
         }
     }
 }
@@ -63,70 +90,112 @@ impl LintOptions {
     }
 
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.fix = yes;
+    pub fn with_config_lookup_root(mut self, root: Option<PathBuf>) -> Self {
+        self.config_lookup_root = root;
         self
     }
 
     #[must_use]
-    pub fn with_react_plugin(mut self, yes: bool) -> Self {
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.fix = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_timing(mut self, yes: bool) -> Self {
+        self.timing = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_type_aware(mut self, yes: bool) -> Self {
+        self.type_aware = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_react_plugin(mut self, yes: Option<bool>) -> Self {
         self.react_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_unicorn_plugin(mut self, yes: bool) -> Self {
+    pub fn with_unicorn_plugin(mut self, yes: Option<bool>) -> Self {
         self.unicorn_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_typescript_plugin(mut self, yes: bool) -> Self {
+    pub fn with_typescript_plugin(mut self, yes: Option<bool>) -> Self {
         self.typescript_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_oxc_plugin(mut self, yes: bool) -> Self {
+    pub fn with_oxc_plugin(mut self, yes: Option<bool>) -> Self {
         self.oxc_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_import_plugin(mut self, yes: bool) -> Self {
+    pub fn with_import_plugin(mut self, yes: Option<bool>) -> Self {
         self.import_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_jsdoc_plugin(mut self, yes: bool) -> Self {
+    pub fn with_jsdoc_plugin(mut self, yes: Option<bool>) -> Self {
         self.jsdoc_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_jest_plugin(mut self, yes: bool) -> Self {
+    pub fn with_jest_plugin(mut self, yes: Option<bool>) -> Self {
         self.jest_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_jsx_a11y_plugin(mut self, yes: bool) -> Self {
+    pub fn with_jsx_a11y_plugin(mut self, yes: Option<bool>) -> Self {
         self.jsx_a11y_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_nextjs_plugin(mut self, yes: bool) -> Self {
+    pub fn with_nextjs_plugin(mut self, yes: Option<bool>) -> Self {
         self.nextjs_plugin = yes;
         self
     }
 
     #[must_use]
-    pub fn with_react_perf_plugin(mut self, yes: bool) -> Self {
+    pub fn with_react_perf_plugin(mut self, yes: Option<bool>) -> Self {
         self.react_perf_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_promise_plugin(mut self, yes: Option<bool>) -> Self {
+        self.promise_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_node_plugin(mut self, yes: Option<bool>) -> Self {
+        self.node_plugin = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_vitest_plugin(mut self, yes: Option<bool>) -> Self {
+        self.vitest_plugin = yes;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -207,9 +276,14 @@ impl LintOptions {
     pub fn derive_rules_and_config(&self) -> Result<(Vec<RuleWithSeverity>, OxlintConfig), Error> {
         let config =
             self.config_path.as_ref().map(|path| OxlintConfig::from_file(path)).transpose()?;
+        let rules = self.derive_rules(config.as_ref());
+        Ok((rules, config.unwrap_or_default()))
+    }
 
+    /// Rules enabled by `self.filter`, then overridden by the `categories` and `rules` of `config`.
+    pub(crate) fn derive_rules(&self, config: Option<&OxlintConfig>) -> Vec<RuleWithSeverity> {
         let mut rules: FxHashSet<RuleWithSeverity> = FxHashSet::default();
-        let all_rules = self.get_filtered_rules();
+        let all_rules = self.get_filtered_rules(config.and_then(|config| config.plugins.as_ref()));
 
         for (severity, name_or_category) in &self.filter {
             let maybe_category = RuleCategory::from(name_or_category.as_str());
@@ -256,7 +330,7 @@ impl LintOptions {
             }
         }
 
-        if let Some(config) = &config {
+        if let Some(config) = config {
             config.override_rules(&mut rules, &all_rules);
         }
 
@@ -265,28 +339,53 @@ impl LintOptions {
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(|rule| rule.id());
 
-        Ok((rules, config.unwrap_or_default()))
+        rules
     }
 
-    /// Get final filtered rules by reading `self.xxx_plugin`
-    fn get_filtered_rules(&self) -> Vec<RuleEnum> {
+    /// Get final filtered rules of the enabled plugins, see [`LintOptions::is_plugin_enabled`].
+    pub(crate) fn get_filtered_rules(&self, plugins: Option<&OxlintPlugins>) -> Vec<RuleEnum> {
         RULES
             .iter()
-            .filter(|rule| match rule.plugin_name() {
-                "react" => self.react_plugin,
-                "unicorn" => self.unicorn_plugin,
-                "typescript" => self.typescript_plugin,
-                "import" => self.import_plugin,
-                "jsdoc" => self.jsdoc_plugin,
-                "jest" => self.jest_plugin,
-                "jsx_a11y" => self.jsx_a11y_plugin,
-                "nextjs" => self.nextjs_plugin,
-                "react_perf" => self.react_perf_plugin,
-                "oxc" => self.oxc_plugin,
-                "eslint" | "tree_shaking" => true,
-                name => panic!("Unhandled plugin: {name}"),
-            })
+            .filter(|rule| self.is_plugin_enabled(rule.plugin_name(), plugins))
             .cloned()
             .collect::<Vec<_>>()
     }
+
+    /// A plugin is enabled by `self.xxx_plugin` if set,
+    /// otherwise by the `plugins` of the configuration if any,
+    /// otherwise if it is one of the [`DEFAULT_PLUGINS`].
+    ///
+    /// The jest rules are enabled by the vitest plugin as well, since they also check Vitest tests.
+    pub(crate) fn is_plugin_enabled(
+        &self,
+        plugin_name: &str,
+        plugins: Option<&OxlintPlugins>,
+    ) -> bool {
+        if plugin_name == "jest" && self.is_plugin_enabled("vitest", plugins) {
+            return true;
+        }
+        let enabled = match plugin_name {
+            "react" => self.react_plugin,
+            "unicorn" => self.unicorn_plugin,
+            "typescript" => self.typescript_plugin,
+            "import" => self.import_plugin,
+            "jsdoc" => self.jsdoc_plugin,
+            "jest" => self.jest_plugin,
+            "jsx_a11y" => self.jsx_a11y_plugin,
+            "nextjs" => self.nextjs_plugin,
+            "react_perf" => self.react_perf_plugin,
+            "promise" => self.promise_plugin,
+            "node" => self.node_plugin,
+            "vitest" => self.vitest_plugin,
+            "oxc" => self.oxc_plugin,
+            "eslint" | "tree_shaking" => return true,
+            name => panic!("Unhandled plugin: {name}"),
+        };
+        enabled.unwrap_or_else(|| {
+            plugins.map_or_else(
+                || DEFAULT_PLUGINS.contains(&plugin_name),
+                |plugins| plugins.contains(plugin_name),
+            )
+        })
+    }
 }
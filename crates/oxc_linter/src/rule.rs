@@ -2,11 +2,14 @@ use std::{
     fmt,
     hash::{Hash, Hasher},
     ops::Deref,
+    path::{Path, PathBuf},
 };
 
+use oxc_ast::AstType;
 use oxc_semantic::SymbolId;
+use schemars::{gen::SchemaGenerator, schema::Schema};
 
-use crate::{context::LintContext, AllowWarnDeny, AstNode, RuleEnum};
+use crate::{context::LintContext, fixer::FixKind, AllowWarnDeny, AstNode, RuleEnum};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -22,6 +25,13 @@ pub trait Rule: Sized + Default + fmt::Debug {
 
     /// Run only once. Useful for inspecting scopes and trivias etc.
     fn run_once(&self, _ctx: &LintContext) {}
+
+    /// Files read by the rule to lint the file at `file_path`, besides the modules it imports,
+    /// e.g. the closest `package.json`. The results cached by `--cache` are invalidated when
+    /// one of them changes, or is created for a file which does not exist.
+    fn read_files(&self, _file_path: &Path) -> Vec<PathBuf> {
+        vec![]
+    }
 }
 
 pub trait RuleMeta {
@@ -29,6 +39,31 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// Kinds of fixes provided by the rule.
+    const FIX: FixKind = FixKind::None;
+
+    /// AST types of the nodes given to [`Rule::run`], given by `ast_types = [..]` in `declare_oxc_lint!`.
+    /// `None` runs the rule on every node, rules without [`Rule::run`] declare `ast_types = []`.
+    const AST_TYPES: Option<&'static [AstType]> = None;
+
+    /// Whether every option of the rule has the type given by `config = [Type]` in `declare_oxc_lint!`,
+    /// instead of only the first one with `config = Type`.
+    const REPEATED_CONFIG: bool = false;
+
+    /// JSON schema of the options of the rule, given by `config = Type` in `declare_oxc_lint!`.
+    fn config_schema(_gen: &mut SchemaGenerator) -> Option<Schema> {
+        None
+    }
+
+    /// Check the options of the rule in a configuration, i.e. the values following the severity.
+    ///
+    /// # Errors
+    ///
+    /// * The options do not match the type given by `config = Type` or `config = [Type]` in `declare_oxc_lint!`
+    fn validate_config(_value: &serde_json::Value) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+
     fn documentation() -> Option<&'static str> {
         None
     }
@@ -134,7 +169,7 @@ impl RuleWithSeverity {
 
 #[cfg(test)]
 mod test {
-    use crate::rules::RULES;
+    use crate::{fixer::FixKind, rules::RULES};
 
     #[test]
     fn ensure_documentation() {
@@ -143,4 +178,13 @@ mod test {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
         }
     }
+
+    #[test]
+    fn fix_kind() {
+        let fix = |name: &str| RULES.iter().find(|rule| rule.name() == name).unwrap().fix();
+        assert_eq!(fix("no-debugger"), FixKind::SafeFix);
+        assert_eq!(fix("no-useless-spread"), FixKind::DangerousFix);
+        assert_eq!(fix("no-null"), FixKind::Suggestion);
+        assert_eq!(fix("no-console"), FixKind::None);
+    }
 }
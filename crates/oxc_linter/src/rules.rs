@@ -16,9 +16,11 @@ mod import {
     pub mod no_default_export;
     // pub mod no_deprecated;
     pub mod no_duplicates;
+    pub mod no_extraneous_dependencies;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_self_import;
+    pub mod no_unresolved;
     // pub mod no_unused_modules;
 }
 
@@ -74,6 +76,7 @@ mod eslint {
     pub mod no_inner_declarations;
     pub mod no_irregular_whitespace;
     pub mod no_iterator;
+    pub mod no_loop_func;
     pub mod no_loss_of_precision;
     pub mod no_new;
     pub mod no_new_native_nonconstructor;
@@ -85,10 +88,12 @@ mod eslint {
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_restricted_syntax;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_template_curly_in_string;
@@ -101,6 +106,8 @@ mod eslint {
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
+    pub mod no_unused_vars;
+    pub mod no_use_before_define;
     pub mod no_useless_catch;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
@@ -109,6 +116,7 @@ mod eslint {
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
+    pub mod prefer_const;
     pub mod prefer_exponentiation_operator;
     pub mod radix;
     pub mod require_await;
@@ -123,6 +131,7 @@ mod eslint {
 mod typescript {
     pub mod adjacent_overload_signatures;
     pub mod array_type;
+    pub mod await_thenable;
     pub mod ban_ts_comment;
     pub mod ban_tslint_comment;
     pub mod ban_types;
@@ -133,8 +142,10 @@ mod typescript {
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_misused_new;
+    pub mod no_misused_promises;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
     pub mod no_this_alias;
@@ -201,6 +212,7 @@ mod jest {
 mod react {
     pub mod button_has_type;
     pub mod checked_requires_onchange_or_readonly;
+    pub mod exhaustive_deps;
     pub mod jsx_key;
     pub mod jsx_no_comment_textnodes;
     pub mod jsx_no_duplicate_props;
@@ -412,10 +424,36 @@ mod jsdoc {
     pub mod require_yields;
 }
 
+/// <https://github.com/eslint-community/eslint-plugin-n>
+mod node {
+    pub mod no_deprecated_api;
+    pub mod no_path_concat;
+    pub mod no_process_exit;
+    pub mod no_sync;
+    pub mod no_unpublished_import;
+    pub mod no_unsupported_features_es_syntax;
+}
+
+/// <https://github.com/eslint-community/eslint-plugin-promise>
+mod promise {
+    pub mod always_return;
+    pub mod catch_or_return;
+    pub mod no_nesting;
+    pub mod no_promise_in_callback;
+    pub mod no_return_wrap;
+    pub mod param_names;
+}
+
 mod tree_shaking {
     pub mod no_side_effects_in_initialization;
 }
 
+/// <https://github.com/veritem/eslint-plugin-vitest>
+mod vitest {
+    pub mod consistent_test_filename;
+    pub mod no_conditional_tests;
+}
+
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
     eslint::constructor_super,
@@ -470,6 +508,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_inner_declarations,
     eslint::no_irregular_whitespace,
     eslint::no_iterator,
+    eslint::no_loop_func,
     eslint::no_loss_of_precision,
     eslint::no_new,
     eslint::no_new_wrappers,
@@ -483,6 +522,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_self_assign,
     eslint::no_self_compare,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
@@ -492,6 +532,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
+    eslint::no_unused_vars,
+    eslint::no_use_before_define,
     eslint::no_useless_catch,
     eslint::no_useless_escape,
     eslint::no_useless_rename,
@@ -510,6 +552,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_await_in_loop,
     eslint::no_new_native_nonconstructor,
     eslint::no_restricted_globals,
+    eslint::no_restricted_syntax,
+    eslint::prefer_const,
     eslint::prefer_exponentiation_operator,
     eslint::no_constructor_return,
     typescript::adjacent_overload_signatures,
@@ -540,6 +584,9 @@ oxc_macros::declare_all_lint_rules! {
     typescript::triple_slash_reference,
     typescript::prefer_literal_enum_member,
     typescript::explicit_function_return_type,
+    typescript::no_floating_promises,
+    typescript::await_thenable,
+    typescript::no_misused_promises,
     jest::expect_expect,
     jest::max_expects,
     jest::max_nested_describe,
@@ -667,6 +714,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::throw_new_error,
     react::button_has_type,
     react::checked_requires_onchange_or_readonly,
+    react::exhaustive_deps,
     react::jsx_no_target_blank,
     react::jsx_key,
     react::jsx_no_comment_textnodes,
@@ -703,6 +751,8 @@ oxc_macros::declare_all_lint_rules! {
     // import::no_unused_modules,
     import::no_duplicates,
     import::no_default_export,
+    import::no_extraneous_dependencies,
+    import::no_unresolved,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -789,5 +839,19 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::require_returns_description,
     jsdoc::require_returns_type,
     jsdoc::require_yields,
+    node::no_deprecated_api,
+    node::no_path_concat,
+    node::no_process_exit,
+    node::no_sync,
+    node::no_unpublished_import,
+    node::no_unsupported_features_es_syntax,
+    promise::always_return,
+    promise::catch_or_return,
+    promise::no_nesting,
+    promise::no_promise_in_callback,
+    promise::no_return_wrap,
+    promise::param_names,
     tree_shaking::no_side_effects_in_initialization,
+    vitest::consistent_test_filename,
+    vitest::no_conditional_tests,
 }
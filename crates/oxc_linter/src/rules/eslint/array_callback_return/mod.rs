@@ -7,6 +7,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::phf_set;
+use schemars::JsonSchema;
+use serde::Deserialize;
 use serde_json::Value;
 
 use self::return_checker::{check_function_body, StatementReturnStatus};
@@ -33,12 +35,15 @@ fn expect_no_return(x0: &str, span1: Span) -> OxcDiagnostic {
     .with_labels([span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct ArrayCallbackReturn {
     /// When set to true, rule will also report forEach callbacks that return a value.
+    #[serde(rename = "checkForEach")]
     check_for_each: bool,
     /// When set to true, allows callbacks of methods that require a return value to
     /// implicitly return undefined with a return statement containing no expression.
+    #[serde(rename = "allowImplicit")]
     allow_implicit_return: bool,
 }
 
@@ -60,20 +65,16 @@ declare_oxc_lint!(
     /// });
     /// ```
     ArrayCallbackReturn,
-    pedantic
+    pedantic,
+    config = ArrayCallbackReturn
 );
 
 impl Rule for ArrayCallbackReturn {
     fn from_configuration(value: Value) -> Self {
-        let (check_for_each, allow_implicit_return) =
-            value.get(0).map_or((false, false), |config| {
-                (
-                    config.get("checkForEach").and_then(Value::as_bool).unwrap_or_default(),
-                    config.get("allowImplicit").and_then(Value::as_bool).unwrap_or_default(),
-                )
-            });
-
-        Self { check_for_each, allow_implicit_return }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -4,6 +4,8 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
 use regex::RegexBuilder;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -21,6 +23,14 @@ pub struct DefaultCaseConfig {
     comment_pattern: Option<Regex>,
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct DefaultCaseOptions {
+    /// Regular expression matched case-insensitively against the comment that marks
+    /// the omitted `default` case, instead of `/^no default$/i`.
+    comment_pattern: Option<String>,
+}
+
 impl std::ops::Deref for DefaultCase {
     type Target = DefaultCaseConfig;
 
@@ -48,19 +58,21 @@ declare_oxc_lint!(
     /// ```
     DefaultCase,
     restriction,
+    config = DefaultCaseOptions,
+    ast_types = [SwitchStatement],
 );
 
 impl Rule for DefaultCase {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut cfg = DefaultCaseConfig::default();
-
-        if let Some(config) = value.get(0) {
-            if let Some(val) = config.get("commentPattern").and_then(serde_json::Value::as_str) {
-                cfg.comment_pattern = RegexBuilder::new(val).case_insensitive(true).build().ok();
-            }
-        }
+        let options: DefaultCaseOptions = value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default();
+        let comment_pattern = options
+            .comment_pattern
+            .and_then(|pattern| RegexBuilder::new(&pattern).case_insensitive(true).build().ok());
 
-        Self(Box::new(cfg))
+        Self(Box::new(DefaultCaseConfig { comment_pattern }))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     DefaultCaseLast,
-    style
+    style,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for DefaultCaseLast {
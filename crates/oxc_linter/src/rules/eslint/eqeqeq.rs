@@ -7,6 +7,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -36,7 +38,9 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     Eqeqeq,
-    pedantic
+    pedantic, fix,
+    config = CompareType,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for Eqeqeq {
@@ -46,8 +50,7 @@ impl Rule for Eqeqeq {
 
         Self {
             compare_type: obj1
-                .and_then(serde_json::Value::as_str)
-                .map(CompareType::from)
+                .and_then(|value| serde_json::from_value(value.clone()).ok())
                 .unwrap_or_default(),
             null_type: obj2
                 .and_then(|v| v.get("null"))
@@ -133,22 +136,17 @@ impl Rule for Eqeqeq {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum CompareType {
+    /// Require `===` and `!==` everywhere.
     #[default]
+    #[serde(alias = "allow-null")]
     Always,
+    /// Allow `==` and `!=` between two literals, in `typeof` comparisons and with `null`.
     Smart,
 }
 
-impl CompareType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "smart" => Self::Smart,
-            _ => Self::Always,
-        }
-    }
-}
-
 #[derive(Debug, Default, Clone)]
 enum NullType {
     #[default]
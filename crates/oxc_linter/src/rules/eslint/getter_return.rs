@@ -13,6 +13,8 @@ use oxc_semantic::{
     ReturnInstructionKind,
 };
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -22,8 +24,10 @@ fn getter_return_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetterReturn {
+    /// Allow getters to implicitly return `undefined` with `return;`.
     pub allow_implicit: bool,
 }
 
@@ -50,7 +54,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     GetterReturn,
-    nursery
+    nursery,
+    config = GetterReturn
 );
 
 impl Rule for GetterReturn {
@@ -71,13 +76,10 @@ impl Rule for GetterReturn {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_implicit = value
+        value
             .get(0)
-            .and_then(|config| config.get("allowImplicit"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self { allow_implicit }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     // }
     /// ```
     GuardForIn,
-    style
+    style,
+    ast_types = [ForInStatement]
 );
 
 impl Rule for GuardForIn {
@@ -57,6 +57,7 @@ declare_oxc_lint!(
     /// ```
     MaxClassesPerFile,
     pedantic,
+    ast_types = [],
 );
 
 impl Rule for MaxClassesPerFile {
@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     MaxLines,
-    pedantic
+    pedantic,
+    ast_types = []
 );
 
 impl Rule for MaxLines {
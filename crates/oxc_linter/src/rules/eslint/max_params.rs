@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     MaxParams,
-    style
+    style,
+    ast_types = [Function, ArrowFunctionExpression]
 );
 
 impl Rule for MaxParams {
@@ -4,6 +4,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::BinaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -16,9 +18,12 @@ fn no_bitwise_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoBitwise(Box<NoBitwiseConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoBitwiseConfig {
+    /// Bitwise operators to allow, e.g. `["|", "&"]`.
     allow: Vec<String>,
+    /// Allow `x | 0`, which is used to truncate numbers to 32-bit integers.
     int32_hint: bool,
 }
 
@@ -46,29 +51,17 @@ declare_oxc_lint!(
     /// var x = y | z;
     /// ```
     NoBitwise,
-    restriction
+    restriction,
+    config = NoBitwiseConfig,
+    ast_types = [BinaryExpression, UnaryExpression, AssignmentExpression]
 );
 
 impl Rule for NoBitwise {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self(Box::new(NoBitwiseConfig {
-            allow: obj
-                .and_then(|v| v.get("allow"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                })
-                .unwrap_or_default(),
-            int32_hint: obj
-                .and_then(|v| v.get("int32Hint"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
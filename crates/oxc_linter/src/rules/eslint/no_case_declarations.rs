@@ -45,7 +45,8 @@ declare_oxc_lint!(
     // }
     /// ```
     NoCaseDeclarations,
-    pedantic
+    pedantic,
+    ast_types = [SwitchCase]
 );
 
 impl Rule for NoCaseDeclarations {
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// let a = new A() // Error
     /// ```
     NoClassAssign,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoClassAssign {
@@ -40,7 +40,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoCondAssign,
-    correctness
+    correctness,
+    ast_types = [
+        IfStatement,
+        WhileStatement,
+        DoWhileStatement,
+        ForStatement,
+        ConditionalExpression,
+        AssignmentExpression,
+    ]
 );
 
 impl Rule for NoCondAssign {
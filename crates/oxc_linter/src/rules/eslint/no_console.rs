@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -14,7 +16,8 @@ fn no_console_diagnostic(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoConsole(Box<NoConsoleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct NoConsoleConfig {
     /// A list of methods allowed to be used.
     ///
@@ -49,24 +52,17 @@ declare_oxc_lint!(
     /// console.log('here');
     /// ```
     NoConsole,
-    restriction
+    restriction,
+    config = NoConsoleConfig,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoConsole {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(NoConsoleConfig {
-            allow: value
-                .get(0)
-                .and_then(|v| v.get("allow"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                })
-                .unwrap_or_default(),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// a = 1;
     /// ```
     NoConstAssign,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoConstAssign {
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// // However, this will always result in `isEmpty` being `false`.
     /// ```
     NoConstantBinaryExpression,
-    correctness
+    correctness,
+    ast_types = [LogicalExpression, BinaryExpression]
 );
 
 fn constant_short_circuit(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
 
@@ -12,8 +14,11 @@ fn no_constant_condition_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoConstantCondition {
+    /// Also report constant conditions of loops.
+    #[serde(rename = "checkLoops")]
     _check_loops: bool,
 }
 
@@ -34,19 +39,17 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoConstantCondition,
-    correctness
+    correctness,
+    config = NoConstantCondition
+    ast_types = [IfStatement, ConditionalExpression]
 );
 
 impl Rule for NoConstantCondition {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self {
-            _check_loops: obj
-                .and_then(|v| v.get("checkLoops"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     // }
     /// ```
     NoContinue,
-    style
+    style,
+    ast_types = [ContinueStatement]
 );
 
 impl Rule for NoContinue {
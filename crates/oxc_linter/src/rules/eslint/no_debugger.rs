@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness, fix,
+    ast_types = [DebuggerStatement]
 );
 
 impl Rule for NoDebugger {
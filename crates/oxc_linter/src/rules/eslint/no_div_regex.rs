@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// function bar() { return /=foo/; }
     /// ```
     NoDivRegex,
-    restriction,
+    restriction, fix,
+    ast_types = [RegExpLiteral]
 );
 
 impl Rule for NoDivRegex {
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// a.foo() // Uncaught TypeError: a.foo is not a function
     /// ```
     NoDupeClassMembers,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoDupeClassMembers {
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoDupeElseIf,
-    correctness
+    correctness,
+    ast_types = [IfStatement]
 );
 
 impl Rule for NoDupeElseIf {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoDuplicateCase,
-    correctness
+    correctness,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for NoDuplicateCase {
@@ -3,6 +3,8 @@ use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -12,8 +14,10 @@ fn no_empty_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([LabeledSpan::new_with_span(Some(format!("Empty {x0} statement")), span1)])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoEmpty {
+    /// Allow empty `catch` clauses.
     allow_empty_catch: bool,
 }
 
@@ -33,17 +37,16 @@ declare_oxc_lint!(
     /// ```
     NoEmpty,
     restriction,
+    config = NoEmpty,
+    ast_types = [BlockStatement, CatchClause, FinallyClause, SwitchStatement]
 );
 
 impl Rule for NoEmpty {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        Self {
-            allow_empty_catch: obj
-                .and_then(|v| v.get("allowEmptyCatch"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -34,6 +34,7 @@ declare_oxc_lint!(
     /// ```
     NoEmptyFunction,
     restriction,
+    ast_types = [FunctionBody],
 );
 
 impl Rule for NoEmptyFunction {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoEmptyStaticBlock,
-    correctness
+    correctness,
+    ast_types = [StaticBlock]
 );
 
 impl Rule for NoEmptyStaticBlock {
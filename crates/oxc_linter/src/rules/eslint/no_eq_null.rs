@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoEqNull,
-    restriction
+    restriction,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoEqNull {
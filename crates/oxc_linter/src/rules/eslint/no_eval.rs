@@ -6,6 +6,8 @@ use oxc_ast::{ast::Expression, AstKind};
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -13,7 +15,8 @@ fn no_eval_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-eval): eval can be harmful.").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoEval {
     /// Whether to allow references to the `eval` function as long as they are
     /// not called. For example, the following code is valid if this property is
@@ -46,16 +49,16 @@ declare_oxc_lint!(
     /// eval(someString);
     /// ```
     NoEval,
-    restriction
+    restriction,
+    config = NoEval
 );
 
 impl Rule for NoEval {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_indirect = value.get(0).map_or(false, |config| {
-            config.get("allowIndirect").and_then(serde_json::Value::as_bool).unwrap_or(false)
-        });
-
-        Self { allow_indirect }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -5,6 +5,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -20,8 +22,11 @@ fn no_extra_boolean_cast_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoExtraBooleanCast {
+    /// Also report redundant boolean casts in the operands of logical expressions
+    /// used as a condition, e.g. `if (!!a || b)`.
     pub enforce_for_logical_operands: bool,
 }
 
@@ -45,18 +50,17 @@ declare_oxc_lint!(
     /// if (!!foo || bar) {}
     /// ```
     NoExtraBooleanCast,
-    correctness
+    correctness,
+    config = NoExtraBooleanCast
+    ast_types = [CallExpression, UnaryExpression]
 );
 
 impl Rule for NoExtraBooleanCast {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            enforce_for_logical_operands: value
-                .get(0)
-                .and_then(|x| x.get("enforceForLogicalOperands"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -17,6 +17,8 @@ use oxc_semantic::{
 use oxc_span::{GetSpan, Span};
 use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -47,6 +49,18 @@ struct Config {
 #[derive(Debug, Clone)]
 pub struct NoFallthrough(Box<Config>);
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoFallthroughOptions {
+    /// Regular expression of the comments that mark an intentional fallthrough,
+    /// instead of `falls?\s?through`.
+    comment_pattern: Option<String>,
+    /// Allow a case to fall through when it has no statements but a comment.
+    allow_empty_case: Option<bool>,
+    /// Report the fallthrough comments of the cases that cannot fall through.
+    report_unused_fallthrough_comment: Option<bool>,
+}
+
 impl NoFallthrough {
     fn new(
         comment_pattern: Option<&str>,
@@ -74,18 +88,25 @@ declare_oxc_lint!(
     /// Disallow fallthrough of `case` statements
     ///
     NoFallthrough,
-    correctness
+    correctness,
+    config = NoFallthroughOptions
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for NoFallthrough {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(value) = value.get(0) else { return Self::default() };
-        let comment_pattern = value.get("commentPattern").and_then(serde_json::Value::as_str);
-        let allow_empty_case = value.get("allowEmptyCase").and_then(serde_json::Value::as_bool);
-        let report_unused_fallthrough_comment =
-            value.get("reportUnusedFallthroughComment").and_then(serde_json::Value::as_bool);
+        let Some(options) = value
+            .get(0)
+            .and_then(|value| serde_json::from_value::<NoFallthroughOptions>(value.clone()).ok())
+        else {
+            return Self::default();
+        };
 
-        Self::new(comment_pattern, allow_empty_case, report_unused_fallthrough_comment)
+        Self::new(
+            options.comment_pattern.as_deref(),
+            options.allow_empty_case,
+            options.report_unused_fallthrough_comment,
+        )
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// foo = bar;
     /// ```
     NoFuncAssign,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoFuncAssign {
@@ -1,7 +1,9 @@
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, Span};
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -18,9 +20,12 @@ fn no_global_assign_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoGlobalAssign(Box<NoGlobalAssignConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoGlobalAssignConfig {
-    excludes: Vec<CompactStr>,
+    /// Names of the globals that may be assigned to, e.g. `["Object"]`.
+    #[serde(rename = "exceptions")]
+    excludes: Vec<String>,
 }
 
 impl std::ops::Deref for NoGlobalAssign {
@@ -43,24 +48,17 @@ declare_oxc_lint!(
     /// Object = null
     /// ```
     NoGlobalAssign,
-    correctness
+    correctness,
+    config = NoGlobalAssignConfig
+    ast_types = []
 );
 
 impl Rule for NoGlobalAssign {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self(Box::new(NoGlobalAssignConfig {
-            excludes: obj
-                .and_then(|v| v.get("exceptions"))
-                .and_then(serde_json::Value::as_array)
-                .unwrap_or(&vec![])
-                .iter()
-                .map(serde_json::Value::as_str)
-                .filter(Option::is_some)
-                .map(|x| x.unwrap().into())
-                .collect::<Vec<CompactStr>>(),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -70,7 +68,9 @@ impl Rule for NoGlobalAssign {
                 let reference = symbol_table.get_reference(reference_id);
                 if reference.is_write() {
                     let name = reference.name();
-                    if !self.excludes.contains(name) && ctx.env_contains_var(name) {
+                    if !self.excludes.iter().any(|exclude| exclude == name.as_str())
+                        && ctx.env_contains_var(name)
+                    {
                         ctx.diagnostic(no_global_assign_diagnostic(name, reference.span()));
                     }
                 }
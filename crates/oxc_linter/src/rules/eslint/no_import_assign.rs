@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// Object.assign(mod_ns, { foo: "foo" }) // ERROR: The members of 'mod_ns' are readonly.
     /// ```
     NoImportAssign,
-    nursery
+    nursery,
+    ast_types = []
 );
 
 const OBJECT_MUTATION_METHODS: phf::Set<&'static str> =
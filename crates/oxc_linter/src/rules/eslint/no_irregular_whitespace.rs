@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoIrregularWhitespace,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoIrregularWhitespace {
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoIterator,
-    restriction
+    restriction,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for NoIterator {
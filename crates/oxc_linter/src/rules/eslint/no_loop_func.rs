@@ -0,0 +1,303 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_loop_func_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(no-loop-func): Function declared in a loop contains unsafe references to variable(s) {x0}."
+    ))
+    .with_labels([span1.into()])
+}
+
+// doc: https://github.com/eslint/eslint/blob/main/docs/src/rules/no-loop-func.md
+// code: https://github.com/eslint/eslint/blob/main/lib/rules/no-loop-func.js
+// test: https://github.com/eslint/eslint/blob/main/tests/lib/rules/no-loop-func.js
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoopFunc;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow function declarations that contain unsafe references inside loop statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function created in a loop closes over the variables of the loop. When a variable is
+    /// shared by all the iterations, e.g. a `var`, every function sees its last value instead of
+    /// the value of the iteration the function was created in.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// for (var i = 10; i; i--) {
+    ///     funcs[i] = function() { return i; };
+    /// }
+    ///
+    /// let foo = 0;
+    /// for (let i = 0; i < 10; ++i) {
+    ///     setTimeout(() => console.log(foo));
+    ///     foo += 1;
+    /// }
+    /// ```
+    NoLoopFunc,
+    nursery,
+    ast_types = [Function, ArrowFunctionExpression]
+);
+
+impl Rule for NoLoopFunc {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+            || is_skipped_iife(node, ctx)
+        {
+            return;
+        }
+        let Some(loop_node) = containing_loop(node, ctx) else { return };
+
+        // The references from inside the function to the variables declared outside of it.
+        let span = node.kind().span();
+        let symbols = ctx.symbols();
+        let scopes = ctx.scopes();
+        let mut references = scopes
+            .ancestors(node.scope_id())
+            .flat_map(|scope_id| scopes.get_bindings(scope_id).values().copied())
+            .filter(|symbol_id| !contains(span, symbols.get_span(*symbol_id)))
+            .flat_map(|symbol_id| {
+                symbols
+                    .get_resolved_references(symbol_id)
+                    .filter(|reference| !reference.is_type() && contains(span, reference.span()))
+                    .map(move |reference| (reference.span(), symbol_id))
+            })
+            .collect::<Vec<_>>();
+        references.sort_unstable_by_key(|(span, _)| *span);
+
+        let mut names: Vec<&str> = vec![];
+        for (_, symbol_id) in references {
+            let name = symbols.get_name(symbol_id);
+            if !names.contains(&name) && !is_safe(loop_node, symbol_id, ctx) {
+                names.push(name);
+            }
+        }
+        if names.is_empty() {
+            return;
+        }
+        let names = names.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>().join(", ");
+        ctx.diagnostic(no_loop_func_diagnostic(&names, span));
+    }
+}
+
+/// Whether the function is an immediately invoked function expression which is neither async nor
+/// a generator, and is not referenced by its name. Such a function runs in the iteration it is
+/// created in.
+fn is_skipped_iife(node: &AstNode, ctx: &LintContext) -> bool {
+    let (is_plain, name_symbol) = match node.kind() {
+        AstKind::Function(function) => (
+            function.is_expression() && !function.r#async && !function.generator,
+            function.id.as_ref().and_then(|id| id.symbol_id.get()),
+        ),
+        AstKind::ArrowFunctionExpression(arrow) => (!arrow.r#async, None),
+        _ => return false,
+    };
+    if !is_plain
+        || name_symbol
+            .is_some_and(|symbol_id| ctx.symbols().get_resolved_references(symbol_id).count() > 0)
+    {
+        return false;
+    }
+    let span = node.kind().span();
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))
+        .is_some_and(|parent| match parent.kind() {
+            AstKind::CallExpression(call) => call.callee.without_parenthesized().span() == span,
+            _ => false,
+        })
+}
+
+/// The loop whose iterations create the node, `None` when the node is created once, e.g. in the
+/// initializer of a `for` statement, or only when a function declared outside of the loop runs.
+fn containing_loop<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let span = node.kind().span();
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::WhileStatement(_) | AstKind::DoWhileStatement(_) => return Some(parent),
+            AstKind::ForStatement(statement) => {
+                if !statement.init.as_ref().is_some_and(|init| contains(init.span(), span)) {
+                    return Some(parent);
+                }
+            }
+            AstKind::ForInStatement(statement) => {
+                if !contains(statement.right.span(), span) {
+                    return Some(parent);
+                }
+            }
+            AstKind::ForOfStatement(statement) => {
+                if !contains(statement.right.span(), span) {
+                    return Some(parent);
+                }
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                if !is_skipped_iife(parent, ctx) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The outermost loop containing `loop_node` which starts after `border`.
+fn top_loop<'a, 'b>(
+    loop_node: &'b AstNode<'a>,
+    border: u32,
+    ctx: &'b LintContext<'a>,
+) -> &'b AstNode<'a> {
+    let mut top = loop_node;
+    let mut current = Some(loop_node);
+    while let Some(node) = current.filter(|node| node.kind().span().start >= border) {
+        top = node;
+        current = containing_loop(node, ctx);
+    }
+    top
+}
+
+/// Whether every function created by the loop sees the same value of the variable.
+fn is_safe<'a>(loop_node: &AstNode<'a>, symbol_id: SymbolId, ctx: &LintContext<'a>) -> bool {
+    let symbols = ctx.symbols();
+    let declarator = symbols.get_declaration(symbol_id);
+    let declaration = ctx.nodes().parent_node(declarator);
+    let declaration = declaration.and_then(|node| match node.kind() {
+        AstKind::VariableDeclaration(declaration) => Some((node, declaration)),
+        _ => None,
+    });
+    // A declaration with an initializer, or in the head of a `for-in`/`for-of`, writes the
+    // variable too.
+    let is_declaration_write = declaration.is_some_and(|(node, _)| {
+        matches!(ctx.nodes().kind(declarator), AstKind::VariableDeclarator(d) if d.init.is_some())
+            || matches!(
+                ctx.nodes().parent_kind(node.id()),
+                Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+            )
+    });
+    let declaration = declaration.map(|(_, declaration)| declaration);
+    let loop_span = loop_node.kind().span();
+    let border = match declaration {
+        Some(declaration) if declaration.kind.is_const() => return true,
+        // Every iteration has its own binding.
+        Some(declaration)
+            if declaration.kind.is_lexical()
+                && loop_span.start < declaration.span.start
+                && declaration.span.end < loop_span.end =>
+        {
+            return true;
+        }
+        Some(declaration) if declaration.kind.is_lexical() => {
+            top_loop(loop_node, declaration.span.end, ctx).kind().span().start
+        }
+        _ => top_loop(loop_node, 0, ctx).kind().span().start,
+    };
+
+    // The variable is safe when it is only written before the loops, in the same function.
+    if is_declaration_write && symbols.get_span(symbol_id).start >= border {
+        return false;
+    }
+    let variable_scope = variable_scope(symbols.get_scope_id(symbol_id), ctx);
+    symbols.get_resolved_references(symbol_id).all(|reference| {
+        !reference.is_write()
+            || (variable_scope == reference_variable_scope(reference.node_id(), ctx)
+                && reference.span().start < border)
+    })
+}
+
+fn reference_variable_scope(node_id: AstNodeId, ctx: &LintContext) -> ScopeId {
+    variable_scope(ctx.nodes().get_node(node_id).scope_id(), ctx)
+}
+
+fn variable_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scopes = ctx.scopes();
+    scopes
+        .ancestors(scope_id)
+        .find(|scope_id| scopes.get_flags(*scope_id).is_var())
+        .unwrap_or_else(|| scopes.root_scope_id())
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "string = 'function a() {}';",
+        "for (var i=0; i<l; i++) { } var a = function() { i; };",
+        "for (var i=0, a=function() { i; }; i<l; i++) { }",
+        "for (var x in xs.filter(function(x) { return x != upper; })) { }",
+        "for (var x of xs.filter(function(x) { return x != upper; })) { }",
+        "for (var i=0; i<l; i++) { (function() {}) }",
+        "for (var i in {}) { (function() {}) }",
+        "for (var i of {}) { (function() {}) }",
+        "for (let i=0; i<l; i++) { (function() { i; }) }",
+        "for (let i in {}) { i = 7; (function() { i; }) }",
+        "for (const i of {}) { (function() { i; }) }",
+        "for (let i = 0; i < 10; ++i) { for (let x in xs.filter(x => x != i)) {  } }",
+        "let a = 0; for (let i=0; i<l; i++) { (function() { a; }); }",
+        "let a = 0; for (let i in {}) { (function() { a; }); }",
+        "let a = 0; for (let i of {}) { (function() { a; }); }",
+        "let a = 0; for (let i=0; i<l; i++) { (function() { (function() { a; }); }); }",
+        "let a = 0; for (let i in {}) { function foo() { (function() { a; }); } }",
+        "let a = 0; for (let i of {}) { (() => { (function() { a; }); }); }",
+        "var a = 0; for (let i=0; i<l; i++) { (function() { a; }); }",
+        "var a = 0; for (let i in {}) { (function() { a; }); }",
+        "var a = 0; for (let i of {}) { (function() { a; }); }",
+        "let result = {}; for (const score in scores) { const letters = scores[score]; letters.split('').forEach(letter => { result[letter] = score; }); } result.__default = 6;",
+        "while (true) { (function() { a; }); } let a;",
+        "while (i) { (function() { i; }); }",
+        "for (var i = 0; i < 10; i++) { (function() { i; })(); }",
+        "for (var i = 0; i < 10; i++) { (() => { i; })(); }",
+        "let x; for (let i = 0; i < 10; i++) { (function () { x = i; })(); }",
+        "const foo = bar; for (var i = 0; i < 5; i++) { arr.push(() => foo); }",
+        "for (var i = 0; i < 5; i++) { arr.push(() => { type T = number; let x: T; }); }",
+        "var a = 0; for (var i = 0; i < 5; i++) { arr.push(() => a); }",
+    ];
+
+    let fail = vec![
+        "for (var i=0; i<l; i++) { (function() { i; }) }",
+        "for (var i=0; i<l; i++) { for (var j=0; j<m; j++) { (function() { i+j; }) } }",
+        "for (var i in {}) { (function() { i; }) }",
+        "for (var i of {}) { (function() { i; }) }",
+        "for (var i=0; i < l; i++) { (() => { i; }) }",
+        "for (var i=0; i < l; i++) { var a = function() { i; } }",
+        "for (var i=0; i < l; i++) { function a() { i; }; a(); }",
+        "let a; for (let i=0; i<l; i++) { a = 1; (function() { a; });}",
+        "let a; for (let i in {}) { (function() { a; }); a = 1; }",
+        "let a; for (let i of {}) { (function() { a; }); } a = 1; ",
+        "let a; for (let i=0; i<l; i++) { (function() { (function() { a; }); }); a = 1; }",
+        "let a; for (let i in {}) { a = 1; function foo() { (function() { a; }); } }",
+        "let a; for (let i of {}) { (() => { (function() { a; }); }); } a = 1;",
+        "for (var i = 0; i < 10; ++i) { for (let x in xs.filter(x => x != i)) {  } }",
+        "for (let x of xs) { let a; for (let y of ys) { a = 1; (function() { a; }); } }",
+        "for (var x of xs) { for (let y of ys) { (function() { x; }); } }",
+        "for (var x of xs) { (function() { x; }); }",
+        "for (var i = 0; i < 5; i++) { var a = i; arr.push(() => a); }",
+        "var a; for (let x of xs) { a = 1; (function() { a; }); }",
+        "var a; for (let x of xs) { (function() { a; }); a = 1; }",
+        "let a; function foo() { a = 10; } for (let x of xs) { (function() { a; }); } foo();",
+        "let a; function foo() { a = 10; for (let x of xs) { (function() { a; }); } } foo();",
+        "let x; for (let i = 0; i < 10; i++) { (async function () { x = i; })(); }",
+        "let x; for (let i = 0; i < 10; i++) { (function* () { x = i; })(); }",
+        "let x; for (let i = 0; i < 10; i++) { (function f() { x = i; if (i < 5) f(); })(); }",
+        "var a, b; for (var i = 0; i < 10; i++) { arr.push(() => a + b + a); } a = 1; b = 2;",
+    ];
+
+    Tester::new(NoLoopFunc::NAME, pass, fail).change_rule_path_extension("ts").test_and_snapshot();
+}
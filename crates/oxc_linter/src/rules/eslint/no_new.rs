@@ -29,6 +29,7 @@ declare_oxc_lint!(
     /// ```
     NoNew,
     suspicious,
+    ast_types = [NewExpression],
 );
 
 impl Rule for NoNew {
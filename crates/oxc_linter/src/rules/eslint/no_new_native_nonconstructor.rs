@@ -36,6 +36,7 @@ declare_oxc_lint!(
     /// ```
     NoNewNativeNonconstructor,
     correctness,
+    ast_types = [NewExpression],
 );
 
 impl Rule for NoNewNativeNonconstructor {
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// var stringObject = new String('Hello world');
     /// ```
     NoNewWrappers,
-    pedantic
+    pedantic,
+    ast_types = [NewExpression]
 );
 
 impl Rule for NoNewWrappers {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// var barIsEnumerable = foo.propertyIsEnumerable("bar");
     /// ```
     NoPrototypeBuiltins,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 const DISALLOWED_PROPS: &[&str; 3] = &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
@@ -6,6 +6,8 @@ use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -35,8 +37,11 @@ fn no_redeclare_as_builti_in_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic
 //     #[label("It cannot be redeclared here.")] pub Span,
 // );
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoRedeclare {
+    /// Also report redeclarations of builtin globals, e.g. `var Object = 0;`.
+    #[serde(rename = "builtinGlobals")]
     built_in_globals: bool,
 }
 
@@ -55,18 +60,17 @@ declare_oxc_lint!(
     /// var a = 10;
     /// ```
     NoRedeclare,
-    pedantic
+    pedantic,
+    config = NoRedeclare
+    ast_types = []
 );
 
 impl Rule for NoRedeclare {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let built_in_globals = value
+        value
             .get(0)
-            .and_then(|config| config.get("builtinGlobals"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self { built_in_globals }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -39,6 +39,7 @@ declare_oxc_lint!(
     /// ```
     NoRegexSpaces,
     restriction,
+    ast_types = [RegExpLiteral, CallExpression, NewExpression],
 );
 
 impl Rule for NoRegexSpaces {
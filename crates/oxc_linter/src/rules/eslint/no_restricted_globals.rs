@@ -3,7 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use rustc_hash::FxHashMap;
-use serde_json::Value;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -22,6 +23,19 @@ pub struct NoRestrictedGlobals {
     restricted_globals: Box<FxHashMap<String, String>>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RestrictedGlobal {
+    /// Name of the global.
+    Name(String),
+    /// Name of the global with a custom message.
+    WithMessage {
+        name: String,
+        #[serde(default)]
+        message: String,
+    },
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -53,28 +67,24 @@ declare_oxc_lint!(
     /// ```
     NoRestrictedGlobals,
     restriction,
+    config = [RestrictedGlobal]
+    ast_types = [IdentifierReference],
 );
 
 impl Rule for NoRestrictedGlobals {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let list = match value {
-            Value::Array(arr) => arr.iter().fold(FxHashMap::default(), |mut acc, v| match v {
+        let list = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|value| serde_json::from_value(value.clone()).ok())
+            .map(|global| match global {
                 // "no-restricted-globals": ["error", "event"]
-                Value::String(name) => {
-                    acc.insert(name.to_string(), String::new());
-                    acc
-                }
+                RestrictedGlobal::Name(name) => (name, String::new()),
                 // "no-restricted-globals": ["error", { "name": "event", "message": "Use local parameter instead." }]
-                Value::Object(obj) => {
-                    let name = obj.get("name").and_then(Value::as_str).unwrap_or_default();
-                    let message = obj.get("message").and_then(Value::as_str).unwrap_or_default();
-                    acc.insert(name.to_string(), message.to_string());
-                    acc
-                }
-                _ => acc,
-            }),
-            _ => FxHashMap::default(),
-        };
+                RestrictedGlobal::WithMessage { name, message } => (name, message),
+            })
+            .collect();
 
         Self { restricted_globals: Box::new(list) }
     }
@@ -0,0 +1,227 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de::Error, Deserialize, Deserializer};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    selector::{Selector, SelectorTree},
+};
+
+fn no_restricted_syntax_diagnostic(restriction: &RestrictedSyntax, span0: Span) -> OxcDiagnostic {
+    let message = match &restriction.message {
+        Some(message) => message.clone(),
+        None => format!("Using '{}' is not allowed.", restriction.selector),
+    };
+    OxcDiagnostic::warn(format!("eslint(no-restricted-syntax): {message}"))
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+/// A restricted syntax, with the message to report.
+#[derive(Debug, Clone)]
+pub struct RestrictedSyntax {
+    selector: Selector,
+    message: Option<String>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RestrictedSyntaxConfig {
+    /// A selector, e.g. `"WithStatement"`.
+    Selector(String),
+    /// A selector with the message to report, e.g.
+    /// `{ "selector": "CallExpression[callee.name='setTimeout'][arguments.length<2]", "message": "Pass a delay to setTimeout." }`.
+    WithMessage { selector: String, message: Option<String> },
+}
+
+impl<'de> Deserialize<'de> for RestrictedSyntax {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (selector, message) = match RestrictedSyntaxConfig::deserialize(deserializer)? {
+            RestrictedSyntaxConfig::Selector(selector) => (selector, None),
+            RestrictedSyntaxConfig::WithMessage { selector, message } => (selector, message),
+        };
+        let selector = Selector::parse(&selector).map_err(D::Error::custom)?;
+        Ok(Self { selector, message })
+    }
+}
+
+impl JsonSchema for RestrictedSyntax {
+    fn schema_name() -> String {
+        "RestrictedSyntax".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        RestrictedSyntaxConfig::json_schema(gen)
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows the syntax matched by the given selectors, with the syntax of ESLint selectors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some projects restrict the use of language features, e.g. `with` statements,
+    /// or of specific code patterns, which this rule can do without writing a custom rule.
+    ///
+    /// Selectors match the ESTree types, e.g. `Identifier` or `MemberExpression`, as well as the
+    /// AST types of oxc, e.g. `IdentifierReference` or `StaticMemberExpression`.
+    /// Attributes, e.g. `[callee.name='setTimeout']`, are those of the ESTree nodes.
+    ///
+    /// ### Example
+    ///
+    /// If we have options:
+    ///
+    /// ```json
+    /// "no-restricted-syntax": [
+    ///     "error",
+    ///     "WithStatement",
+    ///     {
+    ///         "selector": "CallExpression[callee.name='setTimeout'][arguments.length<2]",
+    ///         "message": "setTimeout must always be invoked with two arguments."
+    ///     }
+    /// ]
+    /// ```
+    ///
+    /// The following patterns are considered problems:
+    ///
+    /// ```javascript
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// setTimeout(() => {});
+    /// ```
+    NoRestrictedSyntax,
+    restriction,
+    config = [RestrictedSyntax],
+    ast_types = []
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let restrictions = value
+            .as_array()
+            .map(|options| {
+                options
+                    .iter()
+                    .filter_map(|option| RestrictedSyntax::deserialize(option).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self(Box::new(NoRestrictedSyntaxConfig { restrictions }))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self.0.restrictions.is_empty() {
+            return;
+        }
+        let tree = SelectorTree::new(ctx.nodes());
+        for node_id in tree.iter() {
+            for restriction in &self.0.restrictions {
+                if restriction.selector.matches(node_id, &tree) {
+                    let span = tree.kind(node_id).span();
+                    ctx.diagnostic(no_restricted_syntax_diagnostic(restriction, span));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }]))),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        (
+            "setTimeout(foo, 100);",
+            Some(json!(["CallExpression[callee.name='setTimeout'][arguments.length<2]"])),
+        ),
+        ("foo.bar();", Some(json!(["CallExpression[callee.type='Identifier']"]))),
+        ("foo(a, b);", Some(json!(["CallExpression > Identifier:nth-child(3)"]))),
+        ("function foo() { return; }", Some(json!([":function:not(:has(ReturnStatement))"]))),
+        ("foo(bar)", Some(json!(["Identifier + Identifier"]))),
+        ("if (a) b; else c;", Some(json!(["IfStatement > :expression:nth-last-child(4)"]))),
+        ("foo(/bar/)", Some(json!(["Literal[regex.pattern=/^baz/]"]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }]))),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(
+                json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom error message." }]),
+            ),
+        ),
+        (
+            "setTimeout(() => {});",
+            Some(json!([{
+                "selector": "CallExpression[callee.name='setTimeout'][arguments.length<2]",
+                "message": "setTimeout must always be invoked with two arguments."
+            }])),
+        ),
+        ("a?.b", Some(json!(["ChainExpression"]))),
+        ("foo.bar();", Some(json!(["CallExpression > MemberExpression > Identifier[name='foo']"]))),
+        ("foo.bar();", Some(json!(["CallExpression[callee.type='MemberExpression']"]))),
+        ("foo(a, b);", Some(json!(["CallExpression > Identifier:nth-child(2)"]))),
+        (
+            "foo(a, b);",
+            Some(json!([
+                "CallExpression > Identifier:first-child, CallExpression > Identifier:last-child"
+            ])),
+        ),
+        ("foo(a, b);", Some(json!(["Identifier ~ Identifier[name='b']"]))),
+        ("foo(a, b);", Some(json!(["Identifier[name='a'] + Identifier"]))),
+        ("function foo() { bar(); }", Some(json!([":function:not(:has(ReturnStatement))"]))),
+        (
+            "function foo() { bar(); }",
+            Some(json!(["FunctionDeclaration:has(> BlockStatement > ExpressionStatement)"])),
+        ),
+        ("function foo() { (bar); }", Some(json!(["ExpressionStatement > Identifier"]))),
+        ("foo(/bar/i)", Some(json!(["Literal[regex.pattern=/^BAR$/i]"]))),
+        ("foo('bar')", Some(json!(["Literal[value=/^b/]", "StringLiteral[value='bar']"]))),
+        ("foo(1, 2)", Some(json!(["Literal[value>=2]"]))),
+        ("let { a, ...b } = c;", Some(json!(["ObjectPattern > RestElement"]))),
+        ("class A { foo() {} }", Some(json!(["ClassDeclaration MethodDefinition[kind='method']"]))),
+        ("if (a) b; else c;", Some(json!([":statement:not(Program, IfStatement)"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// location.href = `javascript:void(0)`;
     /// ```
     NoScriptUrl,
-    style
+    style,
+    ast_types = [StringLiteral, TemplateLiteral]
 );
 
 impl Rule for NoScriptUrl {
@@ -11,6 +11,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::AssignmentOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -19,9 +21,10 @@ fn no_self_assign_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoSelfAssign {
-    /// if this is true, no-self-assign rule warns self-assignments of properties. Default is true.
+    /// If this is true, no-self-assign rule warns self-assignments of properties. Default is true.
     props: bool,
 }
 
@@ -46,18 +49,17 @@ declare_oxc_lint!(
     /// [bar, baz] = [bar, qiz];
     /// ```
     NoSelfAssign,
-    correctness
+    correctness,
+    config = NoSelfAssign
+    ast_types = [AssignmentExpression]
 );
 
 impl Rule for NoSelfAssign {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            props: value
-                .get(0)
-                .and_then(|v| v.get("props"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
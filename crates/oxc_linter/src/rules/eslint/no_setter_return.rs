@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoSetterReturn,
-    correctness
+    correctness,
+    ast_types = [ReturnStatement]
 );
 
 impl Rule for NoSetterReturn {
@@ -0,0 +1,311 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_shadow_diagnostic(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(no-shadow): '{x0}' is already declared in the upper scope."
+    ))
+    .with_labels([
+        LabeledSpan::new_with_span(Some(format!("'{x0}' is declared here")), span1),
+        LabeledSpan::new_with_span(Some("and shadowed here".into()), span2),
+    ])
+}
+
+fn no_shadow_global_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(no-shadow): '{x0}' is already a global variable."))
+        .with_labels([span1.into()])
+}
+
+// doc: https://github.com/eslint/eslint/blob/main/docs/src/rules/no-shadow.md
+// code: https://github.com/eslint/eslint/blob/main/lib/rules/no-shadow.js
+// test: https://github.com/eslint/eslint/blob/main/tests/lib/rules/no-shadow.js
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoShadowConfig {
+    /// Report the shadowing of the global variables, e.g. `Object`.
+    builtin_globals: bool,
+    /// Report the shadowing of the variables declared later in the upper scope.
+    hoist: Hoist,
+    /// Names which may be shadowed.
+    allow: Vec<String>,
+    /// Do not report the shadowing of a variable in its own initializer, e.g.
+    /// `var x = foo(function (x) {})`.
+    ignore_on_initialization: bool,
+    /// Do not report a type shadowing a value, or a value shadowing a type.
+    ignore_type_value_shadow: bool,
+    /// Do not report the parameters of function types and of function declarations without a
+    /// body shadowing a value, e.g. `declare function f(value: string): void`.
+    ignore_function_type_parameter_name_value_shadow: bool,
+}
+
+impl Default for NoShadowConfig {
+    fn default() -> Self {
+        Self {
+            builtin_globals: false,
+            hoist: Hoist::default(),
+            allow: vec![],
+            ignore_on_initialization: false,
+            ignore_type_value_shadow: true,
+            ignore_function_type_parameter_name_value_shadow: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum Hoist {
+    /// Report the shadowing of all the variables declared later.
+    All,
+    /// Only report the shadowing of the functions declared later.
+    #[default]
+    Functions,
+    /// Never report the shadowing of the variables declared later.
+    Never,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable declarations from shadowing variables declared in the outer scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shadowing makes the variable of the outer scope inaccessible in the inner scope, and
+    /// makes it unclear which of the two variables is read or written.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    ///
+    /// if (true) {
+    ///     let a = 5;
+    /// }
+    /// ```
+    NoShadow,
+    nursery,
+    config = NoShadowConfig,
+    ast_types = []
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|value| NoShadowConfig::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let scopes = ctx.scopes();
+        let name = symbols.get_name(symbol_id);
+        if symbols.get_flag(symbol_id).contains(SymbolFlags::EnumMember)
+            || self.0.allow.iter().any(|allowed| allowed == name)
+        {
+            return;
+        }
+
+        let scope_id = symbols.get_scope_id(symbol_id);
+        // `var` declarations in blocks are also bound in the enclosing function scope.
+        let shadowed = scopes.ancestors(scope_id).skip(1).find_map(|scope_id| {
+            scopes.get_binding(scope_id, name).filter(|shadowed| *shadowed != symbol_id)
+        });
+        let Some(shadowed) = shadowed else {
+            // The variables of the global scope of a script are the global variables.
+            let is_global_scope =
+                scope_id == scopes.root_scope_id() && !ctx.source_type().is_module();
+            if self.0.builtin_globals
+                && !is_global_scope
+                && (ctx.env_contains_var(name) || ctx.globals().is_enabled(name))
+            {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, symbols.get_span(symbol_id)));
+            }
+            return;
+        };
+
+        if self.is_allowed(symbol_id, shadowed, ctx) {
+            return;
+        }
+        ctx.diagnostic(no_shadow_diagnostic(
+            name,
+            symbols.get_span(shadowed),
+            symbols.get_span(symbol_id),
+        ));
+    }
+}
+
+impl NoShadow {
+    fn is_allowed(&self, symbol_id: SymbolId, shadowed: SymbolId, ctx: &LintContext) -> bool {
+        let symbols = ctx.symbols();
+        let span = symbols.get_span(symbol_id);
+        let declaration = ctx.nodes().kind(symbols.get_declaration(symbol_id));
+        let shadowed_declaration = ctx.nodes().kind(symbols.get_declaration(shadowed));
+        let is_shadowed_value = is_value(shadowed, ctx);
+
+        if self.0.ignore_type_value_shadow && is_value(symbol_id, ctx) != is_shadowed_value {
+            return true;
+        }
+
+        if self.0.ignore_function_type_parameter_name_value_shadow
+            && is_shadowed_value
+            && matches!(declaration, AstKind::FormalParameter(_))
+        {
+            let function =
+                ctx.nodes().iter_parents(symbols.get_declaration(symbol_id)).find_map(|node| {
+                    match node.kind() {
+                        AstKind::Function(function) => Some(function),
+                        _ => None,
+                    }
+                });
+            if function.is_some_and(|function| function.body.is_none()) {
+                return true;
+            }
+        }
+
+        let shadowed_init = match shadowed_declaration {
+            AstKind::VariableDeclarator(declarator) => declarator.init.as_ref(),
+            _ => None,
+        }
+        .map(GetSpan::span);
+        let is_in_shadowed_init =
+            shadowed_init.is_some_and(|init| init.start <= span.start && span.end <= init.end);
+
+        // `var a = function a() {}` and `var A = class A {}` do not hide anything.
+        let is_expression_name = match declaration {
+            AstKind::Function(function) => function.is_expression(),
+            AstKind::Class(class) => class.is_expression(),
+            _ => false,
+        };
+        if is_expression_name && is_in_shadowed_init {
+            return true;
+        }
+
+        if self.0.ignore_on_initialization && is_in_shadowed_init {
+            return true;
+        }
+
+        let shadowed_span = symbols.get_span(shadowed);
+        let is_declared_later = span.end < shadowed_span.start;
+        match self.0.hoist {
+            Hoist::All => false,
+            Hoist::Functions => {
+                is_declared_later && !matches!(shadowed_declaration, AstKind::Function(_))
+            }
+            Hoist::Never => is_declared_later,
+        }
+    }
+}
+
+/// Whether the symbol is a value rather than only a type, `import type` bindings are types.
+fn is_value(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let flags = ctx.symbols().get_flag(symbol_id);
+    if flags.contains(SymbolFlags::ImportBinding) {
+        let declaration = ctx.symbols().get_declaration(symbol_id);
+        let is_type_import = ctx.nodes().iter_parents(declaration).any(|node| match node.kind() {
+            AstKind::ImportSpecifier(specifier) => specifier.import_kind.is_type(),
+            AstKind::ImportDeclaration(import) => import.import_kind.is_type(),
+            _ => false,
+        });
+        return !is_type_import;
+    }
+    !flags.intersects(
+        SymbolFlags::TypeAlias
+            | SymbolFlags::Interface
+            | SymbolFlags::TypeParameter
+            | SymbolFlags::TypeLiteral,
+    ) || flags.intersects(SymbolFlags::Value | SymbolFlags::Function)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("{ const a = 0; } const a = 1;", None),
+        ("{ const a = 0; } var a;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { let a; } var a;", None),
+        ("function foo(a) { } let a;", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var top = 0; }", None),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        ("var x = foo(function(x) {});", Some(json!([{ "ignoreOnInitialization": true }]))),
+        ("var x = foo(x => x);", Some(json!([{ "ignoreOnInitialization": true }]))),
+        ("const a = [].find(a => a)", Some(json!([{ "ignoreOnInitialization": true }]))),
+        ("function foo() { try {} catch (e) {} try {} catch (e) {} }", None),
+        ("type Foo = 1; function f() { const Foo = 1; }", None),
+        ("const Foo = 1; function f() { type Foo = 2; }", None),
+        ("const T = 1; function f<T>(t: T) {}", None),
+        ("import type { Foo } from 'foo'; function f() { const Foo = 1; }", None),
+        ("const test = 1; declare function f(test: string): void;", None),
+        ("function f(a: string): void; function f(a: any) {}", None),
+        ("enum E { A } const A = 1;", None),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("let x = 1; { let x = 2; }", None),
+        ("{ let a; } function a() {}", None),
+        ("{ let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { let a; } var a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        ("var x = foo(function(x) {});", None),
+        ("var y = bar(function(y) {});", Some(json!([{ "ignoreOnInitialization": false }]))),
+        ("function foo(cb) { (function (cb) { cb(42); })(cb); }", None),
+        ("class C { m() { var C; } }", None),
+        ("try {} catch (e) { function f() { var e; } }", None),
+        ("type Foo = 1; function f() { type Foo = 2; }", None),
+        (
+            "type Foo = 1; function f() { const Foo = 1; }",
+            Some(json!([{ "ignoreTypeValueShadow": false }])),
+        ),
+        ("import { Foo } from 'foo'; function f() { const Foo = 1; }", None),
+        (
+            "const test = 1; declare function f(test: string): void;",
+            Some(json!([{ "ignoreFunctionTypeParameterNameValueShadow": false }])),
+        ),
+        ("interface I {} function f() { interface I {} }", None),
+    ];
+
+    Tester::new(NoShadow::NAME, pass, fail).change_rule_path_extension("ts").test_and_snapshot();
+}
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// try {} catch(eval){}
     /// ```
     NoShadowRestrictedNames,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 #[inline]
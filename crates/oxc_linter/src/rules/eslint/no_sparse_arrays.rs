@@ -22,7 +22,8 @@ declare_oxc_lint!(
     /// var colors = [ "red",, "blue" ];
     /// ```
     NoSparseArrays,
-    correctness
+    correctness,
+    ast_types = [ArrayExpression]
 );
 
 impl Rule for NoSparseArrays {
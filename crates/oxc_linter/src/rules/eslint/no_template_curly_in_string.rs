@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// "Time: ${12 * 60 * 60 * 1000}";
     /// ```
     NoTemplateCurlyInString,
-    style
+    style,
+    ast_types = [StringLiteral]
 );
 
 impl Rule for NoTemplateCurlyInString {
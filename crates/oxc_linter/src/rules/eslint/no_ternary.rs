@@ -31,7 +31,8 @@ declare_oxc_lint!(
     // }
     /// ```
     NoTernary,
-    style
+    style,
+    ast_types = [ConditionalExpression]
 );
 
 impl Rule for NoTernary {
@@ -4,6 +4,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -13,9 +15,12 @@ fn no_undef_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUndef {
+    /// Also report the undeclared variables used in `typeof` checks.
     #[allow(dead_code)]
+    #[serde(rename = "typeof")]
     type_of: bool,
 }
 
@@ -34,17 +39,17 @@ declare_oxc_lint!(
     /// var bar = a + 1;
     /// ```
     NoUndef,
-    nursery
+    nursery,
+    config = NoUndef
+    ast_types = []
 );
 
 impl Rule for NoUndef {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let type_of = value
+        value
             .get(0)
-            .and_then(|config| config.get("typeof"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        Self { type_of }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext) {
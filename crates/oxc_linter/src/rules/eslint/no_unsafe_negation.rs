@@ -7,6 +7,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, fixer::RuleFixer, rule::Rule, AstNode};
 
@@ -16,10 +18,11 @@ fn no_unsafe_negation_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUnsafeNegation {
-    /// true: disallow negation of the left-hand side of ordering relational operators
-    /// false: allow negation of the left-hand side of ordering relational operators (<, >, <=, >=)
+    /// Also disallow the negation of the left-hand side of ordering relational operators
+    /// (`<`, `>`, `<=`, `>=`).
     enforce_for_ordering_relations: bool,
 }
 
@@ -40,17 +43,17 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnsafeNegation,
-    correctness
+    correctness, suggestion,
+    config = NoUnsafeNegation
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoUnsafeNegation {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let enforce_for_ordering_relations = value
+        value
             .get(0)
-            .and_then(|config| config.get("enforceForOrderingRelations"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        Self { enforce_for_ordering_relations }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -96,7 +99,7 @@ impl NoUnsafeNegation {
             fixer.replace(expr.span, modified_code)
         };
 
-        ctx.diagnostic_with_fix(diagnostic, fix_producer);
+        ctx.diagnostic_with_suggestion(diagnostic, fix_producer);
     }
 }
 
@@ -7,6 +7,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::LogicalOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -24,7 +26,8 @@ fn no_unsafe_arithmetic_diagnostic(span0: Span) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUnsafeOptionalChaining {
     /// Disallow arithmetic operations on optional chaining expressions (Default false).
     /// If this is true, this rule warns arithmetic operations on optional chaining expressions, which possibly result in NaN.
@@ -52,18 +55,32 @@ declare_oxc_lint!(
     /// const { bar } = obj?.foo;  // TypeError
     /// ```
     NoUnsafeOptionalChaining,
-    restriction // TypeScript checks optional chaining
+    restriction // TypeScript checks optional chaining,
+    config = NoUnsafeOptionalChaining,
+    ast_types = [
+        CallExpression,
+        MemberExpression,
+        TaggedTemplateExpression,
+        NewExpression,
+        AssignmentExpression,
+        BinaryExpression,
+        UnaryExpression,
+        ForOfStatement,
+        WithStatement,
+        Class,
+        AssignmentPattern,
+        Argument,
+        VariableDeclarator,
+        AssignmentTargetWithDefault,
+    ]
 );
 
 impl Rule for NoUnsafeOptionalChaining {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            disallow_arithmetic_operators: value
-                .get(0)
-                .and_then(|v| v.get("disallowArithmeticOperators"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
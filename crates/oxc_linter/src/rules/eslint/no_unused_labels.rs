@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnusedLabels,
-    correctness
+    correctness, fix,
+    ast_types = []
 );
 
 impl Rule for NoUnusedLabels {
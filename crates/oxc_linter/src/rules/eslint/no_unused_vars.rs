@@ -0,0 +1,711 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPattern, BindingPatternKind, Expression, FunctionType,
+        ImportDeclarationSpecifier, ModifierKind, Statement, TSModuleDeclarationKind,
+        TSModuleDeclarationName,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNode, AstNodeId, Reference, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+fn no_unused_vars_diagnostic(
+    name: &str,
+    action: &str,
+    span: Span,
+    ignore_pattern: Option<(&str, &Regex)>,
+) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "eslint(no-unused-vars): '{name}' is {action} but never used."
+    ))
+    .with_labels([span.into()]);
+    match ignore_pattern {
+        Some((kind, pattern)) => {
+            diagnostic.with_help(format!("Allowed unused {kind} must match /{pattern}/"))
+        }
+        None => diagnostic,
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVars(Box<NoUnusedVarsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVarsConfig {
+    vars: VarsOption,
+    vars_ignore_pattern: Option<Regex>,
+    args: ArgsOption,
+    args_ignore_pattern: Option<Regex>,
+    caught_errors: CaughtErrorsOption,
+    caught_errors_ignore_pattern: Option<Regex>,
+    destructured_array_ignore_pattern: Option<Regex>,
+    ignore_rest_siblings: bool,
+}
+
+/// Options of the rule, either the `vars` option alone or an object.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum NoUnusedVarsOptions {
+    Vars(VarsOption),
+    Object(NoUnusedVarsObjectOptions),
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoUnusedVarsObjectOptions {
+    vars: VarsOption,
+    vars_ignore_pattern: Option<String>,
+    args: ArgsOption,
+    args_ignore_pattern: Option<String>,
+    caught_errors: CaughtErrorsOption,
+    caught_errors_ignore_pattern: Option<String>,
+    destructured_array_ignore_pattern: Option<String>,
+    ignore_rest_siblings: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum VarsOption {
+    /// Check all variables, including those in the global scope.
+    #[default]
+    All,
+    /// Only check variables declared in a local scope.
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum ArgsOption {
+    /// Only report unused arguments declared after the last used argument.
+    #[default]
+    AfterUsed,
+    /// Report all unused arguments.
+    All,
+    /// Do not check arguments.
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum CaughtErrorsOption {
+    #[default]
+    All,
+    None,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unused variables
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables that are declared and not used anywhere in the code are most likely
+    /// an error due to incomplete refactoring. Such variables take up space in the code
+    /// and can lead to confusion by readers.
+    ///
+    /// A variable is considered to be used if it is read, or referenced from a type
+    /// annotation. Assigning a value to a variable, or modifying it with itself
+    /// (`a += 1`, `a = a + 1`), does not count as a use. Exported symbols and ambient
+    /// (`declare`) declarations are never reported.
+    ///
+    /// ### Options
+    ///
+    /// * `vars`: `"all"` (default) or `"local"` to skip variables of the global scope.
+    /// * `varsIgnorePattern`: variables whose name matches this regex are not reported.
+    /// * `args`: `"after-used"` (default), `"all"` or `"none"`.
+    ///   With `"after-used"`, unused positional arguments that occur before the last used one are not reported.
+    /// * `argsIgnorePattern`: arguments whose name matches this regex are not reported.
+    /// * `caughtErrors`: `"all"` (default) or `"none"` to skip `catch` parameters.
+    /// * `caughtErrorsIgnorePattern`: `catch` parameters whose name matches this regex are not reported.
+    /// * `destructuredArrayIgnorePattern`: elements of array destructuring whose name matches this regex are not reported.
+    /// * `ignoreRestSiblings`: do not report properties that are siblings of a rest property, e.g. `const { a, ...rest } = obj`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { used, unused } from 'foo';
+    ///
+    /// function f(a, b) {
+    ///     return a;
+    /// }
+    ///
+    /// let x = 1;
+    /// x = 2;
+    ///
+    /// f(used);
+    /// ```
+    NoUnusedVars,
+    nursery,
+    suggestion,
+    config = NoUnusedVarsOptions,
+    ast_types = []
+);
+
+/// What a symbol was declared as, which decides the options that apply to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclarationKind {
+    Variable,
+    Import,
+    Parameter,
+    CaughtError,
+}
+
+/// Where a binding identifier sits within the binding pattern of its declaration.
+#[derive(Debug, Default, Clone, Copy)]
+struct BindingPosition {
+    /// The identifier is the whole pattern, e.g. `a` or `a = 1` but not `{ a }`.
+    top_level: bool,
+    /// The identifier is a property of an object pattern with a rest element.
+    has_rest_sibling: bool,
+    /// The identifier is an element of an array pattern.
+    array_element: bool,
+}
+
+impl Rule for NoUnusedVars {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| NoUnusedVarsOptions::deserialize(value).ok())
+            .map(|options| match options {
+                NoUnusedVarsOptions::Vars(vars) => {
+                    NoUnusedVarsObjectOptions { vars, ..NoUnusedVarsObjectOptions::default() }
+                }
+                NoUnusedVarsOptions::Object(options) => options,
+            })
+            .unwrap_or_default();
+
+        let pattern = |pattern: Option<String>| pattern.and_then(|p| Regex::new(&p).ok());
+        Self(Box::new(NoUnusedVarsConfig {
+            vars: options.vars,
+            vars_ignore_pattern: pattern(options.vars_ignore_pattern),
+            args: options.args,
+            args_ignore_pattern: pattern(options.args_ignore_pattern),
+            caught_errors: options.caught_errors,
+            caught_errors_ignore_pattern: pattern(options.caught_errors_ignore_pattern),
+            destructured_array_ignore_pattern: pattern(options.destructured_array_ignore_pattern),
+            ignore_rest_siblings: options.ignore_rest_siblings,
+        }))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        if ctx.source_type().is_typescript_definition() {
+            return;
+        }
+
+        let symbols = ctx.symbols();
+        let flags = symbols.get_flag(symbol_id);
+        if flags.intersects(
+            SymbolFlags::Export
+                | SymbolFlags::Ambient
+                | SymbolFlags::TypeParameter
+                | SymbolFlags::EnumMember,
+        ) {
+            return;
+        }
+
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        if is_in_ambient_context(declaration.id(), ctx) {
+            return;
+        }
+        let Some((kind, position, span)) = Self::classify(symbol_id, declaration, ctx) else {
+            return;
+        };
+
+        let config = &self.0;
+        let name = symbols.get_name(symbol_id);
+        let (ignore_pattern, pattern_kind) = match kind {
+            DeclarationKind::Variable | DeclarationKind::Import => {
+                if kind == DeclarationKind::Variable
+                    && config.vars == VarsOption::Local
+                    && ctx.source_type().is_script()
+                    && symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id()
+                {
+                    return;
+                }
+                (config.vars_ignore_pattern.as_ref(), "vars")
+            }
+            DeclarationKind::Parameter => {
+                if config.args == ArgsOption::None {
+                    return;
+                }
+                (config.args_ignore_pattern.as_ref(), "args")
+            }
+            DeclarationKind::CaughtError => {
+                if config.caught_errors == CaughtErrorsOption::None {
+                    return;
+                }
+                (config.caught_errors_ignore_pattern.as_ref(), "caught errors")
+            }
+        };
+
+        if ignore_pattern.is_some_and(|pattern| pattern.is_match(name)) {
+            return;
+        }
+        if position.array_element
+            && config
+                .destructured_array_ignore_pattern
+                .as_ref()
+                .is_some_and(|pattern| pattern.is_match(name))
+        {
+            return;
+        }
+        if position.has_rest_sibling && config.ignore_rest_siblings {
+            return;
+        }
+
+        if is_used(symbol_id, ctx) || is_exported_as_type(symbol_id, ctx) {
+            return;
+        }
+
+        if kind == DeclarationKind::Parameter
+            && position.top_level
+            && config.args == ArgsOption::AfterUsed
+            && is_followed_by_used_parameter(declaration, ctx)
+        {
+            return;
+        }
+
+        let assigned = matches!(declaration.kind(), AstKind::VariableDeclarator(decl) if decl.init.is_some())
+            || symbols.get_resolved_references(symbol_id).any(Reference::is_write);
+        let action = if assigned { "assigned a value" } else { "defined" };
+        let diagnostic = no_unused_vars_diagnostic(
+            name,
+            action,
+            span,
+            ignore_pattern.map(|pattern| (pattern_kind, pattern)),
+        );
+
+        if kind == DeclarationKind::Import {
+            if let Some(fix) = remove_import_fix(declaration, ctx) {
+                ctx.diagnostic_with_suggestion(diagnostic, |_| fix);
+                return;
+            }
+        }
+        ctx.diagnostic(diagnostic);
+    }
+}
+
+impl NoUnusedVars {
+    /// Returns the kind of declaration of a symbol, its position in the binding pattern,
+    /// and the span to report, or `None` if the symbol should never be reported.
+    fn classify(
+        symbol_id: SymbolId,
+        declaration: &AstNode,
+        ctx: &LintContext,
+    ) -> Option<(DeclarationKind, BindingPosition, Span)> {
+        let span = ctx.symbols().get_span(symbol_id);
+        let top_level = BindingPosition { top_level: true, ..BindingPosition::default() };
+        match declaration.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let parent = ctx.nodes().parent_kind(declaration.id());
+                if matches!(parent, Some(AstKind::VariableDeclaration(decl)) if decl.is_typescript_syntax())
+                {
+                    return None;
+                }
+                let position = find_binding(&decl.id, symbol_id, top_level)?;
+                Some((DeclarationKind::Variable, position, span))
+            }
+            AstKind::FormalParameter(param) => {
+                if param.accessibility.is_some() || param.readonly || param.r#override {
+                    return None;
+                }
+                if !has_function_body(declaration.id(), ctx) {
+                    return None;
+                }
+                let position = find_binding(&param.pattern, symbol_id, top_level)?;
+                Some((DeclarationKind::Parameter, position, span))
+            }
+            AstKind::BindingRestElement(rest) => {
+                if !has_function_body(declaration.id(), ctx) {
+                    return None;
+                }
+                let position = find_binding(&rest.argument, symbol_id, top_level)?;
+                Some((DeclarationKind::Parameter, position, span))
+            }
+            AstKind::CatchParameter(param) => {
+                let position = find_binding(&param.pattern, symbol_id, top_level)?;
+                Some((DeclarationKind::CaughtError, position, span))
+            }
+            AstKind::ImportSpecifier(_)
+            | AstKind::ImportDefaultSpecifier(_)
+            | AstKind::ImportNamespaceSpecifier(_) => {
+                Some((DeclarationKind::Import, BindingPosition::default(), span))
+            }
+            // The name of a function or class expression is only visible inside of it.
+            AstKind::Function(func) => (func.r#type == FunctionType::FunctionDeclaration
+                && !func.modifiers.contains(ModifierKind::Declare))
+            .then_some((DeclarationKind::Variable, BindingPosition::default(), span)),
+            AstKind::Class(class) => class.is_declaration().then_some((
+                DeclarationKind::Variable,
+                BindingPosition::default(),
+                span,
+            )),
+            AstKind::TSInterfaceDeclaration(_)
+            | AstKind::TSTypeAliasDeclaration(_)
+            | AstKind::TSEnumDeclaration(_) => {
+                Some((DeclarationKind::Variable, BindingPosition::default(), span))
+            }
+            AstKind::TSModuleDeclaration(module) => match &module.id {
+                TSModuleDeclarationName::Identifier(ident)
+                    if module.kind != TSModuleDeclarationKind::Global =>
+                {
+                    Some((DeclarationKind::Variable, BindingPosition::default(), ident.span))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Finds the binding identifier of `symbol_id` within `pattern`.
+fn find_binding(
+    pattern: &BindingPattern,
+    symbol_id: SymbolId,
+    position: BindingPosition,
+) -> Option<BindingPosition> {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            (ident.symbol_id.get() == Some(symbol_id)).then_some(position)
+        }
+        BindingPatternKind::AssignmentPattern(assign) => {
+            find_binding(&assign.left, symbol_id, position)
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            let property_position = BindingPosition {
+                has_rest_sibling: object.rest.is_some(),
+                ..BindingPosition::default()
+            };
+            object
+                .properties
+                .iter()
+                .find_map(|property| find_binding(&property.value, symbol_id, property_position))
+                .or_else(|| {
+                    object.rest.as_ref().and_then(|rest| {
+                        find_binding(&rest.argument, symbol_id, BindingPosition::default())
+                    })
+                })
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let element_position =
+                BindingPosition { array_element: true, ..BindingPosition::default() };
+            array
+                .elements
+                .iter()
+                .flatten()
+                .find_map(|element| find_binding(element, symbol_id, element_position))
+                .or_else(|| {
+                    array.rest.as_ref().and_then(|rest| {
+                        find_binding(&rest.argument, symbol_id, BindingPosition::default())
+                    })
+                })
+        }
+    }
+}
+
+/// Whether the symbol is exported by `export type { Foo }`, which is left out of the module record
+/// and references `Foo` without resolving to it when `Foo` is a type.
+fn is_exported_as_type(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    if ctx.symbols().get_scope_id(symbol_id) != ctx.scopes().root_scope_id() {
+        return false;
+    }
+    let Some(AstKind::Program(program)) = ctx.nodes().root_node().map(AstNode::kind) else {
+        return false;
+    };
+    let name = ctx.symbols().get_name(symbol_id);
+    program.body.iter().any(|statement| {
+        let Statement::ExportNamedDeclaration(decl) = statement else {
+            return false;
+        };
+        decl.source.is_none()
+            && decl.specifiers.iter().any(|specifier| {
+                (decl.export_kind.is_type() || specifier.export_kind.is_type())
+                    && specifier.local.name() == name
+            })
+    })
+}
+
+/// Whether the symbol is read or referenced from a type anywhere but from within its own
+/// declaration, or by modifying itself.
+fn is_used(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let declaration_id = ctx.symbols().get_declaration(symbol_id);
+    let self_span = match nodes.kind(declaration_id) {
+        AstKind::Function(func) => Some(func.span),
+        AstKind::Class(class) => Some(class.span),
+        AstKind::TSInterfaceDeclaration(decl) => Some(decl.span),
+        AstKind::TSTypeAliasDeclaration(decl) => Some(decl.span),
+        AstKind::TSEnumDeclaration(decl) => Some(decl.span),
+        AstKind::TSModuleDeclaration(decl) => Some(decl.span),
+        // `const f = () => f()`
+        AstKind::VariableDeclarator(decl) => decl.init.as_ref().and_then(|init| {
+            matches!(
+                init.without_parenthesized(),
+                Expression::FunctionExpression(_)
+                    | Expression::ArrowFunctionExpression(_)
+                    | Expression::ClassExpression(_)
+            )
+            .then(|| init.span())
+        }),
+        _ => None,
+    };
+
+    ctx.symbols().get_resolved_references(symbol_id).any(|reference| {
+        if !reference.is_read() && !reference.is_type() {
+            return false;
+        }
+        if self_span.is_some_and(|span| {
+            span.start <= reference.span().start && reference.span().end <= span.end
+        }) {
+            return false;
+        }
+        !is_self_modification(reference, symbol_id, ctx)
+    })
+}
+
+/// `a++`, `a += 1` and `a = a + 1` used as statements only modify `a` with itself.
+fn is_self_modification(reference: &Reference, symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    for node in nodes.iter_parents(reference.node_id()).skip(1) {
+        match node.kind() {
+            AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::BinaryExpression(_)
+            | AstKind::LogicalExpression(_) => {}
+            AstKind::UpdateExpression(_) => return is_expression_statement(node.id(), ctx),
+            AstKind::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                    return false;
+                };
+                let target = ident
+                    .reference_id
+                    .get()
+                    .and_then(|id| ctx.symbols().get_reference(id).symbol_id());
+                return target == Some(symbol_id) && is_expression_statement(node.id(), ctx);
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn is_expression_statement(node_id: AstNodeId, ctx: &LintContext) -> bool {
+    ctx.nodes()
+        .iter_parents(node_id)
+        .skip(1)
+        .find(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)))
+        .is_some_and(|node| matches!(node.kind(), AstKind::ExpressionStatement(_)))
+}
+
+/// Whether any parameter after the given one is used.
+fn is_followed_by_used_parameter(declaration: &AstNode, ctx: &LintContext) -> bool {
+    let Some(AstKind::FormalParameters(params)) = ctx.nodes().parent_kind(declaration.id()) else {
+        return false;
+    };
+    let span = declaration.kind().span();
+    let is_pattern_used = |pattern: &BindingPattern| {
+        let mut used = false;
+        pattern.bound_names(&mut |ident| {
+            used |= ident.symbol_id.get().is_some_and(|id| is_used(id, ctx));
+        });
+        used
+    };
+    params
+        .items
+        .iter()
+        .skip_while(|param| param.span != span)
+        .skip(1)
+        .any(|param| is_pattern_used(&param.pattern))
+        || params
+            .rest
+            .as_ref()
+            .is_some_and(|rest| rest.span != span && is_pattern_used(&rest.argument))
+}
+
+/// Parameters of overloads, `declare` functions and abstract methods are only type annotations.
+fn has_function_body(node_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let Some(params_id) = nodes.parent_id(node_id) else { return false };
+    match nodes.parent_kind(params_id) {
+        Some(AstKind::Function(func)) => !func.is_typescript_syntax(),
+        Some(AstKind::ArrowFunctionExpression(_)) => true,
+        _ => false,
+    }
+}
+
+/// Declarations within `declare namespace`, `declare module` or `declare global` only describe types.
+fn is_in_ambient_context(node_id: AstNodeId, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(node_id).any(|node| {
+        matches!(node.kind(), AstKind::TSModuleDeclaration(module)
+            if module.modifiers.contains(ModifierKind::Declare)
+                || module.kind == TSModuleDeclarationKind::Global)
+    })
+}
+
+/// Removes an unused import specifier, or the whole import declaration if none of its
+/// specifiers is used.
+fn remove_import_fix<'a>(specifier: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Fix<'a>> {
+    let Some(AstKind::ImportDeclaration(import)) = ctx.nodes().parent_kind(specifier.id()) else {
+        return None;
+    };
+    let specifiers = import.specifiers.as_ref()?;
+    let all_unused = specifiers.iter().all(|specifier| {
+        let local = match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(s) => &s.local,
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => &s.local,
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => &s.local,
+        };
+        local.symbol_id.get().is_some_and(|id| !is_used(id, ctx))
+    });
+    if all_unused {
+        return Some(Fix::delete(import.span));
+    }
+
+    let span = specifier.kind().span();
+    let index = specifiers.iter().position(|s| s.span() == span)?;
+    let is_named = |s: &ImportDeclarationSpecifier| {
+        matches!(s, ImportDeclarationSpecifier::ImportSpecifier(_))
+    };
+    let delete_span = match (specifiers.get(index.wrapping_sub(1)), specifiers.get(index + 1)) {
+        // `import a, { b } from 'x'` -> `import a from 'x'`
+        (Some(prev), None) if is_named(&specifiers[index]) && !is_named(prev) => {
+            let close_brace = ctx.source_text()[span.end as usize..].find('}')?;
+            #[allow(clippy::cast_possible_truncation)]
+            Span::new(prev.span().end, span.end + close_brace as u32 + 1)
+        }
+        // `import a, { b } from 'x'` -> `import { b } from 'x'`
+        (None, Some(next)) if is_named(next) && !is_named(&specifiers[index]) => {
+            let open_brace = ctx.source_text()[span.end as usize..].find('{')?;
+            #[allow(clippy::cast_possible_truncation)]
+            Span::new(span.start, span.end + open_brace as u32)
+        }
+        (_, Some(next)) => Span::new(span.start, next.span().start),
+        (Some(prev), None) => Span::new(prev.span().end, span.end),
+        (None, None) => return None,
+    };
+    Some(Fix::delete(delete_span))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = 5; foo;", None),
+        ("function foo() {} foo();", None),
+        ("var foo = function() {}; foo();", None),
+        ("(function foo() {})", None),
+        ("var a = class A {}; a;", None),
+        ("export var foo = 1;", None),
+        ("export function foo() {}", None),
+        ("var foo = 1; export { foo };", None),
+        ("const foo = 1; export default foo;", None),
+        ("function f(a, b) { return b; } f();", None),
+        (
+            "function f(a, b) { return b; } f();",
+            Some(serde_json::json!([{ "args": "after-used" }])),
+        ),
+        ("function f(a) {} f();", Some(serde_json::json!([{ "args": "none" }]))),
+        ("function f(_a) {} f();", Some(serde_json::json!([{ "argsIgnorePattern": "^_" }]))),
+        ("var _foo = 1;", Some(serde_json::json!([{ "varsIgnorePattern": "^_" }]))),
+        ("try {} catch (e) {}", Some(serde_json::json!([{ "caughtErrors": "none" }]))),
+        (
+            "try {} catch (ignored) {}",
+            Some(serde_json::json!([{ "caughtErrorsIgnorePattern": "^ignore" }])),
+        ),
+        (
+            "const { a, ...rest } = obj; rest;",
+            Some(serde_json::json!([{ "ignoreRestSiblings": true }])),
+        ),
+        (
+            "const [_a, b] = arr; b;",
+            Some(serde_json::json!([{ "destructuredArrayIgnorePattern": "^_" }])),
+        ),
+        ("let a = 1; a += 1; foo(a);", None),
+        ("let a = 1; foo(a++);", None),
+        ("let a = 0; for (;;) { b = a; a = 1; }", None),
+        ("import { foo } from 'foo'; foo();", None),
+        ("import foo from 'foo'; <foo.bar />;", None),
+        ("import Foo from 'foo'; <Foo />;", None),
+        ("import type { Foo } from 'foo'; let a: Foo; a;", None),
+        ("import { Foo } from 'foo'; export type Bar = Foo;", None),
+        ("const foo = 1; export type Bar = typeof foo;", None),
+        ("interface Foo {} export const a: Foo = {};", None),
+        ("interface Foo { a: string } interface Foo { b: string } export let a: Foo;", None),
+        ("class Foo {} interface Foo {} export { Foo };", None),
+        ("interface Foo {} export type { Foo };", None),
+        ("type Bar = 1; export type { Bar };", None),
+        ("type Bar = 1; export { type Bar };", None),
+        ("namespace Foo { export const a = 1; } Foo.a;", None),
+        ("enum Color { Red } Color.Red;", None),
+        ("declare const foo: number;", None),
+        ("declare function foo(a: number): void;", None),
+        ("declare module 'foo' { const bar: number; }", None),
+        ("declare global { interface Window { foo: string } }", None),
+        ("export function foo(a: string): void; export function foo(a: any) { return a; }", None),
+        ("export abstract class Foo { abstract bar(a: number): void; }", None),
+        ("export class Foo { constructor(private a: number) {} }", None),
+        ("export type Fn = (a: string) => void;", None),
+        ("export function foo<T>() {}", None),
+    ];
+
+    let fail = vec![
+        ("var foo = 5;", None),
+        ("var foo;", None),
+        ("let foo = 1; foo = 2;", None),
+        ("let foo = 1; foo += 1;", None),
+        ("let foo = 1; foo++;", None),
+        ("let foo = 1; foo = foo + 1;", None),
+        ("function foo() {}", None),
+        ("function foo() { foo(); }", None),
+        ("const foo = () => foo();", None),
+        ("class Foo {}", None),
+        ("class Foo { static create() { return new Foo(); } }", None),
+        ("function f(a, b) { return a; } f();", None),
+        ("function f(a, b) { return b; } f();", Some(serde_json::json!([{ "args": "all" }]))),
+        ("function f({ a }) {} f();", None),
+        ("(a) => {};", None),
+        ("function f(...rest) {} f();", None),
+        ("try {} catch (e) {}", None),
+        ("try {} catch ({ message }) {}", None),
+        ("var foo = 1;", Some(serde_json::json!([{ "varsIgnorePattern": "^_" }]))),
+        ("function f(a) {} f();", Some(serde_json::json!([{ "argsIgnorePattern": "^_" }]))),
+        ("const { a, ...rest } = obj; rest;", None),
+        ("const [a, b] = arr; b;", None),
+        (
+            "const [_a, b] = arr;",
+            Some(serde_json::json!([{ "destructuredArrayIgnorePattern": "^_" }])),
+        ),
+        ("import foo from 'foo';", None),
+        ("import { foo, bar } from 'foo'; bar();", None),
+        ("import * as foo from 'foo';", None),
+        ("interface Foo {}", None),
+        ("interface Foo { next: Foo }", None),
+        ("type Foo = string;", None),
+        ("type Foo = string; export type { Foo } from 'foo';", None),
+        ("enum Color { Red }", None),
+        ("namespace Foo { export const a = 1; }", None),
+        ("var foo = 1;", Some(serde_json::json!([{ "vars": "local" }]))),
+    ];
+
+    let fix = vec![
+        ("import foo from 'foo';", "", None),
+        ("import { foo, bar } from 'foo'; bar();", "import { bar } from 'foo'; bar();", None),
+        ("import { bar, foo } from 'foo'; bar();", "import { bar } from 'foo'; bar();", None),
+        ("import foo, { bar } from 'foo'; bar();", "import { bar } from 'foo'; bar();", None),
+        ("import foo, { bar } from 'foo'; foo();", "import foo from 'foo'; foo();", None),
+        ("import foo, * as bar from 'foo'; foo();", "import foo from 'foo'; foo();", None),
+        ("import { foo, bar } from 'foo';", "", None),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,358 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::ExportLocalName;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_use_before_define_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(no-use-before-define): '{x0}' was used before it was defined."
+    ))
+    .with_labels([span1.into()])
+}
+
+// doc: https://github.com/eslint/eslint/blob/main/docs/src/rules/no-use-before-define.md
+// code: https://github.com/eslint/eslint/blob/main/lib/rules/no-use-before-define.js
+// test: https://github.com/eslint/eslint/blob/main/tests/lib/rules/no-use-before-define.js
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUseBeforeDefine(Box<NoUseBeforeDefineConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoUseBeforeDefineConfig {
+    /// Check the functions, which are hoisted. `"nofunc"` is the same as `{ "functions": false }`.
+    functions: bool,
+    /// Check the classes used in functions declared before them.
+    classes: bool,
+    /// Check the variables used in functions declared before them.
+    variables: bool,
+    /// Allow `export { foo }` before the declaration of `foo`.
+    allow_named_exports: bool,
+    /// Check the enums used in functions declared before them.
+    enums: bool,
+    /// Check the type aliases and the interfaces, when `ignoreTypeReferences` is `false`.
+    typedefs: bool,
+    /// Do not check the references in type annotations.
+    ignore_type_references: bool,
+}
+
+impl Default for NoUseBeforeDefineConfig {
+    fn default() -> Self {
+        Self {
+            functions: true,
+            classes: true,
+            variables: true,
+            allow_named_exports: false,
+            enums: true,
+            typedefs: true,
+            ignore_type_references: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of variables before they are defined.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables declared with `let`, `const` and `class` cannot be used before their declaration,
+    /// which throws a `ReferenceError`. Variables declared with `var` are `undefined` before their
+    /// declaration, and functions are hoisted, which is confusing to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// alert(a);
+    /// var a = 10;
+    ///
+    /// f();
+    /// function f() {}
+    ///
+    /// new A();
+    /// class A {}
+    /// ```
+    NoUseBeforeDefine,
+    nursery,
+    config = NoUseBeforeDefineConfig,
+    ast_types = []
+);
+
+impl Rule for NoUseBeforeDefine {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = match value.get(0) {
+            Some(serde_json::Value::String(option)) if option == "nofunc" => {
+                NoUseBeforeDefineConfig { functions: false, ..NoUseBeforeDefineConfig::default() }
+            }
+            Some(value) => NoUseBeforeDefineConfig::deserialize(value).unwrap_or_default(),
+            None => NoUseBeforeDefineConfig::default(),
+        };
+        Self(Box::new(config))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let config = &self.0;
+        let symbols = ctx.symbols();
+        let flags = symbols.get_flag(symbol_id);
+        let name = symbols.get_name(symbol_id);
+        let span = symbols.get_span(symbol_id);
+        let is_typedef = flags.intersects(SymbolFlags::TypeAlias | SymbolFlags::Interface);
+        if (!config.functions && flags.contains(SymbolFlags::Function))
+            || (!config.typedefs && is_typedef)
+        {
+            return;
+        }
+        // Whether the references from functions declared before the symbol are allowed.
+        let is_allowed_in_functions = if flags.contains(SymbolFlags::Class) {
+            !config.classes
+        } else if flags.intersects(SymbolFlags::Enum) {
+            !config.enums
+        } else {
+            flags.intersects(SymbolFlags::Variable) && !config.variables
+        };
+
+        let variable_scope = variable_scope(ctx, symbols.get_scope_id(symbol_id));
+        for reference in symbols.get_resolved_references(symbol_id) {
+            if config.ignore_type_references && reference.is_type() {
+                continue;
+            }
+            let is_separate =
+                is_from_separate_execution_context(ctx, reference.node_id(), span, variable_scope);
+            if is_allowed_in_functions && is_separate {
+                continue;
+            }
+            if span.end < reference.span().end
+                && (is_separate
+                    || !is_evaluated_during_initialization(ctx, symbol_id, reference.node_id()))
+            {
+                continue;
+            }
+            ctx.diagnostic(no_use_before_define_diagnostic(name, reference.span()));
+        }
+
+        // `export { a }` does not create references.
+        if config.allow_named_exports
+            || (config.ignore_type_references && is_typedef)
+            || symbols.get_scope_id(symbol_id) != ctx.scopes().root_scope_id()
+        {
+            return;
+        }
+        for entry in &ctx.module_record().local_export_entries {
+            if let ExportLocalName::Name(local_name) = &entry.local_name {
+                if local_name.name().as_str() == name && local_name.span().end < span.start {
+                    ctx.diagnostic(no_use_before_define_diagnostic(name, local_name.span()));
+                }
+            }
+        }
+    }
+}
+
+/// The scope of the function, the class static block or the program containing `scope_id`.
+fn variable_scope(ctx: &LintContext, scope_id: ScopeId) -> ScopeId {
+    ctx.scopes()
+        .ancestors(scope_id)
+        .find(|scope_id| ctx.scopes().get_flags(*scope_id).is_var())
+        .unwrap_or_else(|| ctx.scopes().root_scope_id())
+}
+
+/// Whether the reference `node_id` is in a function, or in a class field initializer,
+/// which may be called after the symbol declared at `span` in `symbol_scope` is defined.
+fn is_from_separate_execution_context(
+    ctx: &LintContext,
+    node_id: AstNodeId,
+    span: Span,
+    symbol_scope: ScopeId,
+) -> bool {
+    // Class field initializers are evaluated when the class is instantiated.
+    let reference_span = ctx.nodes().kind(node_id).span();
+    for node in ctx.nodes().iter_parents(node_id) {
+        match node.kind() {
+            AstKind::PropertyDefinition(property) => {
+                let is_in_value = property
+                    .value
+                    .as_ref()
+                    .is_some_and(|value| contains(value.span(), reference_span));
+                if is_in_value && !contains(property.span, span) {
+                    return true;
+                }
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) | AstKind::Program(_) => {
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    // The static blocks of classes are evaluated when the class is defined.
+    let mut scope_id = ctx.nodes().get_node(node_id).scope_id();
+    loop {
+        let reference_scope = variable_scope(ctx, scope_id);
+        if reference_scope == symbol_scope {
+            return false;
+        }
+        if !ctx.scopes().get_flags(reference_scope).is_class_static_block() {
+            return true;
+        }
+        let Some(parent_id) = ctx.scopes().get_parent_id(reference_scope) else {
+            return true;
+        };
+        scope_id = parent_id;
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Whether the reference `node_id` is evaluated while the symbol is being defined, e.g.
+/// `let a = a`, `const { a = a } = {}` or `class A extends A {}`.
+fn is_evaluated_during_initialization(
+    ctx: &LintContext,
+    symbol_id: SymbolId,
+    node_id: AstNodeId,
+) -> bool {
+    let symbol_span = ctx.symbols().get_span(symbol_id);
+    let reference_span = ctx.nodes().kind(node_id).span();
+
+    if let AstKind::Class(class) = ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id)) {
+        if !contains(class.span, reference_span) {
+            return false;
+        }
+        // Static blocks are evaluated after the class is defined.
+        return !ctx.nodes().iter_parents(node_id).any(|node| {
+            matches!(node.kind(), AstKind::StaticBlock(block) if contains(class.span, block.span))
+        });
+    }
+
+    for node in ctx.nodes().iter_parents(node_id) {
+        match node.kind() {
+            AstKind::AssignmentPattern(pattern) => {
+                if contains(pattern.left.span(), symbol_span)
+                    && contains(pattern.right.span(), reference_span)
+                {
+                    return true;
+                }
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                return contains(declarator.id.span(), symbol_span)
+                    && declarator
+                        .init
+                        .as_ref()
+                        .is_some_and(|init| contains(init.span(), reference_span));
+            }
+            AstKind::ForInStatement(statement) => {
+                return contains(statement.left.span(), symbol_span)
+                    && contains(statement.right.span(), reference_span);
+            }
+            AstKind::ForOfStatement(statement) => {
+                return contains(statement.left.span(), symbol_span)
+                    && contains(statement.right.span(), reference_span);
+            }
+            AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::Class(_)
+            | AstKind::Program(_) => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("unresolved", None),
+        ("Array", None),
+        ("function foo () { arguments; }", None),
+        ("var a=10; alert(a);", None),
+        ("function b(a) { alert(a); }", None),
+        ("Object.hasOwnProperty.call(a);", None),
+        ("function a() { alert(arguments);}", None),
+        ("a(); function a() { alert(arguments); }", Some(json!(["nofunc"]))),
+        ("(() => { var a = 42; alert(a); })();", None),
+        ("a(); try { throw new Error() } catch (a) {}", None),
+        ("class A {} new A();", None),
+        ("var a = 0, b = a;", None),
+        ("var {a = 0, b = a} = {};", None),
+        ("var [a = 0, b = a] = {};", None),
+        ("function foo() { foo(); }", None),
+        ("var foo = function() { foo(); };", None),
+        ("var a; for (a in a) {}", None),
+        ("var a; for (a of a) {}", None),
+        ("let a; class C { static { a; } }", None),
+        ("class C { static { let a; a; } }", None),
+        ("class C { static { C; } }", None),
+        ("const C = class { static { C; } }", None),
+        ("class C { static { class D extends C {} } }", None),
+        ("class C { x = C; }", None),
+        ("class C { static x = C; }", None),
+        ("function foo() { new A(); } class A {};", Some(json!([{ "classes": false }]))),
+        ("function foo() { bar; } var bar;", Some(json!([{ "variables": false }]))),
+        ("var foo = () => bar; var bar;", Some(json!([{ "variables": false }]))),
+        ("function foo() { return E.A; } enum E { A }", Some(json!([{ "enums": false }]))),
+        ("export { a }; const a = 1;", Some(json!([{ "allowNamedExports": true }]))),
+        ("export { a as b }; const a = 1;", Some(json!([{ "allowNamedExports": true }]))),
+        ("let x: Foo; interface Foo {}", None),
+        ("let x: Foo; type Foo = string;", None),
+        (
+            "let x: Foo; interface Foo {}",
+            Some(json!([{ "ignoreTypeReferences": false, "typedefs": false }])),
+        ),
+        ("function f<T>(t: T): T { return t; }", None),
+        ("export const a = 1; a;", None),
+    ];
+
+    let fail = vec![
+        ("a++; var a=19;", None),
+        ("a(); var a=function() {};", None),
+        ("alert(a[1]); var a=[1,3];", None),
+        ("a(); function a() { alert(b); var b=10; a(); }", None),
+        ("a(); var a=function() {};", Some(json!(["nofunc"]))),
+        ("(() => { alert(a); var a = 42; })();", None),
+        ("(() => a())(); function a() { }", None),
+        ("a(); try { throw new Error() } catch (foo) {var a;}", None),
+        ("var f = () => a; var a;", None),
+        ("new A(); class A {};", None),
+        ("function foo() { new A(); } class A {};", None),
+        ("new A(); var A = class {};", None),
+        ("function foo() { new A(); } var A = class {};", None),
+        ("a++; { var a; }", None),
+        ("var a = a;", None),
+        ("let a = a + b;", None),
+        ("const a = foo(a);", None),
+        ("function foo(a = a) {}", None),
+        ("var {a = a} = [];", None),
+        ("var [a = a] = [];", None),
+        ("var {b = a, a} = {};", None),
+        ("var [b = a, a] = {};", None),
+        ("var {a = 0} = a;", None),
+        ("var [a = 0] = a;", None),
+        ("for (var a in a) {}", None),
+        ("for (var a of a) {}", None),
+        ("class A extends A {}", None),
+        ("class A { [A] = 1; }", None),
+        ("new A(); class A {};", Some(json!([{ "classes": false }]))),
+        ("bar; var bar;", Some(json!([{ "variables": false }]))),
+        ("class C { static { a; } } let a;", None),
+        ("class C { static { C.a = 1; } static a; x = D; } let D;", None),
+        ("export { a }; const a = 1;", None),
+        ("export { a as b }; const a = 1;", None),
+        ("function foo() { return E.A; } enum E { A }", None),
+        ("E.A; enum E { A }", Some(json!([{ "enums": false }]))),
+        ("let x: Foo; interface Foo {}", Some(json!([{ "ignoreTypeReferences": false }]))),
+        ("let x: Foo; type Foo = string;", Some(json!([{ "ignoreTypeReferences": false }]))),
+    ];
+
+    Tester::new(NoUseBeforeDefine::NAME, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
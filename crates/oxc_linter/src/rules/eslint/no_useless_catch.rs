@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUselessCatch,
-    correctness
+    correctness,
+    ast_types = [TryStatement]
 );
 
 impl Rule for NoUselessCatch {
@@ -78,7 +78,8 @@ declare_oxc_lint!(
     /// }
     ///```
     NoUselessConstructor,
-    nursery,
+    nursery, fix,
+    ast_types = [MethodDefinition]
 );
 
 impl Rule for NoUselessConstructor {
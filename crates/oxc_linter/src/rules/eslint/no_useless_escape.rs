@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoUselessEscape,
-    correctness
+    correctness, fix,
+    ast_types = [RegExpLiteral, StringLiteral, TemplateLiteral]
 );
 
 impl Rule for NoUselessEscape {
@@ -6,6 +6,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 use oxc_span::GetSpan;
@@ -20,10 +22,14 @@ fn no_useless_rename_diagnostic(span0: Span) -> OxcDiagnostic {
 pub struct NoUselessRename(Box<NoUselessRenameConfig>);
 
 #[allow(clippy::struct_field_names)]
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUselessRenameConfig {
+    /// Allow useless renames in destructuring, e.g. `const { a: a } = b;`.
     ignore_destructuring: bool,
+    /// Allow useless renames in imports, e.g. `import { a as a } from "b";`.
     ignore_import: bool,
+    /// Allow useless renames in exports, e.g. `export { a as a };`.
     ignore_export: bool,
 }
 
@@ -57,27 +63,17 @@ declare_oxc_lint!(
     /// export { baz };
     /// ```
     NoUselessRename,
-    correctness
+    correctness,
+    config = NoUselessRenameConfig
+    ast_types = [ObjectPattern, AssignmentTarget, ImportSpecifier, ExportNamedDeclaration]
 );
 
 impl Rule for NoUselessRename {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self(Box::new(NoUselessRenameConfig {
-            ignore_destructuring: obj
-                .and_then(|v| v.get("ignoreDestructuring"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            ignore_import: obj
-                .and_then(|v| v.get("ignoreImport"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            ignore_export: obj
-                .and_then(|v| v.get("ignoreExport"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
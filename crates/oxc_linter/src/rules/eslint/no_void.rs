@@ -5,6 +5,8 @@ use oxc_ast::AstKind;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 fn no_void_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-void): Disallow `void` operators")
@@ -12,8 +14,10 @@ fn no_void_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoVoid {
+    /// Allow `void` used as a statement, e.g. `void foo();`.
     pub allow_as_statement: bool,
 }
 
@@ -36,17 +40,16 @@ declare_oxc_lint!(
     /// ```
     NoVoid,
     restriction,
+    config = NoVoid
+    ast_types = [UnaryExpression],
 );
 
 impl Rule for NoVoid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_as_statement = value
+        value
             .get(0)
-            .and_then(|config| config.get("allowAsStatement"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self { allow_as_statement }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
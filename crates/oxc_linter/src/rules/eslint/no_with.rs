@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoWith,
-    correctness
+    correctness,
+    ast_types = [WithStatement]
 );
 
 impl Rule for NoWith {
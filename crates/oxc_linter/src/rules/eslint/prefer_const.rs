@@ -0,0 +1,381 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetProperty,
+        BindingPatternKind, IdentifierReference, VariableDeclarationKind,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_const_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(prefer-const): '{x0}' is never reassigned."))
+        .with_help("Use 'const' instead.")
+        .with_labels([span1.into()])
+}
+
+// doc: https://github.com/eslint/eslint/blob/main/docs/src/rules/prefer-const.md
+// code: https://github.com/eslint/eslint/blob/main/lib/rules/prefer-const.js
+// test: https://github.com/eslint/eslint/blob/main/tests/lib/rules/prefer-const.js
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferConst(Box<PreferConstConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PreferConstConfig {
+    /// How to handle the variables assigned together by a destructuring.
+    destructuring: Destructuring,
+    /// Do not report the variables which are read before their only assignment.
+    ignore_read_before_assign: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum Destructuring {
+    /// Report a variable of a destructuring when it alone can be `const`.
+    #[default]
+    Any,
+    /// Only report the variables of a destructuring when all of them can be `const`.
+    All,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `const` declarations for variables that are never reassigned after declared.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A variable which is never reassigned is easier to reason about when it is declared with
+    /// `const`, and the reader does not have to look for the assignments.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let a = 3;
+    /// console.log(a);
+    ///
+    /// let b;
+    /// b = 0;
+    /// console.log(b);
+    ///
+    /// for (let i in [1, 2, 3]) {
+    ///     console.log(i);
+    /// }
+    /// ```
+    PreferConst,
+    nursery,
+    config = PreferConstConfig,
+    ast_types = [VariableDeclaration]
+);
+
+/// A variable which can be declared with `const`.
+struct Candidate {
+    symbol_id: SymbolId,
+    /// Where the variable is reported.
+    span: Span,
+    /// The variables which must be declared with `const` together, i.e. the variables of the
+    /// same destructuring.
+    group: Vec<SymbolId>,
+}
+
+impl Rule for PreferConst {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|value| PreferConstConfig::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(declaration) = node.kind() else { return };
+        if declaration.kind != VariableDeclarationKind::Let || declaration.is_typescript_syntax() {
+            return;
+        }
+        let parent = ctx.nodes().parent_kind(node.id());
+        let is_for_in_of =
+            matches!(parent, Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)));
+
+        let mut symbol_ids = vec![];
+        declaration.bound_names(&mut |ident| symbol_ids.extend(ident.symbol_id.get()));
+        let mut candidates = symbol_ids
+            .iter()
+            .filter_map(|symbol_id| self.check_symbol(*symbol_id, is_for_in_of, ctx))
+            .collect::<Vec<_>>();
+        if self.0.destructuring == Destructuring::All {
+            // The variables of a destructuring assignment may belong to other declarations.
+            let candidate_ids = candidates.iter().map(|c| c.symbol_id).collect::<Vec<_>>();
+            candidates.retain(|c| {
+                c.group.iter().all(|id| {
+                    candidate_ids.contains(id) || self.check_symbol(*id, false, ctx).is_some()
+                })
+            });
+        }
+        // The declaration of a `for` statement cannot be split.
+        if matches!(parent, Some(AstKind::ForStatementInit(_)))
+            && candidates.len() != symbol_ids.len()
+        {
+            return;
+        }
+
+        // Replacing `let` is only possible when every variable of the declaration is initialized
+        // where it is declared and can be `const`.
+        let is_fixable = candidates.len() == symbol_ids.len()
+            && (is_for_in_of || declaration.declarations.iter().all(|d| d.init.is_some()));
+        let symbols = ctx.symbols();
+        for candidate in candidates {
+            let diagnostic =
+                prefer_const_diagnostic(symbols.get_name(candidate.symbol_id), candidate.span);
+            if is_fixable {
+                let let_span = Span::new(declaration.span.start, declaration.span.start + 3);
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(let_span, "const"));
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+impl PreferConst {
+    fn check_symbol(
+        &self,
+        symbol_id: SymbolId,
+        is_for_in_of: bool,
+        ctx: &LintContext,
+    ) -> Option<Candidate> {
+        let symbols = ctx.symbols();
+        let declaration = symbols.get_declaration(symbol_id);
+        let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(declaration) else {
+            return None;
+        };
+        if !matches!(
+            ctx.nodes().parent_kind(declaration),
+            Some(AstKind::VariableDeclaration(declaration)) if declaration.kind == VariableDeclarationKind::Let
+        ) {
+            return None;
+        }
+        let write_references = symbols
+            .get_resolved_references(symbol_id)
+            .filter(|reference| reference.is_write())
+            .collect::<Vec<_>>();
+
+        if declarator.init.is_some() || is_for_in_of {
+            if !write_references.is_empty() {
+                return None;
+            }
+            let group = if matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+                vec![]
+            } else {
+                let mut group = vec![];
+                declarator.id.bound_names(&mut |ident| group.extend(ident.symbol_id.get()));
+                group
+            };
+            return Some(Candidate { symbol_id, span: symbols.get_span(symbol_id), group });
+        }
+
+        // A variable declared without an initializer must be assigned exactly once, in the scope
+        // it is declared in, by an assignment which can become its declaration.
+        let [writer] = write_references.as_slice() else { return None };
+        let scope_id = symbols.get_scope_id(symbol_id);
+        if ctx.nodes().get_node(writer.node_id()).scope_id() != scope_id {
+            return None;
+        }
+        let is_read_before_assign = symbols
+            .get_resolved_references(symbol_id)
+            .any(|reference| reference.is_read() && reference.span().start < writer.span().start);
+        if is_read_before_assign && self.0.ignore_read_before_assign {
+            return None;
+        }
+
+        let assignment =
+            ctx.nodes().iter_parents(writer.node_id()).skip(1).find_map(|node| {
+                match node.kind() {
+                    AstKind::AssignmentExpression(_) => Some(Some(node)),
+                    AstKind::SimpleAssignmentTarget(_)
+                    | AstKind::AssignmentTarget(_)
+                    | AstKind::AssignmentTargetWithDefault(_)
+                    | AstKind::ParenthesizedExpression(_) => None,
+                    _ => Some(None),
+                }
+            })??;
+        let AstKind::AssignmentExpression(expression) = assignment.kind() else { return None };
+        if expression.operator != AssignmentOperator::Assign
+            || !is_statement_position(assignment, ctx)
+        {
+            return None;
+        }
+        let group = match &expression.left {
+            AssignmentTarget::AssignmentTargetIdentifier(_) => vec![],
+            target => {
+                let mut targets = vec![];
+                collect_assignment_targets(target, &mut targets);
+                // Every target of the destructuring must be declared in the same scope to turn
+                // the assignment into a declaration.
+                targets
+                    .into_iter()
+                    .map(|ident| {
+                        let symbol_id = ident
+                            .and_then(|ident| ident.reference_id.get())
+                            .and_then(|id| symbols.get_reference(id).symbol_id())?;
+                        (symbols.get_scope_id(symbol_id) == scope_id).then_some(symbol_id)
+                    })
+                    .collect::<Option<Vec<_>>>()?
+            }
+        };
+        Some(Candidate { symbol_id, span: writer.span(), group })
+    }
+}
+
+/// Whether the assignment is a statement of its own, which can be replaced by a declaration.
+fn is_statement_position(assignment: &AstNode, ctx: &LintContext) -> bool {
+    let mut parents = ctx
+        .nodes()
+        .iter_parents(assignment.id())
+        .skip(1)
+        .filter(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)));
+    matches!(parents.next().map(AstNode::kind), Some(AstKind::ExpressionStatement(_)))
+        && matches!(
+            parents.next().map(AstNode::kind),
+            Some(
+                AstKind::Program(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::FunctionBody(_)
+            )
+        )
+}
+
+/// Collects the targets of a destructuring assignment, `None` for the targets which are not an
+/// identifier, e.g. `obj.a` in `({ a: obj.a } = value)`.
+fn collect_assignment_targets<'a, 'b>(
+    target: &'b AssignmentTarget<'a>,
+    targets: &mut Vec<Option<&'b IdentifierReference<'a>>>,
+) {
+    match target {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => targets.push(Some(ident)),
+        AssignmentTarget::ArrayAssignmentTarget(array) => {
+            for element in array.elements.iter().flatten() {
+                match element {
+                    AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(with_default) => {
+                        collect_assignment_targets(&with_default.binding, targets);
+                    }
+                    element => {
+                        if let Some(target) = element.as_assignment_target() {
+                            collect_assignment_targets(target, targets);
+                        }
+                    }
+                }
+            }
+            if let Some(rest) = &array.rest {
+                collect_assignment_targets(&rest.target, targets);
+            }
+        }
+        AssignmentTarget::ObjectAssignmentTarget(object) => {
+            for property in &object.properties {
+                match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                        targets.push(Some(&property.binding));
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        match &property.binding {
+                            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                                with_default,
+                            ) => collect_assignment_targets(&with_default.binding, targets),
+                            binding => {
+                                if let Some(target) = binding.as_assignment_target() {
+                                    collect_assignment_targets(target, targets);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(rest) = &object.rest {
+                collect_assignment_targets(&rest.target, targets);
+            }
+        }
+        _ => targets.push(None),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var x = 0;", None),
+        ("let x;", None),
+        ("let x; { x = 0; } foo(x);", None),
+        ("let x = 0; x = 1;", None),
+        ("const x = 0;", None),
+        ("for (let i = 0, end = 10; i < end; ++i) {}", None),
+        ("for (let i in [1,2,3]) { i = 0; }", None),
+        ("for (let x of [1,2,3]) { x = 0; }", None),
+        ("(function() { var x = 0; })();", None),
+        ("(function() { let x; })();", None),
+        ("(function() { let x; { x = 0; } foo(x); })();", None),
+        ("(function() { let x = 0; x = 1; })();", None),
+        ("let a; if (true) { a = 0; } foo(a);", None),
+        ("let x; x += 1;", None),
+        ("let x = 1; x++;", None),
+        ("let a; a = 0; a = 1;", None),
+        ("let a; foo(a = 0);", None),
+        ("let a; const b = {}; ({ a, c: b.c } = func());", None),
+        ("let a; let b = 0; ({ a, b } = obj); b = 1;", Some(json!([{ "destructuring": "all" }]))),
+        ("let { a, b } = obj; b = 0;", Some(json!([{ "destructuring": "all" }]))),
+        ("let a; var b; ({ a, b } = obj);", Some(json!([{ "destructuring": "all" }]))),
+        (
+            "let x; function foo() { bar(x); } x = 0;",
+            Some(json!([{ "ignoreReadBeforeAssign": true }])),
+        ),
+        ("declare let a: number;", None),
+        ("let a: number;", None),
+    ];
+
+    let fail = vec![
+        ("let x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", None),
+        ("for (let i = 0;;) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", None),
+        ("let [x = -1, y] = [1,2]; y = 0;", None),
+        ("let {a: x = -1, b: y} = {a:1,b:2}; y = 0;", None),
+        ("(function() { let x = 1; foo(x); })();", None),
+        ("let x; x = 0;", None),
+        ("switch (a) { case 0: let x; x = 0; }", None),
+        ("(function() { let x; x = 1; })();", None),
+        ("let a; ({ a } = obj);", None),
+        ("let a, b; [a, b] = [1, 2];", None),
+        ("let a; let b; ({ a, b } = obj);", Some(json!([{ "destructuring": "all" }]))),
+        ("let { a, b } = obj; b = 0;", None),
+        ("let x; function foo() { bar(x); } x = 0;", None),
+        ("let a = 1, b = 2;", None),
+        ("let a = 1, b; b = 2;", None),
+        ("export let a = 1;", None),
+        ("class C { static { let a = 1; foo(a); } }", None),
+    ];
+
+    let fix = vec![
+        ("let x = 1; foo(x);", "const x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", "for (const i in [1,2,3]) { foo(i); }", None),
+        ("let a = 1, b = 2;", "const a = 1, b = 2;", None),
+        ("let [x = -1, y] = [1,2]; foo(x, y);", "const [x = -1, y] = [1,2]; foo(x, y);", None),
+        ("let { a, b } = obj; b = 0;", "let { a, b } = obj; b = 0;", None),
+        ("let x; x = 0;", "let x; x = 0;", None),
+    ];
+
+    Tester::new(PreferConst::NAME, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
@@ -35,6 +35,7 @@ declare_oxc_lint!(
     /// ```
     PreferExponentiationOperator,
     style,
+    ast_types = [CallExpression],
 );
 
 impl Rule for PreferExponentiationOperator {
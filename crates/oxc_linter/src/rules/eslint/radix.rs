@@ -5,6 +5,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -52,7 +54,9 @@ declare_oxc_lint!(
     /// var num = parseInt("071", 10);  // 71
     /// ```
     Radix,
-    pedantic
+    pedantic,
+    config = RadixType
+    ast_types = [CallExpression]
 );
 
 impl Rule for Radix {
@@ -61,8 +65,7 @@ impl Rule for Radix {
 
         Self {
             radix_type: obj
-                .and_then(serde_json::Value::as_str)
-                .map(RadixType::from)
+                .and_then(|value| serde_json::from_value(value.clone()).ok())
                 .unwrap_or_default(),
         }
     }
@@ -124,22 +127,16 @@ impl Radix {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum RadixType {
+    /// Require the radix argument of `parseInt()`.
     #[default]
     Always,
+    /// Disallow the radix argument when it is `10`.
     AsNeeded,
 }
 
-impl RadixType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "as-needed" => Self::AsNeeded,
-            _ => Self::Always,
-        }
-    }
-}
-
 fn is_default_radix(node: &Argument) -> bool {
     node.to_expression().is_specific_raw_number_literal("10")
 }
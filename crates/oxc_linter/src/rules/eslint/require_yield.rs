@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     RequireYield,
-    correctness
+    correctness,
+    ast_types = [Function]
 );
 
 impl Rule for RequireYield {
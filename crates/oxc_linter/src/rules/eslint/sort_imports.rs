@@ -1,7 +1,4 @@
-use std::{
-    fmt::{Display, Write},
-    str::FromStr,
-};
+use std::fmt::{Display, Write};
 
 use itertools::Itertools;
 use oxc_ast::{
@@ -11,6 +8,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -38,12 +37,20 @@ fn sort_members_alphabetically_diagnostic(x0: &str, span0: Span) -> OxcDiagnosti
 #[derive(Debug, Default, Clone)]
 pub struct SortImports(Box<SortImportsOptions>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SortImportsOptions {
+    /// Sort case-insensitively.
     ignore_case: bool,
+    /// Do not check the order of the import declarations.
     ignore_declaration_sort: bool,
+    /// Do not check the order of the members of an import declaration.
     ignore_member_sort: bool,
+    /// Check the order of each group of import declarations separated by a blank line
+    /// or a comment separately.
     allow_separated_groups: bool,
+    /// Order of the import declarations by their member syntax, with each of
+    /// `"none"`, `"all"`, `"multiple"` and `"single"` exactly once.
     member_syntax_sort_order: MemberSyntaxSortOrder,
 }
 
@@ -74,61 +81,17 @@ declare_oxc_lint!(
     /// import e from 'bar.js';
     /// ```
     SortImports,
-    style
+    style, fix,
+    config = SortImportsOptions
+    ast_types = []
 );
 
 impl Rule for SortImports {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(config) = value.get(0) else {
-            return Self(Box::default());
-        };
-
-        let ignore_case =
-            config.get("ignoreCase").and_then(serde_json::Value::as_bool).unwrap_or_default();
-        let ignore_member_sort =
-            config.get("ignoreMemberSort").and_then(serde_json::Value::as_bool).unwrap_or_default();
-        let ignore_declaration_sort = config
-            .get("ignoreDeclarationSort")
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        let allow_separated_groups = config
-            .get("allowSeparatedGroups")
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-
-        let member_syntax_sort_order = config
-            .get("memberSyntaxSortOrder")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                // memberSyntaxSortOrder in config file must have 4 items
-                if arr.len() != 4 {
-                    return MemberSyntaxSortOrder::default();
-                }
-
-                let kinds: Vec<ImportKind> = arr
-                    .iter()
-                    .filter_map(|v| v.as_str())
-                    .map(ImportKind::from_str)
-                    .filter_map(Result::ok)
-                    .unique()
-                    .collect();
-
-                // 4 items must all unique and valid.
-                if kinds.len() != 4 {
-                    return MemberSyntaxSortOrder::default();
-                }
-
-                MemberSyntaxSortOrder(kinds)
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(SortImportsOptions {
-            ignore_case,
-            ignore_declaration_sort,
-            ignore_member_sort,
-            allow_separated_groups,
-            member_syntax_sort_order,
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
     fn run_once(&self, ctx: &LintContext) {
         let Some(root) = ctx.nodes().root_node() else {
@@ -353,9 +316,22 @@ impl SortImports {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(try_from = "Vec<ImportKind>")]
 struct MemberSyntaxSortOrder(Vec<ImportKind>);
 
+impl TryFrom<Vec<ImportKind>> for MemberSyntaxSortOrder {
+    type Error = &'static str;
+
+    fn try_from(kinds: Vec<ImportKind>) -> Result<Self, Self::Error> {
+        // memberSyntaxSortOrder in config file must have 4 unique items
+        if kinds.len() != 4 || kinds.iter().unique().count() != 4 {
+            return Err("memberSyntaxSortOrder must contain each import kind exactly once");
+        }
+        Ok(MemberSyntaxSortOrder(kinds))
+    }
+}
+
 impl Default for MemberSyntaxSortOrder {
     fn default() -> Self {
         MemberSyntaxSortOrder(vec![
@@ -405,7 +381,8 @@ impl MemberSyntaxSortOrder {
     }
 }
 
-#[derive(Debug, Default, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Hash, Eq, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 enum ImportKind {
     // import from 'foo.js'
     #[default]
@@ -418,20 +395,6 @@ enum ImportKind {
     Single,
 }
 
-impl FromStr for ImportKind {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "none" => Ok(ImportKind::None),
-            "all" => Ok(ImportKind::All),
-            "multiple" => Ok(ImportKind::Multiple),
-            "single" => Ok(ImportKind::Single),
-            _ => Err("Invalid import kind"),
-        }
-    }
-}
-
 impl Display for ImportKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
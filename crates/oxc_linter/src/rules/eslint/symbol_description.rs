@@ -28,6 +28,7 @@ declare_oxc_lint!(
     /// ```
     SymbolDescription,
     pedantic,
+    ast_types = [CallExpression],
 );
 
 impl Rule for SymbolDescription {
@@ -1,6 +1,8 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Span, SPAN};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -39,7 +41,9 @@ declare_oxc_lint!(
     /// ﻿var a = 123;"
     /// ```
     UnicodeBom,
-    restriction,
+    restriction, fix,
+    config = BomOptionType
+    ast_types = []
 );
 
 impl Rule for UnicodeBom {
@@ -48,8 +52,7 @@ impl Rule for UnicodeBom {
 
         Self {
             bom_option: obj
-                .and_then(serde_json::Value::as_str)
-                .map(BomOptionType::from)
+                .and_then(|value| serde_json::from_value(value.clone()).ok())
                 .unwrap_or_default(),
         }
     }
@@ -72,22 +75,16 @@ impl Rule for UnicodeBom {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 enum BomOptionType {
+    /// Require the Unicode BOM.
     Always,
+    /// Disallow the Unicode BOM.
     #[default]
     Never,
 }
 
-impl BomOptionType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "always" => Self::Always,
-            _ => Self::Never,
-        }
-    }
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -6,6 +6,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -35,11 +37,12 @@ fn index_of_na_n(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct UseIsnan {
-    /// Whether to disallow NaN in switch cases and discriminants
+    /// Whether to disallow NaN in switch cases and discriminants.
     enforce_for_switch_case: bool,
-    /// Whether to disallow NaN as arguments of `indexOf` and `lastIndexOf`
+    /// Whether to disallow NaN as arguments of `indexOf` and `lastIndexOf`.
     enforce_for_index_of: bool,
 }
 
@@ -74,7 +77,9 @@ declare_oxc_lint!(
     /// foo > NaN;
     /// ```
     UseIsnan,
-    correctness,
+    correctness, suggestion,
+    config = UseIsnan
+    ast_types = [BinaryExpression, SwitchCase, SwitchStatement, CallExpression]
 );
 
 impl Rule for UseIsnan {
@@ -90,14 +95,16 @@ impl Rule for UseIsnan {
             }
             AstKind::BinaryExpression(expr) if expr.operator.is_equality() => {
                 if is_nan_identifier(&expr.left) {
-                    ctx.diagnostic_with_fix(comparison_with_na_n(expr.left.span()), |fixer| {
-                        fixer.replace(expr.span, make_equality_fix(true, expr, ctx))
-                    });
+                    ctx.diagnostic_with_suggestion(
+                        comparison_with_na_n(expr.left.span()),
+                        |fixer| fixer.replace(expr.span, make_equality_fix(true, expr, ctx)),
+                    );
                 }
                 if is_nan_identifier(&expr.right) {
-                    ctx.diagnostic_with_fix(comparison_with_na_n(expr.right.span()), |fixer| {
-                        fixer.replace(expr.span, make_equality_fix(false, expr, ctx))
-                    });
+                    ctx.diagnostic_with_suggestion(
+                        comparison_with_na_n(expr.right.span()),
+                        |fixer| fixer.replace(expr.span, make_equality_fix(false, expr, ctx)),
+                    );
                 }
             }
             AstKind::SwitchCase(case) if self.enforce_for_switch_case => {
@@ -130,21 +137,10 @@ impl Rule for UseIsnan {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let (enforce_for_switch_case, enforce_for_index_of) =
-            value.get(0).map_or((true, false), |config| {
-                (
-                    config
-                        .get("enforceForSwitchCase")
-                        .and_then(serde_json::Value::as_bool)
-                        .unwrap_or(true),
-                    config
-                        .get("enforceForIndexOf")
-                        .and_then(serde_json::Value::as_bool)
-                        .unwrap_or_default(),
-                )
-            });
-
-        Self { enforce_for_switch_case, enforce_for_index_of }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -4,6 +4,8 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 use phf::{phf_set, Set};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -27,9 +29,11 @@ fn invalid_value(x0: Option<&'static str>, span1: Span) -> OxcDiagnostic {
     d
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ValidTypeof {
-    /// true requires typeof expressions to only be compared to string literals or other typeof expressions, and disallows comparisons to any other value.
+    /// Require `typeof` expressions to only be compared to string literals or other
+    /// `typeof` expressions, and disallow comparisons to any other value.
     require_string_literals: bool,
 }
 declare_oxc_lint!(
@@ -52,7 +56,8 @@ declare_oxc_lint!(
     /// typeof foo === baz
     /// ```
     ValidTypeof,
-    correctness,
+    correctness, suggestion,
+    config = ValidTypeof
 );
 
 impl Rule for ValidTypeof {
@@ -98,7 +103,7 @@ impl Rule for ValidTypeof {
 
         if let Expression::Identifier(ident) = sibling {
             if ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident) {
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     if self.require_string_literals {
                         not_string(
                             Some("Use `\"undefined\"` instead of `undefined`."),
@@ -124,14 +129,10 @@ impl Rule for ValidTypeof {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let require_string_literals = value.get(0).map_or(false, |config| {
-            config
-                .get("requireStringLiterals")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false)
-        });
-
-        Self { require_string_literals }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
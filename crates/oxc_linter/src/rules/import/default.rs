@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// import bar from './bar' // no default export found in ./bar
     /// ```
     Default,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for Default {
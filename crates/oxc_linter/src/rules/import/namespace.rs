@@ -9,6 +9,8 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNode, ModuleRecord};
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::module_record::{ExportExportName, ExportImportName, ImportImportName};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -38,8 +40,10 @@ fn assignment(span0: Span, x1: &str) -> OxcDiagnostic {
 }
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/namespace.md>
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Namespace {
+    /// Allow computed references to the members of a namespace, e.g. `ns[name]`.
     allow_computed: bool,
 }
 
@@ -50,18 +54,17 @@ declare_oxc_lint!(
     /// Also, will report for computed references (i.e. foo["bar"]()).
     /// Reports on assignment to a member of an imported namespace.
     Namespace,
-    correctness
+    correctness,
+    config = Namespace,
+    ast_types = []
 );
 
 impl Rule for Namespace {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        Self {
-            allow_computed: obj
-                .and_then(|v| v.get("allowComputed"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.module_record();
@@ -9,6 +9,8 @@ use oxc_syntax::{
     module_graph_visitor::{ModuleGraphVisitorBuilder, ModuleGraphVisitorEvent, VisitFoldWhile},
     module_record::ModuleRecord,
 };
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -19,16 +21,19 @@ fn no_cycle_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
 }
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-cycle.md>
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoCycle {
-    /// maximum dependency depth to traverse
+    /// Maximum dependency depth to traverse, a number or `"∞"`.
+    #[serde(deserialize_with = "deserialize_max_depth")]
+    #[schemars(with = "MaxDepth")]
     max_depth: u32,
-    /// ignore type only imports
+    /// Ignore type-only imports.
     ignore_types: bool,
-    /// ignore external modules
+    /// Ignore external modules.
     #[allow(unused)]
     ignore_external: bool,
-    /// Allow cyclic dependency if there is at least one dynamic import in the chain
+    /// Allow cyclic dependency if there is at least one dynamic import in the chain.
     #[allow(unused)]
     allow_unsafe_dynamic_cyclic_dependency: bool,
 }
@@ -44,6 +49,28 @@ impl Default for NoCycle {
     }
 }
 
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MaxDepth {
+    Depth(u32),
+    Unlimited(Unlimited),
+}
+
+#[derive(Deserialize, JsonSchema)]
+enum Unlimited {
+    #[serde(rename = "∞")]
+    Infinity,
+}
+
+fn deserialize_max_depth<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<u32, D::Error> {
+    Ok(match MaxDepth::deserialize(deserializer)? {
+        MaxDepth::Depth(depth) => depth,
+        MaxDepth::Unlimited(_) => u32::MAX,
+    })
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -70,31 +97,17 @@ declare_oxc_lint!(
     /// import { b } from './dep-b.js' // reported: Dependency cycle detected.
     /// ```
     NoCycle,
-    restriction
+    restriction,
+    config = NoCycle,
+    ast_types = []
 );
 
 impl Rule for NoCycle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        Self {
-            max_depth: obj
-                .and_then(|v| v.get("maxDepth"))
-                .and_then(serde_json::Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(u32::MAX, |n| n as u32),
-            ignore_types: obj
-                .and_then(|v| v.get("ignoreTypes"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            ignore_external: obj
-                .and_then(|v| v.get("ignoreExternal"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            allow_unsafe_dynamic_cyclic_dependency: obj
-                .and_then(|v| v.get("allowUnsafeDynamicCyclicDependency"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
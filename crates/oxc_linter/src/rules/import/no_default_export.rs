@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoDefaultExport,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 impl Rule for NoDefaultExport {
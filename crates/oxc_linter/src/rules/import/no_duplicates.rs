@@ -2,12 +2,17 @@ use itertools::Itertools;
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_syntax::module_record::{ImportImportName, RequestedModule};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoDuplicates {
+    /// Prefer inline type imports, e.g. `import { type A }`, over separate
+    /// `import type` declarations when merging duplicates.
     prefer_inline: bool,
 }
 
@@ -16,17 +21,17 @@ declare_oxc_lint!(
     ///
     /// Reports if a resolved path is imported more than once.
     NoDuplicates,
-    suspicious
+    suspicious,
+    config = NoDuplicates,
+    ast_types = []
 );
 
 impl Rule for NoDuplicates {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            prefer_inline: value
-                .get("preferInline")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -322,7 +327,7 @@ fn test() {
         (r"import {AValue, type x, BValue} from './foo'; import {type y} from './foo'", None),
         (
             r"import {AValue} from './foo'; import type {AType} from './foo'",
-            Some(json!({ "preferInline": true })),
+            Some(json!([{ "preferInline": true }])),
         ),
     ];
 
@@ -0,0 +1,351 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::module_record::ResolvedModule;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    config::OxlintGlobs,
+    context::LintContext,
+    rule::Rule,
+    utils::{package_json_lookup, package_name},
+};
+
+fn missing_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(no-extraneous-dependencies): '{x1}' should be listed in the project's dependencies."
+    ))
+    .with_help(format!("Run `npm i -S {x1}` to add it."))
+    .with_labels([span0.into()])
+}
+
+fn not_allowed_diagnostic(span0: Span, x1: &str, x2: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(no-extraneous-dependencies): '{x1}' should be listed in the project's dependencies, not {x2}."
+    ))
+    .with_labels([span0.into()])
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-extraneous-dependencies.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependenciesConfig {
+    options: NoExtraneousDependenciesOptions,
+    /// Dependencies of the `package.json` files read so far, `None` if a file cannot be read.
+    package_jsons: Arc<DashMap<PathBuf, Option<Arc<PackageDependencies>>>>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoExtraneousDependenciesOptions {
+    /// Allow the packages of `devDependencies`, in all files or in the files matching the globs.
+    dev_dependencies: AllowedIn,
+    /// Allow the packages of `optionalDependencies`, in all files or in the files matching the globs.
+    optional_dependencies: AllowedIn,
+    /// Allow the packages of `peerDependencies`, in all files or in the files matching the globs.
+    peer_dependencies: AllowedIn,
+    /// Allow the packages of `bundledDependencies`, in all files or in the files matching the globs.
+    bundled_dependencies: AllowedIn,
+    /// Check the imports of packages resolved outside of `node_modules`,
+    /// e.g. the packages of a workspace.
+    include_internal: bool,
+    /// Check the type-only imports.
+    include_types: bool,
+    /// Directories of the `package.json` files to read the dependencies from,
+    /// instead of the `package.json` closest to the linted file.
+    package_dir: Option<PackageDir>,
+}
+
+impl Default for NoExtraneousDependenciesOptions {
+    fn default() -> Self {
+        Self {
+            dev_dependencies: AllowedIn::All(true),
+            optional_dependencies: AllowedIn::All(true),
+            peer_dependencies: AllowedIn::All(true),
+            bundled_dependencies: AllowedIn::All(true),
+            include_internal: false,
+            include_types: false,
+            package_dir: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum AllowedIn {
+    /// Allowed in all files, or in none.
+    All(bool),
+    /// Allowed in the files matching these globs, e.g. `["**/*.test.js"]`.
+    Files(OxlintGlobs),
+}
+
+impl AllowedIn {
+    fn is_allowed(&self, path: &Path) -> bool {
+        match self {
+            Self::All(allowed) => *allowed,
+            Self::Files(globs) => {
+                globs.is_match(path)
+                    || std::env::current_dir().is_ok_and(|cwd| {
+                        path.strip_prefix(cwd).is_ok_and(|path| globs.is_match(path))
+                    })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum PackageDir {
+    /// A directory, e.g. `"./packages/app"`.
+    One(PathBuf),
+    /// Several directories, the packages declared in any of them are allowed,
+    /// e.g. `["./", "./packages/app"]`.
+    Many(Vec<PathBuf>),
+}
+
+/// Names of the packages declared in a `package.json`.
+#[derive(Debug, Default)]
+struct PackageDependencies {
+    dependencies: FxHashSet<String>,
+    dev_dependencies: FxHashSet<String>,
+    optional_dependencies: FxHashSet<String>,
+    peer_dependencies: FxHashSet<String>,
+    bundled_dependencies: FxHashSet<String>,
+}
+
+impl PackageDependencies {
+    fn read(path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&text).ok()?;
+        let names = |key: &str| -> FxHashSet<String> {
+            match json.get(key) {
+                Some(serde_json::Value::Object(map)) => map.keys().cloned().collect(),
+                Some(serde_json::Value::Array(array)) => {
+                    array.iter().filter_map(|v| v.as_str().map(ToString::to_string)).collect()
+                }
+                _ => FxHashSet::default(),
+            }
+        };
+        let mut bundled_dependencies = names("bundledDependencies");
+        bundled_dependencies.extend(names("bundleDependencies"));
+        Some(Self {
+            dependencies: names("dependencies"),
+            dev_dependencies: names("devDependencies"),
+            optional_dependencies: names("optionalDependencies"),
+            peer_dependencies: names("peerDependencies"),
+            bundled_dependencies,
+        })
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids the import of packages which are not declared in the `dependencies`,
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies`
+    /// of the `package.json` closest to the linted file, so that each package of a workspace
+    /// is checked against its own `package.json`.
+    ///
+    /// Only the imports resolved to a file in `node_modules` are checked, Node.js builtin modules,
+    /// relative imports and `tsconfig.json` path aliases are not.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A package which is not declared may be installed by chance, e.g. as the dependency of
+    /// another package, and missing when the project is installed elsewhere.
+    /// A package of the `devDependencies` is not installed with the published package.
+    ///
+    /// ### Example
+    ///
+    /// With `"no-extraneous-dependencies": ["error", { "devDependencies": ["**/*.test.js"] }]`
+    /// and a `package.json` which declares `react` in `dependencies` and `vitest` in `devDependencies`:
+    ///
+    /// ```javascript
+    /// // src/index.js
+    /// import react from 'react'; // allowed
+    /// import { expect } from 'vitest'; // reported
+    /// import lodash from 'lodash'; // reported, if lodash is installed
+    ///
+    /// // src/index.test.js
+    /// import { expect } from 'vitest'; // allowed
+    /// ```
+    NoExtraneousDependencies,
+    nursery,
+    config = NoExtraneousDependenciesOptions,
+    ast_types = []
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| NoExtraneousDependenciesOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(NoExtraneousDependenciesConfig { options, package_jsons: Arc::default() }))
+    }
+
+    fn read_files(&self, file_path: &Path) -> Vec<PathBuf> {
+        match &self.0.options.package_dir {
+            None => package_json_lookup(file_path),
+            Some(PackageDir::One(dir)) => vec![dir.join("package.json")],
+            Some(PackageDir::Many(dirs)) => {
+                dirs.iter().map(|dir| dir.join("package.json")).collect()
+            }
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let options = &self.0.options;
+        let path = ctx.file_path();
+        let package_jsons = match &options.package_dir {
+            None => package_json_lookup(path)
+                .pop()
+                .filter(|package_json| package_json.is_file())
+                .into_iter()
+                .collect(),
+            Some(_) => self.read_files(path),
+        };
+        let packages = package_jsons
+            .into_iter()
+            .filter_map(|package_json| {
+                let dependencies = PackageDependencies::read(&package_json).map(Arc::new);
+                self.0.package_jsons.entry(package_json).or_insert(dependencies).clone()
+            })
+            .collect::<Vec<_>>();
+        if packages.is_empty() {
+            return;
+        }
+
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            let Some(resolved_module) = module_record.resolved_modules.get(specifier) else {
+                continue;
+            };
+            let ResolvedModule::Path(resolved_path) = resolved_module.value() else {
+                continue;
+            };
+            let Some(name) = package_name(specifier) else {
+                continue;
+            };
+            let is_external =
+                resolved_path.components().any(|c| c == Component::Normal("node_modules".as_ref()));
+            if !is_external && !options.include_internal {
+                continue;
+            }
+
+            let is_declared = |dependencies: fn(&PackageDependencies) -> &FxHashSet<String>| {
+                packages.iter().any(|package| dependencies(package).contains(name))
+            };
+            let is_allowed = is_declared(|package| &package.dependencies)
+                || (is_declared(|package| &package.dev_dependencies)
+                    && options.dev_dependencies.is_allowed(path))
+                || (is_declared(|package| &package.optional_dependencies)
+                    && options.optional_dependencies.is_allowed(path))
+                || (is_declared(|package| &package.peer_dependencies)
+                    && options.peer_dependencies.is_allowed(path))
+                || (is_declared(|package| &package.bundled_dependencies)
+                    && options.bundled_dependencies.is_allowed(path));
+            if is_allowed {
+                continue;
+            }
+
+            for requested_module in requested_modules {
+                if requested_module.is_type() && !options.include_types {
+                    continue;
+                }
+                let span = requested_module.span();
+                if is_declared(|package| &package.dev_dependencies) {
+                    ctx.diagnostic(not_allowed_diagnostic(span, name, "devDependencies"));
+                } else if is_declared(|package| &package.optional_dependencies) {
+                    ctx.diagnostic(not_allowed_diagnostic(span, name, "optionalDependencies"));
+                } else {
+                    ctx.diagnostic(missing_diagnostic(span, name));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import 'esm-package'", None),
+        ("import es6 from 'esm-package/esm-module'", None),
+        ("import { map } from 'rxjs/operators'", None),
+        ("import '@org/package'", None),
+        ("import '@org/package/internal'", None),
+        ("import '@generated/foo'", None),
+        ("import './foo'", None),
+        ("import '../foo'", None),
+        ("import fs from 'fs'", None),
+        ("import fs from 'node:fs'", None),
+        ("import 'not-installed'", None),
+        ("import type { Foo } from 'not-a-dependency'", None),
+        ("const foo = require('not-a-dependency')", None),
+        ("export { foo } from 'esm-package'", None),
+        (
+            "import 'esm-package'; import 'react'",
+            Some(
+                json!([{ "packageDir": ["./fixtures/import", "./fixtures/import/monorepo/packages/nested-package"] }]),
+            ),
+        ),
+    ];
+
+    let fail = vec![
+        ("import 'not-a-dependency'", None),
+        ("import foo from 'not-a-dependency/index.js'", None),
+        ("import '@org/not-a-dependency'", None),
+        ("import '@org/not-a-dependency/foo'", None),
+        ("import 'react'", None),
+        ("export * from 'not-a-dependency'", None),
+        ("import type { Foo } from 'not-a-dependency'", Some(json!([{ "includeTypes": true }]))),
+        ("import 'esm-package'", Some(json!([{ "packageDir": "./fixtures/import/monorepo" }]))),
+        ("import '@generated/foo'", Some(json!([{ "bundledDependencies": false }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+
+    // Checked against the `package.json` of the workspace
+    let pass = vec![
+        ("import 'left-pad'", None),
+        ("import 'left-pad'", Some(json!([{ "devDependencies": ["**/monorepo/*.js"] }]))),
+        ("import 'left-pad'", Some(json!([{ "devDependencies": ["fixtures/import/monorepo/*"] }]))),
+    ];
+
+    let fail = vec![
+        ("import 'left-pad'", Some(json!([{ "devDependencies": false }]))),
+        ("import 'left-pad'", Some(json!([{ "devDependencies": ["**/*.test.js"] }]))),
+        ("import 'esm-package'", None),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .change_rule_path("monorepo/index.js")
+        .with_import_plugin(true)
+        .test();
+
+    let pass = vec![("import 'react'", None)];
+
+    let fail = vec![("import 'left-pad'", None)];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .change_rule_path("monorepo/packages/nested-package/index.js")
+        .with_import_plugin(true)
+        .test();
+}
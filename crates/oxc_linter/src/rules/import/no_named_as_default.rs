@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// import bar from './foo.js';
     /// ```
     NoNamedAsDefault,
-    suspicious
+    suspicious,
+    ast_types = []
 );
 
 impl Rule for NoNamedAsDefault {
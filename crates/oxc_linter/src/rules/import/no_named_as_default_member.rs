@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// const bar = foo.bar // trying to access named export via default
     /// ```
     NoNamedAsDefaultMember,
-    suspicious
+    suspicious,
+    ast_types = []
 );
 fn get_symbol_id_from_ident(
     ctx: &LintContext<'_>,
@@ -0,0 +1,131 @@
+use oxc_diagnostics::OxcDiagnostic;
+
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::module_record::ResolvedModule;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_unresolved_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(no-unresolved): Unable to resolve path to module '{x1}'."
+    ))
+    .with_labels([span0.into()])
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unresolved.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    ignore: Vec<Regex>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct NoUnresolvedOptions {
+    /// Regular expressions of the module specifiers not to report, e.g. `["^virtual:"]`.
+    ignore: Vec<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that the modules imported or re-exported by a module can be resolved to a file,
+    /// or are Node.js builtin modules.
+    ///
+    /// Modules are resolved like Node.js, with the `exports` and `imports` fields of `package.json`,
+    /// and with the `paths` of `tsconfig.json`, see the `--tsconfig` option.
+    /// Type-only imports are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An import which cannot be resolved fails at runtime or at build time, e.g. because of a typo
+    /// in its path or because the package is not installed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import foo from './non-existent-file';
+    /// export { bar } from 'not-installed-package';
+    /// ```
+    NoUnresolved,
+    nursery,
+    config = NoUnresolvedOptions,
+    ast_types = []
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| NoUnresolvedOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        let ignore = options.ignore.iter().filter_map(|pattern| Regex::new(pattern).ok()).collect();
+        Self(Box::new(NoUnresolvedConfig { ignore }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            let Some(resolved_module) = module_record.resolved_modules.get(specifier) else {
+                continue;
+            };
+            if *resolved_module != ResolvedModule::NotFound
+                || self.0.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            {
+                continue;
+            }
+            for requested_module in requested_modules {
+                if !requested_module.is_type() {
+                    ctx.diagnostic(no_unresolved_diagnostic(requested_module.span(), specifier));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import foo from './bar.js'", None),
+        ("import data from './data.json'", None),
+        ("import { foo } from './deep/a'", None),
+        ("export { foo } from './bar'", None),
+        ("export * from './named-exports'", None),
+        ("import 'esm-package'", None),
+        ("import '@org/package'", None),
+        ("import { map } from 'rxjs/operators'", None),
+        ("import fs from 'fs'", None),
+        ("import fs from 'node:fs'", None),
+        ("import 'exports-package'", None),
+        ("import 'exports-package/feature'", None),
+        ("import type { Foo } from './does-not-exist'", None),
+        ("const foo = require('./does-not-exist')", None),
+        ("import foo from 'virtual:foo'", Some(json!([{ "ignore": ["^virtual:"] }]))),
+    ];
+
+    let fail = vec![
+        ("import foo from './does-not-exist'", None),
+        ("import { foo } from '../does-not-exist'", None),
+        ("import 'not-installed'", None),
+        ("import '@org/not-installed'", None),
+        ("export { foo } from './does-not-exist'", None),
+        ("export * from 'not-installed'", None),
+        ("import foo from './does-not-exist'; import bar from './does-not-exist'", None),
+        ("import 'exports-package/internal.js'", None),
+        ("import foo from 'virtual:foo'", Some(json!([{ "ignore": ["^node:"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -1,5 +1,7 @@
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -9,9 +11,12 @@ fn no_exports_found(span0: Span) -> OxcDiagnostic {
 }
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unused-modules.md>
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUnusedModules {
+    /// Report the modules without any exports.
     missing_exports: bool,
+    /// Report the exports that are not imported by any module.
     unused_exports: bool,
 }
 
@@ -24,21 +29,17 @@ declare_oxc_lint!(
     /// * dynamic imports are supported if argument is a literal string
     ///
     NoUnusedModules,
-    nursery
+    nursery,
+    config = NoUnusedModules,
+    ast_types = []
 );
 
 impl Rule for NoUnusedModules {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            missing_exports: value
-                .get("missingExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            unused_exports: value
-                .get("unusedExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -57,9 +58,9 @@ fn test() {
     use crate::tester::Tester;
     use serde_json::json;
 
-    let missing_exports_options = json!({
+    let missing_exports_options = json!([{
       "missingExports": true,
-    });
+    }]);
 
     let pass = vec![
         ("export default function noOptions() {}", None),
@@ -96,11 +97,11 @@ fn test() {
         .test_and_snapshot();
 
     // TODO: support unused exports
-    // let unused_exports_options = json!({
+    // let unused_exports_options = json!([{
     //   "unusedExports": true,
     //   "src": ["./no-unused-modules/**/*.js"],
     //   "ignoreExports": ["./no-unused-modules/*ignored*.js"],
-    // });
+    // }]);
 
     // let pass = vec![
     //     ("export default function noOptions() {}", None),
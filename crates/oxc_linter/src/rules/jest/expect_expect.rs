@@ -69,7 +69,8 @@ declare_oxc_lint!(
     /// test('should assert something', () => {});
     /// ```
     ExpectExpect,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for ExpectExpect {
@@ -273,6 +274,8 @@ fn test() {
 
     let pass = vec![
         ("it.todo('will test something eventually')", None),
+        ("test('types', () => { expectTypeOf(1).toBeNumber() })", None),
+        ("import { test, expect } from 'vitest'; test('foo', () => { expect(1).toBe(1) })", None),
         ("test.todo('will test something eventually')", None),
         ("['x']();", None),
         ("it('should pass', () => expect(true).toBeDefined())", None),
@@ -396,6 +399,7 @@ fn test() {
 
     let fail = vec![
         ("it(\"should fail\", () => {});", None),
+        ("import { test } from 'vitest'; test('should fail', () => {});", None),
         ("it(\"should fail\", myTest); function myTest() {}", None),
         ("test(\"should fail\", () => {});", None),
         ("test.skip(\"should fail\", () => {});", None),
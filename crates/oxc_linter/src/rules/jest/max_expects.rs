@@ -1,5 +1,7 @@
 use oxc_diagnostics::OxcDiagnostic;
 
+use schemars::JsonSchema;
+use serde::Deserialize;
 use std::{collections::HashMap, hash::BuildHasherDefault};
 
 use crate::{
@@ -18,8 +20,10 @@ fn exceeded_max_assertion(x0: usize, x1: usize, span2: Span) -> OxcDiagnostic {
         .with_labels([span2.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxExpects {
+    /// Maximum number of assertions in a test.
     pub max: usize,
 }
 
@@ -63,18 +67,16 @@ declare_oxc_lint!(
     /// ```
     MaxExpects,
     style,
+    config = MaxExpects,
+    ast_types = [],
 );
 
 impl Rule for MaxExpects {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max = value
+        value
             .get(0)
-            .and_then(|config| config.get("max"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(5, |v| usize::try_from(v).unwrap_or(5));
-
-        Self { max }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext) {
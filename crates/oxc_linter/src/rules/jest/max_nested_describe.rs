@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeId;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -21,8 +23,10 @@ fn exceeded_max_depth(current: usize, max: usize, span0: Span) -> OxcDiagnostic
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxNestedDescribe {
+    /// Maximum number of nested `describe` blocks.
     pub max: usize,
 }
 
@@ -115,18 +119,16 @@ declare_oxc_lint!(
     ///
     MaxNestedDescribe,
     style,
+    config = MaxNestedDescribe,
+    ast_types = [],
 );
 
 impl Rule for MaxNestedDescribe {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max = value
+        value
             .get(0)
-            .and_then(|config| config.get("max"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(5, |v| usize::try_from(v).unwrap_or(5));
-
-        Self { max }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// expect(a).toThrowError();
     /// ```
     NoAliasMethods,
-    style
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for NoAliasMethods {
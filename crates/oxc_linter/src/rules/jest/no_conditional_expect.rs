@@ -52,7 +52,8 @@ declare_oxc_lint!(
     // });
     /// ```
     NoConditionalExpect,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 // To flag we encountered a conditional block/catch block when traversing the parents.
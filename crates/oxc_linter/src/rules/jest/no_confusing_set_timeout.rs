@@ -75,7 +75,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoConfusingSetTimeout,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for NoConfusingSetTimeout {
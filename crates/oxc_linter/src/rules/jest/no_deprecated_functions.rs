@@ -4,6 +4,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{phf_map, Map};
+use schemars::JsonSchema;
+use serde::Deserialize;
 use std::borrow::Cow;
 
 use crate::{context::LintContext, rule::Rule};
@@ -16,16 +18,20 @@ fn deprecated_function(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     .with_labels([span2.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct JestConfig {
+    /// Version of Jest, e.g. `"29"`.
     version: String,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct NoDeprecatedFunctions(Box<NoDeprecatedFunctionsConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoDeprecatedFunctionsConfig {
+    /// Settings of Jest.
     jest: JestConfig,
 }
 
@@ -76,7 +82,9 @@ declare_oxc_lint!(
     /// jest.addMatchers // since Jest 17
     /// ```
     NoDeprecatedFunctions,
-    style,
+    style, fix,
+    config = NoDeprecatedFunctionsConfig,
+    ast_types = [MemberExpression]
 );
 
 const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_map! {
@@ -90,16 +98,15 @@ const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_m
 
 impl Rule for NoDeprecatedFunctions {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let version = value
+        let config: NoDeprecatedFunctionsConfig = value
             .get(0)
-            .and_then(|v| v.get("jest"))
-            .and_then(|v| v.get("version"))
-            .and_then(|v| serde_json::Value::as_str(v))
-            // Todo: Fixed Me
-            // Currently set the default version to the (maybe) latest, to help to find more problems in
-            // the codebase. In the future, the version should come from the cli option or the config files,
-            // such as `package.json` or `eslint.config.js`.
-            .unwrap_or("29");
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default();
+        // Todo: Fixed Me
+        // Currently set the default version to the (maybe) latest, to help to find more problems in
+        // the codebase. In the future, the version should come from the cli option or the config files,
+        // such as `package.json` or `eslint.config.js`.
+        let version = if config.jest.version.is_empty() { "29" } else { &config.jest.version };
 
         let major: Vec<&str> = version.split('.').collect();
 
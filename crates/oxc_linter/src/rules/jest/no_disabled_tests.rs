@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoDisabledTests,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 fn no_disabled_tests_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
@@ -96,7 +97,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
             let ParsedGeneralJestFnCall { kind, members, name, .. } = jest_fn_call;
             // `test('foo')`
             let kind = match kind {
-                JestFnKind::Expect | JestFnKind::Unknown => return,
+                JestFnKind::Expect | JestFnKind::ExpectTypeOf | JestFnKind::Unknown => return,
                 JestFnKind::General(kind) => kind,
             };
             if matches!(kind, JestGeneralFnKind::Test)
@@ -171,6 +172,9 @@ fn test() {
         ("test('foo', () => { const pending = getPending(); expect(pending()).toEqual({}) })", None),
         ("test('foo', () => { expect(pending()).toEqual({}) }); function pending() { return {} }", None),
         ("import { test } from './test-utils'; test('something');", None),
+        ("test.skipIf(isCI)('foo', () => {})", None),
+        ("test.runIf(isCI)('foo', () => {})", None),
+        ("test.fails('foo', () => {})", None),
     ];
 
     let fail = vec![
@@ -200,6 +204,9 @@ fn test() {
         ("pending()", None),
         ("describe('contains a call to pending', function () { pending() })", None),
         ("import { test } from '@jest/globals';test('something');", None),
+        ("suite.skip('foo', () => {})", None),
+        ("describe.concurrent.skip('foo', () => {})", None),
+        ("import { test } from 'vitest'; test.skip('foo', () => {})", None),
     ];
 
     Tester::new(NoDisabledTests::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
@@ -77,7 +77,8 @@ declare_oxc_lint!(
     /// ```
     NoDoneCallback,
     // TODO: add suggestion (see jest-community/eslint-plugin-jest#586)
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for NoDoneCallback {
@@ -99,6 +99,7 @@ declare_oxc_lint!(
     /// ```
     NoDuplicateHooks,
     style,
+    ast_types = [],
 );
 
 impl Rule for NoDuplicateHooks {
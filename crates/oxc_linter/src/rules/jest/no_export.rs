@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoExport,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoExport {
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    correctness
+    correctness, fix,
+    ast_types = []
 );
 
 impl Rule for NoFocusedTests {
@@ -113,6 +114,8 @@ fn test() {
         ("test.each()()", None),
         ("test.each`table`()", None),
         ("test.concurrent()", None),
+        ("suite('foo', () => {})", None),
+        ("test.sequential('foo', () => {})", None),
     ];
 
     let fail = vec![
@@ -136,6 +139,10 @@ fn test() {
         ("fit()", None),
         ("fit.each()()", None),
         ("fit.each`table`()", None),
+        ("suite.only('foo', () => {})", None),
+        ("test.concurrent.only('foo', () => {})", None),
+        ("describe.shuffle.only('foo', () => {})", None),
+        ("import { it } from 'vitest'; it.only('foo', () => {})", None),
     ];
 
     let fix = vec![
@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -21,8 +23,10 @@ fn unexpected_hook_diagonsitc(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoHooks(Box<NoHooksConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoHooksConfig {
+    /// Hooks to allow, e.g. `["beforeEach"]`.
     allow: Vec<String>,
 }
 
@@ -80,20 +84,16 @@ declare_oxc_lint!(
     /// ```
     NoHooks,
     style,
+    config = NoHooksConfig,
+    ast_types = [],
 );
 
 impl Rule for NoHooks {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow = value
+        value
             .get(0)
-            .and_then(|config| config.get("allow"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(NoHooksConfig { allow }))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     ///  });
     /// ```
     NoIdenticalTitle,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for NoIdenticalTitle {
@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// );
     /// ```
     NoInterpolationInSnapshots,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for NoInterpolationInSnapshots {
@@ -126,6 +126,7 @@ declare_oxc_lint!(
     ///
     NoLargeSnapshots,
     style,
+    ast_types = [],
 );
 
 impl Rule for NoLargeSnapshots {
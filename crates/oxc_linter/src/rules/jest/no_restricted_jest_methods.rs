@@ -11,8 +11,7 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::HashMap, hash::BuildHasherDefault};
+use rustc_hash::FxHashMap;
 
 fn restricted_jest_method(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -70,19 +69,23 @@ declare_oxc_lint!(
     ///
     NoRestrictedJestMethods,
     style,
+    config = FxHashMap<String, Option<String>>,
+    ast_types = [],
 );
 
 impl Rule for NoRestrictedJestMethods {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let restricted_jest_methods = &value
+        let restricted_jest_methods = value
             .get(0)
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_restricted_jest_methods)
-            .unwrap_or_default();
-
-        Self(Box::new(NoRestrictedJestMethodsConfig {
-            restricted_jest_methods: restricted_jest_methods.clone(),
-        }))
+            .and_then(|value| {
+                serde_json::from_value::<FxHashMap<String, Option<String>>>(value.clone()).ok()
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, message)| (name, message.unwrap_or_default()))
+            .collect();
+
+        Self(Box::new(NoRestrictedJestMethodsConfig { restricted_jest_methods }))
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -141,20 +144,6 @@ impl NoRestrictedJestMethods {
             );
         }
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_restricted_jest_methods(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<HashMap<String, String, BuildHasherDefault<FxHasher>>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    (String::from(key), String::from(value.as_str().unwrap_or_default()))
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
@@ -189,6 +178,14 @@ fn test() {
             ",
             Some(serde_json::json!([{ "advanceTimersByTime": null }])),
         ),
+        ("vi.mock('foo')", Some(serde_json::json!([{ "mock": "Do not use mocks" }]))),
+        (
+            "
+                import { vi } from 'vitest';
+                vi.useFakeTimers();
+            ",
+            Some(serde_json::json!([{ "useFakeTimers": null }])),
+        ),
     ];
 
     Tester::new(NoRestrictedJestMethods::NAME, pass, fail)
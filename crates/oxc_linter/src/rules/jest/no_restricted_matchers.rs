@@ -12,8 +12,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
-use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::HashMap, hash::BuildHasherDefault, path::Path};
+use rustc_hash::FxHashMap;
+use std::path::Path;
 
 fn restricted_chain(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -74,21 +74,25 @@ declare_oxc_lint!(
     ///
     NoRestrictedMatchers,
     style,
+    config = FxHashMap<String, Option<String>>,
+    ast_types = [],
 );
 
 const MODIFIER_NAME: phf::Set<&'static str> = phf_set!["not", "rejects", "resolves"];
 
 impl Rule for NoRestrictedMatchers {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let restricted_matchers = &value
+        let restricted_matchers = value
             .get(0)
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_restricted_matchers)
-            .unwrap_or_default();
-
-        Self(Box::new(NoRestrictedMatchersConfig {
-            restricted_matchers: restricted_matchers.clone(),
-        }))
+            .and_then(|value| {
+                serde_json::from_value::<FxHashMap<String, Option<String>>>(value.clone()).ok()
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, message)| (name, message.unwrap_or_default()))
+            .collect();
+
+        Self(Box::new(NoRestrictedMatchersConfig { restricted_matchers }))
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -150,20 +154,6 @@ impl NoRestrictedMatchers {
 
         chain_call == restriction
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_restricted_matchers(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<HashMap<String, String, BuildHasherDefault<FxHasher>>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    (String::from(key), String::from(value.as_str().unwrap_or_default()))
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
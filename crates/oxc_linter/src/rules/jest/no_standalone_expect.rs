@@ -6,6 +6,8 @@ use oxc_ast::AstKind;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNodeId;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -30,8 +32,10 @@ fn no_standalone_expect_diagnostic(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoStandaloneExpect(Box<NoStandaloneExpectConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoStandaloneExpectConfig {
+    /// Names of the functions besides `it` and `test` whose callbacks are test blocks.
     additional_test_block_functions: Vec<String>,
 }
 
@@ -60,21 +64,17 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoStandaloneExpect,
-    correctness
+    correctness,
+    config = NoStandaloneExpectConfig,
+    ast_types = []
 );
 
 impl Rule for NoStandaloneExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let additional_test_block_functions = value
+        value
             .get(0)
-            .and_then(|v| v.get("additionalTestBlockFunctions"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(NoStandaloneExpectConfig { additional_test_block_functions }))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
     fn run_once(&self, ctx: &LintContext<'_>) {
         let possible_jest_nodes = collect_possible_jest_call_node(ctx);
@@ -241,6 +241,7 @@ fn test() {
 
     let pass = vec![
         ("expect.any(String)", None),
+        ("expectTypeOf(a).toBeString()", None),
         ("expect.extend({})", None),
         ("describe('a test', () => { it('an it', () => {expect(1).toBe(1); }); });", None),
         ("describe('a test', () => { it('an it', () => { const func = () => { expect(1).toBe(1); }; }); });", None),
@@ -292,6 +293,7 @@ fn test() {
 
     let fail = vec![
         ("(() => {})('testing', () => expect(true).toBe(false))", None),
+        ("import { expect } from 'vitest'; expect(1).toBe(1)", None),
         ("expect.hasAssertions()", None),
         ("expect().hasAssertions()", None),
         (
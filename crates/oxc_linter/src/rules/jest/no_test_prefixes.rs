@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// xdescribe('foo'); // invalid
     /// ```
     NoTestPrefixes,
-    style
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for NoTestPrefixes {
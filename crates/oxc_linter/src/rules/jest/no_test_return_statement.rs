@@ -40,6 +40,7 @@ declare_oxc_lint!(
     /// ```
     NoTestReturnStatement,
     style,
+    ast_types = [CallExpression, Function],
 );
 
 impl Rule for NoTestReturnStatement {
@@ -85,7 +85,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoUntypedMockFactory,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for NoUntypedMockFactory {
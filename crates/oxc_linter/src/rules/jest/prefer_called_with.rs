@@ -45,6 +45,7 @@ declare_oxc_lint!(
     ///
     PreferCalledWith,
     style,
+    ast_types = [],
 );
 
 impl Rule for PreferCalledWith {
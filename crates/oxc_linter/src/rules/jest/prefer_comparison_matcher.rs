@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// ```
     ///
     PreferComparisonMatcher,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for PreferComparisonMatcher {
@@ -42,6 +42,7 @@ declare_oxc_lint!(
     /// ```
     PreferEqualityMatcher,
     style,
+    ast_types = [],
 );
 
 impl Rule for PreferEqualityMatcher {
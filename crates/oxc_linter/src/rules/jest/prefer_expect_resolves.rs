@@ -72,7 +72,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     PreferExpectResolves,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for PreferExpectResolves {
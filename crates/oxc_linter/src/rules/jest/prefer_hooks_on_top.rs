@@ -139,6 +139,7 @@ declare_oxc_lint!(
     /// ```
     PreferHooksOnTop,
     style,
+    ast_types = [],
 );
 
 impl Rule for PreferHooksOnTop {
@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -19,10 +21,14 @@ fn unexpected_lowercase(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PreferLowercaseTitleConfig {
+    /// Prefixes of the titles that may begin with an uppercase letter.
     allowed_prefixes: Vec<String>,
+    /// Functions not to check, any of `"describe"`, `"test"` and `"it"`.
     ignore: Vec<String>,
+    /// Do not check the titles of the top-level `describe` blocks.
     ignore_top_level_describe: bool,
 }
 
@@ -129,36 +135,17 @@ declare_oxc_lint!(
     /// ```
     ///
     PreferLowercaseTitle,
-    style,
+    style, fix,
+    config = PreferLowercaseTitleConfig,
+    ast_types = []
 );
 
 impl Rule for PreferLowercaseTitle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        let ignore_top_level_describe = obj
-            .and_then(|config| config.get("ignoreTopLevelDescribe"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-        let ignore = obj
-            .and_then(|config| config.get("ignore"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-        let allowed_prefixes = obj
-            .and_then(|config| config.get("allowedPrefixes"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(PreferLowercaseTitleConfig {
-            allowed_prefixes,
-            ignore,
-            ignore_top_level_describe,
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// ```
     ///
     PreferMockPromiseShorthand,
-    style,
+    style, fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferMockPromiseShorthand {
@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// jest.spyOn(Date, 'now').mockImplementation(() => 10);
     /// ```
     PreferSpyOn,
-    style,
+    style, fix,
+    ast_types = [AssignmentExpression]
 );
 
 impl Rule for PreferSpyOn {
@@ -126,8 +127,13 @@ impl PreferSpyOn {
                         false,
                     )
                 };
-                let content =
-                    Self::build_code(call_expr, left_assign, has_mock_implementation, fixer);
+                let content = Self::build_code(
+                    call_expr,
+                    left_assign,
+                    &jest_fn_call.local,
+                    has_mock_implementation,
+                    fixer,
+                );
                 fixer.replace(Span::new(assign_expr.span.start, end), content)
             },
         );
@@ -136,11 +142,13 @@ impl PreferSpyOn {
     fn build_code<'a>(
         call_expr: &'a CallExpression<'a>,
         left_assign: &MemberExpression,
+        // `jest`, or e.g. `vi` of Vitest
+        local: &str,
         has_mock_implementation: bool,
         fixer: RuleFixer<'_, 'a>,
     ) -> String {
         let mut formatter = fixer.codegen();
-        formatter.print_str(b"jest.spyOn(");
+        formatter.print_str(format!("{local}.spyOn(").as_bytes());
 
         match left_assign {
             MemberExpression::ComputedMemberExpression(cmp_mem_expr) => {
@@ -176,7 +184,7 @@ impl PreferSpyOn {
     }
 
     fn get_jest_fn_call<'a>(call_expr: &'a CallExpression<'a>) -> Option<&'a Expression<'a>> {
-        let is_jest_fn = get_node_name(&call_expr.callee) == "jest.fn";
+        let is_jest_fn = matches!(get_node_name(&call_expr.callee).as_str(), "jest.fn" | "vi.fn");
 
         if is_jest_fn {
             return call_expr.arguments.first().and_then(Argument::as_expression);
@@ -233,6 +241,8 @@ fn tests() {
         ("obj['prop' + 1] = jest['fn']()", None),
         ("obj.one.two = jest.fn(); const test = 10;", None),
         ("obj.a = jest.fn(() => 10,)", None),
+        ("obj.a = vi.fn(() => 10)", None),
+        ("import { vi } from 'vitest'; Date.now = vi.fn();", None),
         ("obj.a.b = jest.fn(() => ({})).mockReturnValue('default').mockReturnValueOnce('first call'); test();", None),
         ("window.fetch = jest.fn(() => ({})).one.two().three().four", None),
         ("foo[bar] = jest.fn().mockReturnValue(undefined)", None),
@@ -252,6 +262,7 @@ fn tests() {
         ("obj['prop' + 1] = jest['fn']()", "jest.spyOn(obj, 'prop' + 1).mockImplementation()", None),
         ("obj.one.two = jest.fn(); const test = 10;", "jest.spyOn(obj.one, 'two').mockImplementation(); const test = 10;", None),
         ("obj.a = jest.fn(() => 10,)", "jest.spyOn(obj, 'a').mockImplementation(() => 10)", None),
+        ("obj.a = vi.fn(() => 10)", "vi.spyOn(obj, 'a').mockImplementation(() => 10)", None),
         (
             "obj.a.b = jest.fn(() => ({})).mockReturnValue('default').mockReturnValueOnce('first call'); test();",
             "jest.spyOn(obj.a, 'b').mockImplementation(() => ({})).mockReturnValue('default').mockReturnValueOnce('first call'); test();",
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// ```
     ///
     PreferStrictEqual,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for PreferStrictEqual {
@@ -73,7 +73,8 @@ declare_oxc_lint!(
     /// expect(loadMessage()).resolves.toEqual('hello world');
     /// ```
     PreferToBe,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 #[derive(Clone, Debug, PartialEq)]
@@ -52,6 +52,7 @@ declare_oxc_lint!(
     ///
     PreferToContain,
     style,
+    ast_types = [],
 );
 
 impl Rule for PreferToContain {
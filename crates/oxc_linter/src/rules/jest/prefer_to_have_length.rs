@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// ```
     ///
     PreferToHaveLength,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for PreferToHaveLength {
@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// test.todo('i need to write this test');
     /// ```
     PreferTodo,
-    style,
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for PreferTodo {
@@ -8,6 +8,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -26,8 +28,10 @@ fn use_hook(span0: Span) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct RequireHookConfig {
+    /// Names of the functions that may be called outside of hooks.
     allowed_function_calls: Vec<String>,
 }
 
@@ -147,21 +151,16 @@ declare_oxc_lint!(
     /// ```
     ///
     RequireHook,
-    style
+    style,
+    config = RequireHookConfig
 );
 
 impl Rule for RequireHook {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allowed_function_calls = value
+        value
             .get(0)
-            .and_then(|config| config.get("allowedFunctionCalls"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(RequireHookConfig { allowed_function_calls }))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// ```
     ///
     RequireToThrowMessage,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for RequireToThrowMessage {
@@ -5,6 +5,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeId;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -39,8 +41,10 @@ fn unexpected_hook(span0: Span) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct RequireTopLevelDescribe {
+    /// Maximum number of top-level `describe` blocks in a file.
     pub max_number_of_top_level_describes: usize,
 }
 
@@ -108,18 +112,16 @@ declare_oxc_lint!(
     ///
     RequireTopLevelDescribe,
     style,
+    config = RequireTopLevelDescribe,
+    ast_types = [],
 );
 
 impl Rule for RequireTopLevelDescribe {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max_number_of_top_level_describes = value
+        value
             .get(0)
-            .and_then(|config| config.get("maxNumberOfTopLevelDescribes"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(usize::MAX, |v| usize::try_from(v).unwrap_or(usize::MAX));
-
-        Self { max_number_of_top_level_describes }
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -60,7 +60,8 @@ declare_oxc_lint!(
     /// }));
     /// ```
     ValidDescribeCallback,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for ValidDescribeCallback {
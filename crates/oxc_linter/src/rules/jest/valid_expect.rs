@@ -67,7 +67,8 @@ declare_oxc_lint!(
     /// expect(Promise.resolve('Hi!')).resolves.toBe('Hi!');
     /// ```
     ValidExpect,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for ValidExpect {
@@ -419,6 +420,7 @@ fn test() {
 
     let pass = vec![
         ("expect.hasAssertions", None),
+        ("expectTypeOf<string>().toBeString()", None),
         ("expect.hasAssertions()", None),
         ("expect('something').toEqual('else');", None),
         ("expect(true).toBeDefined();", None),
@@ -546,6 +548,7 @@ fn test() {
 
     let fail = vec![
         ("expect().toBe(2);", None),
+        ("import { expect } from 'vitest'; expect().toBe(2);", None),
         ("expect().toBe(true);", None),
         ("expect().toEqual('something');", None),
         ("expect('something', 'else').toEqual('something');", None),
@@ -68,7 +68,8 @@ declare_oxc_lint!(
     /// xtest('', () => {});
     /// ```
     ValidTitle,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for ValidTitle {
@@ -53,7 +53,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     CheckPropertyNames,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for CheckPropertyNames {
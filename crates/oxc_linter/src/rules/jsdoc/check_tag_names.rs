@@ -3,6 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
@@ -43,10 +44,12 @@ declare_oxc_lint!(
     ///  */
     /// ```
     CheckTagNames,
-    correctness
+    correctness,
+    config = CheckTagnamesConfig,
+    ast_types = []
 );
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct CheckTagnamesConfig {
     #[serde(default, rename = "definedTags")]
     defined_tags: Vec<String>,
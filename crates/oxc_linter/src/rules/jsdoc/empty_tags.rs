@@ -3,6 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, utils::should_ignore_as_private};
@@ -56,7 +57,9 @@ declare_oxc_lint!(
     /// /** @private bar */
     /// ```
     EmptyTags,
-    restriction
+    restriction,
+    config = EmptyTagsConfig,
+    ast_types = []
 );
 
 const EMPTY_TAGS: phf::Set<&'static str> = phf_set! {
@@ -80,7 +83,7 @@ const EMPTY_TAGS: phf::Set<&'static str> = phf_set! {
     "static",
 };
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct EmptyTagsConfig {
     #[serde(default)]
     tags: Vec<String>,
@@ -6,6 +6,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNode, JSDoc};
 use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Mutex;
 
@@ -40,9 +41,10 @@ declare_oxc_lint!(
     /// ```
     RequireParam,
     pedantic,
+    config = RequireParamConfig
 );
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RequireParamConfig {
     #[serde(default = "default_exempted_by", rename = "exemptedBy")]
     exempted_by: Vec<String>,
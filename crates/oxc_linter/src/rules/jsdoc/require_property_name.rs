@@ -42,7 +42,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequirePropertyName,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for RequirePropertyName {
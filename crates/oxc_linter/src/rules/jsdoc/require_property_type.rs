@@ -42,7 +42,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequirePropertyType,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for RequirePropertyType {
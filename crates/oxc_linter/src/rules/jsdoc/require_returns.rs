@@ -8,6 +8,7 @@ use oxc_semantic::{JSDoc, JSDocTag};
 use oxc_span::Span;
 use phf::phf_set;
 use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
@@ -60,9 +61,11 @@ declare_oxc_lint!(
     /// ```
     RequireReturns,
     pedantic,
+    config = RequireReturnsConfig,
+    ast_types = []
 );
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RequireReturnsConfig {
     #[serde(default = "default_exempted_by", rename = "exemptedBy")]
     exempted_by: Vec<String>,
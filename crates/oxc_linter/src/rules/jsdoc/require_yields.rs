@@ -5,6 +5,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{JSDoc, JSDocTag};
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
@@ -61,10 +62,11 @@ declare_oxc_lint!(
     /// function * quux (foo) {}
     /// ```
     RequireYields,
-    correctness
+    correctness,
+    config = RequireYieldsConfig
 );
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RequireYieldsConfig {
     #[serde(default = "default_exempted_by", rename = "exemptedBy")]
     exempted_by: Vec<String>,
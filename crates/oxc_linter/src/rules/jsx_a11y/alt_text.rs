@@ -5,6 +5,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::utils::{
     get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase,
@@ -83,6 +85,23 @@ impl std::ops::Deref for AltText {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct AltTextOptions {
+    /// Elements to check, any of `"img"`, `"object"`, `"area"` and `"input[type=\"image\"]"`.
+    /// All of them are checked by default.
+    elements: Option<Vec<String>>,
+    /// Custom components to check like `img`.
+    img: Vec<String>,
+    /// Custom components to check like `object`.
+    object: Vec<String>,
+    /// Custom components to check like `area`.
+    area: Vec<String>,
+    /// Custom components to check like `input[type="image"]`.
+    #[serde(rename = "input[type=\"image\"]")]
+    input_type_image: Vec<String>,
+}
+
 impl std::default::Default for AltTextConfig {
     fn default() -> Self {
         Self {
@@ -125,40 +144,43 @@ declare_oxc_lint!(
     /// <img src="flower.jpg" alt="A close-up of a white daisy">
     /// ```
     AltText,
-    correctness
+    correctness,
+    config = AltTextOptions,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for AltText {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(options) = value
+            .get(0)
+            .and_then(|value| serde_json::from_value::<AltTextOptions>(value.clone()).ok())
+        else {
+            return Self::default();
+        };
+
         let mut alt_text = AltTextConfig::default();
-        if let Some(config) = value.get(0) {
-            if let Some(elements) = config.get("elements").and_then(|v| v.as_array()) {
-                alt_text =
-                    AltTextConfig { img: None, object: None, area: None, input_type_image: None };
-                for el in elements {
-                    match el.as_str() {
-                        Some("img") => alt_text.img = Some(vec![]),
-                        Some("object") => alt_text.object = Some(vec![]),
-                        Some("area") => alt_text.area = Some(vec![]),
-                        Some("input[type=\"image\"]") => alt_text.input_type_image = Some(vec![]),
-                        _ => {}
-                    }
+        if let Some(elements) = options.elements {
+            alt_text =
+                AltTextConfig { img: None, object: None, area: None, input_type_image: None };
+            for element in elements {
+                match element.as_str() {
+                    "img" => alt_text.img = Some(vec![]),
+                    "object" => alt_text.object = Some(vec![]),
+                    "area" => alt_text.area = Some(vec![]),
+                    "input[type=\"image\"]" => alt_text.input_type_image = Some(vec![]),
+                    _ => {}
                 }
             }
+        }
 
-            for (tags, field) in [
-                (&mut alt_text.img, "img"),
-                (&mut alt_text.object, "object"),
-                (&mut alt_text.area, "area"),
-                (&mut alt_text.input_type_image, "input[type=\"image\"]"),
-            ] {
-                if let (Some(tags), Some(elements)) =
-                    (tags, config.get(field).and_then(|v| v.as_array()))
-                {
-                    tags.extend(
-                        elements.iter().filter_map(|v| v.as_str().map(ToString::to_string)),
-                    );
-                }
+        for (tags, components) in [
+            (&mut alt_text.img, options.img),
+            (&mut alt_text.object, options.object),
+            (&mut alt_text.area, options.area),
+            (&mut alt_text.input_type_image, options.input_type_image),
+        ] {
+            if let Some(tags) = tags {
+                tags.extend(components);
             }
         }
 
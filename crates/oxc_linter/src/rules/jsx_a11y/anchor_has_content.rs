@@ -59,7 +59,8 @@ declare_oxc_lint!(
     /// ```
     ///
     AnchorHasContent,
-    correctness
+    correctness,
+    ast_types = [JSXElement]
 );
 
 impl Rule for AnchorHasContent {
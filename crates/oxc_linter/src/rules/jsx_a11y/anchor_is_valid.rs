@@ -5,6 +5,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -40,8 +42,10 @@ fn cant_be_anchor(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct AnchorIsValid(Box<AnchorIsValidConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 struct AnchorIsValidConfig {
+    /// Values of `href` to consider valid, e.g. `["#"]`.
     valid_hrefs: Vec<String>,
 }
 
@@ -118,16 +122,17 @@ declare_oxc_lint!(
     ///
     /// - [WCAG 2.1.1](https://www.w3.org/WAI/WCAG21/Understanding/keyboard)
     AnchorIsValid,
-    correctness
+    correctness,
+    config = AnchorIsValidConfig,
+    ast_types = [JSXElement]
 );
 
 impl Rule for AnchorIsValid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let valid_hrefs =
-            value.get("validHrefs").and_then(|v| v.as_array()).map_or_else(Vec::new, |array| {
-                array.iter().filter_map(|v| v.as_str().map(String::from)).collect::<Vec<String>>()
-            });
-        Self(Box::new(AnchorIsValidConfig { valid_hrefs }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -231,42 +236,42 @@ fn test() {
     let pass = vec![
         (r"<Anchor />", None, None),
         (r"<a {...props} />", None, None),
-        (r"<a href='foo' />", Some(serde_json::json!({ "validHrefs": ["foo"] })), None),
+        (r"<a href='foo' />", Some(serde_json::json!([{ "validHrefs": ["foo"] }])), None),
         (r"<a href={foo} />", None, None),
-        (r"<a href='/foo' />", Some(serde_json::json!({ "validHrefs": ["/foo"] })), None),
+        (r"<a href='/foo' />", Some(serde_json::json!([{ "validHrefs": ["/foo"] }])), None),
         (
             r"<a href='https://foo.bar.com' />",
-            Some(serde_json::json!({ "validHrefs": ["https://foo.bar.com"] })),
+            Some(serde_json::json!([{ "validHrefs": ["https://foo.bar.com"] }])),
             None,
         ),
         (r"<div href='foo' />", None, None),
         (
             r"<a href='javascript' />",
-            Some(serde_json::json!({ "validHrefs": ["javascript"] })),
+            Some(serde_json::json!([{ "validHrefs": ["javascript"] }])),
             None,
         ),
         (
             r"<a href='javascriptFoo' />",
-            Some(serde_json::json!({ "validHrefs": ["javascriptFoo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["javascriptFoo"] }])),
             None,
         ),
         (r"<a href={`#foo`}/>", None, None),
-        (r"<a href={'foo'}/>", Some(serde_json::json!({ "validHrefs": ["foo"] })), None),
+        (r"<a href={'foo'}/>", Some(serde_json::json!([{ "validHrefs": ["foo"] }])), None),
         (
             r"<a href={'javascript'}/>",
-            Some(serde_json::json!({ "validHrefs": ["javascript"] })),
+            Some(serde_json::json!([{ "validHrefs": ["javascript"] }])),
             None,
         ),
         (r"<a href={`#javascript`}/>", None, None),
-        (r"<a href='#foo' />", Some(serde_json::json!({ "validHrefs": ["#foo"] })), None),
+        (r"<a href='#foo' />", Some(serde_json::json!([{ "validHrefs": ["#foo"] }])), None),
         (
             r"<a href='#javascript' />",
-            Some(serde_json::json!({ "validHrefs": ["#javascript"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#javascript"] }])),
             None,
         ),
         (
             r"<a href='#javascriptFoo' />",
-            Some(serde_json::json!({ "validHrefs": ["#javascriptFoo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#javascriptFoo"] }])),
             None,
         ),
         (r"<UX.Layout>test</UX.Layout>", None, None),
@@ -291,7 +296,7 @@ fn test() {
         // (r#"<Link href='#foo' />"#, Some(serde_json::json!(components))),
         (
             r"<Link href='#foo' />",
-            Some(serde_json::json!({ "validHrefs": ["#foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#foo"] }])),
             Some(
                 serde_json::json!({ "settings": { "jsx-a11y": { "components": { "Anchor": "a", "Link": "a" } } } }),
             ),
@@ -334,30 +339,30 @@ fn test() {
         (r"<a {...props} onClick={() => void 0} />", None, None),
         (
             r"<a href='foo' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["foo"] }])),
             None,
         ),
         (r"<a href={foo} onClick={() => void 0} />", None, None),
         (
             r"<a href='/foo' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["/foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["/foo"] }])),
             None,
         ),
         (
             r"<a href='https://foo.bar.com' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["https://foo.bar.com"] })),
+            Some(serde_json::json!([{ "validHrefs": ["https://foo.bar.com"] }])),
             None,
         ),
         (r"<div href='foo' onClick={() => void 0} />", None, None),
         (r"<a href={`#foo`} onClick={() => void 0} />", None, None),
         (
             r"<a href={'foo'} onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["foo"] }])),
             None,
         ),
         (
             r"<a href='#foo' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["#foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#foo"] }])),
             None,
         ),
         (r"<a href={this} onClick={() => void 0} />", None, None),
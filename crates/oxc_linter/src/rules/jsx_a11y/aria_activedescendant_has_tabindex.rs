@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// <div aria-activedescendant={someID} />
     /// ```
     AriaActivedescendantHasTabindex,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for AriaActivedescendantHasTabindex {
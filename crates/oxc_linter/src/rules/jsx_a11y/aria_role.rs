@@ -6,6 +6,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -24,9 +26,13 @@ fn aria_role_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct AriaRole(Box<AriaRoleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct AriaRoleConfig {
+    /// Do not check the `role` of custom components.
+    #[serde(rename = "ignoreNonDOM")]
     ignore_non_dom: bool,
+    /// Invalid roles to allow.
     allowed_invalid_roles: Vec<String>,
 }
 
@@ -87,34 +93,18 @@ declare_oxc_lint!(
     ///  <Foo role={role}></Foo>       <!-- Bad: ignoreNonDOM is set to false or not set -->
     /// ```
     AriaRole,
-    correctness
+    correctness,
+    config = AriaRoleConfig,
+    ast_types = [JSXElement]
 );
 
 impl Rule for AriaRole {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(value) = value.as_array() else {
-            return Self::default();
-        };
-        let mut ignore_non_dom = false;
-        let mut allowed_invalid_roles: Vec<String> = vec![];
-
-        let _ = value.iter().find(|v| {
-            if let serde_json::Value::Object(obj) = v {
-                if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                    ignore_non_dom = *val;
-                }
-
-                if let Some(serde_json::Value::Array(val)) = obj.get("allowedInvalidRoles") {
-                    allowed_invalid_roles =
-                        val.iter().map(|v| v.as_str().unwrap().to_string()).collect();
-                }
-
-                return true;
-            }
-            false
-        });
-
-        Self(Box::new(AriaRoleConfig { ignore_non_dom, allowed_invalid_roles }))
+        value
+            .as_array()
+            .and_then(|value| value.iter().find(|v| v.is_object()))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -13,6 +13,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::{phf_map, phf_set};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 fn autocomplete_valid_diagnostic(span: Span, autocomplete: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint-plugin-jsx-a11y(autocomplete-valid): `{autocomplete}` is not a valid value for autocomplete."))
@@ -38,11 +40,15 @@ declare_oxc_lint!(
     /// <input autocomplete="name" />
     /// ```
     AutocompleteValid,
-    correctness
+    correctness,
+    config = AutocompleteValidConfig,
+    ast_types = [JSXOpeningElement]
 );
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct AutocompleteValidConfig {
+    /// Custom components to check like `input`.
     input_components: Vec<String>,
 }
 
@@ -158,20 +164,15 @@ fn is_valid_autocomplete_value(value: &str) -> bool {
 
 impl Rule for AutocompleteValid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut input_components: Vec<String> = vec!["input".to_string()];
-        if let Some(config) = value.get(0) {
-            if let Some(serde_json::Value::Array(components)) = config.get("inputComponents") {
-                input_components = components
-                    .iter()
-                    .filter_map(|c| c.as_str().map(std::string::ToString::to_string))
-                    .collect();
-            }
-        }
+        let mut config: AutocompleteValidConfig = value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default();
 
         // Add default input component
-        input_components.push("input".to_string());
+        config.input_components.push("input".to_string());
 
-        Self(Box::new(AutocompleteValidConfig { input_components }))
+        Self(Box::new(config))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// <div onClick={() => void 0} />
     /// ```
     ClickEventsHaveKeyEvents,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for ClickEventsHaveKeyEvents {
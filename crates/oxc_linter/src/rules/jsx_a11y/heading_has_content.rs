@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -20,8 +22,10 @@ fn heading_has_content_diagnostic(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct HeadingHasContent(Box<HeadingHasContentConfig>);
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct HeadingHasContentConfig {
+    /// Custom components to check like headings.
     components: Option<Vec<String>>,
 }
 
@@ -56,7 +60,9 @@ declare_oxc_lint!(
     /// <h1>Foo</h1>
     /// ```
     HeadingHasContent,
-    correctness
+    correctness,
+    config = HeadingHasContentConfig,
+    ast_types = [JSXOpeningElement]
 );
 
 // always including <h1> thru <h6>
@@ -64,18 +70,10 @@ const DEFAULT_COMPONENTS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
 
 impl Rule for HeadingHasContent {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(HeadingHasContentConfig {
-            components: value
-                .get(0)
-                .and_then(|v| v.get("components"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                }),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// <iframe title={uniqueTitle} />
     /// ```
     IframeHasTitle,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for IframeHasTitle {
@@ -8,6 +8,8 @@ use oxc_ast::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::utils::{
     get_element_type, get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader,
@@ -35,6 +37,15 @@ impl std::ops::Deref for ImgRedundantAlt {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct ImgRedundantAltOptions {
+    /// Custom components to check like `img`.
+    components: Vec<String>,
+    /// Redundant words to report besides "image", "photo" and "picture".
+    words: Vec<String>,
+}
+
 impl Default for ImgRedundantAltConfig {
     fn default() -> Self {
         Self {
@@ -77,7 +88,9 @@ declare_oxc_lint!(
     /// <img src="baz" alt={`Baz taking a ${photo}`} /> // This is valid since photo is a variable name.
     /// ```
     ImgRedundantAlt,
-    correctness
+    correctness,
+    config = ImgRedundantAltOptions,
+    ast_types = [JSXOpeningElement]
 );
 const COMPONENTS_FIXED_TO_VALIDATE: [&str; 1] = ["img"];
 const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
@@ -85,18 +98,12 @@ const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
 impl Rule for ImgRedundantAlt {
     fn from_configuration(value: serde_json::Value) -> Self {
         let mut img_redundant_alt = ImgRedundantAltConfig::default();
-        if let Some(config) = value.get(0) {
-            if let Some(components) = config.get("components").and_then(|v| v.as_array()) {
-                img_redundant_alt
-                    .types_to_validate
-                    .extend(components.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
-            }
-
-            if let Some(words) = config.get("words").and_then(|v| v.as_array()) {
-                img_redundant_alt
-                    .redundant_words
-                    .extend(words.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
-            }
+        if let Some(options) = value
+            .get(0)
+            .and_then(|value| serde_json::from_value::<ImgRedundantAltOptions>(value.clone()).ok())
+        {
+            img_redundant_alt.types_to_validate.extend(options.components);
+            img_redundant_alt.redundant_words.extend(options.words);
         }
 
         Self(Box::new(img_redundant_alt))
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// - [eslint-plugin-jsx-a11y/lang](https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/lang.md)
     /// - [IANA Language Subtag Registry](https://www.iana.org/assignments/language-subtag-registry/language-subtag-registry)
     Lang,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for Lang {
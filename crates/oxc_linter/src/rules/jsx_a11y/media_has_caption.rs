@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// <video></video>
     /// ```
     MediaHasCaption,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for MediaHasCaption {
@@ -60,7 +60,8 @@ declare_oxc_lint!(
     /// <div onMouseOver={() => void 0} />
     /// ```
     MouseEventsHaveKeyEvents,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for MouseEventsHaveKeyEvents {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// <div aria-hidden="true" />
     /// ```
     NoAriaHiddenOnFocusable,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoAriaHiddenOnFocusable {
@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -18,8 +20,11 @@ fn no_autofocus_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoAutofocus {
+    /// Do not check the `autoFocus` of custom components.
+    #[serde(rename = "ignoreNonDOM")]
     ignore_non_dom: bool,
 }
 
@@ -59,33 +64,18 @@ declare_oxc_lint!(
     /// ```
     ///
     NoAutofocus,
-    correctness
+    correctness,
+    config = NoAutofocus,
+    ast_types = [JSXElement]
 );
 
-impl NoAutofocus {
-    pub fn set_option(&mut self, value: bool) {
-        self.ignore_non_dom = value;
-    }
-}
-
 impl Rule for NoAutofocus {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut no_focus = Self::default();
-
-        if let Some(arr) = value.as_array() {
-            if arr.iter().any(|v| {
-                if let serde_json::Value::Object(obj) = v {
-                    if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                        return *val;
-                    }
-                }
-                false
-            }) {
-                no_focus.set_option(true);
-            }
-        }
-
-        no_focus
+        value
+            .as_array()
+            .and_then(|value| value.iter().find(|v| v.is_object()))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
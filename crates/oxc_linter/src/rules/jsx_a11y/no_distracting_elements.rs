@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// <Blink />
     /// ```
     NoDistractingElements,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoDistractingElements {
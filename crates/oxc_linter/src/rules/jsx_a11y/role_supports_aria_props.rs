@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```
     ///
     RoleSupportsAriaProps,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 #[derive(Debug, Default, Clone)]
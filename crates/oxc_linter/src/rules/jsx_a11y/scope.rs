@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// <th scope={scope} />
     /// ```
     Scope,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for Scope {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// <span tabIndex="-1">bar</span>
     /// ```
     TabindexNoPositive,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for TabindexNoPositive {
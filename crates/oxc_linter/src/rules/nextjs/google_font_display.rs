@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     GoogleFontDisplay,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for GoogleFontDisplay {
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     GoogleFontPreconnect,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for GoogleFontPreconnect {
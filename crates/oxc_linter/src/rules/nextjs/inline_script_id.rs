@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     InlineScriptId,
-    correctness
+    correctness,
+    ast_types = [ImportDefaultSpecifier]
 );
 
 impl Rule for InlineScriptId {
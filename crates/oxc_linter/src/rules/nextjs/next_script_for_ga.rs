@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NextScriptForGa,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NextScriptForGa {
@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoAssignModuleVariable,
-    correctness
+    correctness,
+    ast_types = [VariableDeclaration]
 );
 
 impl Rule for NoAssignModuleVariable {
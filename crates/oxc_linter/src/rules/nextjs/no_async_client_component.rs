@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoAsyncClientComponent,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoAsyncClientComponent {
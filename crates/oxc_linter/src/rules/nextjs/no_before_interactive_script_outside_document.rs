@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoBeforeInteractiveScriptOutsideDocument,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoBeforeInteractiveScriptOutsideDocument {
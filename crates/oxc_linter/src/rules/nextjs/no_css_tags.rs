@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoCssTags,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoCssTags {
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     ///export default MyDocument
     /// ```
     NoDuplicateHead,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoDuplicateHead {
@@ -26,7 +26,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoImgElement,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoImgElement {
@@ -36,6 +36,7 @@ declare_oxc_lint!(
     /// ```
     NoPageCustomFont,
     correctness,
+    ast_types = [JSXOpeningElement],
 );
 
 impl Rule for NoPageCustomFont {
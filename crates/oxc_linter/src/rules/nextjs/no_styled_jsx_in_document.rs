@@ -32,6 +32,7 @@ declare_oxc_lint!(
     /// ```
     NoStyledJsxInDocument,
     correctness,
+    ast_types = [JSXOpeningElement],
 );
 
 impl Rule for NoStyledJsxInDocument {
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoSyncScripts,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoSyncScripts {
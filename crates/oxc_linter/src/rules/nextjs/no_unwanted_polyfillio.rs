@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// <script src='https://polyfill.io/v3/polyfill.min.js?features=WeakSet%2CPromise%2CPromise.prototype.finally%2Ces2015%2Ces5%2Ces6'></script>
     /// ```
     NoUnwantedPolyfillio,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 // Keep in sync with next.js polyfills file : https://github.com/vercel/next.js/blob/master/packages/next-polyfill-nomodule/src/index.js
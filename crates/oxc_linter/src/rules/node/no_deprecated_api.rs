@@ -0,0 +1,409 @@
+use oxc_ast::{ast::BindingPatternKind, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+use phf::{phf_map, Map};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    ast_util::is_global_reference,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_module_binding, get_require_call_module, get_required_module, strip_node_protocol,
+    },
+    AstNode,
+};
+
+fn deprecated_diagnostic(x0: &str, api: &DeprecatedApi, span2: Span) -> OxcDiagnostic {
+    let (since, replacement) = api;
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "eslint-plugin-n(no-deprecated-api): '{x0}' was deprecated since v{since}."
+    ));
+    match replacement {
+        Some(replacement) => diagnostic.with_help(format!("Use {replacement} instead.")),
+        None => diagnostic,
+    }
+    .with_labels([span2.into()])
+}
+
+fn deprecated_module_diagnostic(x0: &str, api: &DeprecatedApi, span2: Span) -> OxcDiagnostic {
+    let (since, replacement) = api;
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "eslint-plugin-n(no-deprecated-api): '{x0}' module was deprecated since v{since}."
+    ));
+    match replacement {
+        Some(replacement) => diagnostic.with_help(format!("Use {replacement} instead.")),
+        None => diagnostic,
+    }
+    .with_labels([span2.into()])
+}
+
+/// The version of Node.js which deprecated the API, and its replacement.
+type DeprecatedApi = (&'static str, Option<&'static str>);
+
+const DEPRECATED_MODULES: Map<&'static str, DeprecatedApi> = phf_map! {
+    "_linklist" => ("5.0.0", None),
+    "_stream_wrap" => ("12.0.0", None),
+    "constants" => ("6.3.0", Some("the 'constants' property of each module")),
+    "domain" => ("4.0.0", None),
+    "punycode" => ("7.0.0", Some("'https://www.npmjs.com/package/punycode'")),
+    "sys" => ("0.3.0", Some("the 'util' module")),
+};
+
+/// Deprecated members of the modules, e.g. `fs.exists`.
+/// A name ending with `()` is only deprecated when it is called or constructed.
+const DEPRECATED_MODULE_ITEMS: Map<&'static str, DeprecatedApi> = phf_map! {
+    "async_hooks.currentId" => ("8.2.0", Some("'async_hooks.executionAsyncId()'")),
+    "async_hooks.triggerId" => ("8.2.0", Some("'async_hooks.triggerAsyncId()'")),
+    "buffer.Buffer()" => ("6.0.0", Some("'buffer.Buffer.alloc()' or 'buffer.Buffer.from()'")),
+    "buffer.SlowBuffer" => ("6.0.0", Some("'buffer.Buffer.allocUnsafeSlow()'")),
+    "crypto._toBuf" => ("11.0.0", None),
+    "crypto.Credentials" => ("0.12.0", Some("'tls.SecureContext'")),
+    "crypto.DEFAULT_ENCODING" => ("10.0.0", None),
+    "crypto.createCipher" => ("10.0.0", Some("'crypto.createCipheriv()'")),
+    "crypto.createCredentials" => ("0.12.0", Some("'tls.createSecureContext()'")),
+    "crypto.createDecipher" => ("10.0.0", Some("'crypto.createDecipheriv()'")),
+    "crypto.fips" => ("10.0.0", Some("'crypto.getFips()' and 'crypto.setFips()'")),
+    "crypto.prng" => ("11.0.0", Some("'crypto.randomBytes()'")),
+    "crypto.pseudoRandomBytes" => ("11.0.0", Some("'crypto.randomBytes()'")),
+    "crypto.rng" => ("11.0.0", Some("'crypto.randomBytes()'")),
+    "events.EventEmitter.listenerCount" => ("3.2.0", Some("'events.EventEmitter#listenerCount()'")),
+    "events.listenerCount" => ("3.2.0", Some("'events.EventEmitter#listenerCount()'")),
+    "fs.SyncWriteStream" => ("4.0.0", None),
+    "fs.exists" => ("4.0.0", Some("'fs.stat()' or 'fs.access()'")),
+    "fs.lchmod" => ("0.4.0", None),
+    "fs.lchmodSync" => ("0.4.0", None),
+    "http.createClient" => ("0.10.0", Some("'http.request()'")),
+    "module.Module._debug" => ("9.0.0", None),
+    "module.Module.createRequireFromPath" => ("12.2.0", Some("'module.createRequire()'")),
+    "module.Module.requireRepl" => ("6.0.0", Some("'require(\"repl\")'")),
+    "module._debug" => ("9.0.0", None),
+    "module.createRequireFromPath" => ("12.2.0", Some("'module.createRequire()'")),
+    "module.requireRepl" => ("6.0.0", Some("'require(\"repl\")'")),
+    "net._setSimultaneousAccepts" => ("12.0.0", None),
+    "os.getNetworkInterfaces" => ("0.6.0", Some("'os.networkInterfaces()'")),
+    "os.tmpDir" => ("7.0.0", Some("'os.tmpdir()'")),
+    "path._makeLong" => ("9.0.0", Some("'path.toNamespacedPath()'")),
+    "process.EventEmitter" => ("0.6.0", Some("'require(\"events\")'")),
+    "process.assert" => ("10.0.0", Some("'require(\"assert\")'")),
+    "process.binding" => ("10.9.0", None),
+    "process.env.NODE_REPL_HISTORY_FILE" => ("4.0.0", Some("'NODE_REPL_HISTORY'")),
+    "process.report.triggerReport" => ("11.12.0", Some("'process.report.writeReport()'")),
+    "readline.codePointAt" => ("4.0.0", None),
+    "readline.getStringWidth" => ("6.0.0", None),
+    "readline.isFullWidthCodePoint" => ("6.0.0", None),
+    "readline.stripVTControlCharacters" => ("6.0.0", None),
+    "timers._unrefActive" => ("10.0.0", Some("'timeout.refresh()'")),
+    "timers.active" => ("10.0.0", Some("'timeout.refresh()'")),
+    "timers.enroll" => ("10.0.0", Some("'setTimeout()' or 'setInterval()'")),
+    "timers.unenroll" => ("10.0.0", Some("'clearTimeout()' or 'clearInterval()'")),
+    "tls.CleartextStream" => ("0.10.0", None),
+    "tls.CryptoStream" => ("0.12.0", Some("'tls.TLSSocket'")),
+    "tls.SecurePair" => ("6.0.0", Some("'tls.TLSSocket'")),
+    "tls.convertNPNProtocols" => ("10.0.0", None),
+    "tls.createSecurePair" => ("6.0.0", Some("'tls.TLSSocket'")),
+    "tls.parseCertString" => ("8.6.0", Some("'querystring.parse()'")),
+    "tty.setRawMode" => ("0.10.0", Some("'tty.ReadStream#setRawMode()'")),
+    "url.parse" => ("11.0.0", Some("the 'url.URL' constructor")),
+    "url.resolve" => ("11.0.0", Some("the 'url.URL' constructor")),
+    "util._extend" => ("6.0.0", Some("'Object.assign()'")),
+    "util.debug" => ("0.12.0", Some("'console.error()'")),
+    "util.error" => ("0.12.0", Some("'console.error()'")),
+    "util.isArray" => ("4.0.0", Some("'Array.isArray()'")),
+    "util.isBoolean" => ("4.0.0", None),
+    "util.isBuffer" => ("4.0.0", Some("'Buffer.isBuffer()'")),
+    "util.isDate" => ("4.0.0", None),
+    "util.isError" => ("4.0.0", None),
+    "util.isFunction" => ("4.0.0", None),
+    "util.isNull" => ("4.0.0", None),
+    "util.isNullOrUndefined" => ("4.0.0", None),
+    "util.isNumber" => ("4.0.0", None),
+    "util.isObject" => ("4.0.0", None),
+    "util.isPrimitive" => ("4.0.0", None),
+    "util.isRegExp" => ("4.0.0", None),
+    "util.isString" => ("4.0.0", None),
+    "util.isSymbol" => ("4.0.0", None),
+    "util.isUndefined" => ("4.0.0", None),
+    "util.log" => ("6.0.0", Some("a third party module")),
+    "util.print" => ("0.12.0", Some("'console.log()'")),
+    "util.pump" => ("0.10.0", Some("'stream.Readable#pipe()'")),
+    "util.puts" => ("0.12.0", Some("'console.log()'")),
+    "vm.runInDebugContext" => ("8.0.0", None),
+};
+
+/// Deprecated global variables and their members, the members of the global `process`
+/// are those of the `process` module.
+const DEPRECATED_GLOBAL_ITEMS: Map<&'static str, DeprecatedApi> = phf_map! {
+    "Buffer()" => ("6.0.0", Some("'Buffer.alloc()' or 'Buffer.from()'")),
+    "GLOBAL" => ("6.0.0", Some("'global'")),
+    "Intl.v8BreakIterator" => ("7.0.0", None),
+    "require.extensions" => ("0.12.0", None),
+    "root" => ("6.0.0", Some("'global'")),
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDeprecatedApi(Box<NoDeprecatedApiConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoDeprecatedApiConfig {
+    /// Deprecated modules and module members to allow, e.g. `["domain", "fs.exists", "buffer.Buffer()"]`.
+    ignore_module_items: Vec<String>,
+    /// Deprecated global variables to allow, e.g. `["Buffer()", "process.binding"]`.
+    ignore_global_items: Vec<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the deprecated APIs of Node.js, such as the `domain` module, `fs.exists()`
+    /// and `new Buffer()`.
+    ///
+    /// The members of the modules are found through `import` declarations, `require()` calls
+    /// and the variables they are assigned to, e.g. `const { exists } = require('fs')`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs may be removed by a later version of Node.js, and most of them have
+    /// a safer or faster replacement.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const fs = require('fs');
+    /// fs.exists('./foo.js', function() {});
+    /// const buf = new Buffer(10);
+    /// import { parse } from 'node:url';
+    ///
+    /// // Good
+    /// fs.stat('./foo.js', function() {});
+    /// const buf = Buffer.alloc(10);
+    /// ```
+    NoDeprecatedApi,
+    nursery,
+    config = NoDeprecatedApiConfig,
+    ast_types = [ImportDeclaration, CallExpression, IdentifierReference]
+);
+
+impl Rule for NoDeprecatedApi {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|value| NoDeprecatedApiConfig::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) => {
+                let module = strip_node_protocol(decl.source.value.as_str());
+                self.check_module(module, decl.source.span, ctx);
+            }
+            AstKind::CallExpression(call_expr) => {
+                let Some(module) = get_require_call_module(call_expr, ctx) else {
+                    return;
+                };
+                self.check_module(module, call_expr.span, ctx);
+                // e.g. `require('fs').exists`
+                let ignores = &self.0.ignore_module_items;
+                check_members(module.to_string(), node, &DEPRECATED_MODULE_ITEMS, ignores, ctx);
+            }
+            AstKind::IdentifierReference(ident) => {
+                if is_global_reference(ident, ctx) {
+                    let items = match ident.name.as_str() {
+                        "process" => &DEPRECATED_MODULE_ITEMS,
+                        "Buffer" | "GLOBAL" | "Intl" | "require" | "root" => {
+                            &DEPRECATED_GLOBAL_ITEMS
+                        }
+                        _ => return,
+                    };
+                    let ignores = &self.0.ignore_global_items;
+                    check_members(ident.name.to_string(), node, items, ignores, ctx);
+                    return;
+                }
+                let Some(symbol_id) = ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                else {
+                    return;
+                };
+                if let Some(name) = get_binding_name(symbol_id, ctx) {
+                    let ignores = &self.0.ignore_module_items;
+                    check_members(name, node, &DEPRECATED_MODULE_ITEMS, ignores, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoDeprecatedApi {
+    fn check_module(&self, module: &str, span: Span, ctx: &LintContext) {
+        if self.0.ignore_module_items.iter().any(|item| item == module) {
+            return;
+        }
+        if let Some(api) = DEPRECATED_MODULES.get(module) {
+            ctx.diagnostic(deprecated_module_diagnostic(module, api, span));
+        }
+    }
+}
+
+/// Check the node, whose name is `name`, and the chain of its members,
+/// e.g. `fs`, `fs.exists` and `fs.exists.call` for the `fs` of `fs.exists.call()`.
+fn check_members<'a>(
+    mut name: String,
+    node: &AstNode<'a>,
+    items: &Map<&'static str, DeprecatedApi>,
+    ignores: &[String],
+    ctx: &LintContext<'a>,
+) {
+    let mut node = node;
+    loop {
+        let span = node.kind().span();
+        let parent = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .skip(1)
+            .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+        if let Some(api) = items.get(name.as_str()) {
+            if !ignores.contains(&name) {
+                ctx.diagnostic(deprecated_diagnostic(&name, api, span));
+            }
+            return;
+        }
+        let called = format!("{name}()");
+        if let Some(api) = items.get(called.as_str()) {
+            let report = match parent.map(AstNode::kind) {
+                Some(AstKind::CallExpression(call_expr))
+                    if call_expr.callee.without_parenthesized().span() == span =>
+                {
+                    Some((called.clone(), call_expr.span))
+                }
+                Some(AstKind::NewExpression(new_expr))
+                    if new_expr.callee.without_parenthesized().span() == span =>
+                {
+                    Some((format!("new {called}"), new_expr.span))
+                }
+                _ => None,
+            };
+            if let Some((display_name, span)) = report {
+                if !ignores.contains(&called) {
+                    ctx.diagnostic(deprecated_diagnostic(&display_name, api, span));
+                }
+            }
+            return;
+        }
+        let Some(parent) = parent else { return };
+        let AstKind::MemberExpression(member_expr) = parent.kind() else { return };
+        if member_expr.object().without_parenthesized().span() != span {
+            return;
+        }
+        let Some(property) = member_expr.static_property_name() else { return };
+        name.push('.');
+        name.push_str(property);
+        node = parent;
+    }
+}
+
+/// The name of the module, or module member, which the symbol is bound to, e.g. `fs` of
+/// `import * as fs from 'fs'` or `fs.exists` of `const { exists } = require('fs')`.
+fn get_binding_name(symbol_id: SymbolId, ctx: &LintContext) -> Option<String> {
+    if let Some(module) = get_module_binding(symbol_id, ctx) {
+        return Some(module.to_string());
+    }
+    let declaration = ctx.symbols().get_declaration(symbol_id);
+    match ctx.nodes().kind(declaration) {
+        AstKind::ImportSpecifier(specifier) => {
+            let AstKind::ImportDeclaration(decl) = ctx.nodes().parent_kind(declaration)? else {
+                return None;
+            };
+            let module = strip_node_protocol(decl.source.value.as_str());
+            Some(format!("{module}.{}", specifier.imported.name()))
+        }
+        AstKind::VariableDeclarator(declarator) => {
+            let BindingPatternKind::ObjectPattern(pattern) = &declarator.id.kind else {
+                return None;
+            };
+            let module = get_required_module(declarator.init.as_ref()?, ctx)?;
+            pattern.properties.iter().find_map(|property| {
+                let BindingPatternKind::BindingIdentifier(ident) = &property.value.kind else {
+                    return None;
+                };
+                if ident.symbol_id.get() != Some(symbol_id) {
+                    return None;
+                }
+                Some(format!("{module}.{}", property.key.static_name()?))
+            })
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("require('buffer').Buffer.alloc(10)", None),
+        ("new (require('buffer').Buffer.alloc)(10)", None),
+        ("var Buffer = require('buffer').Buffer; Buffer.from('a')", None),
+        ("Buffer.alloc(10)", None),
+        ("function f(Buffer) { new Buffer(10) }", None),
+        ("var fs = require('fs'); fs.stat('a')", None),
+        ("var { stat } = require('fs'); stat('a')", None),
+        ("import fs from 'fs'; fs.access('a')", None),
+        ("import { exists } from 'foo'; exists('a')", None),
+        (
+            "var exists = require('fs').exists",
+            Some(json!([{ "ignoreModuleItems": ["fs.exists"] }])),
+        ),
+        ("new Buffer(10)", Some(json!([{ "ignoreGlobalItems": ["Buffer()"] }]))),
+        ("require('domain')", Some(json!([{ "ignoreModuleItems": ["domain"] }]))),
+        ("process.exit()", None),
+        ("var util = require('util'); util.inspect(a)", None),
+        ("var url = require('url'); new url.URL('a')", None),
+        ("Buffer.isBuffer(a)", None),
+        ("var process = { binding() {} }; process.binding()", None),
+        ("require(foo)", None),
+    ];
+
+    let fail = vec![
+        ("require('domain')", None),
+        ("require('node:punycode')", None),
+        ("import domain from 'domain'", None),
+        ("import * as sys from 'sys'", None),
+        ("require('buffer').Buffer()", None),
+        ("new (require('buffer').Buffer)(10)", None),
+        ("var b = require('buffer'); new b.Buffer(10)", None),
+        ("var { Buffer } = require('buffer'); Buffer(10)", None),
+        ("import { Buffer } from 'buffer'; new Buffer(10)", None),
+        ("new Buffer(10)", None),
+        ("Buffer(10)", None),
+        ("var fs = require('fs'); fs.exists('a')", None),
+        ("var fs = require('node:fs'); fs.exists('a')", None),
+        ("require('fs').exists('a')", None),
+        ("var { exists } = require('fs'); exists('a')", None),
+        ("import fs from 'fs'; fs.exists('a')", None),
+        ("import * as fs from 'fs'; fs.exists('a')", None),
+        ("import { exists } from 'fs'; exists('a')", None),
+        ("import { exists as e } from 'node:fs'; e('a')", None),
+        ("var util = require('util'); util.isArray(a)", None),
+        ("var util = require('util'); (util).isArray(a)", None),
+        ("var events = require('events'); events.EventEmitter.listenerCount(e, 'x')", None),
+        ("import { parse } from 'url'; parse('a')", None),
+        ("process.binding('fs')", None),
+        ("process.env.NODE_REPL_HISTORY_FILE", None),
+        ("require.extensions", None),
+        ("GLOBAL.foo", None),
+        ("import process from 'process'; process.assert(a)", None),
+        ("new Buffer(10)", Some(json!([{ "ignoreModuleItems": ["buffer.Buffer()"] }]))),
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, pass, fail).with_node_plugin(true).test_and_snapshot();
+}
@@ -0,0 +1,130 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule, AstNode};
+
+fn no_path_concat_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-n(no-path-concat): Use `path.join()` or `path.resolve()` instead of string concatenation to create paths.",
+    )
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPathConcat;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow string concatenation with `__dirname` and `__filename`, e.g.
+    /// `__dirname + "/foo.js"` or `` `${__dirname}/foo.js` ``.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The path separator is `\` on Windows, a path built with `/` may not work on all platforms.
+    /// `path.join()` and `path.resolve()` use the separator of the platform.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const fullPath = __dirname + "/foo.js";
+    /// const fullPath = `${__filename}/../foo.js`;
+    ///
+    /// // Good
+    /// const fullPath = path.join(__dirname, "foo.js");
+    /// const fullPath = path.resolve(__filename, "../foo.js");
+    /// ```
+    NoPathConcat,
+    nursery,
+    ast_types = [BinaryExpression, TemplateLiteral]
+);
+
+impl Rule for NoPathConcat {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+                if ends_with_path_variable(&expr.left, ctx) && contains_path_separator(&expr.right)
+                {
+                    ctx.diagnostic(no_path_concat_diagnostic(expr.span));
+                }
+            }
+            AstKind::TemplateLiteral(lit) => {
+                let is_concat =
+                    lit.expressions.iter().zip(lit.quasis.iter().skip(1)).any(|(expr, quasi)| {
+                        is_path_variable(expr, ctx) && quasi.value.raw.contains(['/', '\\'])
+                    });
+                if is_concat {
+                    ctx.diagnostic(no_path_concat_diagnostic(lit.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether the expression is the global `__dirname` or `__filename`.
+fn is_path_variable(expr: &Expression, ctx: &LintContext) -> bool {
+    matches!(expr.without_parenthesized(), Expression::Identifier(ident)
+        if matches!(ident.name.as_str(), "__dirname" | "__filename")
+            && is_global_reference(ident, ctx))
+}
+
+/// Whether the last operand of the concatenation is `__dirname` or `__filename`,
+/// e.g. `a + __dirname`.
+fn ends_with_path_variable(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.without_parenthesized() {
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            ends_with_path_variable(&expr.right, ctx)
+        }
+        expr => is_path_variable(expr, ctx),
+    }
+}
+
+/// Whether the string, or the first part of the template, contains a path separator.
+fn contains_path_separator(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(lit) => lit.value.contains(['/', '\\']),
+        Expression::TemplateLiteral(lit) => {
+            lit.quasis.first().is_some_and(|quasi| quasi.value.raw.contains(['/', '\\']))
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var fp = path.join(__dirname, 'foo.js');",
+        "var fp = path.join(__filename, 'foo.js');",
+        "var fp = __dirname + '.js';",
+        "var fp = __filename + '.map';",
+        "var fp = `${__dirname}.js`;",
+        "var fp = 'foo/' + __dirname;",
+        "var fp = __dirname + foo;",
+        "var fp = __dirname - '/foo.js';",
+        "var fp = dirname + '/foo.js';",
+        "function f(__dirname) { return __dirname + '/foo.js'; }",
+        "var fp = `${__dirname}`;",
+    ];
+
+    let fail = vec![
+        "var fp = __dirname + '/foo.js';",
+        "var fp = __filename + '/foo.js';",
+        "var fp = __dirname + '\\\\foo.js';",
+        "var fp = __dirname + 'foo/bar.js';",
+        "var fp = 'a' + __dirname + '/foo.js';",
+        "var fp = __dirname + `/foo.js`;",
+        "var fp = __dirname + `/${foo}.js`;",
+        "var fp = `${__dirname}/foo.js`;",
+        "var fp = `${__filename}/../foo.js`;",
+        "var fp = `${__dirname}\\\\foo.js`;",
+        "var fp = `a/${__dirname}/foo.js`;",
+    ];
+
+    Tester::new(NoPathConcat::NAME, pass, fail).with_node_plugin(true).test_and_snapshot();
+}
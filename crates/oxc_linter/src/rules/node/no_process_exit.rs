@@ -0,0 +1,110 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::is_global_reference, context::LintContext, rule::Rule, utils::get_module_binding,
+    AstNode,
+};
+
+fn no_process_exit_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-n(no-process-exit): Don't use `process.exit()`.")
+        .with_help("Throw an error or set `process.exitCode` instead.")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoProcessExit;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `process.exit()`, including the `process` imported from the `process` module.
+    ///
+    /// Unlike `unicorn/no-process-exit`, calls in CLI files with a hashbang, in
+    /// `process.on()` handlers and in worker threads are reported as well.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `process.exit()` ends the process immediately, pending I/O such as writes to
+    /// `stdout` may be lost and the `exit` handlers of the other modules cannot clean up.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// if (problem) process.exit(1);
+    ///
+    /// // Good
+    /// if (problem) throw new Error("message");
+    /// if (problem) process.exitCode = 1;
+    /// ```
+    NoProcessExit,
+    nursery,
+    ast_types = [CallExpression]
+);
+
+impl Rule for NoProcessExit {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let Some(member_expr) = call_expr.callee.without_parenthesized().as_member_expression()
+        else {
+            return;
+        };
+        if member_expr.static_property_name() != Some("exit") {
+            return;
+        }
+        if is_process(member_expr.object(), ctx) {
+            ctx.diagnostic(no_process_exit_diagnostic(call_expr.span));
+        }
+    }
+}
+
+/// Whether the expression is the global `process`, or `process` of the `process` module,
+/// e.g. `import process from 'node:process'` or `const process = require('process')`.
+fn is_process(expr: &Expression, ctx: &LintContext) -> bool {
+    let Expression::Identifier(ident) = expr.without_parenthesized() else { return false };
+    if is_global_reference(ident, ctx) {
+        return ident.name == "process";
+    }
+    ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+        .and_then(|symbol_id| get_module_binding(symbol_id, ctx))
+        .is_some_and(|module| module == "process")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Process.exit()",
+        "const x = process.exit;",
+        "x(process.exit)",
+        "process.exitCode = 1",
+        "foo.exit()",
+        "function f(process) { process.exit() }",
+        "const process = { exit() {} }; process.exit()",
+        "import { exit } from 'process'; process2.exit()",
+    ];
+
+    let fail = vec![
+        "process.exit(0);",
+        "process.exit(1);",
+        "process.exit();",
+        "process['exit']();",
+        "#!/usr/bin/env node\nprocess.exit();",
+        "process.on('SIGINT', function() { process.exit(1); })",
+        "const { workerData } = require('worker_threads'); process.exit(1);",
+        "import process from 'process'; process.exit(1);",
+        "import * as process from 'node:process'; process.exit(1);",
+        "const proc = require('node:process'); proc.exit(1);",
+    ];
+
+    Tester::new(NoProcessExit::NAME, pass, fail)
+        .with_node_plugin(true)
+        .with_plugin_name("node")
+        .test_and_snapshot();
+}
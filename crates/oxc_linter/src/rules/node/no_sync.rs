@@ -0,0 +1,117 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{ast_util::is_function_node, context::LintContext, rule::Rule, AstNode};
+
+fn no_sync_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint-plugin-n(no-sync): Unexpected sync method: '{x0}'."))
+        .with_help("Use the asynchronous version of the method instead.")
+        .with_labels([span1.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSync(Box<NoSyncConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoSyncConfig {
+    /// Allow the synchronous methods outside of functions, e.g. while a module is loaded.
+    allow_at_root_level: bool,
+    /// Names of the synchronous methods to allow, e.g. `["readFileSync"]`.
+    ignores: Vec<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the synchronous methods, i.e. the methods whose name ends with `Sync`,
+    /// e.g. `fs.readFileSync()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A synchronous method blocks the event loop, the process cannot handle any other
+    /// event, such as a request, until the method returns.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// fs.existsSync(somePath);
+    /// function foo() {
+    ///     var contents = fs.readFileSync(somePath).toString();
+    /// }
+    ///
+    /// // Good
+    /// obj.sync();
+    /// async(function() {
+    ///     // ...
+    /// });
+    /// ```
+    NoSync,
+    nursery,
+    config = NoSyncConfig,
+    ast_types = [CallExpression]
+);
+
+impl Rule for NoSync {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|value| NoSyncConfig::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        let (name, span) = match call_expr.callee.without_parenthesized() {
+            Expression::Identifier(ident) => (ident.name.as_str(), ident.span),
+            Expression::StaticMemberExpression(member_expr) => {
+                (member_expr.property.name.as_str(), member_expr.property.span)
+            }
+            _ => return,
+        };
+        if !name.ends_with("Sync") || self.0.ignores.iter().any(|ignore| ignore == name) {
+            return;
+        }
+        if self.0.allow_at_root_level
+            && !ctx.nodes().iter_parents(node.id()).any(|parent| is_function_node(parent))
+        {
+            return;
+        }
+        ctx.diagnostic(no_sync_diagnostic(name, span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var foo = fs.foo.foo();", None),
+        ("var foo = fs.fooSync;", None),
+        ("var foo = fs.sync();", None),
+        ("var foo = fs['readFileSync']();", None),
+        ("if (true) { fs.existsSync(); }", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("var foo = fs.fooSync();", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("var foo = fooSync();", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("var foo = fs.fooSync();", Some(json!([{ "ignores": ["fooSync"] }]))),
+        ("function f() { readFileSync(); }", Some(json!([{ "ignores": ["readFileSync"] }]))),
+    ];
+
+    let fail = vec![
+        ("var foo = fs.fooSync();", None),
+        ("var foo = fooSync();", None),
+        ("var foo = (fs.fooSync)();", None),
+        ("if (true) { fs.fooSync(); }", None),
+        ("function foo() { var foo = fs.fooSync(); }", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("const foo = () => fooSync();", Some(json!([{ "allowAtRootLevel": true }]))),
+        ("var foo = fs.barSync();", Some(json!([{ "ignores": ["fooSync"] }]))),
+    ];
+
+    Tester::new(NoSync::NAME, pass, fail).with_node_plugin(true).test_and_snapshot();
+}
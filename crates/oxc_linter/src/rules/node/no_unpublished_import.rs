@@ -0,0 +1,217 @@
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::module_record::ResolvedModule;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{normalize_path, package_json_lookup, package_name, NodePackages},
+};
+
+fn unpublished_file_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint-plugin-n(no-unpublished-import): '{x1}' is not published."))
+        .with_help("Add the file to the `files` of `package.json`.")
+        .with_labels([span0.into()])
+}
+
+fn dev_dependency_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint-plugin-n(no-unpublished-import): '{x1}' is not published."))
+        .with_help(format!(
+            "Move '{x1}' from the `devDependencies` to the `dependencies` of `package.json`."
+        ))
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnpublishedImport(Box<NoUnpublishedImportConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnpublishedImportConfig {
+    options: NoUnpublishedImportOptions,
+    packages: NodePackages,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoUnpublishedImportOptions {
+    /// Packages of the `devDependencies` to allow, e.g. `["electron"]`.
+    allow_modules: Vec<String>,
+    /// Do not check the type-only imports, e.g. `import type { Foo } from 'vitest'`.
+    ignore_type_import: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow importing, from a published file, a file or a package which is not published.
+    ///
+    /// A file is published if it matches the `files` of the `package.json` closest to the
+    /// linted file, every file is published if there is no `files` and none is if the package
+    /// is `"private": true`. A package is not published if it is only declared in the
+    /// `devDependencies`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The import fails once the package is installed by its users, since the imported
+    /// file or package is not installed with it.
+    ///
+    /// ### Example
+    ///
+    /// With a `package.json` of `{ "files": ["lib"], "devDependencies": { "vitest": "^1.0.0" } }`:
+    ///
+    /// ```javascript
+    /// // lib/index.js
+    /// import { test } from 'vitest'; // reported
+    /// import helper from '../test/helper.js'; // reported
+    /// import util from './util.js'; // allowed
+    ///
+    /// // test/index.test.js, which is not published
+    /// import { test } from 'vitest'; // allowed
+    /// ```
+    NoUnpublishedImport,
+    nursery,
+    config = NoUnpublishedImportOptions,
+    ast_types = []
+);
+
+impl Rule for NoUnpublishedImport {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| NoUnpublishedImportOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(NoUnpublishedImportConfig { options, packages: NodePackages::default() }))
+    }
+
+    fn read_files(&self, file_path: &Path) -> Vec<PathBuf> {
+        package_json_lookup(file_path)
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let options = &self.0.options;
+        let path = ctx.file_path();
+        let Some(package) = self.0.packages.closest(path) else { return };
+        // Nothing is published, or the linted file itself is not.
+        if package.private || !package.is_published(path) {
+            return;
+        }
+
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            let requested_modules = requested_modules
+                .iter()
+                .filter(|requested_module| {
+                    !(options.ignore_type_import && requested_module.is_type())
+                })
+                .collect::<Vec<_>>();
+            if requested_modules.is_empty() {
+                continue;
+            }
+
+            if specifier.starts_with('.') {
+                // Resolved when the import plugin is enabled, e.g. `./util` to `./util.js`.
+                let resolved_path = module_record
+                    .resolved_modules
+                    .get(specifier)
+                    .and_then(|module| match module.value() {
+                        ResolvedModule::Path(resolved_path) => Some(resolved_path.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| {
+                        normalize_path(&path.parent().unwrap_or(path).join(specifier.as_str()))
+                    });
+                if package.is_published(&resolved_path) {
+                    continue;
+                }
+                for requested_module in requested_modules {
+                    ctx.diagnostic(unpublished_file_diagnostic(requested_module.span(), specifier));
+                }
+                continue;
+            }
+
+            let Some(name) = package_name(specifier) else { continue };
+            if options.allow_modules.iter().any(|allowed| allowed == name)
+                || package.dependencies.contains(name)
+                || !package.dev_dependencies.contains(name)
+            {
+                continue;
+            }
+            for requested_module in requested_modules {
+                ctx.diagnostic(dev_dependency_diagnostic(requested_module.span(), name));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let published = || Some(PathBuf::from("../node/package/lib/index.js"));
+    let published_ts = || Some(PathBuf::from("../node/package/lib/index.ts"));
+    let unpublished = || Some(PathBuf::from("../node/package/test/index.test.js"));
+
+    let pass = vec![
+        ("import React from 'react'", None, None, published()),
+        ("import fs from 'node:fs'", None, None, published()),
+        ("import path from 'path'", None, None, published()),
+        ("import 'not-a-dependency'", None, None, published()),
+        ("import util from './util.js'", None, None, published()),
+        ("import util from './nested/util'", None, None, published()),
+        ("import main from '../bin/main.js'", None, None, published()),
+        ("import pkg from '../package.json'", None, None, published()),
+        ("import { test } from 'vitest'", None, None, unpublished()),
+        ("import helper from './helper.js'", None, None, unpublished()),
+        (
+            "import { test } from 'vitest'",
+            Some(json!([{ "allowModules": ["vitest"] }])),
+            None,
+            published(),
+        ),
+        (
+            "import type { Config } from 'vitest'",
+            Some(json!([{ "ignoreTypeImport": true }])),
+            None,
+            published_ts(),
+        ),
+        (
+            "import { test } from 'vitest'",
+            None,
+            None,
+            Some(PathBuf::from("../node/private-package/index.js")),
+        ),
+    ];
+
+    let fail = vec![
+        ("import { test } from 'vitest'", None, None, published()),
+        ("import { test } from 'vitest/config'", None, None, published()),
+        ("import reporter from '@vitest/ui/reporter'", None, None, published()),
+        ("import helper from '../test/helper.js'", None, None, published()),
+        ("import build from '../scripts/build'", None, None, published()),
+        ("export * from '../test/fixtures.js'", None, None, published()),
+        ("export { test } from 'vitest'", None, None, published()),
+        ("import type { Config } from 'vitest'", None, None, published_ts()),
+        (
+            "import { test } from 'vitest'",
+            Some(json!([{ "allowModules": ["jest"] }])),
+            None,
+            published(),
+        ),
+        (
+            "import { test } from 'vitest'",
+            None,
+            None,
+            Some(PathBuf::from("../node/package/bin/main.js")),
+        ),
+    ];
+
+    Tester::new(NoUnpublishedImport::NAME, pass, fail).with_node_plugin(true).test_and_snapshot();
+}
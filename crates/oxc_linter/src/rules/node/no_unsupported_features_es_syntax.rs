@@ -0,0 +1,552 @@
+use std::path::{Path, PathBuf};
+
+use oxc_ast::{
+    ast::{AssignmentTarget, RegExpFlags, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+use oxc_transformer::{plugin_versions, Version};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{package_json_lookup, NodePackages},
+    AstNode,
+};
+
+fn no_unsupported_features_es_syntax_diagnostic(
+    span0: Span,
+    x1: &str,
+    x2: &str,
+    x3: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-n(no-unsupported-features/es-syntax): {x1} is not supported until Node.js {x2}. The configured version range is '{x3}'."
+    ))
+    .with_labels([span0.into()])
+}
+
+/// The version range used when neither the `version` option nor the `engines.node` field of
+/// `package.json` is set.
+const DEFAULT_VERSION_RANGE: &str = ">=16.0.0";
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesEsSyntax(Box<NoUnsupportedFeaturesEsSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedFeaturesEsSyntaxConfig {
+    options: NoUnsupportedFeaturesEsSyntaxOptions,
+    packages: NodePackages,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
+struct NoUnsupportedFeaturesEsSyntaxOptions {
+    /// The supported Node.js version range, e.g. `">=14.0.0"`. Defaults to the `engines.node`
+    /// field of the closest `package.json`, or `">=16.0.0"`.
+    version: Option<String>,
+    /// Names of the features to allow, e.g. `["optionalChaining"]`.
+    ignores: Vec<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the ECMAScript syntax which is not supported by the Node.js versions the
+    /// package supports, i.e. `n/no-unsupported-features/es-syntax`.
+    ///
+    /// The supported versions come from the `version` option, or the `engines.node` field of
+    /// the closest `package.json`. The Node.js version of each feature is the one the
+    /// transformer uses to decide whether to downlevel it, with the bugfix transforms, so the
+    /// lint and the transformer targets agree. The features the transformer doesn't downlevel,
+    /// the RegExp `d` flag and lookbehind assertions, use the versions of eslint-plugin-n.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The unsupported syntax is a `SyntaxError` on the older Node.js versions, the module
+    /// fails to load.
+    ///
+    /// ### Example
+    ///
+    /// With `"engines": { "node": ">=12.0.0" }`:
+    ///
+    /// ```javascript
+    /// // Bad
+    /// const name = user?.name;
+    /// const value = input ?? fallback;
+    ///
+    /// // Good
+    /// const name = user && user.name;
+    /// const value = input === undefined || input === null ? fallback : input;
+    /// ```
+    NoUnsupportedFeaturesEsSyntax,
+    nursery,
+    config = NoUnsupportedFeaturesEsSyntaxOptions,
+    ast_types = []
+);
+
+impl Rule for NoUnsupportedFeaturesEsSyntax {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| NoUnsupportedFeaturesEsSyntaxOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(NoUnsupportedFeaturesEsSyntaxConfig {
+            options,
+            packages: NodePackages::default(),
+        }))
+    }
+
+    fn read_files(&self, file_path: &Path) -> Vec<PathBuf> {
+        if self.0.options.version.is_some() {
+            vec![]
+        } else {
+            package_json_lookup(file_path)
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let options = &self.0.options;
+        let package =
+            if options.version.is_some() { None } else { self.0.packages.closest(ctx.file_path()) };
+        let range = options
+            .version
+            .as_deref()
+            .or_else(|| package.as_ref().and_then(|package| package.engines_node.as_deref()))
+            .unwrap_or(DEFAULT_VERSION_RANGE);
+        let Some(min_version) = min_version(range) else { return };
+
+        for node in ctx.nodes().iter() {
+            for feature in features(node, ctx) {
+                if options.ignores.iter().any(|ignore| ignore == feature.name) {
+                    continue;
+                }
+                let Some(since) = feature.since().filter(|since| min_version < *since) else {
+                    continue;
+                };
+                ctx.diagnostic(no_unsupported_features_es_syntax_diagnostic(
+                    node.kind().span(),
+                    feature.description,
+                    &since.to_string(),
+                    range,
+                ));
+            }
+        }
+    }
+}
+
+/// An ECMAScript feature, and the first Node.js version which supports it.
+struct Feature {
+    /// Name of the feature in the `ignores` option.
+    name: &'static str,
+    description: &'static str,
+    since: Since,
+}
+
+enum Since {
+    /// The version from the compat data of the transformer plugin which downlevels the feature.
+    Plugin(&'static str),
+    /// The version of a feature which no transformer plugin downlevels.
+    Version(Version),
+}
+
+impl Feature {
+    const fn new(name: &'static str, description: &'static str, plugin: &'static str) -> Self {
+        Self { name, description, since: Since::Plugin(plugin) }
+    }
+
+    const fn untransformed(name: &'static str, description: &'static str, since: Version) -> Self {
+        Self { name, description, since: Since::Version(since) }
+    }
+
+    /// The first Node.js version which supports the feature, bugfix transforms aside, e.g.
+    /// `14.0.0` for optional chaining even though Node.js 14 needs a bugfix for `(a?.b)()`.
+    fn since(&self) -> Option<Version> {
+        match self.since {
+            Since::Plugin(plugin) => {
+                plugin_versions(plugin, true).and_then(|versions| versions.get("node")).copied()
+            }
+            Since::Version(version) => Some(version),
+        }
+    }
+}
+
+const ARROW_FUNCTIONS: Feature =
+    Feature::new("arrowFunctions", "Arrow function", "transform-arrow-functions");
+const CLASSES: Feature = Feature::new("classes", "Class", "transform-classes");
+const TEMPLATE_LITERALS: Feature =
+    Feature::new("templateLiterals", "Template literal", "transform-template-literals");
+const DESTRUCTURING: Feature =
+    Feature::new("destructuring", "Destructuring", "transform-destructuring");
+const BLOCK_SCOPED_VARIABLES: Feature = Feature::new(
+    "blockScopedVariables",
+    "Block-scoped variable ('let' and 'const')",
+    "transform-block-scoping",
+);
+const GENERATORS: Feature =
+    Feature::new("generators", "Generator function", "transform-regenerator");
+const ASYNC_FUNCTIONS: Feature =
+    Feature::new("asyncFunctions", "Async function", "transform-async-to-generator");
+const ASYNC_ITERATION: Feature = Feature::new(
+    "asyncIteration",
+    "Async iteration (async generator and 'for await')",
+    "transform-async-generator-functions",
+);
+const FOR_OF_LOOPS: Feature = Feature::new("forOfLoops", "'for-of' loop", "transform-for-of");
+const SPREAD_ELEMENTS: Feature =
+    Feature::new("spreadElements", "Spread element", "transform-spread");
+const REST_SPREAD_PROPERTIES: Feature =
+    Feature::new("restSpreadProperties", "Rest/spread property", "transform-object-rest-spread");
+const NEW_TARGET: Feature = Feature::new("newTarget", "'new.target'", "transform-new-target");
+const DEFAULT_PARAMETERS: Feature =
+    Feature::new("defaultParameters", "Default parameter", "transform-parameters");
+const REST_PARAMETERS: Feature =
+    Feature::new("restParameters", "Rest parameter", "transform-parameters");
+const EXPONENTIAL_OPERATORS: Feature = Feature::new(
+    "exponentialOperators",
+    "Exponential operator ('**' and '**=')",
+    "transform-exponentiation-operator",
+);
+const OPTIONAL_CATCH_BINDING: Feature = Feature::new(
+    "optionalCatchBinding",
+    "Optional catch binding",
+    "transform-optional-catch-binding",
+);
+const NULLISH_COALESCING_OPERATORS: Feature = Feature::new(
+    "nullishCoalescingOperators",
+    "Nullish coalescing operator ('??')",
+    "transform-nullish-coalescing-operator",
+);
+const OPTIONAL_CHAINING: Feature =
+    Feature::new("optionalChaining", "Optional chaining", "transform-optional-chaining");
+const LOGICAL_ASSIGNMENT_OPERATORS: Feature = Feature::new(
+    "logicalAssignmentOperators",
+    "Logical assignment operator ('&&=', '||=' and '??=')",
+    "transform-logical-assignment-operators",
+);
+const NUMERIC_SEPARATORS: Feature =
+    Feature::new("numericSeparators", "Numeric separator", "transform-numeric-separator");
+const CLASS_FIELDS: Feature =
+    Feature::new("classFields", "Class field", "transform-class-properties");
+const CLASS_PRIVATE_METHODS: Feature =
+    Feature::new("classPrivateMethods", "Private class method", "transform-private-methods");
+const PRIVATE_IN: Feature = Feature::new(
+    "privateIn",
+    "Private field check ('#field in obj')",
+    "transform-private-property-in-object",
+);
+const CLASS_STATIC_BLOCK: Feature =
+    Feature::new("classStaticBlock", "Class static block", "transform-class-static-block");
+const EXPORT_NAMESPACE_FROM: Feature = Feature::new(
+    "exportNamespaceFrom",
+    "Namespace re-export ('export * as ns from')",
+    "transform-export-namespace-from",
+);
+const REGEXP_S: Feature = Feature::new("regexpS", "RegExp 's' flag", "transform-dotall-regex");
+const REGEXP_Y: Feature = Feature::new("regexpY", "RegExp 'y' flag", "transform-sticky-regex");
+const REGEXP_U: Feature = Feature::new("regexpU", "RegExp 'u' flag", "transform-unicode-regex");
+const REGEXP_V: Feature =
+    Feature::new("regexpV", "RegExp 'v' flag", "transform-unicode-sets-regex");
+// The versions of eslint-plugin-n, the transformer doesn't downlevel these.
+const REGEXP_D: Feature =
+    Feature::untransformed("regexpD", "RegExp 'd' flag", Version { major: 16, minor: 0, patch: 0 });
+const REGEXP_LOOKBEHIND: Feature = Feature::untransformed(
+    "regexpLookbehind",
+    "RegExp lookbehind assertion",
+    Version { major: 8, minor: 10, patch: 0 },
+);
+const REGEXP_NAMED_CAPTURE_GROUPS: Feature = Feature::new(
+    "regexpNamedCaptureGroups",
+    "RegExp named capture group",
+    "transform-named-capturing-groups-regex",
+);
+const REGEXP_UNICODE_PROPERTIES: Feature = Feature::new(
+    "regexpUnicodePropertyEscapes",
+    "RegExp Unicode property escape",
+    "transform-unicode-property-regex",
+);
+
+/// The features used by the node itself, e.g. `optionalChaining` for `a?.b`.
+fn features(node: &AstNode, ctx: &LintContext) -> Vec<&'static Feature> {
+    match node.kind() {
+        AstKind::ArrowFunctionExpression(arrow) => {
+            if arrow.r#async {
+                vec![&ARROW_FUNCTIONS, &ASYNC_FUNCTIONS]
+            } else {
+                vec![&ARROW_FUNCTIONS]
+            }
+        }
+        AstKind::Function(func) => match (func.r#async, func.generator) {
+            (true, true) => vec![&ASYNC_ITERATION],
+            (true, false) => vec![&ASYNC_FUNCTIONS],
+            (false, true) => vec![&GENERATORS],
+            (false, false) => vec![],
+        },
+        AstKind::Class(_) => vec![&CLASSES],
+        AstKind::TemplateLiteral(_) => vec![&TEMPLATE_LITERALS],
+        AstKind::ObjectPattern(pattern) => {
+            if pattern.rest.is_some() {
+                vec![&DESTRUCTURING, &REST_SPREAD_PROPERTIES]
+            } else {
+                vec![&DESTRUCTURING]
+            }
+        }
+        AstKind::ArrayPattern(_) => vec![&DESTRUCTURING],
+        AstKind::AssignmentTarget(AssignmentTarget::ArrayAssignmentTarget(_)) => {
+            vec![&DESTRUCTURING]
+        }
+        AstKind::AssignmentTarget(AssignmentTarget::ObjectAssignmentTarget(target)) => {
+            if target.rest.is_some() {
+                vec![&DESTRUCTURING, &REST_SPREAD_PROPERTIES]
+            } else {
+                vec![&DESTRUCTURING]
+            }
+        }
+        AstKind::VariableDeclaration(decl) if decl.kind != VariableDeclarationKind::Var => {
+            vec![&BLOCK_SCOPED_VARIABLES]
+        }
+        AstKind::ForOfStatement(stmt) => {
+            if stmt.r#await {
+                vec![&ASYNC_ITERATION]
+            } else {
+                vec![&FOR_OF_LOOPS]
+            }
+        }
+        AstKind::SpreadElement(_) => {
+            if matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::ObjectExpression(_))) {
+                vec![&REST_SPREAD_PROPERTIES]
+            } else {
+                vec![&SPREAD_ELEMENTS]
+            }
+        }
+        AstKind::MetaProperty(meta) if meta.meta.name == "new" => vec![&NEW_TARGET],
+        AstKind::FormalParameter(param) if param.pattern.kind.is_assignment_pattern() => {
+            vec![&DEFAULT_PARAMETERS]
+        }
+        AstKind::BindingRestElement(_)
+            if matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::FormalParameters(_))) =>
+        {
+            vec![&REST_PARAMETERS]
+        }
+        AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Exponential => {
+            vec![&EXPONENTIAL_OPERATORS]
+        }
+        AstKind::AssignmentExpression(expr) => match expr.operator {
+            AssignmentOperator::Exponential => vec![&EXPONENTIAL_OPERATORS],
+            AssignmentOperator::LogicalAnd
+            | AssignmentOperator::LogicalOr
+            | AssignmentOperator::LogicalNullish => vec![&LOGICAL_ASSIGNMENT_OPERATORS],
+            _ => vec![],
+        },
+        AstKind::CatchClause(clause) if clause.param.is_none() => vec![&OPTIONAL_CATCH_BINDING],
+        AstKind::LogicalExpression(expr) if expr.operator == LogicalOperator::Coalesce => {
+            vec![&NULLISH_COALESCING_OPERATORS]
+        }
+        AstKind::ChainExpression(_) => vec![&OPTIONAL_CHAINING],
+        AstKind::NumericLiteral(lit) if lit.raw.contains('_') => vec![&NUMERIC_SEPARATORS],
+        AstKind::BigintLiteral(lit) if lit.raw.contains('_') => vec![&NUMERIC_SEPARATORS],
+        AstKind::PropertyDefinition(_) => vec![&CLASS_FIELDS],
+        AstKind::MethodDefinition(method) if method.key.is_private_identifier() => {
+            vec![&CLASS_PRIVATE_METHODS]
+        }
+        AstKind::PrivateInExpression(_) => vec![&PRIVATE_IN],
+        AstKind::StaticBlock(_) => vec![&CLASS_STATIC_BLOCK],
+        AstKind::ExportAllDeclaration(decl) if decl.exported.is_some() => {
+            vec![&EXPORT_NAMESPACE_FROM]
+        }
+        AstKind::RegExpLiteral(lit) => regexp_features(lit.regex.flags, &lit.regex.pattern),
+        _ => vec![],
+    }
+}
+
+fn regexp_features(flags: RegExpFlags, pattern: &str) -> Vec<&'static Feature> {
+    let mut features = vec![];
+    for (flag, feature) in [
+        (RegExpFlags::S, &REGEXP_S),
+        (RegExpFlags::Y, &REGEXP_Y),
+        (RegExpFlags::U, &REGEXP_U),
+        (RegExpFlags::D, &REGEXP_D),
+        (RegExpFlags::V, &REGEXP_V),
+    ] {
+        if flags.contains(flag) {
+            features.push(feature);
+        }
+    }
+    // The lookbehinds `(?<=` and `(?<!`, and the named groups `(?<name>`.
+    let (lookbehinds, named_groups): (Vec<_>, Vec<_>) =
+        pattern.match_indices("(?<").partition(|(i, _)| pattern[i + 3..].starts_with(['=', '!']));
+    if !lookbehinds.is_empty() {
+        features.push(&REGEXP_LOOKBEHIND);
+    }
+    if !named_groups.is_empty() {
+        features.push(&REGEXP_NAMED_CAPTURE_GROUPS);
+    }
+    if flags.intersects(RegExpFlags::U | RegExpFlags::V)
+        && (pattern.contains("\\p{") || pattern.contains("\\P{"))
+    {
+        features.push(&REGEXP_UNICODE_PROPERTIES);
+    }
+    features
+}
+
+/// The minimum version of a semver range, e.g. `12.0.0` for `^12.0.0 || >=14`.
+fn min_version(range: &str) -> Option<Version> {
+    range.split("||").filter_map(lower_bound).min()
+}
+
+/// The lower bound of a semver range without `||`, e.g. `12.22.0` for `>=12.22.0 <13`, or
+/// `12.0.0` for the hyphen range `12 - 14`.
+fn lower_bound(range: &str) -> Option<Version> {
+    // Join the operators with their versions, e.g. `>= 18` to `>=18`.
+    let mut comparators: Vec<String> = vec![];
+    let mut operator = String::new();
+    for token in range.split_whitespace() {
+        if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) {
+            operator.push_str(token);
+        } else {
+            comparators.push(std::mem::take(&mut operator) + token);
+        }
+    }
+    if let [from, hyphen, _] = comparators.as_slice() {
+        if hyphen == "-" {
+            return parse_version(from);
+        }
+    }
+    let mut lower = None;
+    for comparator in &comparators {
+        let version = if comparator.starts_with('<') {
+            Version { major: 0, minor: 0, patch: 0 }
+        } else {
+            parse_version(comparator.trim_start_matches(['>', '=', '^', '~']))?
+        };
+        lower = lower.max(Some(version));
+    }
+    lower
+}
+
+/// Parses a partial version, e.g. `12`, `12.x` or `v12.22.0`.
+fn parse_version(version: &str) -> Option<Version> {
+    let mut parts = version.trim_start_matches(['=', 'v']).split('.').map(|part| match part {
+        "x" | "X" | "*" | "" => Some(0),
+        part => part.parse::<u32>().ok(),
+    });
+    let major = parts.next().flatten()?;
+    let minor = parts.next().flatten().unwrap_or(0);
+    let patch = parts.next().flatten().unwrap_or(0);
+    Some(Version { major, minor, patch })
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let node = |version: &str| Some(json!([{ "version": version }]));
+    let ignores = |names: &[&str]| Some(json!([{ "version": ">=4.0.0", "ignores": names }]));
+    let engines_12 = || Some(PathBuf::from("../node/package/lib/index.js"));
+    let no_engines = || Some(PathBuf::from("../node/no-engines/index.js"));
+
+    let pass = vec![
+        ("var a = b", node(">=0.10.0"), None, None),
+        ("const f = () => 1", node(">=6.0.0"), None, None),
+        ("class A {}", node(">=6.0.0"), None, None),
+        ("var s = `a${b}`", node(">=4.0.0"), None, None),
+        ("let { a, b } = c", node(">=6.5.0"), None, None),
+        ("function* f() {}", node("^6.0.0"), None, None),
+        ("async function f() {}", node(">=7.6.0"), None, None),
+        ("async function* f() { for await (const a of b) {} }", node(">=10.0.0"), None, None),
+        ("const a = { ...b }", node(">=8.3.0"), None, None),
+        ("try {} catch {}", node(">=10.0.0"), None, None),
+        ("a ??= b?.c ?? d", node(">=16.9.0"), None, None),
+        (
+            "class A { #a = 1; #b() {} static {} has(o) { return #a in o } }",
+            node(">=16.11.0"),
+            None,
+            None,
+        ),
+        ("export * as ns from 'mod'", node(">=13.2.0"), None, None),
+        ("var n = 1_000_000", node(">=12.5.0"), None, None),
+        ("var r = /(?<year>\\d+)/su", node(">=10.0.0"), None, None),
+        ("var r = /(?<=a)b/", node(">=8.10.0"), None, None),
+        ("var r = /a/d", node(">=16.0.0"), None, None),
+        ("var a = b?.c", node(">=14.0.0"), None, None),
+        ("var r = /\\p{L}/", node(">=0.10.0"), None, None),
+        ("var r = /[\\p{L}--\\p{N}]/v", node(">=20.0.0"), None, None),
+        ("var a = b?.c", ignores(&["optionalChaining"]), None, None),
+        (
+            "const { a, ...b } = c",
+            ignores(&["destructuring", "restSpreadProperties", "blockScopedVariables"]),
+            None,
+            None,
+        ),
+        ("var a = b?.c", node(">= 18"), None, None),
+        ("class A { static {} }", node(">=18.0.0 <20"), None, None),
+        ("var a = b ?? c", node("^14 || ^16"), None, None),
+        ("async function* f() {}", node("12 - 14"), None, None),
+        ("var a = b ?? c", None, None, no_engines()),
+        ("class A { a = 1 }", None, None, engines_12()),
+    ];
+
+    let fail = vec![
+        ("const f = () => 1", node(">=4.0.0"), None, None),
+        ("class A {}", node(">=4.0.0"), None, None),
+        ("var s = `a${b}`", node(">=0.10.0"), None, None),
+        ("var { a, b } = c", node(">=6.0.0"), None, None),
+        ("[a, b] = [b, a]", node(">=6.0.0"), None, None),
+        ("function* f() {}", node(">=4.0.0"), None, None),
+        ("async function f() {}", node(">=7.0.0"), None, None),
+        ("var f = async () => {}", node(">=7.0.0"), None, None),
+        ("async function* f() {}", node(">=8.0.0"), None, None),
+        ("async function f() { for await (var a of b) {} }", node(">=8.0.0"), None, None),
+        ("for (var a of b) {}", node(">=6.0.0"), None, None),
+        ("f(...a)", node(">=4.0.0"), None, None),
+        ("var a = { ...b }", node(">=8.0.0"), None, None),
+        ("var { a, ...b } = c", node(">=8.0.0"), None, None),
+        ("function F() { new.target }", node(">=4.0.0"), None, None),
+        ("function f(a = 1) {}", node(">=4.0.0"), None, None),
+        ("function f(...a) {}", node(">=4.0.0"), None, None),
+        ("var a = b ** c", node(">=6.0.0"), None, None),
+        ("a **= b", node(">=6.0.0"), None, None),
+        ("try {} catch {}", node(">=8.0.0"), None, None),
+        ("var a = b ?? c", node(">=12.0.0"), None, None),
+        ("var a = b?.c", node(">=13.0.0"), None, None),
+        ("a ||= b", node(">=14.0.0"), None, None),
+        ("var n = 1_000", node(">=12.0.0"), None, None),
+        ("var n = 1_000n", node(">=12.0.0"), None, None),
+        ("class A { a = 1 }", node(">=10.0.0"), None, None),
+        ("class A { #a() {} }", node(">=14.0.0"), None, None),
+        ("class A { #a; has(o) { return #a in o } }", node(">=16.0.0"), None, None),
+        ("class A { static {} }", node(">=16.0.0"), None, None),
+        ("export * as ns from 'mod'", node(">=12.0.0"), None, None),
+        ("var r = /a/s", node(">=8.0.0"), None, None),
+        ("var r = /a/y", node(">=4.0.0"), None, None),
+        ("var r = /a/u", node(">=4.0.0"), None, None),
+        ("var r = /[a--b]/v", node(">=18.0.0"), None, None),
+        ("var r = /(?<year>\\d+)/", node(">=8.0.0"), None, None),
+        ("var r = /(?<=a)b/", node(">=8.0.0"), None, None),
+        ("var r = /(?<!a)b/", node(">=8.0.0"), None, None),
+        ("var r = /a/d", node(">=14.0.0"), None, None),
+        ("var r = /\\p{L}/u", node(">=8.10.0"), None, None),
+        ("var a = b?.c", node("^13.0.0 || >=16.9.0"), None, None),
+        ("var a = b?.c", node(">=12.x"), None, None),
+        ("var r = /[a--b]/v", node(">= 18"), None, None),
+        ("var r = /[a--b]/v", node(">=18.0.0 <20"), None, None),
+        ("a ||= b", node("^14 || ^16"), None, None),
+        ("var a = b?.c", node("12 - 14"), None, None),
+        ("var a = b?.c", ignores(&["nullishCoalescingOperators"]), None, None),
+        ("var a = b ?? c", None, None, engines_12()),
+        ("var a = b?.c", None, None, engines_12()),
+        ("class A { static {} }", None, None, no_engines()),
+    ];
+
+    Tester::new(NoUnsupportedFeaturesEsSyntax::NAME, pass, fail)
+        .with_node_plugin(true)
+        .test_and_snapshot();
+}
@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// ```
     BadBitwiseOperator,
     restriction // Restricted because there are false positives for enum bitflags in TypeScript,
-                // e.g. in the vscode repo
+                // e.g. in the vscode repo,
+    ast_types = [BinaryExpression, AssignmentExpression]
 );
 
 impl Rule for BadBitwiseOperator {
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     BadComparisonSequence,
-    correctness
+    correctness,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for BadComparisonSequence {
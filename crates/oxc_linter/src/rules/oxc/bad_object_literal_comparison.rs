@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// if (Array.isArray(x) && x.length === 0) { }
     /// ```
     BadObjectLiteralComparison,
-    correctness
+    correctness,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for BadObjectLiteralComparison {
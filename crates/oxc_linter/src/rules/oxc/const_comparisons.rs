@@ -68,7 +68,8 @@ declare_oxc_lint!(
     /// 500 <= status_code && status_code <= 600;
     /// ```
     ConstComparisons,
-    correctness
+    correctness,
+    ast_types = [LogicalExpression]
 );
 
 impl Rule for ConstComparisons {
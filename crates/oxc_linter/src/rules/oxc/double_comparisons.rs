@@ -40,6 +40,7 @@ declare_oxc_lint!(
     /// ```
     DoubleComparisons,
     correctness,
+    ast_types = [LogicalExpression],
 );
 
 #[allow(clippy::similar_names)]
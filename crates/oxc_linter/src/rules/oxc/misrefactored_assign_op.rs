@@ -48,6 +48,7 @@ declare_oxc_lint!(
     /// ```
     MisrefactoredAssignOp,
     suspicious,
+    ast_types = [AssignmentExpression],
 );
 
 impl Rule for MisrefactoredAssignOp {
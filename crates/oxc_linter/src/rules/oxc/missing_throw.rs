@@ -26,7 +26,8 @@ declare_oxc_lint!(
     /// const foo = () => { new Error() }
     /// ```
     MissingThrow,
-    correctness
+    correctness,
+    ast_types = [NewExpression]
 );
 
 impl Rule for MissingThrow {
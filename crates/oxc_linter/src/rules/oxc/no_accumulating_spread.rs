@@ -72,6 +72,7 @@ declare_oxc_lint!(
     /// ```
     NoAccumulatingSpread,
     perf,
+    ast_types = [SpreadElement],
 );
 
 impl Rule for NoAccumulatingSpread {
@@ -27,7 +27,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoAsyncAwait,
-    restriction
+    restriction,
+    ast_types = [Function, ArrowFunctionExpression]
 );
 
 impl Rule for NoAsyncAwait {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoConstEnum,
-    restriction,
+    restriction, fix,
+    ast_types = [TSEnumDeclaration]
 );
 
 impl Rule for NoConstEnum {
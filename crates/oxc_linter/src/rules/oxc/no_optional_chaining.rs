@@ -64,6 +64,7 @@ declare_oxc_lint!(
     ///
     NoOptionalChaining,
     restriction,
+    ast_types = [ChainExpression],
 );
 
 impl Rule for NoOptionalChaining {
@@ -60,6 +60,7 @@ declare_oxc_lint!(
     ///
     NoRestSpreadProperties,
     restriction,
+    ast_types = [SpreadElement, BindingRestElement, AssignmentTarget],
 );
 
 impl Rule for NoRestSpreadProperties {
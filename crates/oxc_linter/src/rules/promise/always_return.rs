@@ -0,0 +1,286 @@
+use oxc_ast::{
+    ast::{Expression, MemberExpression, Statement, SwitchCase},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_callback_call, is_method_call},
+    AstNode,
+};
+
+fn always_return_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-promise(always-return): Each then() should return a value or throw",
+    )
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AlwaysReturn(Box<AlwaysReturnConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AlwaysReturnConfig {
+    /// Do not report the callback of the last `then()` of a chain which is not used, e.g.
+    /// `promise.then(fn);` or `promise.then(fn).catch(handle);`.
+    ignore_last_callback: bool,
+    /// Do not report the callback of the last `then()` of a chain which assigns one of these
+    /// variables, or one of their properties. Defaults to `["globalThis"]`.
+    ignore_assignment_variable: Vec<String>,
+}
+
+impl Default for AlwaysReturnConfig {
+    fn default() -> Self {
+        Self { ignore_last_callback: false, ignore_assignment_variable: vec!["globalThis".into()] }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require returning inside each `then()` to create readable and reusable Promise chains.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `then()` callback which does not return resolves the next promise of the chain with
+    /// `undefined`, which is usually a mistake, e.g. a forgotten `return` before a nested promise.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// myPromise.then(function (val) {}).then(function (x) { console.log(x) });
+    ///
+    /// // Good
+    /// myPromise.then((val) => val * 2);
+    /// myPromise.then(function (val) { return val * 2 });
+    /// myPromise.then(function (val) { throw new Error('error') });
+    /// ```
+    AlwaysReturn,
+    nursery,
+    config = AlwaysReturnConfig,
+    ast_types = [Function, ArrowFunctionExpression]
+);
+
+impl Rule for AlwaysReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value
+            .get(0)
+            .and_then(|value| AlwaysReturnConfig::deserialize(value).ok())
+            .unwrap_or_default();
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let body = match node.kind() {
+            AstKind::Function(function) if function.is_expression() => function.body.as_ref(),
+            // An arrow function with an expression body always returns.
+            AstKind::ArrowFunctionExpression(arrow) if !arrow.expression => Some(&arrow.body),
+            _ => return,
+        };
+        let Some(body) = body else { return };
+
+        // Only the first argument of `then()` is checked.
+        let Some(call_expr) = get_callback_call(node, ctx) else { return };
+        if !is_method_call(call_expr, &["then"])
+            || !call_expr.arguments.first().is_some_and(|arg| arg.span() == node.kind().span())
+        {
+            return;
+        }
+        if always_returns_or_throws(&body.statements) {
+            return;
+        }
+        if is_last_callback(node, ctx)
+            && (self.0.ignore_last_callback || self.assigns_ignored_variable(&body.statements))
+        {
+            return;
+        }
+        ctx.diagnostic(always_return_diagnostic(node.kind().span()));
+    }
+}
+
+impl AlwaysReturn {
+    /// Whether a statement of the callback assigns one of `ignoreAssignmentVariable`,
+    /// e.g. `globalThis.value = value`.
+    fn assigns_ignored_variable(&self, statements: &[Statement]) -> bool {
+        statements.iter().any(|statement| {
+            let Statement::ExpressionStatement(statement) = statement else { return false };
+            let Expression::AssignmentExpression(assignment) = &statement.expression else {
+                return false;
+            };
+            let mut target = assignment
+                .left
+                .as_simple_assignment_target()
+                .and_then(|target| target.as_member_expression().map(MemberExpression::object));
+            while let Some(Expression::StaticMemberExpression(member_expr)) = target {
+                target = Some(&member_expr.object);
+            }
+            let name = match (target, assignment.left.get_identifier()) {
+                (Some(Expression::Identifier(ident)), _) => ident.name.as_str(),
+                (None, Some(name)) => name,
+                _ => return false,
+            };
+            self.0.ignore_assignment_variable.iter().any(|ignored| ignored == name)
+        })
+    }
+}
+
+/// Whether the callback is passed to the last call of a promise chain whose value is unused.
+fn is_last_callback(node: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    // Skip the `Argument` and the `then()` call.
+    let Some(call) = nodes.iter_parents(node.id()).nth(2) else { return false };
+    let mut target = call.kind().span();
+    for parent in nodes.iter_parents(call.id()).skip(1) {
+        match parent.kind() {
+            AstKind::ExpressionStatement(_) => return true,
+            AstKind::UnaryExpression(unary) => return unary.operator == UnaryOperator::Void,
+            AstKind::SequenceExpression(sequence) => {
+                if sequence.expressions.last().map(GetSpan::span) != Some(target) {
+                    return false;
+                }
+            }
+            AstKind::ChainExpression(_)
+            | AstKind::AwaitExpression(_)
+            | AstKind::ParenthesizedExpression(_) => {}
+            // `.catch()` and `.finally()` do not use the value of the chain.
+            AstKind::MemberExpression(member_expr)
+                if matches!(member_expr.static_property_name(), Some("catch" | "finally")) => {}
+            // The chain is the callee of `.catch()` or `.finally()`, not one of its arguments.
+            AstKind::CallExpression(call_expr)
+                if call_expr.callee.without_parenthesized().span() == target => {}
+            _ => return false,
+        }
+        target = parent.kind().span();
+    }
+    false
+}
+
+/// Whether every path of the statements ends with a `return` or a `throw`.
+fn always_returns_or_throws(statements: &[Statement]) -> bool {
+    statements.iter().any(returns_or_throws)
+}
+
+fn returns_or_throws(statement: &Statement) -> bool {
+    match statement {
+        Statement::ReturnStatement(_) | Statement::ThrowStatement(_) => true,
+        Statement::BlockStatement(block) => always_returns_or_throws(&block.body),
+        Statement::IfStatement(statement) => {
+            returns_or_throws(&statement.consequent)
+                && statement.alternate.as_ref().is_some_and(returns_or_throws)
+        }
+        Statement::TryStatement(statement) => {
+            statement
+                .finalizer
+                .as_ref()
+                .is_some_and(|finalizer| always_returns_or_throws(&finalizer.body))
+                || (always_returns_or_throws(&statement.block.body)
+                    && statement
+                        .handler
+                        .as_ref()
+                        .map_or(true, |handler| always_returns_or_throws(&handler.body.body)))
+        }
+        Statement::SwitchStatement(statement) => {
+            statement.cases.iter().any(SwitchCase::is_default_case)
+                && statement.cases.iter().enumerate().all(|(i, case)| {
+                    // An empty case falls through to the next one.
+                    case.consequent.is_empty() && i + 1 < statement.cases.len()
+                        || always_returns_or_throws(&case.consequent)
+                })
+        }
+        Statement::LabeledStatement(statement) => returns_or_throws(&statement.body),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("hey.then(x => x)", None),
+        ("hey.then(x => ({}))", None),
+        ("hey.then(x => { return; })", None),
+        ("hey.then(x => { return x ? x.id : null })", None),
+        ("hey.then(x => { return x * 10 })", None),
+        ("hey.then(x => { throw x })", None),
+        ("hey.then(function() { return 42; })", None),
+        ("hey.then(function() { return new Promise(); })", None),
+        ("hey.then(function() { return \"x\"; }).then(doSomethingWicked)", None),
+        ("hey.then(x => x).then(function() { return \"3\" })", None),
+        ("hey.then(function() { throw new Error(\"msg\"); })", None),
+        ("hey.then(function(x) { if (!x) { throw new Error(\"no x\"); } return x; })", None),
+        ("hey.then(function(x) { if (x) { return x; } throw new Error(\"no x\"); })", None),
+        ("hey.then(function(x) { if (x) { return x; } else { return 0; } })", None),
+        (
+            "hey.then(function(x) { switch (x) { case 1: case 2: return 1; default: throw x; } })",
+            None,
+        ),
+        ("hey.then(function(x) { try { return f(x); } catch (e) { throw e; } })", None),
+        ("hey.then(function(x) { try { f(x); } finally { return 1; } })", None),
+        ("hey.then(x => { throw new Error(\"msg\"); })", None),
+        ("hey.then(x => { if (!x) { throw new Error(\"no x\"); } return x; })", None),
+        ("hey.then(x => { var f = function() { }; return f; })", None),
+        ("hey.then(x => { if (x) { return x; } else { return x; } })", None),
+        ("hey.then(x => { return x; var y = \"unreachable\"; })", None),
+        ("hey.then(x => { return; }, err => { log(err); })", None),
+        ("hey.then(x => { return x && x(); }, err => { log(err); })", None),
+        ("hey.then(x => { return x.y || x(); }, err => { log(err); })", None),
+        ("hey.catch(x => { log(x); })", None),
+        ("hey.then(null, x => { log(x); })", None),
+        ("hey.then(fn)", None),
+        ("doSomething(function(x) { log(x); })", None),
+        ("hey.then(function() {}.bind(this))", None),
+        ("hey.then(function x() { x(); return 1; })", None),
+        ("hey.then(x => { log(x); })", Some(json!([{ "ignoreLastCallback": true }]))),
+        (
+            "hey.then(x => { log(x); }).catch(e => {})",
+            Some(json!([{ "ignoreLastCallback": true }])),
+        ),
+        ("void hey.then(x => { log(x); })", Some(json!([{ "ignoreLastCallback": true }]))),
+        (
+            "async function foo() { await hey.then(x => { log(x); }) }",
+            Some(json!([{ "ignoreLastCallback": true }])),
+        ),
+        ("hey.then(x => { globalThis.x = x; })", None),
+        ("hey.then(x => { globalThis.a.b = x; })", None),
+        (
+            "hey.then(x => { window.x = x; })",
+            Some(json!([{ "ignoreAssignmentVariable": ["window"] }])),
+        ),
+        (
+            "hey.then(x => { result = x; })",
+            Some(json!([{ "ignoreAssignmentVariable": ["result"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("hey.then(x => {})", None),
+        ("hey.then(function() { })", None),
+        ("hey.then(function() { }).then(x)", None),
+        ("hey.then(function() { }).then(function() { })", None),
+        ("hey.then(function() { return; }).then(function() { })", None),
+        ("hey.then(function() { doSomethingWicked(); })", None),
+        ("hey.then(function() { if (x) { return x; } })", None),
+        ("hey.then(function() { if (x) { return x; } else { }})", None),
+        ("hey.then(function() { if (x) { } else { return x; }})", None),
+        ("hey.then(function() { if (x) { return you.then(function() { return x; }); } })", None),
+        ("hey.then(x => { x(); })", None),
+        ("hey.then(function(x) { switch (x) { case 1: return 1; } })", None),
+        ("hey.then(function(x) { try { return f(x); } catch (e) { log(e); } })", None),
+        ("hey.then(x => { log(x); }).then(y => y)", Some(json!([{ "ignoreLastCallback": true }]))),
+        ("const p = hey.then(x => { log(x); })", Some(json!([{ "ignoreLastCallback": true }]))),
+        ("hey.then(x => { window.x = x; })", None),
+        ("hey.then(x => { globalThis.x = x; }).then(y => y)", None),
+    ];
+
+    Tester::new(AlwaysReturn::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
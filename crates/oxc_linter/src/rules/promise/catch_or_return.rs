@@ -0,0 +1,238 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_method_callee, is_promise},
+    AstNode,
+};
+
+fn catch_or_return_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint-plugin-promise(catch-or-return): Expected {x0} or return"))
+        .with_help("Return the promise or handle its rejection.")
+        .with_labels([span1.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CatchOrReturn(Box<CatchOrReturnConfig>);
+
+#[derive(Debug, Clone)]
+pub struct CatchOrReturnConfig {
+    allow_then: bool,
+    allow_finally: bool,
+    termination_method: Vec<String>,
+}
+
+impl Default for CatchOrReturnConfig {
+    fn default() -> Self {
+        Self { allow_then: false, allow_finally: false, termination_method: vec!["catch".into()] }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CatchOrReturnOptions {
+    /// Allow `.then(onFulfilled, onRejected)` to handle the rejection.
+    allow_then: bool,
+    /// Allow `.finally()` after the termination method, e.g. `.catch(fn).finally(fn)`.
+    allow_finally: bool,
+    /// The method, or methods, which handle the rejection. Defaults to `catch`.
+    termination_method: Option<TerminationMethod>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum TerminationMethod {
+    One(String),
+    Many(Vec<String>),
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensure that each time a `then()` is applied to a promise, a `catch()` is applied as well.
+    /// Exceptions are made if you are returning that promise.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise which is neither returned nor handled swallows its rejection, the error is
+    /// then only reported as an unhandled rejection, if at all.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// myPromise.then(doSomething);
+    /// myPromise.then(doSomething, catchErrors);
+    /// Promise.resolve(x);
+    ///
+    /// // Good
+    /// myPromise.then(doSomething).catch(errors);
+    /// function doSomethingElse() {
+    ///     return myPromise.then(doSomething);
+    /// }
+    /// ```
+    CatchOrReturn,
+    nursery,
+    config = CatchOrReturnOptions,
+    ast_types = [ExpressionStatement]
+);
+
+impl Rule for CatchOrReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| CatchOrReturnOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        let termination_method = match options.termination_method {
+            Some(TerminationMethod::One(method)) => vec![method],
+            Some(TerminationMethod::Many(methods)) => methods,
+            None => vec!["catch".into()],
+        };
+        Self(Box::new(CatchOrReturnConfig {
+            allow_then: options.allow_then,
+            allow_finally: options.allow_finally,
+            termination_method,
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(statement) = node.kind() else { return };
+        // The expression body of an arrow function is returned, e.g. `() => a.then(b)`.
+        let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+        if matches!(parents.next().map(AstNode::kind), Some(AstKind::FunctionBody(_)))
+            && matches!(
+                parents.next().map(AstNode::kind),
+                Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+            )
+        {
+            return;
+        }
+        let expr = statement.expression.without_parenthesized();
+        if !is_promise(expr) || is_cypress_call(expr) || self.is_allowed_termination(expr) {
+            return;
+        }
+        let methods = self
+            .0
+            .termination_method
+            .iter()
+            .map(|method| format!("{method}()"))
+            .collect::<Vec<_>>()
+            .join(" or ");
+        ctx.diagnostic(catch_or_return_diagnostic(&methods, expr.span()));
+    }
+}
+
+impl CatchOrReturn {
+    fn is_allowed_termination(&self, expr: &Expression) -> bool {
+        let Expression::CallExpression(call_expr) = expr.without_parenthesized() else {
+            return false;
+        };
+        let Some(callee) = get_method_callee(call_expr) else { return false };
+        let method = callee.property.name.as_str();
+        // `promise.then(onFulfilled, onRejected)`
+        if self.0.allow_then && method == "then" && call_expr.arguments.len() == 2 {
+            return true;
+        }
+        // `promise.catch(onRejected).finally(onFinally)`
+        if self.0.allow_finally
+            && method == "finally"
+            && is_promise(&callee.object)
+            && self.is_allowed_termination(&callee.object)
+        {
+            return true;
+        }
+        self.0.termination_method.iter().any(|termination| termination == method)
+    }
+}
+
+/// Cypress chains look like promises but are not, e.g. `cy.get('a').then(fn)`.
+fn is_cypress_call(expr: &Expression) -> bool {
+    let mut expr = expr.without_parenthesized();
+    loop {
+        match expr {
+            Expression::CallExpression(call_expr) => {
+                expr = call_expr.callee.without_parenthesized();
+            }
+            Expression::StaticMemberExpression(member_expr) => {
+                expr = member_expr.object.without_parenthesized();
+            }
+            Expression::Identifier(ident) => {
+                return matches!(ident.name.as_str(), "cy" | "Cypress")
+            }
+            _ => return false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("frank().then(go).catch(doIt)", None),
+        ("const g = () => p.then(x => x)", None),
+        ("const f = () => Promise.resolve(1)", None),
+        ("foo(() => p.then(y => y))", None),
+        ("frank().then(go).then().then().then().catch(doIt)", None),
+        ("frank().then(go).then().catch(function() { /* why bother */ })", None),
+        ("frank.then(go).then(to).catch(jail)", None),
+        ("Promise.resolve(frank).catch(jail)", None),
+        ("Promise.resolve(frank)[\"catch\"](jail)", None),
+        ("frank.then(to).finally(fn).catch(jail)", None),
+        ("function a() { return frank().then(go) }", None),
+        ("function a() { return frank().then(go).then().then().then() }", None),
+        ("function a() { return frank().then(go).then().then().then().catch(doIt) }", None),
+        ("function a() { return Promise.resolve(frank).then(go) }", None),
+        ("function a() { return Promise.all(frank).then(go) }", None),
+        ("function a() { return Promise.reject(frank).catch(go) }", None),
+        ("cy.get('.myClass').then(go)", None),
+        ("cy.get('button').click().then()", None),
+        ("frank().then(go).then(null, doIt)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(a, b)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(go).catch(doIt).finally(fn)", Some(json!([{ "allowFinally": true }]))),
+        (
+            "frank().then(go).then(a, b).finally(fn)",
+            Some(json!([{ "allowThen": true, "allowFinally": true }])),
+        ),
+        ("frank().then(go).done()", Some(json!([{ "terminationMethod": "done" }]))),
+        (
+            "frank().then(go).catch()",
+            Some(json!([{ "terminationMethod": ["catch", "asCallback"] }])),
+        ),
+        (
+            "frank().then(go).asCallback()",
+            Some(json!([{ "terminationMethod": ["catch", "asCallback"] }])),
+        ),
+        ("frank()", None),
+        ("frank.catch", None),
+    ];
+
+    let fail = vec![
+        ("function callPromise(promise, cb) { promise.then(cb) }", None),
+        ("fetch('http://www.yahoo.com').then(console.log.bind(console))", None),
+        ("a.then(function() { return 'x'; }).then(function(y) { throw y; })", None),
+        ("Promise.resolve(frank)", None),
+        ("Promise.all([])", None),
+        ("Promise.allSettled([])", None),
+        ("Promise.any([])", None),
+        ("Promise.race([])", None),
+        ("frank().then(go).catch(doIt).then(fn)", None),
+        ("frank().then(go).finally(fn)", None),
+        ("frank().then(go).catch(doIt).finally(fn)", None),
+        ("frank().then(a, b)", None),
+        ("frank().then(go).then(a, b).finally(fn)", Some(json!([{ "allowThen": true }]))),
+        ("frank().then(go).catch(doIt)", Some(json!([{ "terminationMethod": "done" }]))),
+        (
+            "frank().catch(go).someOtherMethod()",
+            Some(json!([{ "terminationMethod": ["catch", "asCallback"] }])),
+        ),
+    ];
+
+    Tester::new(CatchOrReturn::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
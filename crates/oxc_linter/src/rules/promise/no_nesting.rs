@@ -0,0 +1,151 @@
+use oxc_ast::{ast::CallExpression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_method_callee, is_inside_promise, is_method_call},
+    AstNode,
+};
+
+fn no_nesting_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-promise(no-nesting): Avoid nesting promises.")
+        .with_help("Return the promise and chain the next `then()` or `catch()` instead.")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNesting;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow nested `then()` or `catch()` statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Nesting promises brings back the "callback hell" that promises avoid, returning the
+    /// promise from the callback and chaining it is flatter and propagates the errors.
+    ///
+    /// A nested call whose arguments use a variable of the closest enclosing callback is allowed,
+    /// since it cannot be moved to the outer chain.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// doThing().then(function() { a.then() })
+    /// doThing().then(function() { return a.then() })
+    ///
+    /// // Good
+    /// doThing().then(function() { return 4 })
+    /// doThing().then(() => a).then(() => b)
+    /// doThing().then((a) => getB(a).then((b) => getC(a, b)))
+    /// ```
+    NoNesting,
+    nursery,
+    ast_types = [CallExpression]
+);
+
+impl Rule for NoNesting {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !is_method_call(call_expr, &["then", "catch"]) {
+            return;
+        }
+        let Some(callback) = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .skip(1)
+            .find(|parent| is_inside_promise(parent, ctx))
+        else {
+            return;
+        };
+        if uses_callback_variables(call_expr, callback, ctx) {
+            return;
+        }
+        let Some(callee) = get_method_callee(call_expr) else { return };
+        ctx.diagnostic(no_nesting_diagnostic(callee.property.span));
+    }
+}
+
+/// Whether an argument of the nested call references a variable declared by the closest
+/// enclosing promise callback, e.g. `a` in `getA().then((a) => getB().then((b) => a + b))`.
+fn uses_callback_variables(
+    call_expr: &CallExpression,
+    callback: &AstNode,
+    ctx: &LintContext,
+) -> bool {
+    let scope_id = match callback.kind() {
+        AstKind::Function(function) => function.scope_id.get(),
+        AstKind::ArrowFunctionExpression(arrow) => arrow.scope_id.get(),
+        _ => None,
+    };
+    let Some(scope_id) = scope_id else { return false };
+    let symbols = ctx.symbols();
+    ctx.scopes().get_bindings(scope_id).values().any(|symbol_id| {
+        symbols.get_resolved_references(*symbol_id).any(|reference| {
+            call_expr.arguments.iter().any(|arg| contains(arg.span(), reference.span()))
+        })
+    })
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Promise.resolve(4).then(function(x) { return x })",
+        "Promise.reject(4).then(function(x) { return x })",
+        "Promise.resolve(4).then(function() {})",
+        "Promise.reject(4).then(function() {})",
+        "doThing().then(function() { return 4 })",
+        "doThing().then(function() { throw 4 })",
+        "doThing().then(null, function() { return 4 })",
+        "doThing().then(null, function() { throw 4 })",
+        "doThing().catch(null, function() { return 4 })",
+        "doThing().catch(null, function() { throw 4 })",
+        "doThing().then(() => 4)",
+        "doThing().then(() => { throw 4 })",
+        "doThing().then(()=>{}, () => 4)",
+        "doThing().then(()=>{}, () => { throw 4 })",
+        "doThing().catch(() => 4)",
+        "doThing().catch(() => { throw 4 })",
+        "var x = function() { return Promise.resolve(4) }",
+        "function y() { return Promise.resolve(4) }",
+        "function then() { return Promise.reject() }",
+        "doThing(function(x) { return Promise.reject(x) })",
+        "doThing().then(function() { return Promise.all([a,b,c]) })",
+        "doThing().then(function() { return Promise.resolve(4) })",
+        "doThing().then(() => Promise.resolve(4))",
+        "doThing().then(() => Promise.all([a]))",
+        "doThing().then(a => getB(a).then(b => getC(a, b)))",
+        "doThing().then(a => { const c = a * 2; return getB(c).then(b => getC(c, b)) })",
+        "doThing().then(function(a) { return getB(a).catch(function(e) { log(a, e) }) })",
+        "doThing().then(a => { function f() { return a } return getB().then(b => f(b)) })",
+    ];
+
+    let fail = vec![
+        "doThing().then(function() { a.then() })",
+        "doThing().then(function() { b.catch() })",
+        "doThing().then(function() { return a.then() })",
+        "doThing().then(function() { return b.catch() })",
+        "doThing().then(() => { a.then() })",
+        "doThing().then(() => { b.catch() })",
+        "doThing().then(() => a.then())",
+        "doThing().then(() => b.catch())",
+        "doThing().then(a => getB(a).then(b => getC(b)))",
+        "doThing().then(a => getB(a).then(b => getC(a, b).then(c => getD(a, c))))",
+        "doThing().then(a => getB(a).then(b => getC(b).then(c => getD(a, c))))",
+        "doThing().then(() => { const a = 1; return getB().then(b => b) })",
+        "doThing().catch(function() { return doOther().then(function(x) { return x }) })",
+        "doThing().then(function() { function f() { return a.then() } })",
+    ];
+
+    Tester::new(NoNesting::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
@@ -0,0 +1,167 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, FormalParameters},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_inside_promise, is_promise_call},
+    AstNode,
+};
+
+fn no_promise_in_callback_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-promise(no-promise-in-callback): Avoid using promises inside of callbacks.",
+    )
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoPromiseInCallback {
+    /// Do not report the promises inside of function declarations.
+    exempt_declarations: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow using promises inside of callbacks, i.e. functions whose first parameter is
+    /// named `err` or `error`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixing the callback and the promise styles makes the error handling hard to follow, an
+    /// error of the promise is neither passed to a callback nor propagated to the caller.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// a(function (err) { doThing().then(a) })
+    /// function x(err) { Promise.all() }
+    ///
+    /// // Good
+    /// promisify(a)().then(a)
+    /// a(function (err) { return doThing().then(a) })
+    /// ```
+    NoPromiseInCallback,
+    nursery,
+    config = NoPromiseInCallback,
+    ast_types = [CallExpression]
+);
+
+impl Rule for NoPromiseInCallback {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value.get(0).and_then(|value| Self::deserialize(value).ok()).unwrap_or_default()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else { return };
+        if !is_promise_call(call_expr) {
+            return;
+        }
+        let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+        match parents.next().map(AstNode::kind) {
+            // A returned promise is likely not inside of a real callback.
+            Some(AstKind::ReturnStatement(_)) => return,
+            // The expression body of an arrow function is returned as well, e.g. `(err) => a.then(b)`.
+            Some(AstKind::ExpressionStatement(_))
+                if matches!(
+                    parents.nth(1).map(AstNode::kind),
+                    Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+                ) =>
+            {
+                return;
+            }
+            // Only the whole chain is reported, e.g. `a.then(b).catch(c)`.
+            Some(AstKind::MemberExpression(member_expr))
+                if member_expr.object().without_parenthesized().span() == call_expr.span =>
+            {
+                return;
+            }
+            _ => {}
+        }
+        if ctx.nodes().iter_parents(node.id()).skip(1).any(|parent| self.is_callback(parent, ctx)) {
+            ctx.diagnostic(no_promise_in_callback_diagnostic(call_expr.span));
+        }
+    }
+}
+
+impl NoPromiseInCallback {
+    /// Whether the node is a function whose first parameter is `err` or `error`, other than a
+    /// callback of a promise.
+    fn is_callback<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+        let params = match node.kind() {
+            AstKind::Function(function) => {
+                if self.exempt_declarations && function.is_declaration() {
+                    return false;
+                }
+                &function.params
+            }
+            AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+            _ => return false,
+        };
+        is_error_first(params) && !is_inside_promise(node, ctx)
+    }
+}
+
+fn is_error_first(params: &FormalParameters) -> bool {
+    params.items.first().is_some_and(|param| {
+        matches!(&param.pattern.kind, BindingPatternKind::BindingIdentifier(ident)
+            if matches!(ident.name.as_str(), "err" | "error"))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("go(function() { return Promise.resolve(4) })", None),
+        ("go(function() { return a.then(b) })", None),
+        ("go(function() { b.catch(c) })", None),
+        ("go(function() { b.then(c, d) })", None),
+        ("go(() => Promise.resolve(4))", None),
+        ("go((errrr) => a.then(b))", None),
+        ("go((elephant) => b.catch(c))", None),
+        ("go((e) => b.then(c, d))", None),
+        ("a.catch((err) => { b.then(c) })", None),
+        ("a.then(null, (err) => { b.then(c) })", None),
+        ("go(function(err) { return Promise.resolve(4) })", None),
+        ("go((err) => { return a.then(b) })", None),
+        ("go((err) => a.then(b))", None),
+        ("doSomething(function(err) { return a.then(b) })", None),
+        ("let x = (err) => doThingWith(err).then(a)", None),
+        ("function thing(err) { cb() }", None),
+        (
+            "function thing(err) { doSomething().then(a) }",
+            Some(json!([{ "exemptDeclarations": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("a(function(err) { doThing().then(a) })", None),
+        ("a(function(error, zup, supa) { doThing().then(a) })", None),
+        ("a(function(error) { doThing().then(a) })", None),
+        ("a((err) => { doThing().then(a) })", None),
+        ("a((err) => { doThing().then(a).catch(b) })", None),
+        ("a((error, zup, supa) => { doThing().then(a) })", None),
+        ("a((error) => { doThing().then(a) })", None),
+        ("a(function(err) { Promise.all([]) })", None),
+        ("function x(err) { Promise.all() }", None),
+        ("function x(err) { Promise.allSettled() }", None),
+        ("function x(err) { Promise.any() }", None),
+        ("a(function(err) { const b = doThing().then(a); b.then(c) })", None),
+    ];
+
+    Tester::new(NoPromiseInCallback::NAME, pass, fail)
+        .with_promise_plugin(true)
+        .test_and_snapshot();
+}
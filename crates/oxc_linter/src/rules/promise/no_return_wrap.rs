@@ -0,0 +1,188 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_method_callee, is_promise_call},
+    AstNode,
+};
+
+fn resolve_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-promise(no-return-wrap): Avoid wrapping return values in Promise.resolve",
+    )
+    .with_help("Return the value itself.")
+    .with_labels([span0.into()])
+}
+
+fn reject_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-promise(no-return-wrap): Expected throw instead of Promise.reject",
+    )
+    .with_help("Throw the error instead.")
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoReturnWrap {
+    /// Allow returning `Promise.reject()` inside the callbacks.
+    allow_reject: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prevent wrapping values in `Promise.resolve` or `Promise.reject` when not needed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The value returned by a `then()` or `catch()` callback is already wrapped in a promise, and
+    /// an error thrown by it already rejects the promise.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// myPromise.then(function (val) {
+    ///     return Promise.resolve(val * 2);
+    /// });
+    /// myPromise.then(function (val) {
+    ///     return Promise.reject('bad thing');
+    /// });
+    ///
+    /// // Good
+    /// myPromise.then(function (val) {
+    ///     return val * 2;
+    /// });
+    /// myPromise.then(function (val) {
+    ///     throw new Error('bad thing');
+    /// });
+    /// ```
+    NoReturnWrap,
+    nursery,
+    config = NoReturnWrap,
+    ast_types = [ReturnStatement, ArrowFunctionExpression]
+);
+
+impl Rule for NoReturnWrap {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value.get(0).and_then(|value| Self::deserialize(value).ok()).unwrap_or_default()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let returned = match node.kind() {
+            AstKind::ReturnStatement(statement) => statement.argument.as_ref(),
+            AstKind::ArrowFunctionExpression(arrow) if arrow.expression => arrow.get_expression(),
+            _ => return,
+        };
+        let Some(Expression::CallExpression(call_expr)) =
+            returned.map(Expression::without_parenthesized)
+        else {
+            return;
+        };
+        let Some(callee) = get_method_callee(call_expr) else { return };
+        if !callee.object.is_specific_id("Promise") || !is_in_promise_callback(node, ctx) {
+            return;
+        }
+        match callee.property.name.as_str() {
+            "resolve" => ctx.diagnostic(resolve_diagnostic(call_expr.span)),
+            "reject" if !self.allow_reject => ctx.diagnostic(reject_diagnostic(call_expr.span)),
+            _ => {}
+        }
+    }
+}
+
+/// Whether the closest function of the node, possibly bound with `.bind()`, is a callback of a
+/// promise, e.g. `promise.then(function() { return x; }.bind(this))`.
+fn is_in_promise_callback(node: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let Some(function) = nodes.iter_parents(node.id()).find(|node| {
+        matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+    }) else {
+        return false;
+    };
+    if matches!(function.kind(), AstKind::Function(function) if !function.is_expression()) {
+        return false;
+    }
+    let mut parents = nodes.iter_parents(function.id()).skip(1).filter(|node| {
+        !matches!(node.kind(), AstKind::ParenthesizedExpression(_) | AstKind::Argument(_))
+    });
+    loop {
+        match parents.next().map(AstNode::kind) {
+            // `function() {}.bind(this)`
+            Some(AstKind::MemberExpression(member_expr))
+                if member_expr.static_property_name() == Some("bind") =>
+            {
+                if !matches!(parents.next().map(AstNode::kind), Some(AstKind::CallExpression(_))) {
+                    return false;
+                }
+            }
+            Some(AstKind::CallExpression(call_expr)) => return is_promise_call(call_expr),
+            _ => return false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("Promise.resolve(4).then(function(x) { return x })", None),
+        ("Promise.reject(4).then(function(x) { return x })", None),
+        ("Promise.resolve(4).then(function() {})", None),
+        ("Promise.reject(4).then(function() {})", None),
+        ("doThing().then(function() { return 4 })", None),
+        ("doThing().then(function() { throw 4 })", None),
+        ("doThing().then(null, function() { return 4 })", None),
+        ("doThing().then(null, function() { throw 4 })", None),
+        ("doThing().catch(null, function() { return 4 })", None),
+        ("doThing().catch(null, function() { throw 4 })", None),
+        ("doThing().then(function() { return Promise.all([a,b,c]) })", None),
+        ("doThing().then(() => 4)", None),
+        ("doThing().then(() => { throw 4 })", None),
+        ("doThing().then(()=>{}, () => 4)", None),
+        ("doThing().then(()=>{}, () => { throw 4 })", None),
+        ("doThing().catch(() => 4)", None),
+        ("doThing().catch(() => { throw 4 })", None),
+        ("var x = function() { return Promise.resolve(4) }", None),
+        ("function y() { return Promise.resolve(4) }", None),
+        ("function then() { return Promise.reject() }", None),
+        ("doThing(function(x) { return Promise.reject(x) })", None),
+        ("doThing().then(function() { return })", None),
+        ("doThing().then(function() { function f() { return Promise.resolve(4) } })", None),
+        (
+            "doThing().then(function() { return Promise.reject(4) })",
+            Some(json!([{ "allowReject": true }])),
+        ),
+        ("doThing().then((function() { return Promise.resolve(4) }).toString())", None),
+        ("doThing().then(() => Promise.reject(4))", Some(json!([{ "allowReject": true }]))),
+    ];
+
+    let fail = vec![
+        ("doThing().then(function() { return Promise.resolve(4) })", None),
+        ("doThing().then(null, function() { return Promise.resolve(4) })", None),
+        ("doThing().catch(function() { return Promise.resolve(4) })", None),
+        ("doThing().then(function() { return Promise.reject(4) })", None),
+        ("doThing().then(null, function() { return Promise.reject(4) })", None),
+        ("doThing().catch(function() { return Promise.reject(4) })", None),
+        ("doThing().then(function(x) { if (x>1) { return Promise.resolve(4) } else { throw \"bad\" } })", None),
+        ("doThing().then(function(x) { if (x>1) { return Promise.reject(4) } })", None),
+        ("doThing().then(null, function() { if (true && false) { return Promise.resolve() } })", None),
+        ("doThing().catch(function(x) {if (x) { return Promise.resolve(4) } else { return Promise.reject() } })", None),
+        ("fn(function() { doThing().then(function() { return Promise.resolve(4) }) })", None),
+        ("doThing().then(function() { return Promise.resolve(4) }.bind(this))", None),
+        ("doThing().then(function() { return Promise.resolve(4) }.bind(this).bind(this))", None),
+        ("doThing().then(() => { return Promise.resolve(4) })", None),
+        ("doThing().then(() => Promise.resolve(4))", None),
+        ("doThing().then(() => Promise.reject(4))", None),
+    ];
+
+    Tester::new(NoReturnWrap::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
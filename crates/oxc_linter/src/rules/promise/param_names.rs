@@ -0,0 +1,154 @@
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, FormalParameter},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn param_names_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-promise(param-names): Promise constructor parameters must be named to match \"{x0}\""
+    ))
+    .with_labels([span1.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ParamNames(Box<ParamNamesConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ParamNamesConfig {
+    resolve_pattern: Regex,
+    reject_pattern: Regex,
+}
+
+impl Default for ParamNamesConfig {
+    fn default() -> Self {
+        Self {
+            resolve_pattern: Regex::new(DEFAULT_RESOLVE_PATTERN).unwrap(),
+            reject_pattern: Regex::new(DEFAULT_REJECT_PATTERN).unwrap(),
+        }
+    }
+}
+
+const DEFAULT_RESOLVE_PATTERN: &str = "^_?resolve$";
+const DEFAULT_REJECT_PATTERN: &str = "^_?reject$";
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParamNamesOptions {
+    /// Pattern of the name of the first parameter, defaults to `^_?resolve$`.
+    resolve_pattern: Option<String>,
+    /// Pattern of the name of the second parameter, defaults to `^_?reject$`.
+    reject_pattern: Option<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce standard parameter names for Promise constructors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Consistent names make it obvious which of the two functions settles the promise with a
+    /// value and which with an error, swapping them by mistake is a hard to spot bug.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// new Promise(function (reject, resolve) { ... }) // incorrect order
+    /// new Promise(function (ok, fail) { ... }) // non-standard parameter names
+    ///
+    /// // Good
+    /// new Promise(function (resolve, reject) { ... })
+    /// new Promise(function (_resolve, _reject) { ... }) // unused marker for parameters are allowed
+    /// ```
+    ParamNames,
+    nursery,
+    config = ParamNamesOptions,
+    ast_types = [NewExpression]
+);
+
+impl Rule for ParamNames {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| ParamNamesOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        let pattern = |pattern: Option<String>, default: &str| {
+            pattern
+                .and_then(|pattern| Regex::new(&pattern).ok())
+                .unwrap_or_else(|| Regex::new(default).unwrap())
+        };
+        Self(Box::new(ParamNamesConfig {
+            resolve_pattern: pattern(options.resolve_pattern, DEFAULT_RESOLVE_PATTERN),
+            reject_pattern: pattern(options.reject_pattern, DEFAULT_REJECT_PATTERN),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NewExpression(new_expr) = node.kind() else { return };
+        if !new_expr.callee.is_specific_id("Promise") {
+            return;
+        }
+        let params = match new_expr.arguments.first() {
+            Some(Argument::FunctionExpression(function)) => &function.params,
+            Some(Argument::ArrowFunctionExpression(arrow)) => &arrow.params,
+            _ => return,
+        };
+        let mut params = params.items.iter();
+        check_param(params.next(), &self.0.resolve_pattern, ctx);
+        check_param(params.next(), &self.0.reject_pattern, ctx);
+    }
+}
+
+fn check_param(param: Option<&FormalParameter>, pattern: &Regex, ctx: &LintContext) {
+    let Some(param) = param else { return };
+    // Destructured parameters are not checked.
+    let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else { return };
+    if !pattern.is_match(&ident.name) {
+        ctx.diagnostic(param_names_diagnostic(pattern.as_str(), param.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("new Promise(function(resolve, reject) {})", None),
+        ("new Promise(function(resolve, _reject) {})", None),
+        ("new Promise(function(_resolve, reject) {})", None),
+        ("new Promise(function(_resolve, _reject) {})", None),
+        ("new Promise(function(resolve) {})", None),
+        ("new Promise(function(_resolve) {})", None),
+        ("new Promise(resolve => {})", None),
+        ("new Promise((resolve, reject) => {})", None),
+        ("new Promise(() => {})", None),
+        ("new NonPromise()", None),
+        (
+            "new Promise((yes, no) => {})",
+            Some(json!([{ "resolvePattern": "^yes$", "rejectPattern": "^no$" }])),
+        ),
+        ("new Promise(({ resolve }, reject) => {})", None),
+    ];
+
+    let fail = vec![
+        ("new Promise(function(reject, resolve) {})", None),
+        ("new Promise(function(resolve, rej) {})", None),
+        ("new Promise(yes => {})", None),
+        ("new Promise((yes, no) => {})", None),
+        (
+            "new Promise(function(resolve, reject) { })",
+            Some(json!([{ "resolvePattern": "^yes$", "rejectPattern": "^no$" }])),
+        ),
+    ];
+
+    Tester::new(ParamNames::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
@@ -8,6 +8,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -28,10 +30,14 @@ fn invalid_type_prop(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ButtonHasType {
+    /// Allow `type="button"`.
     button: bool,
+    /// Allow `type="submit"`.
     submit: bool,
+    /// Allow `type="reset"`.
     reset: bool,
 }
 
@@ -61,7 +67,9 @@ declare_oxc_lint!(
     /// <button type="submit" />
     /// ```
     ButtonHasType,
-    restriction
+    restriction,
+    config = ButtonHasType,
+    ast_types = [JSXOpeningElement, CallExpression]
 );
 
 impl Rule for ButtonHasType {
@@ -132,19 +140,10 @@ impl Rule for ButtonHasType {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
-
-        Self {
-            button: value
-                .and_then(|val| val.get("button").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-            submit: value
-                .and_then(|val| val.get("submit").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-            reset: value
-                .and_then(|val| val.get("reset").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -5,6 +5,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -25,9 +27,12 @@ fn exclusive_checked_attribute(span0: Span, span1: Span) -> OxcDiagnostic {
         .with_labels([span0.into(), span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct CheckedRequiresOnchangeOrReadonly {
+    /// Do not report `checked` without `onChange` or `readOnly`.
     ignore_missing_properties: bool,
+    /// Do not report `checked` together with `defaultChecked`.
     ignore_exclusive_checked_attribute: bool,
 }
 
@@ -59,7 +64,9 @@ declare_oxc_lint!(
     /// React.createElement('input', { type: 'checkbox', defaultChecked: true });
     /// ```
     CheckedRequiresOnchangeOrReadonly,
-    pedantic
+    pedantic,
+    config = CheckedRequiresOnchangeOrReadonly,
+    ast_types = [JSXOpeningElement, CallExpression]
 );
 
 impl Rule for CheckedRequiresOnchangeOrReadonly {
@@ -183,20 +190,10 @@ impl Rule for CheckedRequiresOnchangeOrReadonly {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
-
-        Self {
-            ignore_missing_properties: value
-                .and_then(|val| {
-                    val.get("ignoreMissingProperties").and_then(serde_json::Value::as_bool)
-                })
-                .unwrap_or(false),
-            ignore_exclusive_checked_attribute: value
-                .and_then(|val| {
-                    val.get("ignoreExclusiveCheckedAttribute").and_then(serde_json::Value::as_bool)
-                })
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -0,0 +1,748 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpression, ArrayExpressionElement, BindingPatternKind, CallExpression,
+        ChainElement, Expression, IdentifierReference, MemberExpression, VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+mod diagnostics {
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+
+    pub(super) fn missing_callback(span: Span, hook: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): \
+            React Hook {hook} requires an effect callback. \
+            Did you forget to pass a callback to the hook?"
+        ))
+        .with_label(span)
+    }
+
+    pub(super) fn missing_dependency_array(span: Span, hook: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): \
+            React Hook {hook} does nothing when called with only one argument. \
+            Did you forget to pass an array of dependencies?"
+        ))
+        .with_label(span)
+    }
+
+    pub(super) fn unknown_function(span: Span, hook: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): \
+            React Hook {hook} received a function whose dependencies are unknown. \
+            Pass an inline function instead."
+        ))
+        .with_label(span)
+    }
+
+    pub(super) fn async_effect(span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(
+            "eslint-plugin-react-hooks(exhaustive-deps): \
+            Effect callbacks are synchronous to prevent race conditions.",
+        )
+        .with_help("Put the async function inside the effect and call it from there.")
+        .with_label(span)
+    }
+
+    pub(super) fn not_array_literal(span: Span, hook: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): \
+            React Hook {hook} was passed a dependency list that is not an array literal. \
+            This means we can't statically verify whether you've passed the correct dependencies."
+        ))
+        .with_label(span)
+    }
+
+    pub(super) fn complex_expression(span: Span, hook: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): \
+            React Hook {hook} has a complex expression in the dependency array. \
+            Extract it to a separate variable so it can be statically checked."
+        ))
+        .with_label(span)
+    }
+
+    pub(super) fn function_changes_every_render(
+        span: Span,
+        name: &str,
+        hook: &str,
+        line: usize,
+        move_into_effect: bool,
+    ) -> OxcDiagnostic {
+        let help = if move_into_effect {
+            format!(
+                "Move it inside the {hook} callback. \
+                Alternatively, wrap the definition of '{name}' in its own useCallback() Hook."
+            )
+        } else {
+            format!("To fix this, wrap the definition of '{name}' in its own useCallback() Hook.")
+        };
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): The '{name}' function makes \
+            the dependencies of {hook} Hook (at line {line}) change on every render."
+        ))
+        .with_help(help)
+        .with_label(span)
+    }
+
+    pub(super) fn dependencies(
+        span: Span,
+        hook: &str,
+        message: &str,
+        suggestion: &str,
+    ) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook} has {message}"
+        ))
+        .with_help(format!("Update the dependencies array to be: {suggestion}"))
+        .with_label(span)
+    }
+}
+
+/// <https://github.com/facebook/react/blob/main/packages/eslint-plugin-react-hooks/src/ExhaustiveDeps.js>
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps(Box<ExhaustiveDepsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDepsConfig {
+    additional_hooks: Option<Regex>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct ExhaustiveDepsOptions {
+    /// Regular expression of the names of custom hooks which take a callback and an array of
+    /// dependencies as their first two arguments, e.g. `"(useMyEffect|useMyMemo)"`.
+    additional_hooks: Option<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies that the dependency arrays of `useEffect`, `useLayoutEffect`,
+    /// `useInsertionEffect`, `useCallback`, `useMemo` and `useImperativeHandle` list every value
+    /// of the component which is read by their callback, and nothing else.
+    ///
+    /// The `useState` and `useReducer` setters, the refs returned by `useRef` and the values
+    /// declared outside of the component never change between renders, and do not need to be
+    /// listed. Effects may list additional values of the component, to run when these change.
+    /// The functions declared in the component are different on every render, listing one makes
+    /// the hook run on every render, unless it is wrapped in `useCallback`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A callback which reads a value missing from its dependencies keeps seeing the value of
+    /// the render in which it was created, so effects do not run again and memoized values are
+    /// not recomputed when the value changes.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function MyComponent(props) {
+    ///     useEffect(() => {
+    ///         console.log(props.foo);
+    ///     }, []);
+    /// }
+    /// ```
+    ExhaustiveDeps,
+    nursery,
+    config = ExhaustiveDepsOptions,
+    ast_types = [CallExpression]
+);
+
+impl Rule for ExhaustiveDeps {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| ExhaustiveDepsOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        let additional_hooks =
+            options.additional_hooks.and_then(|pattern| Regex::new(&pattern).ok());
+        Self(Box::new(ExhaustiveDepsConfig { additional_hooks }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(hook_name) = self.reactive_hook_name(call) else { return };
+        let hook = ctx.source_range(call.callee.span());
+        let is_effect = is_effect_hook_name(hook_name);
+        let callback_index = usize::from(hook_name == "useImperativeHandle");
+
+        let Some(callback) = call.arguments.get(callback_index) else {
+            ctx.diagnostic(diagnostics::missing_callback(call.callee.span(), hook));
+            return;
+        };
+        let dependencies = call.arguments.get(callback_index + 1);
+        if dependencies.is_none() && !is_effect {
+            if matches!(hook_name, "useMemo" | "useCallback") {
+                ctx.diagnostic(diagnostics::missing_dependency_array(call.callee.span(), hook));
+            }
+            return;
+        }
+
+        let pure_scopes = pure_scopes(ctx, node.scope_id());
+        let function_span = match callback {
+            Argument::ArrowFunctionExpression(arrow) => {
+                if is_effect && arrow.r#async {
+                    ctx.diagnostic(diagnostics::async_effect(arrow.span));
+                }
+                arrow.span
+            }
+            Argument::FunctionExpression(function) => {
+                if is_effect && function.r#async {
+                    ctx.diagnostic(diagnostics::async_effect(function.span));
+                }
+                function.span
+            }
+            Argument::Identifier(ident) => {
+                let Some(Argument::ArrayExpression(array)) = dependencies else { return };
+                let is_listed = array.elements.iter().any(|element| {
+                    matches!(element, ArrayExpressionElement::Identifier(dependency) if dependency.name == ident.name)
+                });
+                if is_listed {
+                    return;
+                }
+                let Some(span) = local_function_span(ctx, ident, &pure_scopes) else {
+                    ctx.diagnostic(diagnostics::unknown_function(call.callee.span(), hook));
+                    return;
+                };
+                span
+            }
+            _ => {
+                if dependencies.is_some() {
+                    ctx.diagnostic(diagnostics::unknown_function(call.callee.span(), hook));
+                }
+                return;
+            }
+        };
+
+        let Some(dependencies) = dependencies else { return };
+        let Argument::ArrayExpression(dependencies) = dependencies else {
+            ctx.diagnostic(diagnostics::not_array_literal(dependencies.span(), hook));
+            return;
+        };
+        check_dependencies(ctx, hook, is_effect, function_span, dependencies, &pure_scopes);
+    }
+}
+
+impl ExhaustiveDeps {
+    /// Name of the hook called by `call`, without the `React.` namespace,
+    /// if it takes a callback and an array of dependencies.
+    fn reactive_hook_name<'a>(&self, call: &CallExpression<'a>) -> Option<&'a str> {
+        let name = match &call.callee {
+            Expression::Identifier(ident) => ident.name.as_str(),
+            Expression::StaticMemberExpression(member) => match &member.object {
+                Expression::Identifier(object) if object.name == "React" => {
+                    member.property.name.as_str()
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let is_reactive_hook =
+            matches!(
+                name,
+                "useEffect"
+                    | "useLayoutEffect"
+                    | "useInsertionEffect"
+                    | "useCallback"
+                    | "useMemo"
+                    | "useImperativeHandle"
+            ) || self.0.additional_hooks.as_ref().is_some_and(|pattern| pattern.is_match(name));
+        is_reactive_hook.then_some(name)
+    }
+}
+
+/// A value of the component read by the callback, e.g. `props.foo` for `props.foo.bar()`.
+struct Captured {
+    /// Path with the optional chains, e.g. `props?.foo`.
+    display: String,
+    /// Whether the value never changes between renders, e.g. a `useState` setter.
+    is_stable: bool,
+}
+
+/// An element of the dependency array.
+struct Declared {
+    /// Path without the optional chains, e.g. `props.foo` for `props?.foo`.
+    key: String,
+    display: String,
+    /// Whether the value is declared outside of the component.
+    is_external: bool,
+}
+
+fn check_dependencies<'a>(
+    ctx: &LintContext<'a>,
+    hook: &str,
+    is_effect: bool,
+    function_span: Span,
+    dependencies: &ArrayExpression<'a>,
+    pure_scopes: &[ScopeId],
+) {
+    let captured = captured_dependencies(ctx, function_span, pure_scopes);
+
+    let mut declared = vec![];
+    // The functions declared in the component, which are different on every render.
+    let mut functions = vec![];
+    for element in &dependencies.elements {
+        if matches!(element, ArrayExpressionElement::Elision(_)) {
+            continue;
+        }
+        let Some((ident, key, display)) = element.as_expression().and_then(dependency_path) else {
+            ctx.diagnostic(diagnostics::complex_expression(element.span(), hook));
+            continue;
+        };
+        let is_external = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+            .map_or(true, |symbol_id| {
+                !pure_scopes.contains(&ctx.symbols().get_scope_id(symbol_id))
+            });
+        if key == ident.name.as_str() {
+            if let Some(span) = local_function_span(ctx, ident, pure_scopes) {
+                functions.push((ident, span));
+            }
+        }
+        declared.push(Declared { key, display, is_external });
+    }
+
+    let mut suggested = vec![];
+    let mut unnecessary = BTreeSet::new();
+    let mut duplicate = BTreeSet::new();
+    let mut external = None;
+    let mut seen = FxHashSet::default();
+    for dependency in &declared {
+        if !seen.insert(dependency.key.as_str()) {
+            duplicate.insert(dependency.display.as_str());
+        } else if captured.keys().any(|key| is_covered_by(key, &dependency.key))
+            || (is_effect && !dependency.key.ends_with(".current") && !dependency.is_external)
+        {
+            suggested.push(dependency.display.as_str());
+        } else {
+            unnecessary.insert(dependency.display.as_str());
+            if dependency.is_external && external.is_none() {
+                external = Some(dependency.display.as_str());
+            }
+        }
+    }
+
+    let missing_keys = captured
+        .iter()
+        .filter(|(key, captured)| {
+            !captured.is_stable && !declared.iter().any(|d| is_covered_by(key, &d.key))
+        })
+        .map(|(key, _)| key.as_str())
+        .collect::<Vec<_>>();
+    let missing = missing_keys
+        .iter()
+        .filter(|key| !missing_keys.iter().any(|other| other != *key && is_covered_by(key, other)))
+        .map(|key| captured[*key].display.as_str())
+        .collect::<BTreeSet<_>>();
+
+    let Some(message) = warning_message(&missing, "a", "missing", "include")
+        .or_else(|| warning_message(&unnecessary, "an", "unnecessary", "exclude"))
+        .or_else(|| warning_message(&duplicate, "a", "duplicate", "omit"))
+    else {
+        let line = ctx.source_text()[..dependencies.span.start as usize].matches('\n').count() + 1;
+        for (ident, span) in functions {
+            let move_into_effect =
+                is_effect && !is_read_outside(ctx, ident, &[function_span, dependencies.span]);
+            ctx.diagnostic(diagnostics::function_changes_every_render(
+                span,
+                &ident.name,
+                hook,
+                line,
+                move_into_effect,
+            ));
+        }
+        return;
+    };
+    let extra = if let Some(mutable) = unnecessary.iter().find(|dep| dep.ends_with(".current")) {
+        format!(
+            " Mutable values like '{mutable}' aren't valid dependencies \
+            because mutating them doesn't re-render the component."
+        )
+    } else if let Some(external) = external {
+        format!(
+            " Outer scope values like '{external}' aren't valid dependencies \
+            because mutating them doesn't re-render the component."
+        )
+    } else {
+        String::new()
+    };
+
+    // Keep the dependencies sorted if they already are.
+    let is_sorted = declared.windows(2).all(|pair| pair[0].key <= pair[1].key);
+    suggested.extend(missing);
+    if is_sorted {
+        suggested.sort_unstable();
+    }
+    let suggestion = format!("[{}]", suggested.join(", "));
+
+    ctx.diagnostic_with_suggestion(
+        diagnostics::dependencies(
+            dependencies.span,
+            hook,
+            &format!("{message}{extra}"),
+            &suggestion,
+        ),
+        |fixer| fixer.replace(dependencies.span, suggestion),
+    );
+}
+
+/// The values of the component read in `function_span`, keyed by their path without the
+/// optional chains.
+fn captured_dependencies(
+    ctx: &LintContext,
+    function_span: Span,
+    pure_scopes: &[ScopeId],
+) -> BTreeMap<String, Captured> {
+    let mut captured = BTreeMap::new();
+    for scope_id in pure_scopes {
+        for (name, symbol_id) in ctx.scopes().get_bindings(*scope_id) {
+            let mut is_stable = None;
+            for reference in ctx.symbols().get_resolved_references(*symbol_id) {
+                let span = reference.span();
+                if span.start < function_span.start
+                    || span.end > function_span.end
+                    || reference.is_type()
+                    || !reference.is_read()
+                {
+                    continue;
+                }
+                let (key, display) = captured_path(ctx, reference.node_id(), name);
+                let is_stable = *is_stable.get_or_insert_with(|| is_stable_value(ctx, *symbol_id));
+                captured.entry(key).or_insert(Captured { display, is_stable });
+            }
+        }
+    }
+    captured
+}
+
+/// The path of the value read by the reference `node_id` to `name`, e.g. `props.foo` for
+/// `props.foo.bar()`, without and with the optional chains.
+///
+/// The path stops before the called function of a method call and before `.current`,
+/// as the values of the refs are not dependencies.
+fn captured_path(ctx: &LintContext, node_id: AstNodeId, name: &str) -> (String, String) {
+    let nodes = ctx.nodes();
+    let mut key = name.to_string();
+    let mut display = name.to_string();
+    let mut current = nodes.get_node(node_id);
+    while let Some(parent) = nodes.parent_node(current.id()) {
+        let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member)) =
+            parent.kind()
+        else {
+            break;
+        };
+        if member.object.span() != current.kind().span() || member.property.name == "current" {
+            break;
+        }
+        match nodes.parent_kind(parent.id()) {
+            Some(AstKind::CallExpression(call)) if call.callee.span() == member.span => break,
+            Some(AstKind::SimpleAssignmentTarget(_)) => break,
+            _ => {}
+        }
+        key.push('.');
+        key.push_str(&member.property.name);
+        display.push_str(if member.optional { "?." } else { "." });
+        display.push_str(&member.property.name);
+        current = parent;
+    }
+    (key, display)
+}
+
+/// The root identifier and the path of an element of the dependency array, if it is an
+/// identifier or a chain of static member expressions.
+fn dependency_path<'b, 'a>(
+    expr: &'b Expression<'a>,
+) -> Option<(&'b IdentifierReference<'a>, String, String)> {
+    match expr {
+        Expression::Identifier(ident) => {
+            Some((ident, ident.name.to_string(), ident.name.to_string()))
+        }
+        Expression::StaticMemberExpression(member) => {
+            let (ident, mut key, mut display) = dependency_path(&member.object)?;
+            key.push('.');
+            key.push_str(&member.property.name);
+            display.push_str(if member.optional { "?." } else { "." });
+            display.push_str(&member.property.name);
+            Some((ident, key, display))
+        }
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::StaticMemberExpression(member) => {
+                let (ident, mut key, mut display) = dependency_path(&member.object)?;
+                key.push('.');
+                key.push_str(&member.property.name);
+                display.push_str(if member.optional { "?." } else { "." });
+                display.push_str(&member.property.name);
+                Some((ident, key, display))
+            }
+            _ => None,
+        },
+        Expression::ParenthesizedExpression(expr) => dependency_path(&expr.expression),
+        _ => None,
+    }
+}
+
+/// Whether the dependency `dependency` covers the captured value `key`, e.g. `props` covers
+/// `props.foo`.
+fn is_covered_by(key: &str, dependency: &str) -> bool {
+    key.strip_prefix(dependency).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// The scopes between the function component or hook calling the hook, included,
+/// and the call, included. The values declared in these scopes may change between renders.
+fn pure_scopes(ctx: &LintContext, scope_id: ScopeId) -> Vec<ScopeId> {
+    let mut scopes = vec![];
+    for scope_id in ctx.scopes().ancestors(scope_id) {
+        scopes.push(scope_id);
+        if ctx.scopes().get_flags(scope_id).is_function() {
+            return scopes;
+        }
+    }
+    // The hook is not called in a function, all the values are outside of a component.
+    vec![]
+}
+
+/// The span of the function declared in the component which `ident` refers to.
+fn local_function_span(
+    ctx: &LintContext,
+    ident: &IdentifierReference,
+    pure_scopes: &[ScopeId],
+) -> Option<Span> {
+    let reference_id = ident.reference_id.get()?;
+    let symbol_id = ctx.symbols().get_reference(reference_id).symbol_id()?;
+    if !pure_scopes.contains(&ctx.symbols().get_scope_id(symbol_id)) {
+        return None;
+    }
+    match ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id)) {
+        AstKind::Function(function) => Some(function.span),
+        AstKind::VariableDeclarator(declarator) => match &declarator.init {
+            Some(Expression::ArrowFunctionExpression(arrow)) => Some(arrow.span),
+            Some(Expression::FunctionExpression(function)) => Some(function.span),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the variable `ident` refers to is read outside of `spans`.
+fn is_read_outside(ctx: &LintContext, ident: &IdentifierReference, spans: &[Span]) -> bool {
+    let Some(symbol_id) = ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+    else {
+        return false;
+    };
+    ctx.symbols().get_resolved_references(symbol_id).any(|reference| {
+        let span = reference.span();
+        reference.is_read()
+            && !spans.iter().any(|outer| outer.start <= span.start && span.end <= outer.end)
+    })
+}
+
+/// Whether the value of `symbol_id` is the same for every render: a `useState` or `useReducer`
+/// setter, a `useRef` ref or a primitive constant.
+fn is_stable_value(ctx: &LintContext, symbol_id: SymbolId) -> bool {
+    let AstKind::VariableDeclarator(declarator) =
+        ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id))
+    else {
+        return false;
+    };
+    if declarator.kind != VariableDeclarationKind::Const {
+        return false;
+    }
+    let Some(init) = &declarator.init else { return false };
+    match (init.without_parenthesized(), &declarator.id.kind) {
+        (
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::NullLiteral(_),
+            BindingPatternKind::BindingIdentifier(_),
+        ) => true,
+        (Expression::CallExpression(call), BindingPatternKind::BindingIdentifier(_)) => {
+            call.callee_name() == Some("useRef")
+        }
+        (Expression::CallExpression(call), BindingPatternKind::ArrayPattern(pattern)) => {
+            matches!(
+                call.callee_name(),
+                Some("useState" | "useReducer" | "useActionState" | "useTransition")
+            ) && pattern.elements.get(1).is_some_and(|element| {
+                matches!(
+                    element.as_ref().map(|element| &element.kind),
+                    Some(BindingPatternKind::BindingIdentifier(ident))
+                        if ident.symbol_id.get() == Some(symbol_id)
+                )
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Whether the hook is an effect, which may list values of the component not read by its
+/// callback, e.g. `useEffect` or `useMyEffect`.
+fn is_effect_hook_name(name: &str) -> bool {
+    name.match_indices("Effect")
+        .any(|(index, _)| !name[index + 6..].starts_with(|c: char| c.is_ascii_lowercase()))
+}
+
+/// e.g. "a missing dependency: 'foo'. Either include it or remove the dependency array."
+fn warning_message(
+    dependencies: &BTreeSet<&str>,
+    single_prefix: &str,
+    label: &str,
+    fix_verb: &str,
+) -> Option<String> {
+    let names = dependencies.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>();
+    let list = match names.as_slice() {
+        [] => return None,
+        [name] => {
+            return Some(format!(
+                "{single_prefix} {label} dependency: {name}. \
+                Either {fix_verb} it or remove the dependency array."
+            ))
+        }
+        [first, second] => format!("{first} and {second}"),
+        [init @ .., last] => format!("{}, and {last}", init.join(", ")),
+    };
+    Some(format!(
+        "{label} dependencies: {list}. Either {fix_verb} them or remove the dependency array."
+    ))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }); }", None),
+        ("function MyComponent() { useEffect(() => { const local = {}; console.log(local); }, []); }", None),
+        ("function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props?.foo); }, [props?.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo.bar); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { props.onChange(); }, [props]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.bar]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent() { useEffect(() => { window.addEventListener('resize', handler); }, []); }", None),
+        ("function MyComponent() { const [state, setState] = useState(); useEffect(() => { setState(1); }, []); }", None),
+        ("function MyComponent() { const [state, dispatch] = React.useReducer(); useEffect(() => { dispatch(1); }, []); }", None),
+        ("function MyComponent() { const [state, setState] = useState(); useEffect(() => { setState(state + 1); }, [state]); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current.foo); }, []); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current); }, [ref]); }", None),
+        ("function MyComponent() { const delay = 1000; useEffect(() => { setTimeout(f, delay); }, []); }", None),
+        ("function MyComponent(props) { const fn = useCallback(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { const value = useMemo(() => props.foo * 2, [props.foo]); }", None),
+        ("function MyComponent(props) { const value = React.useMemo(() => props.foo * 2, [props.foo]); }", None),
+        ("function MyComponent(props, ref) { useImperativeHandle(ref, () => ({ focus: props.focus }), [props.focus]); }", None),
+        ("function MyComponent(props) { useLayoutEffect(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { function handler() { console.log(props.foo); } useEffect(handler, [handler]); }", None),
+        ("function MyComponent(props) { function handler() { console.log(props.foo); } useEffect(handler, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.bar]); }", None),
+        ("function MyComponent(props) { let value; useEffect(() => { value = props.foo; }, [props.foo]); }", None),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }", None),
+        (
+            "function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, [props.foo]); }",
+            Some(json!([{ "additionalHooks": "useCustomEffect" }])),
+        ),
+        ("function useHook(value) { return useMemo(() => ({ value }), [value]); }", None),
+        ("function MyComponent() { const [value, setValue] = useState(); type T = typeof value; useEffect(() => { let x: T; }, []); }", None),
+        ("function MyComponent() { const f = useCallback(() => {}, []); useEffect(() => f(), [f]); }", None),
+    ];
+
+    let fail = vec![
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo, props.bar); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.a, props.b, props.c); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props?.foo); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { props.onChange(); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props, props.foo); }, [props.foo]); }", None),
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent() { let [state, setState] = useState(); useEffect(() => { setState(1); }, []); }", None),
+        ("function MyComponent() { const [state, setState] = useState(); useEffect(() => { console.log(state); }, []); }", None),
+        ("function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo, props.bar]); }", None),
+        ("function MyComponent(props) { const fn = useCallback(() => {}, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.foo]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { console.log(ref.current); }, [ref.current]); }", None),
+        ("function MyComponent(props) { const value = useMemo(() => props.foo); }", None),
+        ("function MyComponent(props) { const fn = useCallback(() => props.foo); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, deps); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo.bar()]); }", None),
+        ("function MyComponent(props) { useEffect(async () => { await props.foo(); }); }", None),
+        ("function MyComponent(props) { useEffect(debounce(() => props.foo()), [props]); }", None),
+        ("function MyComponent(props) { useEffect(props.handler, []); }", None),
+        ("function MyComponent(props) { useEffect(); }", None),
+        ("function MyComponent(props) { function handler() { console.log(props.foo); } useEffect(handler, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo.bar]); }", None),
+        ("function MyComponent(props, ref) { useImperativeHandle(ref, () => ({ focus: props.focus }), []); }", None),
+        ("function MyComponent(props) { React.useEffect(() => { console.log(props.foo); }, []); }", None),
+        (
+            "function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }",
+            Some(json!([{ "additionalHooks": "useCustomEffect" }])),
+        ),
+        ("function MyComponent() { const f = () => {}; useEffect(() => f(), [f]); }", None),
+        ("function MyComponent() { function f() {} useEffect(() => { f(); }, [f]); return f; }", None),
+        ("function MyComponent() { const f = () => {}; const g = useCallback(() => f(), [f]); }", None),
+    ];
+
+    let fix = vec![
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, []); }",
+            "function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }",
+            None,
+        ),
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props.b, props.a); }, [props.b]); }",
+            "function MyComponent(props) { useEffect(() => { console.log(props.b, props.a); }, [props.a, props.b]); }",
+            None,
+        ),
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props.c, props.a); }, [props.c, props.b]); }",
+            "function MyComponent(props) { useEffect(() => { console.log(props.c, props.a); }, [props.c, props.b, props.a]); }",
+            None,
+        ),
+        (
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo, props.bar]); }",
+            "function MyComponent(props) { const value = useMemo(() => props.foo, [props.foo]); }",
+            None,
+        ),
+        (
+            "function MyComponent(props) { useEffect(() => { console.log(props?.foo); }, []); }",
+            "function MyComponent(props) { useEffect(() => { console.log(props?.foo); }, [props?.foo]); }",
+            None,
+        ),
+        (
+            "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }",
+            "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }",
+            None,
+        ),
+        (
+            "function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }",
+            "function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, [props.foo]); }",
+            Some(json!([{ "additionalHooks": "useCustomEffect" }])),
+        ),
+    ];
+
+    Tester::new(ExhaustiveDeps::NAME, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path_extension("tsx")
+        .test_and_snapshot();
+}
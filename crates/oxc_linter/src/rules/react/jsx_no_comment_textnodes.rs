@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     JsxNoCommentTextnodes,
-    suspicious
+    suspicious,
+    ast_types = [JSXText]
 );
 
 impl Rule for JsxNoCommentTextnodes {
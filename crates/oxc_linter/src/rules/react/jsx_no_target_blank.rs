@@ -8,6 +8,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 use std::ops::Deref;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
@@ -30,16 +32,23 @@ fn explicit_props_in_spread_attributes(span0: Span) -> OxcDiagnostic {
 .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct JsxNoTargetBlank {
+    /// Whether to check the links whose `href` is an expression.
     enforce_dynamic_links: EnforceDynamicLinksEnum,
+    /// Report the spread attributes that may set `target="_blank"`.
     warn_on_spread_attributes: bool,
+    /// Allow `rel="noopener"` without `noreferrer`.
     allow_referrer: bool,
+    /// Check the link components.
     links: bool,
+    /// Check the form components.
     forms: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 enum EnforceDynamicLinksEnum {
     Always,
     Never,
@@ -112,7 +121,9 @@ declare_oxc_lint!(
     /// var Hello = <a target='_blank' href={dynamicLink}></a>
     /// ```
     JsxNoTargetBlank,
-    correctness
+    correctness,
+    config = JsxNoTargetBlank,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for JsxNoTargetBlank {
@@ -211,33 +222,10 @@ impl Rule for JsxNoTargetBlank {
         }
     }
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
-
-        Self {
-            enforce_dynamic_links: value
-                .and_then(|val| val.get("enforceDynamicLinks").and_then(serde_json::Value::as_str))
-                .map_or(EnforceDynamicLinksEnum::Always, |str| {
-                    if str == "always" {
-                        EnforceDynamicLinksEnum::Always
-                    } else {
-                        EnforceDynamicLinksEnum::Never
-                    }
-                }),
-            warn_on_spread_attributes: value
-                .and_then(|val| {
-                    val.get("warnOnSpreadAttributes").and_then(serde_json::Value::as_bool)
-                })
-                .unwrap_or(false),
-            links: value
-                .and_then(|val| val.get("links").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-            forms: value
-                .and_then(|val| val.get("forms").and_then(serde_json::Value::as_bool))
-                .unwrap_or(false),
-            allow_referrer: value
-                .and_then(|val| val.get("allowReferrer").and_then(serde_json::Value::as_bool))
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -54,7 +54,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoChildrenProp,
-    correctness
+    correctness,
+    ast_types = [JSXAttributeItem, CallExpression]
 );
 
 impl Rule for NoChildrenProp {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// };
     /// ```
     NoIsMounted,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoIsMounted {
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// ReactDOM.render(<App />, document.body);
     /// ```
     NoRenderReturnValue,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoRenderReturnValue {
@@ -8,6 +8,8 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -26,8 +28,10 @@ fn string_in_ref_deprecated(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-react(no-string-refs): Using string literals in ref attributes is deprecated.").with_help("Using reference callback instead").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoStringRefs {
+    /// Also report template literals used as refs.
     no_template_literals: bool,
 }
 
@@ -67,7 +71,9 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoStringRefs,
-    correctness
+    correctness,
+    config = NoStringRefs,
+    ast_types = [JSXAttributeItem, MemberExpression]
 );
 
 fn contains_string_literal(
@@ -100,10 +106,10 @@ fn is_literal_ref_attribute(attr: &JSXAttribute, no_template_literals: bool) ->
 
 impl Rule for NoStringRefs {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let no_template_literals =
-            value.get("noTemplateLiterals").and_then(serde_json::Value::as_bool).unwrap_or(false);
-
-        Self { no_template_literals }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
@@ -224,7 +230,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -237,7 +243,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -247,7 +253,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -280,7 +286,7 @@ fn test() {
                 }
               }
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
     ];
 
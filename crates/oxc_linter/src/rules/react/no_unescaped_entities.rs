@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// <div> {'>'} </div>
     /// ```
     NoUnescapedEntities,
-    pedantic
+    pedantic,
+    ast_types = [JSXText]
 );
 
 impl Rule for NoUnescapedEntities {
@@ -9,6 +9,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{phf_map, phf_set, Map, Set};
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::collections::hash_map::HashMap;
 use std::collections::hash_set::HashSet;
@@ -42,7 +43,7 @@ fn unknown_prop(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoUnknownProperty(Box<NoUnknownPropertyConfig>);
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NoUnknownPropertyConfig {
     #[serde(default)]
@@ -68,7 +69,8 @@ declare_oxc_lint!(
     ///  const IconButton = <div aria-foo="bar" />;
     /// ```
     NoUnknownProperty,
-    restriction
+    restriction,
+    config = NoUnknownPropertyConfig
 );
 const ATTRIBUTE_TAGS_MAP: Map<&'static str, Set<&'static str>> = phf_map! {
     "abbr" => phf_set! {"th", "td"},
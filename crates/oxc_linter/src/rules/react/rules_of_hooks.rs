@@ -101,7 +101,8 @@ declare_oxc_lint!(
     /// <https://reactjs.org/docs/hooks-rules.html>
     ///
     RulesOfHooks,
-    nursery
+    nursery,
+    ast_types = [CallExpression]
 );
 
 impl Rule for RulesOfHooks {
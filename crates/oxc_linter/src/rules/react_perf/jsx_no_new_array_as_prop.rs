@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// <Item list={this.props.list} />
     /// ```
     JsxNoNewArrayAsProp,
-    perf
+    perf,
+    ast_types = [JSXElement]
 );
 
 impl Rule for JsxNoNewArrayAsProp {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// <Item config={staticConfig} />
     /// ```
     JsxNoNewObjectAsProp,
-    perf
+    perf,
+    ast_types = [JSXElement]
 );
 
 impl Rule for JsxNoNewObjectAsProp {
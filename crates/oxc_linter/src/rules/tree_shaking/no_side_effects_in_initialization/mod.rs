@@ -2,7 +2,8 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use serde_json::Value;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -96,6 +97,30 @@ pub struct NoSideEffectsInInitiallizationOptions {
     modules: Vec<WhitelistModule>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum SideEffectFreeOption {
+    /// A function without side effects, e.g. `{ "function": "Object.freeze" }`.
+    Function { function: String },
+    /// Functions of a module without side effects, e.g.
+    /// `{ "module": "react", "functions": ["createContext", "createRef"] }`,
+    /// or `"*"` for all of them.
+    Module { module: String, functions: ModuleFunctionsOption },
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ModuleFunctionsOption {
+    Specific(Vec<String>),
+    All(AllFunctions),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+enum AllFunctions {
+    #[serde(rename = "*")]
+    All,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -167,7 +192,9 @@ declare_oxc_lint!(
     /// /*@__PURE__*/ x();
     /// ```
     NoSideEffectsInInitialization,
-    nursery
+    nursery,
+    config = [SideEffectFreeOption],
+    ast_types = []
 );
 
 impl Rule for NoSideEffectsInInitialization {
@@ -175,43 +202,22 @@ impl Rule for NoSideEffectsInInitialization {
         let mut functions = vec![];
         let mut modules = vec![];
 
-        if let Value::Array(arr) = value {
-            for obj in arr {
-                let Value::Object(obj) = obj else {
-                    continue;
-                };
-
+        for option in value.as_array().into_iter().flatten() {
+            match serde_json::from_value(option.clone()) {
                 // { "function": "Object.freeze" }
-                if let Some(name) = obj.get("function").and_then(Value::as_str) {
-                    functions.push(name.to_string());
-                    continue;
-                }
-
+                Ok(SideEffectFreeOption::Function { function }) => functions.push(function),
                 // { "module": "react", "functions": ["createContext", "createRef"] }
                 // { "module": "react", "functions": "*" }
-                if let Some(name) = obj.get("module").and_then(Value::as_str) {
-                    let functions = match obj.get("functions") {
-                        Some(Value::Array(arr)) => {
-                            let val = arr
-                                .iter()
-                                .filter_map(Value::as_str)
-                                .map(String::from)
-                                .collect::<Vec<_>>();
-                            Some(ModuleFunctions::Specific(val))
-                        }
-                        Some(Value::String(str)) => {
-                            if str == "*" {
-                                Some(ModuleFunctions::All)
-                            } else {
-                                None
-                            }
+                Ok(SideEffectFreeOption::Module { module, functions }) => {
+                    let functions = match functions {
+                        ModuleFunctionsOption::Specific(functions) => {
+                            ModuleFunctions::Specific(functions)
                         }
-                        _ => None,
+                        ModuleFunctionsOption::All(_) => ModuleFunctions::All,
                     };
-                    if let Some(functions) = functions {
-                        modules.push(WhitelistModule { name: name.to_string(), functions });
-                    }
+                    modules.push(WhitelistModule { name: module, functions });
                 }
+                Err(_) => {}
             }
         }
 
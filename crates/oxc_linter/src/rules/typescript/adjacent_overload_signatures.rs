@@ -74,7 +74,16 @@ declare_oxc_lint!(
     /// export function foo(sn: string | number): void;
     /// ```
     AdjacentOverloadSignatures,
-    style
+    style,
+    ast_types = [
+        Class,
+        TSTypeLiteral,
+        Program,
+        TSModuleBlock,
+        TSInterfaceDeclaration,
+        BlockStatement,
+        FunctionBody,
+    ]
 );
 
 #[derive(PartialEq, Debug)]
@@ -0,0 +1,117 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode, Thenable};
+
+fn await_thenable_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "typescript-eslint(await-thenable): Unexpected `await` of a non-Promise (non-\"Thenable\") value.",
+    )
+    .with_help("Remove the unnecessary `await`.")
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitThenable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows awaiting a value that is not a thenable.
+    ///
+    /// This rule is type-aware, it runs only when `typeAware` is enabled in the configuration
+    /// or with `--type-aware`, and reports only the values known not to be thenables.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Awaiting a value which is not a promise is allowed but only delays the code after it,
+    /// and is often a sign of a misunderstanding of the awaited API.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // Bad
+    /// await 'value';
+    /// const createValue = () => 'value';
+    /// await createValue();
+    ///
+    /// // Good
+    /// await Promise.resolve('value');
+    /// const createValue = async () => 'value';
+    /// await createValue();
+    /// ```
+    AwaitThenable,
+    correctness,
+    suggestion,
+    ast_types = [AwaitExpression]
+);
+
+impl Rule for AwaitThenable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(expr) = node.kind() else {
+            return;
+        };
+        let Some(type_info) = ctx.type_info() else {
+            return;
+        };
+        if type_info.thenable(&expr.argument, ctx) != Thenable::No {
+            return;
+        }
+        ctx.diagnostic_with_suggestion(await_thenable_diagnostic(expr.span), |fixer| {
+            let argument = fixer.source_range(expr.argument.span());
+            fixer.replace(expr.span, argument)
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await Promise.resolve(1); }",
+        "async function f() { await new Promise(() => {}); }",
+        "async function g() {} async function f() { await g(); }",
+        "async function f(p: Promise<number>) { await p; }",
+        "async function f(p: number | Promise<number>) { await p; }",
+        "async function f(p: any) { await p; }",
+        "async function f(p) { await p; }",
+        "async function f() { await unknown(); }",
+        "async function f() { await obj.method(); }",
+        "async function f() { const p = { then() {} }; await p; }",
+        "async function f() { let x = 1; x = getPromise(); await x; }",
+        "function g(): PromiseLike<number> {} async function f() { await g(); }",
+    ];
+
+    let fail = vec![
+        "async function f() { await 1; }",
+        "async function f() { await 'value'; }",
+        "async function f() { await undefined; }",
+        "async function f() { await null; }",
+        "async function f() { await [Promise.resolve()]; }",
+        "async function f() { await (() => {}); }",
+        "async function f(n: number) { await n; }",
+        "async function f(n: string | number) { await n; }",
+        "async function f() { const x = 1; await x; }",
+        "const g = () => 'value'; async function f() { await g(); }",
+        "function g(): number {} async function f() { await g(); }",
+        "async function f() { await { a: 1 }; }",
+        "async function f() { await (await Promise.resolve(1)); }",
+        "async function f() { await (1 as number); }",
+    ];
+
+    let fix = vec![
+        ("async function f() { await 1; }", "async function f() { 1; }", None),
+        (
+            "async function f(n: number) { return await n; }",
+            "async function f(n: number) { return n; }",
+            None,
+        ),
+    ];
+
+    Tester::new(AwaitThenable::NAME, pass, fail)
+        .expect_fix(fix)
+        .with_type_aware(true)
+        .test_and_snapshot();
+}
@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -66,25 +68,54 @@ pub enum DirectiveConfig {
     DescriptionFormat(Option<Regex>),
 }
 
-impl DirectiveConfig {
-    fn from_json(value: &serde_json::Value) -> Option<Self> {
-        match value {
-            serde_json::Value::Bool(b) => Some(Self::Boolean(*b)),
-            serde_json::Value::String(s) => {
-                if s == "allow-with-description" {
-                    Some(Self::RequireDescription)
-                } else {
-                    None
-                }
-            }
-            serde_json::Value::Object(o) => {
-                let re = o
-                    .get("descriptionFormat")
-                    .and_then(serde_json::Value::as_str)
-                    .and_then(|pattern| Regex::new(pattern).ok());
-                Some(Self::DescriptionFormat(re))
-            }
-            _ => None,
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct BanTsCommentOptions {
+    /// How to treat `@ts-expect-error`, `"allow-with-description"` by default.
+    #[serde(rename = "ts-expect-error")]
+    ts_expect_error: Option<DirectiveOption>,
+    /// How to treat `@ts-ignore`, banned by default.
+    #[serde(rename = "ts-ignore")]
+    ts_ignore: Option<DirectiveOption>,
+    /// How to treat `@ts-nocheck`, banned by default.
+    #[serde(rename = "ts-nocheck")]
+    ts_nocheck: Option<DirectiveOption>,
+    /// How to treat `@ts-check`, allowed by default.
+    #[serde(rename = "ts-check")]
+    ts_check: Option<DirectiveOption>,
+    /// Minimum length of the description of the directives allowed with a description.
+    #[serde(rename = "minimumDescriptionLength")]
+    minimum_description_length: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum DirectiveOption {
+    /// `true` to ban the directive, `false` to allow it.
+    Boolean(bool),
+    /// `"allow-with-description"` to allow the directive with a description.
+    AllowWithDescription(AllowWithDescription),
+    /// Allow the directive with a description matching the regular expression.
+    DescriptionFormat {
+        #[serde(rename = "descriptionFormat")]
+        description_format: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+enum AllowWithDescription {
+    #[serde(rename = "allow-with-description")]
+    AllowWithDescription,
+}
+
+impl From<DirectiveOption> for DirectiveConfig {
+    fn from(option: DirectiveOption) -> Self {
+        match option {
+            DirectiveOption::Boolean(b) => Self::Boolean(b),
+            DirectiveOption::AllowWithDescription(_) => Self::RequireDescription,
+            DirectiveOption::DescriptionFormat { description_format } => Self::DescriptionFormat(
+                description_format.and_then(|pattern| Regex::new(&pattern).ok()),
+            ),
         }
     }
 }
@@ -105,37 +136,27 @@ declare_oxc_lint!(
     /// }
     /// ```
     BanTsComment,
-    pedantic
+    pedantic, fix,
+    config = BanTsCommentOptions,
+    ast_types = []
 );
 
 impl Rule for BanTsComment {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let options: BanTsCommentOptions = value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default();
+        let default = BanTsCommentConfig::default();
+
         Self(Box::new(BanTsCommentConfig {
-            ts_expect_error: value
-                .get(0)
-                .and_then(|x| x.get("ts-expect-error"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::RequireDescription),
-            ts_ignore: value
-                .get(0)
-                .and_then(|x| x.get("ts-ignore"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(true)),
-            ts_nocheck: value
-                .get(0)
-                .and_then(|x| x.get("ts-nocheck"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(true)),
-            ts_check: value
-                .get(0)
-                .and_then(|x| x.get("ts-check"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(false)),
-            minimum_description_length: value
-                .get(0)
-                .and_then(|x| x.get("minimumDescriptionLength"))
-                .and_then(serde_json::Value::as_u64)
-                .unwrap_or(3),
+            ts_expect_error: options.ts_expect_error.map_or(default.ts_expect_error, Into::into),
+            ts_ignore: options.ts_ignore.map_or(default.ts_ignore, Into::into),
+            ts_nocheck: options.ts_nocheck.map_or(default.ts_nocheck, Into::into),
+            ts_check: options.ts_check.map_or(default.ts_check, Into::into),
+            minimum_description_length: options
+                .minimum_description_length
+                .unwrap_or(default.minimum_description_length),
         }))
     }
 
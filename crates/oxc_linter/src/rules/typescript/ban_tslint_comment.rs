@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// someCode();
     /// ```
     BanTslintComment,
-    style
+    style, fix,
+    ast_types = []
 );
 
 impl Rule for BanTslintComment {
@@ -55,7 +55,7 @@ declare_oxc_lint!(
     /// type Foo = Record<string, unknown>;
     /// ```
     ConsistentIndexedObjectStyle,
-    style
+    style, fix
 );
 
 impl Rule for ConsistentIndexedObjectStyle {
@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     ConsistentTypeDefinitions,
-    style
+    style, fix,
+    ast_types = [TSTypeAliasDeclaration, ExportDefaultDeclaration, TSInterfaceDeclaration]
 );
 
 impl Rule for ConsistentTypeDefinitions {
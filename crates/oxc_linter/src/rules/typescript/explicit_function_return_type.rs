@@ -11,6 +11,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     ast_util::outermost_paren_parent,
@@ -25,18 +27,43 @@ use crate::{
 #[derive(Debug, Default, Clone)]
 pub struct ExplicitFunctionReturnType(Box<ExplicitFunctionReturnTypeConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ExplicitFunctionReturnTypeConfig {
+    /// Allow function expressions whose value is not assigned to a variable or property.
     allow_expressions: bool,
+    /// Allow function expressions whose type is already known, e.g. from a type annotation.
     allow_typed_function_expressions: bool,
+    /// Allow arrow functions that directly return an `as const` assertion.
     allow_direct_const_assertion_in_arrow_functions: bool,
+    /// Allow concise arrow functions whose body starts with `void`.
     allow_concise_arrow_function_expressions_starting_with_void: bool,
+    /// Allow functions without type parameters.
     allow_functions_without_type_parameters: bool,
+    /// Names of the functions to allow.
     allowed_names: HashSet<String>,
+    /// Allow functions that directly return another function expression.
     allow_higher_order_functions: bool,
+    /// Allow immediately invoked function expressions.
+    #[serde(rename = "allowIIFEs")]
     allow_iifes: bool,
 }
 
+impl Default for ExplicitFunctionReturnTypeConfig {
+    fn default() -> Self {
+        Self {
+            allow_expressions: false,
+            allow_typed_function_expressions: true,
+            allow_direct_const_assertion_in_arrow_functions: true,
+            allow_concise_arrow_function_expressions_starting_with_void: false,
+            allow_functions_without_type_parameters: false,
+            allowed_names: HashSet::new(),
+            allow_higher_order_functions: true,
+            allow_iifes: false,
+        }
+    }
+}
+
 impl std::ops::Deref for ExplicitFunctionReturnType {
     type Target = ExplicitFunctionReturnTypeConfig;
 
@@ -58,6 +85,8 @@ declare_oxc_lint!(
     /// ```
     ExplicitFunctionReturnType,
     restriction,
+    config = ExplicitFunctionReturnTypeConfig,
+    ast_types = [Function, ArrowFunctionExpression],
 );
 
 fn explicit_function_return_type_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -70,47 +99,10 @@ fn explicit_function_return_type_diagnostic(span0: Span) -> OxcDiagnostic {
 
 impl Rule for ExplicitFunctionReturnType {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
-        Self(Box::new(ExplicitFunctionReturnTypeConfig {
-            allow_expressions: options
-                .and_then(|x| x.get("allowExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allow_typed_function_expressions: options
-                .and_then(|x| x.get("allowTypedFunctionExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-            allow_direct_const_assertion_in_arrow_functions: options
-                .and_then(|x| x.get("allowDirectConstAssertionInArrowFunctions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-            allow_concise_arrow_function_expressions_starting_with_void: options
-                .and_then(|x| x.get("allowConciseArrowFunctionExpressionsStartingWithVoid"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allow_functions_without_type_parameters: options
-                .and_then(|x| x.get("allowFunctionsWithoutTypeParameters"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allowed_names: options
-                .and_then(|x| x.get("allowedNames"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                })
-                .unwrap_or_default(),
-            allow_higher_order_functions: options
-                .and_then(|x| x.get("allowHigherOrderFunctions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-            allow_iifes: options
-                .and_then(|x| x.get("allowIIFEs"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     //  }
     /// ```
     NoDuplicateEnumValues,
-    pedantic
+    pedantic,
+    ast_types = [TSEnumDeclaration]
 );
 
 impl Rule for NoDuplicateEnumValues {
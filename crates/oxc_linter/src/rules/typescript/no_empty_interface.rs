@@ -3,6 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
@@ -18,8 +20,11 @@ fn no_empty_interface_extend_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("typescript-eslint(no-empty-interface): an interface declaring no members is equivalent to its supertype").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct NoEmptyInterface {
+    /// Allow empty interfaces that extend a single interface.
+    #[serde(rename = "allowSingleExtends", alias = "allow_single_extends")]
     allow_single_extends: bool,
 }
 
@@ -40,17 +45,17 @@ declare_oxc_lint!(
     /// interface Bar extends Foo {}
     /// ```
     NoEmptyInterface,
-    style
+    style,
+    config = NoEmptyInterface,
+    ast_types = [TSInterfaceDeclaration]
 );
 
 impl Rule for NoEmptyInterface {
     fn from_configuration(value: Value) -> Self {
-        let allow_single_extends =
-            value.get(0).map_or(Self::default().allow_single_extends, |config| {
-                config.get("allow_single_extends").and_then(Value::as_bool).unwrap_or_default()
-            });
-
-        Self { allow_single_extends }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSInterfaceDeclaration(interface) = node.kind() {
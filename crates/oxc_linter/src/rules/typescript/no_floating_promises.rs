@@ -0,0 +1,194 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule, AstNode, Thenable, TypeInfoProvider};
+
+fn no_floating_promises_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to .catch, end with a call to .then with a rejection handler or be explicitly marked as ignored with the `void` operator.")
+        .with_labels([span.into()])
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoFloatingPromises {
+    /// Allow promises marked as ignored with the `void` operator.
+    ignore_void: bool,
+    /// Allow promises returned by immediately invoked function expressions.
+    #[serde(rename = "ignoreIIFE")]
+    ignore_iife: bool,
+}
+
+impl Default for NoFloatingPromises {
+    fn default() -> Self {
+        Self { ignore_void: true, ignore_iife: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires promise-like statements to be handled appropriately.
+    ///
+    /// This rule is type-aware, it runs only when `typeAware` is enabled in the configuration
+    /// or with `--type-aware`, and reports only the values known to be promises.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise which is neither awaited nor given a rejection handler can fail silently,
+    /// and the code after it runs before it settles.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// async function fetchData() {}
+    ///
+    /// // Bad
+    /// fetchData();
+    /// Promise.resolve(1).then(() => {});
+    ///
+    /// // Good
+    /// await fetchData();
+    /// fetchData().catch(() => {});
+    /// void fetchData();
+    /// ```
+    NoFloatingPromises,
+    correctness,
+    config = NoFloatingPromises,
+    ast_types = [ExpressionStatement]
+);
+
+impl Rule for NoFloatingPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        let Some(type_info) = ctx.type_info() else {
+            return;
+        };
+        if self.is_floating(&stmt.expression, type_info, ctx) {
+            ctx.diagnostic(no_floating_promises_diagnostic(stmt.span));
+        }
+    }
+}
+
+impl NoFloatingPromises {
+    fn is_floating<'a>(
+        &self,
+        expr: &Expression<'a>,
+        type_info: &dyn TypeInfoProvider,
+        ctx: &LintContext<'a>,
+    ) -> bool {
+        match expr.without_parenthesized() {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                !self.ignore_void && type_info.thenable(&unary.argument, ctx) == Thenable::Yes
+            }
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.iter().any(|expr| self.is_floating(expr, type_info, ctx))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.is_floating(&conditional.consequent, type_info, ctx)
+                    || self.is_floating(&conditional.alternate, type_info, ctx)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.is_floating(&logical.left, type_info, ctx)
+                    || self.is_floating(&logical.right, type_info, ctx)
+            }
+            Expression::CallExpression(call) => {
+                if self.ignore_iife && is_iife(call) {
+                    return false;
+                }
+                if let Some(member) = call.callee.without_parenthesized().as_member_expression() {
+                    match member.static_property_name() {
+                        Some("then") if call.arguments.len() >= 2 => return false,
+                        Some("catch") if !call.arguments.is_empty() => return false,
+                        Some("finally") => {
+                            return self.is_floating(member.object(), type_info, ctx);
+                        }
+                        _ => {}
+                    }
+                }
+                type_info.thenable(expr, ctx) == Thenable::Yes
+            }
+            expr => type_info.thenable(expr, ctx) == Thenable::Yes,
+        }
+    }
+}
+
+fn is_iife(call: &CallExpression) -> bool {
+    matches!(
+        call.callee.without_parenthesized(),
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+    )
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function foo() {} await foo();", None),
+        ("async function foo() {} foo().catch(() => {});", None),
+        ("async function foo() {} foo().then(() => {}, () => {});", None),
+        ("async function foo() {} foo().catch(() => {}).finally(() => {});", None),
+        ("async function foo() {} void foo();", None),
+        ("async function foo() {} const p = foo();", None),
+        ("async function foo() {} return foo();", None),
+        ("function foo() {} foo();", None),
+        ("function foo(): number { return 1 } foo();", None),
+        ("const foo = () => 1; foo();", None),
+        ("function* foo() {} foo();", None),
+        ("async function* foo() {} foo();", None),
+        ("declare const p: number; p;", None),
+        ("unknown();", None),
+        ("obj.method();", None),
+        ("let p = Promise.resolve(); p = 1 as any; p;", None),
+        ("const Promise = { resolve() {} }; Promise.resolve();", None),
+        ("class Foo { then() {} } Foo;", None),
+        ("class Foo {} new Foo();", None),
+        ("(async () => {})();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("(async function () {})();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("async function foo() {} foo() ? 1 : 2;", None),
+    ];
+
+    let fail = vec![
+        ("async function foo() {} foo();", None),
+        ("const foo = async () => {}; foo();", None),
+        ("function foo(): Promise<void> { return Promise.resolve() } foo();", None),
+        ("function foo(): PromiseLike<void> { return Promise.resolve() } foo();", None),
+        ("Promise.resolve(1);", None),
+        ("Promise.all([]);", None),
+        ("new Promise(() => {});", None),
+        ("const p = Promise.reject(); p;", None),
+        ("declare const p: Promise<number>; p;", None),
+        ("function f(p: Promise<number>) { p; }", None),
+        ("async function foo() {} foo().then(() => {});", None),
+        ("async function foo() {} foo().catch();", None),
+        ("async function foo() {} foo().finally(() => {});", None),
+        ("async function foo() {} (foo(), 1);", None),
+        ("async function foo() {} cond ? foo() : null;", None),
+        ("async function foo() {} cond && foo();", None),
+        ("(async () => {})();", None),
+        ("const p = { then() {} }; p;", None),
+        ("class Thenable { then() {} } new Thenable();", None),
+        ("const foo = () => Promise.resolve(); foo();", None),
+        ("async function foo() {} void foo();", Some(json!([{ "ignoreVoid": false }]))),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail).with_type_aware(true).test_and_snapshot();
+}
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// import { type A as AA, type B as BB } from 'mod';
     /// ```
     NoImportTypeSideEffects,
-    restriction,
+    restriction, fix,
+    ast_types = [ImportDeclaration]
 );
 
 impl Rule for NoImportTypeSideEffects {
@@ -52,7 +52,8 @@ declare_oxc_lint!(
     // }
     /// ```
     NoMisusedNew,
-    correctness
+    correctness,
+    ast_types = [TSInterfaceDeclaration, TSMethodSignature, Class]
 );
 
 impl Rule for NoMisusedNew {
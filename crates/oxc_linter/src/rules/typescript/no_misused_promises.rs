@@ -0,0 +1,256 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, ObjectPropertyKind, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode, Thenable,
+};
+
+fn conditional_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "typescript-eslint(no-misused-promises): Expected non-Promise value in a boolean conditional.",
+    )
+    .with_help("A promise is always truthy, did you forget to `await` it?")
+    .with_labels([span.into()])
+}
+
+fn spread_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "typescript-eslint(no-misused-promises): Expected a non-Promise value to be spreaded in an object.",
+    )
+    .with_help("Spreading a promise copies none of the properties of its value, did you forget to `await` it?")
+    .with_labels([span.into()])
+}
+
+fn void_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "typescript-eslint(no-misused-promises): Promise returned in function argument where a void return was expected.",
+    )
+    .with_help("`forEach` ignores the returned promises, use a `for...of` loop with `await`, or `Promise.all` with `map`.")
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoMisusedPromises {
+    /// Check the promises used as conditions, e.g. `if (promise) {}`.
+    checks_conditionals: bool,
+    /// Check the promises spread in object literals, e.g. `{ ...promise }`.
+    checks_spreads: bool,
+    /// Check the functions returning promises passed as the callback of `forEach` on an array,
+    /// e.g. `[1, 2].forEach(async (id) => {})`.
+    checks_void_return: bool,
+}
+
+impl Default for NoMisusedPromises {
+    fn default() -> Self {
+        Self { checks_conditionals: true, checks_spreads: true, checks_void_return: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows promises in places not designed to handle them.
+    ///
+    /// This rule is type-aware, it runs only when `typeAware` is enabled in the configuration
+    /// or with `--type-aware`, and reports only the values known to be promises.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise is always truthy, so using one as a condition is almost always
+    /// a missing `await`. Spreading a promise in an object copies none of its resolved properties.
+    /// The promises returned to `forEach` are ignored, their rejections are never handled.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const promise = Promise.resolve('value');
+    ///
+    /// // Bad
+    /// if (promise) {}
+    /// const value = { ...promise };
+    /// [1, 2].forEach(async (id) => { await remove(id); });
+    ///
+    /// // Good
+    /// if (await promise) {}
+    /// const value = { ...(await promise) };
+    /// await Promise.all([1, 2].map(async (id) => { await remove(id); }));
+    /// ```
+    NoMisusedPromises,
+    pedantic,
+    config = NoMisusedPromises,
+    ast_types = [
+        IfStatement,
+        WhileStatement,
+        DoWhileStatement,
+        ForStatement,
+        ConditionalExpression,
+        UnaryExpression,
+        LogicalExpression,
+        ObjectExpression,
+        CallExpression
+    ]
+);
+
+impl Rule for NoMisusedPromises {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if ctx.type_info().is_none() {
+            return;
+        }
+        match node.kind() {
+            AstKind::ObjectExpression(object) if self.checks_spreads => {
+                for property in &object.properties {
+                    if let ObjectPropertyKind::SpreadProperty(spread) = property {
+                        if is_promise(&spread.argument, ctx) {
+                            ctx.diagnostic(spread_diagnostic(spread.argument.span()));
+                        }
+                    }
+                }
+            }
+            AstKind::CallExpression(call) if self.checks_void_return => {
+                if let Some(callback) = for_each_callback(call, ctx) {
+                    if returns_promise(callback, ctx) {
+                        ctx.diagnostic(void_return_diagnostic(callback.span()));
+                    }
+                }
+            }
+            AstKind::CallExpression(_) => {}
+            _ if self.checks_conditionals => {
+                if let Some(test) = conditional_test(node.kind()) {
+                    if is_promise(test, ctx) {
+                        ctx.diagnostic(conditional_diagnostic(test.span()));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The expression of `kind` which is evaluated as a boolean.
+fn conditional_test(kind: AstKind<'_>) -> Option<&Expression<'_>> {
+    match kind {
+        AstKind::IfStatement(stmt) => Some(&stmt.test),
+        AstKind::WhileStatement(stmt) => Some(&stmt.test),
+        AstKind::DoWhileStatement(stmt) => Some(&stmt.test),
+        AstKind::ForStatement(stmt) => stmt.test.as_ref(),
+        AstKind::ConditionalExpression(expr) => Some(&expr.test),
+        AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+            Some(&expr.argument)
+        }
+        // `??` only checks for `null` and `undefined`, the right operand is returned as is
+        AstKind::LogicalExpression(expr)
+            if matches!(expr.operator, LogicalOperator::And | LogicalOperator::Or) =>
+        {
+            Some(&expr.left)
+        }
+        _ => None,
+    }
+}
+
+/// The callback of `array.forEach(callback)` when `array` is known to be an array.
+fn for_each_callback<'a, 'b>(
+    call: &'b CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'b Expression<'a>> {
+    let member = call.callee.without_parenthesized().as_member_expression()?;
+    if member.static_property_name() != Some("forEach") {
+        return None;
+    }
+    let type_info = ctx.type_info()?;
+    if !type_info.is_array(member.object(), ctx) {
+        return None;
+    }
+    match call.arguments.first()? {
+        Argument::SpreadElement(_) => None,
+        argument => argument.as_expression(),
+    }
+}
+
+/// Whether the function `callback` returns a promise.
+fn returns_promise<'a>(callback: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match callback.without_parenthesized() {
+        Expression::ArrowFunctionExpression(arrow) => {
+            arrow.r#async
+                || arrow.expression
+                    && matches!(arrow.body.statements.first(),
+                        Some(Statement::ExpressionStatement(stmt)) if is_promise(&stmt.expression, ctx))
+        }
+        Expression::FunctionExpression(function) => function.r#async && !function.generator,
+        Expression::Identifier(ident) => matches!(
+            get_declaration_of_variable(ident, ctx).map(AstNode::kind),
+            Some(AstKind::Function(function)) if function.r#async && !function.generator
+        ),
+        _ => false,
+    }
+}
+
+fn is_promise<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    ctx.type_info().is_some_and(|type_info| type_info.thenable(expr, ctx) == Thenable::Yes)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function f() { if (await Promise.resolve(true)) {} }", None),
+        ("async function g() {} async function f() { while (await g()) {} }", None),
+        ("declare const x: boolean; if (x) {}", None),
+        ("if (unknown()) {}", None),
+        ("function f(p) { if (p) {} }", None),
+        ("const p = Promise.resolve(); const v = p ?? 1;", None),
+        ("const p = Promise.resolve(); cond && p;", None),
+        ("const p = Promise.resolve(); for (;;) {}", None),
+        ("const v = { ...{ a: 1 } };", None),
+        ("async function f() { const v = { ...(await Promise.resolve({})) }; }", None),
+        ("const v = [...Promise.resolve()];", None),
+        ("const p = Promise.resolve(); if (p) {}", Some(json!([{ "checksConditionals": false }]))),
+        ("const v = { ...Promise.resolve() };", Some(json!([{ "checksSpreads": false }]))),
+        ("[1].forEach(() => {});", None),
+        ("[1].forEach((x) => x);", None),
+        ("[1].map(async () => {});", None),
+        ("items.forEach(async () => {});", None),
+        ("let ids = [1]; ids = other; ids.forEach(async () => {});", None),
+        ("[1].forEach(async () => {});", Some(json!([{ "checksVoidReturn": false }]))),
+    ];
+
+    let fail = vec![
+        ("const p = Promise.resolve(); if (p) {}", None),
+        ("async function g() {} if (g()) {}", None),
+        ("async function g() {} while (g()) {}", None),
+        ("async function g() {} do {} while (g());", None),
+        ("async function g() {} for (; g(); ) {}", None),
+        ("async function g() {} const v = g() ? 1 : 2;", None),
+        ("async function g() {} if (!g()) {}", None),
+        ("async function g() {} g() && foo();", None),
+        ("async function g() {} g() || foo();", None),
+        ("function f(p: Promise<boolean>) { if (p) {} }", None),
+        ("const v = { ...Promise.resolve({}) };", None),
+        ("async function g() {} const v = { a: 1, ...g() };", None),
+        ("[1].forEach(async () => {});", None),
+        ("const ids = [1, 2]; ids.forEach(async (id) => {});", None),
+        ("function f(ids: number[]) { ids.forEach(async function (id) {}); }", None),
+        ("declare const ids: readonly string[]; ids.forEach(async () => {});", None),
+        ("async function g() {} Array.from(items).forEach(g);", None),
+        ("[1].map((x) => x).forEach(() => Promise.resolve());", None),
+    ];
+
+    Tester::new(NoMisusedPromises::NAME, pass, fail).with_type_aware(true).test_and_snapshot();
+}
@@ -6,6 +6,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -17,9 +19,12 @@ fn no_namespace_diagnostic(span0: Span) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoNamespace {
+    /// Allow `declare namespace` and `declare module`.
     allow_declarations: bool,
+    /// Allow namespaces in `.d.ts` files.
     allow_definition_files: bool,
 }
 
@@ -40,23 +45,17 @@ declare_oxc_lint!(
     /// declare namespace foo {}
     /// ```
     NoNamespace,
-    correctness
+    correctness,
+    config = NoNamespace,
+    ast_types = [TSModuleDeclaration]
 );
 
 impl Rule for NoNamespace {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            allow_declarations: value
-                .get(0)
-                .and_then(|x| x.get("allowDeclarations"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allow_definition_files: value
-                .get(0)
-                .and_then(|x| x.get("allowDefinitionFiles"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// foo?.bar()!;
     /// ```
     NoNonNullAssertedOptionalChain,
-    correctness
+    correctness,
+    ast_types = [TSNonNullExpression]
 );
 
 impl Rule for NoNonNullAssertedOptionalChain {
@@ -5,7 +5,9 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -24,10 +26,15 @@ fn no_this_destructure_diagnostic(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoThisAlias(Box<NoThisAliasConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct NoThisAliasConfig {
+    /// Allow destructuring `this`, e.g. `const { props } = this;`.
+    #[serde(rename = "allowDestructuring", alias = "allow_destructuring")]
     allow_destructuring: bool,
-    allow_names: Vec<CompactStr>,
+    /// Names of the variables that may alias `this`, e.g. `["self"]`.
+    #[serde(rename = "allowedNames", alias = "allow_names")]
+    allow_names: Vec<String>,
 }
 
 impl std::ops::Deref for NoThisAlias {
@@ -59,30 +66,16 @@ declare_oxc_lint!(
     /// sampe with obj.<allowedName> = this
     /// ```
     NoThisAlias,
-    correctness
+    correctness,
+    config = NoThisAliasConfig
 );
 
 impl Rule for NoThisAlias {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        let allowed_names = value
+        value
             .get(0)
-            .and_then(|v| v.get("allow_names"))
-            .and_then(serde_json::Value::as_array)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(serde_json::Value::as_str)
-            .filter(std::option::Option::is_some)
-            .map(|x| CompactStr::from(x.unwrap()))
-            .collect::<Vec<CompactStr>>();
-
-        Self(Box::new(NoThisAliasConfig {
-            allow_destructuring: obj
-                .and_then(|v| v.get("allow_destructuring"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            allow_names: allowed_names,
-        }))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// function QuuzAny<T extends any>() {}
     /// ```
     NoUnnecessaryTypeConstraint,
-    suspicious
+    suspicious,
+    ast_types = [TSTypeParameterDeclaration]
 );
 
 impl Rule for NoUnnecessaryTypeConstraint {
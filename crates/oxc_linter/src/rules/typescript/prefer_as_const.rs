@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// let foo = { bar: 'baz' as 'baz' };
     /// ```
     PreferAsConst,
-    correctness
+    correctness, fix,
+    ast_types = [VariableDeclarator, PropertyDefinition, TSAsExpression]
 );
 
 impl Rule for PreferAsConst {
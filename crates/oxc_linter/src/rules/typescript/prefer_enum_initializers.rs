@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferEnumInitializers,
-    pedantic
+    pedantic,
+    ast_types = [TSEnumDeclaration]
 );
 
 impl Rule for PreferEnumInitializers {
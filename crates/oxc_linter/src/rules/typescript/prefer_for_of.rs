@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferForOf,
-    style
+    style,
+    ast_types = [ForStatement]
 );
 
 trait SpanExt {
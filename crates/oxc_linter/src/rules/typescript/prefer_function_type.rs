@@ -75,7 +75,13 @@ declare_oxc_lint!(
     /// type Intersection = ((data: string) => number) & ((id: number) => string);
     /// ```
     PreferFunctionType,
-    style
+    style, fix,
+    ast_types = [
+        TSInterfaceDeclaration,
+        ExportDefaultDeclaration,
+        TSTypeAnnotation,
+        TSTypeAliasDeclaration,
+    ]
 );
 
 fn has_one_super_type(decl: &TSInterfaceDeclaration) -> bool {
@@ -4,6 +4,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -13,8 +15,10 @@ fn prefer_literal_enum_member_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PreferLiteralEnumMember {
+    /// Allow bitwise expressions of literals in enum members, e.g. `A = 1 << 0`.
     allow_bitwise_expressions: bool,
 }
 
@@ -38,19 +42,17 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferLiteralEnumMember,
-    restriction
+    restriction,
+    config = PreferLiteralEnumMember,
+    ast_types = [TSEnumMember]
 );
 
 impl Rule for PreferLiteralEnumMember {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
-
-        Self {
-            allow_bitwise_expressions: options
-                .and_then(|x| x.get("allowBitwiseExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSEnumMember(decl) = node.kind() else {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// const multiLine: number = 'value';
     /// ```
     PreferTsExpectError,
-    pedantic
+    pedantic, fix,
+    ast_types = []
 );
 
 impl Rule for PreferTsExpectError {
@@ -8,6 +8,8 @@ use oxc_ast::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -20,25 +22,33 @@ fn triple_slash_reference_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct TripleSlashReference(Box<TripleSlashReferenceConfig>);
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct TripleSlashReferenceConfig {
+    /// Whether to allow `/// <reference lib="..." />`.
     lib: LibOption,
+    /// Whether to allow `/// <reference path="..." />`.
     path: PathOption,
+    /// Whether to allow `/// <reference types="..." />`, or only when the
+    /// types are not imported.
     types: TypesOption,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum LibOption {
     #[default]
     Always,
     Never,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum PathOption {
     Always,
     #[default]
     Never,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum TypesOption {
     Always,
     Never,
@@ -67,39 +77,17 @@ declare_oxc_lint!(
     /// globalThis.value;
     /// ```
     TripleSlashReference,
-    correctness
+    correctness,
+    config = TripleSlashReferenceConfig,
+    ast_types = []
 );
 
 impl Rule for TripleSlashReference {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
-        Self(Box::new(TripleSlashReferenceConfig {
-            lib: options
-                .and_then(|x| x.get("lib"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(LibOption::default, |value| match value {
-                    "always" => LibOption::Always,
-                    "never" => LibOption::Never,
-                    _ => LibOption::default(),
-                }),
-            path: options
-                .and_then(|x| x.get("path"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(PathOption::default, |value| match value {
-                    "always" => PathOption::Always,
-                    "never" => PathOption::Never,
-                    _ => PathOption::default(),
-                }),
-            types: options
-                .and_then(|x| x.get("types"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(TypesOption::default, |value| match value {
-                    "always" => TypesOption::Always,
-                    "never" => TypesOption::Never,
-                    "prefer-import" => TypesOption::PreferImport,
-                    _ => TypesOption::default(),
-                }),
-        }))
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
     fn run_once(&self, ctx: &LintContext) {
         let Some(root) = ctx.nodes().root_node() else {
@@ -6,7 +6,9 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::SymbolId;
-use oxc_span::{Atom, CompactStr, Span};
+use oxc_span::{Atom, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -17,10 +19,14 @@ fn catch_error_name_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic
 #[derive(Debug, Default, Clone)]
 pub struct CatchErrorName(Box<CatchErrorNameConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct CatchErrorNameConfig {
-    ignore: Vec<CompactStr>,
-    name: CompactStr,
+    /// Names of the errors to allow besides `name`, e.g. `["^_$"]`.
+    #[serde(rename = "ignored")]
+    ignore: Vec<String>,
+    /// Expected name of the errors.
+    name: String,
 }
 
 impl std::ops::Deref for CatchErrorName {
@@ -33,7 +39,7 @@ impl std::ops::Deref for CatchErrorName {
 
 impl Default for CatchErrorNameConfig {
     fn default() -> Self {
-        Self { ignore: vec![], name: CompactStr::new_const("error") }
+        Self { ignore: vec![], name: "error".to_string() }
     }
 }
 
@@ -53,31 +59,16 @@ declare_oxc_lint!(
     ///
     /// ```
     CatchErrorName,
-    style
+    style,
+    config = CatchErrorNameConfig
 );
 
 impl Rule for CatchErrorName {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let ignored_names = value
+        value
             .get(0)
-            .and_then(|v| v.get("ignored"))
-            .and_then(serde_json::Value::as_array)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(serde_json::Value::as_str)
-            .filter(std::option::Option::is_some)
-            .map(|x| CompactStr::from(x.unwrap()))
-            .collect::<Vec<CompactStr>>();
-
-        let allowed_name = CompactStr::from(
-            value
-                .get(0)
-                .and_then(|v| v.get("name"))
-                .and_then(serde_json::Value::as_str)
-                .unwrap_or("error"),
-        );
-
-        Self(Box::new(CatchErrorNameConfig { ignore: ignored_names, name: allowed_name }))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -132,7 +123,7 @@ impl Rule for CatchErrorName {
 
 impl CatchErrorName {
     fn is_name_allowed(&self, name: &Atom) -> bool {
-        self.name == name || self.ignore.iter().any(|s| s.as_str() == name.as_str())
+        self.name == name.as_str() || self.ignore.iter().any(|s| s.as_str() == name.as_str())
     }
     fn check_function_arguments(
         &self,
@@ -29,7 +29,16 @@ declare_oxc_lint!(
     /// }
     /// ```
     EmptyBraceSpaces,
-    style
+    style, fix,
+    ast_types = [
+        StaticBlock,
+        ObjectExpression,
+        FunctionBody,
+        Class,
+        BlockStatement,
+        CatchClause,
+        FinallyClause,
+    ]
 );
 
 impl Rule for EmptyBraceSpaces {
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// const foo = '\cA';
     /// ```
     EscapeCase,
-    pedantic
+    pedantic, fix,
+    ast_types = [StringLiteral, TemplateLiteral, RegExpLiteral]
 );
 
 fn is_hex_char(c: char) -> bool {
@@ -8,6 +8,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -32,22 +34,19 @@ fn zero(span0: Span, x1: &str, x2: &str, x3: Option<String>) -> OxcDiagnostic {
     d.with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum NonZero {
+    /// Enforce `foo.length > 0`.
     #[default]
     GreaterThan,
+    /// Enforce `foo.length !== 0`.
     NotEqual,
 }
-impl NonZero {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "not-equal" => Self::NotEqual,
-            _ => Self::GreaterThan,
-        }
-    }
-}
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "kebab-case")]
 pub struct ExplicitLengthCheck {
+    /// How to check that the length is not zero.
     non_zero: NonZero,
 }
 
@@ -76,7 +75,8 @@ declare_oxc_lint!(
     /// const isEmpty = foo.length === 0;
     /// ```
     ExplicitLengthCheck,
-    pedantic
+    pedantic, fix,
+    config = ExplicitLengthCheck
 );
 fn is_literal(expr: &Expression, value: f64) -> bool {
     matches!(expr, Expression::NumericLiteral(lit) if (lit.value - value).abs() < f64::EPSILON)
@@ -273,14 +273,10 @@ impl Rule for ExplicitLengthCheck {
         }
     }
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            non_zero: value
-                .get(0)
-                .and_then(|v| v.get("non-zero"))
-                .and_then(serde_json::Value::as_str)
-                .map(NonZero::from)
-                .unwrap_or_default(),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
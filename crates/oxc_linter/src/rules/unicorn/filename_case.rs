@@ -3,7 +3,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use serde_json::Value;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -14,16 +15,42 @@ fn filename_case_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 #[allow(clippy::struct_field_names)]
 pub struct FilenameCase {
+    /// Allow `kebab-case`.
     kebab_case: bool,
+    /// Allow `camelCase`.
     camel_case: bool,
+    /// Allow `snake_case`.
     snake_case: bool,
+    /// Allow `PascalCase`.
     pascal_case: bool,
+    /// Allow `underscore_case`.
     underscore_case: bool,
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct FilenameCaseOptions {
+    /// The case that filenames must be in.
+    case: Option<CaseOption>,
+    /// The cases that filenames may be in, e.g. `{ "camelCase": true, "pascalCase": true }`.
+    cases: Option<FilenameCase>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::enum_variant_names)]
+enum CaseOption {
+    KebabCase,
+    CamelCase,
+    SnakeCase,
+    PascalCase,
+    UnderscoreCase,
+}
+
 impl Default for FilenameCase {
     fn default() -> Self {
         Self {
@@ -46,53 +73,29 @@ declare_oxc_lint!(
     /// ### Example
     /// ```
     FilenameCase,
-    style
+    style,
+    config = FilenameCaseOptions,
+    ast_types = []
 );
 
 impl Rule for FilenameCase {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(case_type) = value.get(0) else {
-            return Self::default();
-        };
-
-        if let Some(Value::String(s)) = case_type.get("case") {
-            return match s.as_str() {
-                "kebabCase" => Self { kebab_case: true, ..Self::default() },
-                "camelCase" => Self { camel_case: true, ..Self::default() },
-                "snakeCase" => Self { snake_case: true, ..Self::default() },
-                "pascalCase" => Self { pascal_case: true, ..Self::default() },
-                "underscoreCase" => Self { underscore_case: true, ..Self::default() },
-                _ => Self::default(),
+        let options: FilenameCaseOptions = value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default();
+
+        if let Some(case) = options.case {
+            return match case {
+                CaseOption::KebabCase => Self { kebab_case: true, ..Self::default() },
+                CaseOption::CamelCase => Self { camel_case: true, ..Self::default() },
+                CaseOption::SnakeCase => Self { snake_case: true, ..Self::default() },
+                CaseOption::PascalCase => Self { pascal_case: true, ..Self::default() },
+                CaseOption::UnderscoreCase => Self { underscore_case: true, ..Self::default() },
             };
         }
 
-        if let Some(Value::String(s)) = case_type.get("case") {
-            return match s.as_str() {
-                "kebabCase" => Self { kebab_case: true, ..Self::default() },
-                "camelCase" => Self { camel_case: true, ..Self::default() },
-                "snakeCase" => Self { snake_case: true, ..Self::default() },
-                "pascalCase" => Self { pascal_case: true, ..Self::default() },
-                "underscoreCase" => Self { underscore_case: true, ..Self::default() },
-                _ => Self::default(),
-            };
-        }
-
-        if let Some(Value::Object(map)) = case_type.get("cases") {
-            let mut filename_case = Self::default();
-            for (key, value) in map {
-                match (key.as_str(), value) {
-                    ("kebabCase", Value::Bool(b)) => filename_case.kebab_case = *b,
-                    ("camelCase", Value::Bool(b)) => filename_case.camel_case = *b,
-                    ("snakeCase", Value::Bool(b)) => filename_case.snake_case = *b,
-                    ("pascalCase", Value::Bool(b)) => filename_case.pascal_case = *b,
-                    ("underscoreCase", Value::Bool(b)) => filename_case.underscore_case = *b,
-                    _ => (),
-                }
-            }
-            return filename_case;
-        }
-
-        Self::default()
+        options.cases.unwrap_or_default()
     }
 
     fn run_once<'a>(&self, ctx: &LintContext<'_>) {
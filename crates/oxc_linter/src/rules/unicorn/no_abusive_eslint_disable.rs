@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// console.log(message);
     /// ```
     NoAbusiveEslintDisable,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 impl Rule for NoAbusiveEslintDisable {
@@ -6,6 +6,8 @@ use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     ast_util::is_method_call, context::LintContext, rule::Rule, utils::is_prototype_property,
@@ -18,8 +20,10 @@ fn no_array_reduce_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoArrayReduce {
+    /// Allow simple operations like `array.reduce((total, item) => total + item)`.
     pub allow_simple_operations: bool,
 }
 
@@ -44,18 +48,17 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoArrayReduce,
-    restriction
+    restriction,
+    config = NoArrayReduce,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoArrayReduce {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_simple_operations = value
-            .as_object()
-            .and_then(|v| v.get("allowSimpleOperations"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(true);
-
-        Self { allow_simple_operations }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -370,27 +373,27 @@ fn test() {
         // Option: allowSimpleOperations
         (
             r"array.reduce((total, item) => total + item)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce((total, item) => { return total - item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce(function (total, item) { return total * item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce((total, item) => total + item, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce((total, item) => { return total - item }, 0 )",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce(function (total, item) { return total * item }, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"
@@ -398,7 +401,7 @@ fn test() {
 					return (total / item) * 100;
 				}, 0);
 		",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (r#"array.reduceRight((str, item) => str += item, "")"#, None),
         (
@@ -441,27 +444,27 @@ fn test() {
         // Option: allowSimpleOperations
         (
             r"array.reduceRight((total, item) => total + item)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight((total, item) => { return total - item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight(function (total, item) { return total * item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight((total, item) => total + item, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight((total, item) => { return total - item }, 0 )",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight(function (total, item) { return total * item }, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"
@@ -469,7 +472,7 @@ fn test() {
 					return (total / item) * 100;
 				}, 0);
 		",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
     ];
     Tester::new(NoArrayReduce::NAME, pass, fail).test_and_snapshot();
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoConsoleSpaces,
-    style
+    style, fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoConsoleSpaces {
@@ -51,7 +51,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoDocumentCookie,
-    correctness
+    correctness,
+    ast_types = [AssignmentExpression]
 );
 
 impl Rule for NoDocumentCookie {
@@ -35,6 +35,7 @@ declare_oxc_lint!(
     ///
     NoEmptyFile,
     correctness,
+    ast_types = [],
 );
 
 impl Rule for NoEmptyFile {
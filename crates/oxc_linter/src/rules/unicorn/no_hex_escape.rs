@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// const foo = `\u001B${bar}`;
     /// ```
     NoHexEscape,
-    pedantic
+    pedantic, fix,
+    ast_types = [StringLiteral, TemplateLiteral, RegExpLiteral]
 );
 
 // \x -> \u00
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// el.removeEventListener('click', handler.bind(this));
     /// ```
     NoInvalidRemoveEventListener,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoInvalidRemoveEventListener {
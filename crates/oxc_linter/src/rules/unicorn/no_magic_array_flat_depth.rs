@@ -42,6 +42,7 @@ declare_oxc_lint!(
     /// ```
     NoMagicArrayFlatDepth,
     restriction,
+    ast_types = [CallExpression],
 );
 
 impl Rule for NoMagicArrayFlatDepth {
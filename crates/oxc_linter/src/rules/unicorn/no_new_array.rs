@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// const array = [42];
     /// ```
     NoNewArray,
-    correctness
+    correctness,
+    ast_types = [NewExpression]
 );
 
 impl Rule for NoNewArray {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// const buffer = Buffer.alloc(10);
     /// ```
     NoNewBuffer,
-    pedantic
+    pedantic,
+    ast_types = [NewExpression]
 );
 
 impl Rule for NoNewBuffer {
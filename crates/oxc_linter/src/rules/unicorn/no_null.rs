@@ -9,6 +9,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     ast_util::is_method_call, context::LintContext, fixer::RuleFixer, rule::Rule, AstNode, Fix,
@@ -26,8 +28,10 @@ fn remove_null_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoNull {
+    /// Also report strict equality checks against `null`, e.g. `foo === null`.
     check_strict_equality: Option<bool>,
 }
 
@@ -52,7 +56,9 @@ declare_oxc_lint!(
     /// let foo
     /// ```
     NoNull,
-    style
+    style, suggestion,
+    config = NoNull,
+    ast_types = [NullLiteral]
 );
 
 fn match_null_arg(call_expr: &CallExpression, index: usize, span: Span) -> bool {
@@ -83,7 +89,7 @@ fn diagnose_binary_expression(
 
     // `if (foo != null) {}`
     if matches!(binary_expr.operator, BinaryOperator::Equality | BinaryOperator::Inequality) {
-        ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), |fixer| {
+        ctx.diagnostic_with_suggestion(replace_null_diagnostic(null_literal.span), |fixer| {
             fix_null(fixer, null_literal)
         });
 
@@ -91,7 +97,7 @@ fn diagnose_binary_expression(
     }
 
     // checkStrictEquality=true && `if (foo !== null) {}`
-    ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), |fixer| {
+    ctx.diagnostic_with_suggestion(replace_null_diagnostic(null_literal.span), |fixer| {
         fix_null(fixer, null_literal)
     });
 }
@@ -106,7 +112,7 @@ fn diagnose_variable_declarator(
     if matches!(&variable_declarator.init, Some(Expression::NullLiteral(expr)) if expr.span == null_literal.span)
         && matches!(parent_kind, Some(AstKind::VariableDeclaration(var_declaration)) if !var_declaration.kind.is_const() )
     {
-        ctx.diagnostic_with_fix(remove_null_diagnostic(null_literal.span), |fixer| {
+        ctx.diagnostic_with_suggestion(remove_null_diagnostic(null_literal.span), |fixer| {
             fixer.delete_range(Span::new(variable_declarator.id.span().end, null_literal.span.end))
         });
 
@@ -114,7 +120,7 @@ fn diagnose_variable_declarator(
     }
 
     // `const foo = null`
-    ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), |fixer| {
+    ctx.diagnostic_with_suggestion(replace_null_diagnostic(null_literal.span), |fixer| {
         fix_null(fixer, null_literal)
     });
 }
@@ -161,12 +167,10 @@ fn match_call_expression_pass_case(null_literal: &NullLiteral, call_expr: &CallE
 
 impl Rule for NoNull {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            check_strict_equality: value
-                .get(0)
-                .and_then(|v| v.get("checkStrictEquality"))
-                .and_then(serde_json::Value::as_bool),
-        }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -203,15 +207,16 @@ impl Rule for NoNull {
 
             // `function foo() { return null; }`,
             if matches!(parent_node.kind(), AstKind::ReturnStatement(_)) {
-                ctx.diagnostic_with_fix(remove_null_diagnostic(null_literal.span), |fixer| {
-                    fixer.delete_range(null_literal.span)
-                });
+                ctx.diagnostic_with_suggestion(
+                    remove_null_diagnostic(null_literal.span),
+                    |fixer| fixer.delete_range(null_literal.span),
+                );
 
                 return;
             }
         }
 
-        ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), |fixer| {
+        ctx.diagnostic_with_suggestion(replace_null_diagnostic(null_literal.span), |fixer| {
             fix_null(fixer, null_literal)
         });
     }
@@ -35,6 +35,7 @@ declare_oxc_lint!(
     /// ```
     NoProcessExit,
     restriction,
+    ast_types = [CallExpression],
 );
 
 impl Rule for NoProcessExit {
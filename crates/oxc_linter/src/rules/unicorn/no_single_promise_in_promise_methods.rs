@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoSinglePromiseInPromiseMethods,
-    correctness
+    correctness, fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoSinglePromiseInPromiseMethods {
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoStaticOnlyClass,
-    pedantic
+    pedantic,
+    ast_types = [Class]
 );
 
 impl Rule for NoStaticOnlyClass {
@@ -54,7 +54,15 @@ declare_oxc_lint!(
     /// console.log('after'); //<- This will never execute
     /// ```
     NoThenable,
-    correctness
+    correctness,
+    ast_types = [
+        ObjectExpression,
+        PropertyDefinition,
+        MethodDefinition,
+        ModuleDeclaration,
+        CallExpression,
+        AssignmentExpression,
+    ]
 );
 
 impl Rule for NoThenable {
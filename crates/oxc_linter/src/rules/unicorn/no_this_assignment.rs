@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// new Bar(this).method();
     /// ```
     NoThisAssignment,
-    pedantic
+    pedantic,
+    ast_types = [VariableDeclarator, AssignmentExpression]
 );
 
 impl Rule for NoThisAssignment {
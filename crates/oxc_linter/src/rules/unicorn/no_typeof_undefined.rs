@@ -4,6 +4,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
 
@@ -11,8 +13,10 @@ fn no_typeof_undefined_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-unicorn(no-typeof-undefined): Compare with `undefined` directly instead of using `typeof`.").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoTypeofUndefined {
+    /// Also report `typeof` checks of global variables, which may be undeclared.
     check_global_variables: bool,
 }
 
@@ -35,7 +39,9 @@ declare_oxc_lint!(
     /// foo === undefined;
     /// ```
     NoTypeofUndefined,
-    pedantic
+    pedantic,
+    config = NoTypeofUndefined,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoTypeofUndefined {
@@ -74,10 +80,10 @@ impl Rule for NoTypeofUndefined {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let check_global_variables =
-            value.get("checkGlobalVariables").and_then(serde_json::Value::as_bool).unwrap_or(false);
-
-        Self { check_global_variables }
+        value
+            .get(0)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -130,11 +136,11 @@ fn test() {
         (r#"typeof foo.bar === "undefined""#, None),
         (
             r#"let foo; typeof foo === "undefined""#,
-            Some(serde_json::json!({ "checkGlobalVariables": false })),
+            Some(serde_json::json!([{ "checkGlobalVariables": false }])),
         ),
         (
             r#"typeof foo === "undefined""#,
-            Some(serde_json::json!({ "checkGlobalVariables": true })),
+            Some(serde_json::json!([{ "checkGlobalVariables": true }])),
         ),
     ];
 
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// await await promise;
     /// ```
     NoUnnecessaryAwait,
-    correctness
+    correctness, fix,
+    ast_types = [AwaitExpression]
 );
 
 impl Rule for NoUnnecessaryAwait {
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// })(getBar());
     /// ```
     NoUnreadableIife,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoUnreadableIife {
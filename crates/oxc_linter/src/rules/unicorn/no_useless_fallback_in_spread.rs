@@ -36,7 +36,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUselessFallbackInSpread,
-    correctness
+    correctness, fix,
+    ast_types = [LogicalExpression]
 );
 
 impl Rule for NoUselessFallbackInSpread {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// async () => bar;
     /// ```
     NoUselessPromiseResolveReject,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoUselessPromiseResolveReject {
@@ -126,7 +126,7 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUselessSpread,
-    correctness
+    correctness, dangerous_fix
 );
 
 impl Rule for NoUselessSpread {
@@ -167,7 +167,7 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 AstKind::ArrayExpressionElement(_) => {
                     let diagnostic = spread_in_list(span, "array");
                     if let Some(outer_array) = ctx.nodes().parent_kind(parent_parent.id()) {
-                        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        ctx.diagnostic_with_dangerous_fix(diagnostic, |fixer| {
                             fix_replace(fixer, &outer_array, array_expr)
                         });
                     } else {
@@ -176,7 +176,7 @@ fn check_useless_spread_in_list<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 }
                 // foo(...[ ])
                 AstKind::Argument(_) => {
-                    ctx.diagnostic_with_fix(spread_in_arguments(span), |fixer| {
+                    ctx.diagnostic_with_dangerous_fix(spread_in_arguments(span), |fixer| {
                         fix_by_removing_spread(fixer, array_expr, spread_elem)
                     });
                 }
@@ -258,7 +258,7 @@ fn check_useless_iterable_to_array<'a>(
             {
                 return;
             }
-            ctx.diagnostic_with_fix(
+            ctx.diagnostic_with_dangerous_fix(
                 iterable_to_array(span, get_new_expr_ident_name(new_expr).unwrap_or("unknown")),
                 |fixer| fix_by_removing_spread(fixer, &new_expr.arguments[0], spread_elem),
             );
@@ -300,7 +300,7 @@ fn check_useless_iterable_to_array<'a>(
                 return;
             }
 
-            ctx.diagnostic_with_fix(
+            ctx.diagnostic_with_dangerous_fix(
                 iterable_to_array(
                     span,
                     &get_method_name(call_expr).unwrap_or_else(|| "unknown".into()),
@@ -363,7 +363,7 @@ fn check_useless_array_clone<'a>(array_expr: &ArrayExpression<'a>, ctx: &LintCon
             },
         );
 
-        ctx.diagnostic_with_fix(clone_array(span, &method), |fixer| {
+        ctx.diagnostic_with_dangerous_fix(clone_array(span, &method), |fixer| {
             fix_by_removing_spread(fixer, array_expr, spread_elem)
         });
     }
@@ -382,7 +382,7 @@ fn check_useless_array_clone<'a>(array_expr: &ArrayExpression<'a>, ctx: &LintCon
             let method_name =
                 call_expr.callee.get_member_expr().unwrap().static_property_name().unwrap();
 
-            ctx.diagnostic_with_fix(
+            ctx.diagnostic_with_dangerous_fix(
                 clone_array(span, &format!("Promise.{method_name}")),
                 |fixer| fix_by_removing_spread(fixer, array_expr, spread_elem),
             );
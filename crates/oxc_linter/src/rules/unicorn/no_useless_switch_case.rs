@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUselessSwitchCase,
-    pedantic
+    pedantic,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for NoUselessSwitchCase {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// const foo = 1.1;
     /// ```
     NoZeroFractions,
-    style
+    style, fix,
+    ast_types = [NumericLiteral]
 );
 
 impl Rule for NoZeroFractions {
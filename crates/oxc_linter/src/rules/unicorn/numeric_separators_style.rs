@@ -82,7 +82,8 @@ declare_oxc_lint!(
     /// ];
     /// ```
     NumericSeparatorsStyle,
-    style
+    style, fix,
+    ast_types = [NumericLiteral, BigintLiteral]
 );
 
 impl Rule for NumericSeparatorsStyle {
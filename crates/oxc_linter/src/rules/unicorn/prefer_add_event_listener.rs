@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// foo.addEventListener('click', () => {});
     /// ```
     PreferAddEventListener,
-    suspicious
+    suspicious,
+    ast_types = [AssignmentExpression]
 );
 
 impl Rule for PreferAddEventListener {
@@ -56,7 +56,8 @@ declare_oxc_lint!(
     /// const foo = [maybeArray].flat();
     /// ```
     PreferArrayFlat,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferArrayFlat {
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// const bar = [1,2,3].flatMap(i => [i]); // ✓ pass
     /// ```
     PreferArrayFlatMap,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferArrayFlatMap {
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// String.fromCodePoint(0x1f984);
     /// ```
     PreferCodePoint,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferCodePoint {
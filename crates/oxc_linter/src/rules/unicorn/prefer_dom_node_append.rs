@@ -36,7 +36,8 @@ declare_oxc_lint!(
     //
     /// ```
     PreferDomNodeAppend,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferDomNodeAppend {
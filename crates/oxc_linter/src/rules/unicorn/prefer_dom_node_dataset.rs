@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// element.dataset.unicorn = '🦄';
     /// ```
     PreferDomNodeDataset,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferDomNodeDataset {
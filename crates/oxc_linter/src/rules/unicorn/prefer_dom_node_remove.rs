@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// childNode.remove();
     /// ```
     PreferDomNodeRemove,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferDomNodeRemove {
@@ -36,7 +36,7 @@ declare_oxc_lint!(
     /// const text = foo.textContent;
     /// ```
     PreferDomNodeTextContent,
-    style
+    style, suggestion
 );
 
 impl Rule for PreferDomNodeTextContent {
@@ -44,7 +44,7 @@ impl Rule for PreferDomNodeTextContent {
         if let AstKind::MemberExpression(member_expr) = node.kind() {
             if let Some((span, name)) = member_expr.static_property_info() {
                 if name == "innerText" && !member_expr.is_computed() {
-                    ctx.diagnostic_with_fix(
+                    ctx.diagnostic_with_suggestion(
                         prefer_dom_node_text_content_diagnostic(span),
                         |fixer| fixer.replace(span, "textContent"),
                     );
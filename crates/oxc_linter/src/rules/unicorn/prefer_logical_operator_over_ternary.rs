@@ -38,7 +38,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferLogicalOperatorOverTernary,
-    style
+    style,
+    ast_types = [ConditionalExpression]
 );
 
 impl Rule for PreferLogicalOperatorOverTernary {
@@ -60,6 +60,7 @@ declare_oxc_lint!(
     /// ```
     PreferModernMathApis,
     restriction,
+    ast_types = [BinaryExpression, CallExpression],
 );
 
 impl Rule for PreferModernMathApis {
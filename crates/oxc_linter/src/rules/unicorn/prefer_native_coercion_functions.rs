@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// array.some(Boolean);
     /// ```
     PreferNativeCoercionFunctions,
-    pedantic
+    pedantic,
+    ast_types = [ArrowFunctionExpression, Function]
 );
 
 impl Rule for PreferNativeCoercionFunctions {
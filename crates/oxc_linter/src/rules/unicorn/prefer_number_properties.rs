@@ -47,6 +47,7 @@ declare_oxc_lint!(
     /// ```
     PreferNumberProperties,
     restriction,
+    ast_types = [MemberExpression, IdentifierReference, IdentifierName, CallExpression],
 );
 
 impl Rule for PreferNumberProperties {
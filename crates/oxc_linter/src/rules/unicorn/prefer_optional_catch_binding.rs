@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// } catch { }
     /// ```
     PreferOptionalCatchBinding,
-    style
+    style,
+    ast_types = [CatchParameter]
 );
 
 impl Rule for PreferOptionalCatchBinding {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// const maxValue = Math.max.apply(Math, numbers);
     /// ```
     PreferPrototypeMethods,
-    pedantic
+    pedantic, fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferPrototypeMethods {
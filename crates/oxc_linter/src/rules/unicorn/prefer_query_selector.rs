@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// document.querySelector('li').querySelectorAll('a');
     /// ```
     PreferQuerySelector,
-    pedantic
+    pedantic, fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferQuerySelector {
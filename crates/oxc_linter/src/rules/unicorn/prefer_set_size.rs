@@ -35,7 +35,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferSetSize,
-    correctness
+    correctness,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for PreferSetSize {
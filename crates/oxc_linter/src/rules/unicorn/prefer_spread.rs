@@ -40,7 +40,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferSpread,
-    style
+    style, dangerous_fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferSpread {
@@ -159,7 +160,7 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_dangerous_fix(
                     prefer_spread_diagnostic(call_expr.span, "string.split()"),
                     |fixer| {
                         let callee_obj = member_expr.object().without_parenthesized();
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     PreferStringReplaceAll,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferStringReplaceAll {
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     PreferStringSlice,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferStringSlice {
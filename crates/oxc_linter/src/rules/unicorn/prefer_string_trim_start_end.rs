@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// str.trimEnd();
     /// ```
     PreferStringTrimStartEnd,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferStringTrimStartEnd {
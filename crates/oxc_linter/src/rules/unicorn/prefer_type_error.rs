@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferTypeError,
-    pedantic
+    pedantic,
+    ast_types = [ThrowStatement]
 );
 
 impl Rule for PreferTypeError {
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// foo.join(",")
     /// ```
     RequireArrayJoinSeparator,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 fn is_array_prototype_property(member_expr: &MemberExpression, property: &str) -> bool {
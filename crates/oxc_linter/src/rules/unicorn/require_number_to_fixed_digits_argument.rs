@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// number.toFixed();
     /// ```
     RequireNumberToFixedDigitsArgument,
-    pedantic
+    pedantic, fix,
+    ast_types = [CallExpression]
 );
 
 impl Rule for RequireNumberToFixedDigitsArgument {
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     SwitchCaseBraces,
-    style
+    style, fix,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for SwitchCaseBraces {
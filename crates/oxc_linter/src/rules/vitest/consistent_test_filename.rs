@@ -0,0 +1,147 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn consistent_test_filename_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-vitest(consistent-test-filename): Use the test file name pattern `{x1}`."
+    ))
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentTestFilename(Box<ConsistentTestFilenameConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ConsistentTestFilenameConfig {
+    pattern: Regex,
+    all_test_pattern: Regex,
+}
+
+impl Default for ConsistentTestFilenameConfig {
+    fn default() -> Self {
+        Self {
+            pattern: Regex::new(DEFAULT_PATTERN).unwrap(),
+            all_test_pattern: Regex::new(DEFAULT_ALL_TEST_PATTERN).unwrap(),
+        }
+    }
+}
+
+const DEFAULT_PATTERN: &str = r".*\.test\.[tj]sx?$";
+const DEFAULT_ALL_TEST_PATTERN: &str = r".*\.(test|spec)\.[tj]sx?$";
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConsistentTestFilenameOptions {
+    /// Pattern which the names of the test files must match, defaults to `.*\.test\.[tj]sx?$`.
+    pattern: Option<String>,
+    /// Pattern of the names of the test files to check, defaults to `.*\.(test|spec)\.[tj]sx?$`.
+    all_test_pattern: Option<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a single naming pattern for the test files, e.g. `foo.test.ts` rather than a mix
+    /// of `foo.test.ts` and `bar.spec.ts`.
+    ///
+    /// Only the files whose name matches `allTestPattern` are checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixed names are harder to search for, and a file which does not match the `include`
+    /// of the Vitest configuration is silently left out of the test run.
+    ///
+    /// ### Example
+    ///
+    /// With the default `{ "pattern": ".*\\.test\\.[tj]sx?$" }`:
+    ///
+    /// ```javascript
+    /// // Bad: sum.spec.js
+    /// test('adds', () => expect(sum(1, 2)).toBe(3));
+    ///
+    /// // Good: sum.test.js
+    /// test('adds', () => expect(sum(1, 2)).toBe(3));
+    /// ```
+    ConsistentTestFilename,
+    nursery,
+    config = ConsistentTestFilenameOptions,
+    ast_types = []
+);
+
+impl Rule for ConsistentTestFilename {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value
+            .get(0)
+            .and_then(|value| ConsistentTestFilenameOptions::deserialize(value).ok())
+            .unwrap_or_default();
+        let pattern = |pattern: Option<String>, default: &str| {
+            pattern
+                .and_then(|pattern| Regex::new(&pattern).ok())
+                .unwrap_or_else(|| Regex::new(default).unwrap())
+        };
+        Self(Box::new(ConsistentTestFilenameConfig {
+            pattern: pattern(options.pattern, DEFAULT_PATTERN),
+            all_test_pattern: pattern(options.all_test_pattern, DEFAULT_ALL_TEST_PATTERN),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(file_name) = ctx.file_path().file_name().and_then(|name| name.to_str()) else {
+            return;
+        };
+        if !self.0.all_test_pattern.is_match(file_name) || self.0.pattern.is_match(file_name) {
+            return;
+        }
+        ctx.diagnostic(consistent_test_filename_diagnostic(
+            Span::default(),
+            self.0.pattern.as_str(),
+        ));
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let source = "test('foo', () => {})";
+    let path = |path: &str| Some(PathBuf::from(path));
+
+    let pass = vec![
+        (source, None, None, path("foo.test.ts")),
+        (source, None, None, path("nested/foo.test.tsx")),
+        (source, None, None, path("foo.ts")),
+        (source, None, None, path("foo.spec.mts")),
+        (source, Some(json!([{ "pattern": r".*\.spec\.ts$" }])), None, path("foo.spec.ts")),
+        (
+            source,
+            Some(json!([{ "pattern": r".*\.spec\.ts$", "allTestPattern": r"__tests__" }])),
+            None,
+            path("foo.test.ts"),
+        ),
+    ];
+
+    let fail = vec![
+        (source, None, None, path("foo.spec.ts")),
+        (source, None, None, path("nested/foo.spec.jsx")),
+        (source, Some(json!([{ "pattern": r".*\.spec\.ts$" }])), None, path("foo.test.ts")),
+        (
+            source,
+            Some(json!([{ "pattern": r".*\.test\.ts$", "allTestPattern": r".*\.ts$" }])),
+            None,
+            path("foo.ts"),
+        ),
+    ];
+
+    Tester::new(ConsistentTestFilename::NAME, pass, fail)
+        .with_vitest_plugin(true)
+        .test_and_snapshot();
+}
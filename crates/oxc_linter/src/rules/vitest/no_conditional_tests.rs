@@ -0,0 +1,134 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::LogicalOperator;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
+        PossibleJestNode,
+    },
+};
+
+fn no_conditional_tests_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-vitest(no-conditional-tests): Disallow conditional tests.")
+        .with_help("Use `test.skipIf()` or `test.runIf()` instead.")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoConditionalTests;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the tests and the test suites declared under a condition, e.g. in an `if`
+    /// statement or a ternary expression.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The skipped tests are not reported, nobody notices that they did not run.
+    /// `test.skipIf()` and `test.runIf()` report them as skipped instead.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// if (process.platform === 'win32') {
+    ///     test('foo', () => {});
+    /// }
+    /// isCI ? test('foo', () => {}) : test('bar', () => {});
+    ///
+    /// // Good
+    /// test.runIf(process.platform === 'win32')('foo', () => {});
+    /// test('foo', () => {
+    ///     if (isCI) {
+    ///         expect(a).toBe(b);
+    ///     }
+    /// });
+    /// ```
+    NoConditionalTests,
+    nursery,
+    ast_types = []
+);
+
+impl Rule for NoConditionalTests {
+    fn run_once(&self, ctx: &LintContext) {
+        for possible_jest_node in &collect_possible_jest_call_node(ctx) {
+            run(possible_jest_node, ctx);
+        }
+    }
+}
+
+const TEST_KINDS: [JestFnKind; 2] = [
+    JestFnKind::General(JestGeneralFnKind::Describe),
+    JestFnKind::General(JestGeneralFnKind::Test),
+];
+
+fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
+    let node = possible_jest_node.node;
+    let AstKind::CallExpression(call_expr) = node.kind() else { return };
+    if !is_type_of_jest_fn_call(call_expr, possible_jest_node, ctx, &TEST_KINDS) {
+        return;
+    }
+
+    // Only the conditions up to the enclosing test or suite, which is reported itself if it is
+    // conditional, a condition in a test body decides on the assertions instead.
+    let is_conditional = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .take_while(|parent| {
+            !matches!(parent.kind(), AstKind::CallExpression(parent_call)
+            if is_type_of_jest_fn_call(
+                parent_call,
+                &PossibleJestNode { node: parent, original: None },
+                ctx,
+                &TEST_KINDS,
+            ))
+        })
+        .any(|parent| match parent.kind() {
+            AstKind::IfStatement(_)
+            | AstKind::SwitchStatement(_)
+            | AstKind::ConditionalExpression(_) => true,
+            AstKind::LogicalExpression(expr) => expr.operator != LogicalOperator::Coalesce,
+            _ => false,
+        });
+    if is_conditional {
+        ctx.diagnostic(no_conditional_tests_diagnostic(call_expr.callee.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "test('foo', () => {})",
+        "describe('foo', () => { test('bar', () => {}) })",
+        "test.skipIf(isCI)('foo', () => {})",
+        "test.runIf(process.platform === 'win32')('foo', () => {})",
+        "describe.skipIf(isCI)('foo', () => { test('bar', () => {}) })",
+        "test('foo', () => { if (isCI) { expect(a).toBe(b) } })",
+        "test('foo', () => { isCI ? expect(a).toBe(b) : expect(a).toBe(c) })",
+        "if (isCI) { console.log('CI') }",
+        "if (isCI) { foo('bar', () => {}) }",
+        "const run = isCI && setup()",
+    ];
+
+    let fail = vec![
+        "if (isCI) { test('foo', () => {}) }",
+        "if (isCI) { it('foo', () => {}) } else { it('bar', () => {}) }",
+        "if (isCI) { describe('foo', () => { test('bar', () => {}) }) }",
+        "describe('foo', () => { if (isCI) { test('bar', () => {}) } })",
+        "isCI ? test('foo', () => {}) : test('bar', () => {})",
+        "isCI && test('foo', () => {})",
+        "switch (platform) { case 'win32': test('foo', () => {}) }",
+        "if (isCI) { test.each([1, 2])('foo %i', () => {}) }",
+        "import { test as it } from 'vitest'; if (isCI) { it('foo', () => {}) }",
+    ];
+
+    Tester::new(NoConditionalTests::NAME, pass, fail).with_vitest_plugin(true).test_and_snapshot();
+}
@@ -0,0 +1,785 @@
+//! ESLint-style selectors, e.g. `CallExpression[callee.name='setTimeout'][arguments.length<2]`,
+//! matched against the nodes of the [`AstNodes`].
+//!
+//! Types are the names of the [`AstKind`]s, e.g. `IdentifierReference`, or of the ESTree nodes
+//! they correspond to, e.g. `Identifier`. The wrappers which do not exist in ESTree, e.g. `Argument`,
+//! are skipped. Attributes are read from the serialized node, which has the ESTree shape.
+//!
+//! Supported: `*`, types, attributes with `=`, `!=`, `<`, `<=`, `>`, `>=` and `/regex/flags` values,
+//! the ` `, `>`, `~` and `+` combinators, `,`, `:not()`, `:matches()`, `:is()`, `:has()`,
+//! `:first-child`, `:last-child`, `:nth-child()`, `:nth-last-child()`, `:statement`, `:expression`,
+//! `:declaration`, `:function` and `:pattern`.
+
+use std::{borrow::Cow, cell::RefCell, fmt, rc::Rc};
+
+use oxc_ast::{
+    ast::{ClassType, FunctionType},
+    AstKind,
+};
+use oxc_semantic::{AstNodeId, AstNodes};
+use oxc_span::GetSpan;
+use oxc_syntax::number::ToJsString;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use serde_json::{Map, Value};
+
+/// A parsed selector.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    /// The selector as written.
+    source: String,
+    node: SelectorNode,
+}
+
+#[derive(Debug, Clone)]
+enum SelectorNode {
+    /// `*`
+    Wildcard,
+    /// `CallExpression`
+    Type(String),
+    /// `[callee.name='foo']`, the test is `None` for `[callee.name]`
+    Attribute { path: Vec<String>, test: Option<(Operator, AttributeValue)> },
+    /// `A[b]:c`
+    Compound(Vec<SelectorNode>),
+    /// `A, B`, `:matches(A, B)`, `:is(A, B)`
+    Matches(Vec<SelectorNode>),
+    /// `:not(A, B)`
+    Not(Vec<SelectorNode>),
+    /// `:has(A)`, the relations of its selectors start from [`SelectorNode::Scope`]
+    Has(Vec<SelectorNode>),
+    /// The node of the enclosing `:has()`.
+    Scope,
+    /// `A > B`, matched from `B`
+    Relation { left: Box<SelectorNode>, combinator: Combinator, right: Box<SelectorNode> },
+    /// `:first-child`, `:nth-child(2)`, `:nth-last-child(2)`, with a 1-based index
+    NthChild { index: usize, from_end: bool },
+    /// `:statement`, `:expression`, ...
+    Class(Class),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Combinator {
+    /// `A B`
+    Descendant,
+    /// `A > B`
+    Child,
+    /// `A ~ B`
+    Sibling,
+    /// `A + B`
+    Adjacent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    /// A quoted string, or a bare word, e.g. `true`
+    String(String),
+    Number(f64),
+    Regex(Regex),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Class {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+impl Selector {
+    /// # Errors
+    ///
+    /// * `source` is not a valid selector, or uses a feature which is not supported
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = Parser { source, chars: source.char_indices().peekable() };
+        let node = parser.selectors(false)?;
+        if let Some((offset, c)) = parser.chars.next() {
+            return Err(parser.error(offset, &format!("unexpected `{c}`")));
+        }
+        Ok(Self { source: source.to_string(), node })
+    }
+
+    /// Whether `node_id` is matched by the selector.
+    pub fn matches(&self, node_id: AstNodeId, tree: &SelectorTree) -> bool {
+        self.node.matches(node_id, tree, None)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// The nodes of a file, as seen by the selectors: in the ESTree shape, without the wrappers of oxc.
+pub struct SelectorTree<'a, 'b> {
+    nodes: &'b AstNodes<'a>,
+    /// Whether each node is skipped, see [`is_skipped`].
+    skipped: Vec<bool>,
+    /// Parent of each node, the closest ancestor which is not skipped.
+    parents: Vec<Option<AstNodeId>>,
+    /// Children of each node which is not skipped, in source order.
+    children: Vec<Vec<AstNodeId>>,
+    /// Nodes serialized to read their attributes.
+    serialized: RefCell<FxHashMap<AstNodeId, Rc<Value>>>,
+}
+
+impl<'a, 'b> SelectorTree<'a, 'b> {
+    pub fn new(nodes: &'b AstNodes<'a>) -> Self {
+        let mut skipped = Vec::with_capacity(nodes.len());
+        let mut parents: Vec<Option<AstNodeId>> = Vec::with_capacity(nodes.len());
+        let mut children = vec![vec![]; nodes.len()];
+        // Parents are created before their children.
+        for node in nodes.iter() {
+            let parent = nodes.parent_id(node.id()).and_then(|parent_id| {
+                if skipped[parent_id.index()] {
+                    parents[parent_id.index()]
+                } else {
+                    Some(parent_id)
+                }
+            });
+            let is_skipped = is_skipped(node.kind())
+                // the body of `() => expression` is the expression in ESTree
+                || match node.kind() {
+                    AstKind::FunctionBody(_) => nodes.parent_kind(node.id()).is_some_and(
+                        |kind| matches!(kind, AstKind::ArrowFunctionExpression(arrow) if arrow.expression),
+                    ),
+                    AstKind::ExpressionStatement(_) => nodes
+                        .parent_id(node.id())
+                        .is_some_and(|parent_id| skipped[parent_id.index()]),
+                    _ => false,
+                };
+            skipped.push(is_skipped);
+            parents.push(parent);
+            if let Some(parent) = parent {
+                if !is_skipped {
+                    children[parent.index()].push(node.id());
+                }
+            }
+        }
+        Self { nodes, skipped, parents, children, serialized: RefCell::default() }
+    }
+
+    /// The nodes which can be matched by a selector.
+    pub fn iter(&self) -> impl Iterator<Item = AstNodeId> + '_ {
+        self.nodes.iter().map(oxc_semantic::AstNode::id).filter(|id| !self.skipped[id.index()])
+    }
+
+    pub fn kind(&self, node_id: AstNodeId) -> AstKind<'a> {
+        self.nodes.kind(node_id)
+    }
+
+    fn parent(&self, node_id: AstNodeId) -> Option<AstNodeId> {
+        self.parents[node_id.index()]
+    }
+
+    fn ancestors(&self, node_id: AstNodeId) -> impl Iterator<Item = AstNodeId> + '_ {
+        std::iter::successors(self.parent(node_id), |id| self.parent(*id))
+    }
+
+    fn descendants(&self, node_id: AstNodeId) -> Vec<AstNodeId> {
+        let mut descendants = vec![];
+        let mut stack = vec![node_id];
+        while let Some(id) = stack.pop() {
+            for child in self.children[id.index()].iter().rev() {
+                descendants.push(*child);
+                stack.push(*child);
+            }
+        }
+        descendants
+    }
+
+    fn serialized(&self, node_id: AstNodeId) -> Rc<Value> {
+        let mut serialized = self.serialized.borrow_mut();
+        let value = serialized.entry(node_id).or_insert_with(|| {
+            Rc::new(serde_json::to_value(self.kind(node_id)).unwrap_or(Value::Null))
+        });
+        Rc::clone(value)
+    }
+
+    /// The nodes of the list of the parent containing `node_id`, e.g. the `arguments` of a call,
+    /// with the index of `node_id` in it.
+    fn siblings(&self, node_id: AstNodeId) -> Option<(Vec<AstNodeId>, usize)> {
+        let parent = self.parent(node_id)?;
+        let span = self.kind(node_id).span();
+        let is_at = |value: &Value, start: u32, end: u32| {
+            value.get("start").and_then(Value::as_u64) == Some(u64::from(start))
+                && value.get("end").and_then(Value::as_u64) == Some(u64::from(end))
+        };
+        let serialized = self.serialized(parent);
+        let node = serialized.as_object()?;
+        let list = node.keys().find_map(|key| {
+            let Value::Array(list) = field(node, key)? else { return None };
+            list.iter().any(|item| is_at(item, span.start, span.end)).then_some(list)
+        })?;
+        let children = &self.children[parent.index()];
+        let siblings = list
+            .iter()
+            .filter_map(|item| {
+                children.iter().copied().find(|child| {
+                    let span = self.kind(*child).span();
+                    is_at(item, span.start, span.end)
+                })
+            })
+            .collect::<Vec<_>>();
+        let index = siblings.iter().position(|id| *id == node_id)?;
+        Some((siblings, index))
+    }
+}
+
+/// Wrappers of oxc which have no ESTree node, their children are the children of their parent.
+fn is_skipped(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::Argument(_)
+            | AstKind::ArrayExpressionElement(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::CatchParameter(_)
+            | AstKind::ClassHeritage(_)
+            | AstKind::Elision(_)
+            | AstKind::ExpressionArrayElement(_)
+            | AstKind::FormalParameter(_)
+            | AstKind::FormalParameters(_)
+            | AstKind::ForStatementInit(_)
+            | AstKind::JSXAttributeItem(_)
+            | AstKind::JSXElementName(_)
+            | AstKind::JSXMemberExpressionObject(_)
+            | AstKind::ModuleDeclaration(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::PropertyKey(_)
+            | AstKind::SimpleAssignmentTarget(_)
+    )
+}
+
+/// The ESTree type of a node of oxc, e.g. `Identifier` for `IdentifierReference`.
+fn estree_type(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "IdentifierName" | "IdentifierReference" | "BindingIdentifier" | "LabelIdentifier" => {
+            "Identifier"
+        }
+        "BooleanLiteral" | "NullLiteral" | "NumericLiteral" | "StringLiteral" | "BigintLiteral"
+        | "BigIntLiteral" | "RegExpLiteral" => "Literal",
+        "StaticMemberExpression" | "ComputedMemberExpression" | "PrivateFieldExpression" => {
+            "MemberExpression"
+        }
+        "ObjectProperty" | "BindingProperty" => "Property",
+        "BindingRestElement" | "AssignmentTargetRest" => "RestElement",
+        "FunctionBody" | "FinallyClause" => "BlockStatement",
+        "AssignmentTargetWithDefault" => "AssignmentPattern",
+        "ArrayAssignmentTarget" => "ArrayPattern",
+        "ObjectAssignmentTarget" => "ObjectPattern",
+        "Directive" => "ExpressionStatement",
+        _ => return None,
+    })
+}
+
+/// Whether `name`, a type in a selector, is the type `type_name` of oxc or its ESTree type.
+fn is_type(name: &str, type_name: &str) -> bool {
+    name == type_name || estree_type(type_name) == Some(name)
+}
+
+/// The ESTree type of the node, falling back to the type of oxc.
+fn node_type(kind: AstKind) -> &'static str {
+    match kind {
+        AstKind::Function(function) => match function.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) => match class.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        },
+        kind => estree_type(kind.ty().name()).unwrap_or_else(|| kind.ty().name()),
+    }
+}
+
+impl SelectorNode {
+    fn matches(&self, node_id: AstNodeId, tree: &SelectorTree, scope: Option<AstNodeId>) -> bool {
+        match self {
+            Self::Wildcard => true,
+            Self::Type(name) => {
+                let kind = tree.kind(node_id);
+                is_type(name, kind.ty().name()) || name == node_type(kind)
+            }
+            Self::Attribute { path, test } => {
+                let serialized = tree.serialized(node_id);
+                let value = resolve_path(&serialized, path);
+                match test {
+                    None => value.is_some_and(|value| !value.is_null()),
+                    Some((operator, expected)) => {
+                        let is_type_attribute = path.last().is_some_and(|key| key == "type");
+                        test_attribute(value.as_ref(), *operator, expected, is_type_attribute)
+                    }
+                }
+            }
+            Self::Compound(selectors) => {
+                selectors.iter().all(|selector| selector.matches(node_id, tree, scope))
+            }
+            Self::Matches(selectors) => {
+                selectors.iter().any(|selector| selector.matches(node_id, tree, scope))
+            }
+            Self::Not(selectors) => {
+                !selectors.iter().any(|selector| selector.matches(node_id, tree, scope))
+            }
+            Self::Has(selectors) => tree.descendants(node_id).into_iter().any(|descendant| {
+                selectors.iter().any(|selector| selector.matches(descendant, tree, Some(node_id)))
+            }),
+            Self::Scope => scope == Some(node_id),
+            Self::Relation { left, combinator, right } => {
+                if !right.matches(node_id, tree, scope) {
+                    return false;
+                }
+                match combinator {
+                    Combinator::Descendant => {
+                        tree.ancestors(node_id).any(|ancestor| left.matches(ancestor, tree, scope))
+                    }
+                    Combinator::Child => {
+                        tree.parent(node_id).is_some_and(|parent| left.matches(parent, tree, scope))
+                    }
+                    Combinator::Sibling => {
+                        tree.siblings(node_id).is_some_and(|(siblings, index)| {
+                            siblings[..index]
+                                .iter()
+                                .any(|sibling| left.matches(*sibling, tree, scope))
+                        })
+                    }
+                    Combinator::Adjacent => {
+                        tree.siblings(node_id).is_some_and(|(siblings, index)| {
+                            index > 0 && left.matches(siblings[index - 1], tree, scope)
+                        })
+                    }
+                }
+            }
+            Self::NthChild { index, from_end } => {
+                tree.siblings(node_id).is_some_and(|(siblings, position)| {
+                    let position = if *from_end { siblings.len() - position } else { position + 1 };
+                    position == *index
+                })
+            }
+            Self::Class(class) => {
+                let name = node_type(tree.kind(node_id));
+                let is_expression = || {
+                    name.ends_with("Expression")
+                        || name.ends_with("Literal")
+                        || name == "MetaProperty"
+                        || (name == "Identifier"
+                            && !tree.parent(node_id).is_some_and(|parent| {
+                                matches!(tree.kind(parent), AstKind::MetaProperty(_))
+                            }))
+                };
+                match class {
+                    Class::Statement => {
+                        name.ends_with("Statement") || name.ends_with("Declaration")
+                    }
+                    Class::Declaration => name.ends_with("Declaration"),
+                    Class::Expression => is_expression(),
+                    Class::Function => matches!(
+                        name,
+                        "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+                    ),
+                    Class::Pattern => name.ends_with("Pattern") || is_expression(),
+                }
+            }
+        }
+    }
+}
+
+/// The value at `path` in `value`, e.g. `callee.name` or `arguments.length`.
+fn resolve_path(value: &Value, path: &[String]) -> Option<Value> {
+    let mut value = Cow::Borrowed(value);
+    for key in path {
+        let next = match &*value {
+            Value::Object(object) => field(object, key)?,
+            Value::Array(array) if key == "length" => Value::from(array.len()),
+            Value::Array(array) => array.get(key.parse::<usize>().ok()?)?.clone(),
+            Value::String(string) if key == "length" => Value::from(string.encode_utf16().count()),
+            _ => return None,
+        };
+        value = Cow::Owned(next);
+    }
+    Some(value.into_owned())
+}
+
+/// The field `key` of a serialized node, in the ESTree shape.
+fn field(node: &Map<String, Value>, key: &str) -> Option<Value> {
+    let value = match (node.get("type").and_then(Value::as_str), key) {
+        (Some("FunctionBody"), "body") => node.get("statements")?,
+        (Some("ArrowFunctionExpression"), "body")
+            if node.get("expression") == Some(&true.into()) =>
+        {
+            node.get("body")?.get("statements")?.get(0)?.get("expression")?
+        }
+        _ => node.get(key)?,
+    };
+    Some(estree_value(value))
+}
+
+/// The value without the wrappers of oxc which have no ESTree node, e.g. `FormalParameters`.
+fn estree_value(mut value: &Value) -> Value {
+    loop {
+        let unwrapped = match value.get("type").and_then(Value::as_str) {
+            Some("FormalParameters") => value.get("items"),
+            Some("FormalParameter" | "CatchParameter") => value.get("pattern"),
+            Some("ParenthesizedExpression") => value.get("expression"),
+            _ => break,
+        };
+        let Some(unwrapped) = unwrapped else { break };
+        value = unwrapped;
+    }
+    match value {
+        Value::Array(items) => Value::Array(items.iter().map(estree_value).collect()),
+        value => value.clone(),
+    }
+}
+
+/// The value converted to a string by JavaScript, for the comparisons with `=` and `!=`.
+fn js_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some("null".to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        Value::Number(number) => number.as_f64().map(|number| number.to_js_string()),
+        Value::String(value) => Some(value.clone()),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+fn test_attribute(
+    value: Option<&Value>,
+    operator: Operator,
+    expected: &AttributeValue,
+    is_type_attribute: bool,
+) -> bool {
+    match operator {
+        Operator::Eq | Operator::NotEq => {
+            let string = value.and_then(js_string);
+            let equal = match expected {
+                AttributeValue::Regex(regex) => {
+                    value.and_then(Value::as_str).is_some_and(|value| regex.is_match(value))
+                }
+                AttributeValue::String(expected) if is_type_attribute => {
+                    string.is_some_and(|string| is_type(expected, &string))
+                }
+                AttributeValue::String(expected) => string.as_ref() == Some(expected),
+                AttributeValue::Number(expected) => {
+                    string.is_some_and(|string| string == expected.to_js_string())
+                }
+            };
+            equal == (operator == Operator::Eq)
+        }
+        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+            let (Some(value), AttributeValue::Number(expected)) =
+                (value.and_then(Value::as_f64), expected)
+            else {
+                return false;
+            };
+            match operator {
+                Operator::Lt => value < *expected,
+                Operator::LtEq => value <= *expected,
+                Operator::Gt => value > *expected,
+                _ => value >= *expected,
+            }
+        }
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    chars: std::iter::Peekable<std::str::CharIndices<'s>>,
+}
+
+impl<'s> Parser<'s> {
+    fn error(&self, offset: usize, message: &str) -> String {
+        format!("Invalid selector `{}`: {message} at offset {offset}", self.source)
+    }
+
+    fn offset(&mut self) -> usize {
+        self.chars.peek().map_or(self.source.len(), |(offset, _)| *offset)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let mut skipped = false;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.chars.next();
+            skipped = true;
+        }
+        skipped
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((offset, c)) => {
+                Err(self.error(offset, &format!("expected `{expected}`, found `{c}`")))
+            }
+            None => Err(self.error(self.source.len(), &format!("expected `{expected}`"))),
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, String> {
+        let offset = self.offset();
+        let mut identifier = String::new();
+        while let Some(c) =
+            self.peek().filter(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '-'))
+        {
+            identifier.push(c);
+            self.chars.next();
+        }
+        if identifier.is_empty() {
+            return Err(self.error(offset, "expected a name"));
+        }
+        Ok(identifier)
+    }
+
+    /// Selectors separated by `,`, inside of `:has()` when `relative`.
+    fn selectors(&mut self, relative: bool) -> Result<SelectorNode, String> {
+        let mut selectors = vec![self.relation(relative)?];
+        while self.peek() == Some(',') {
+            self.chars.next();
+            selectors.push(self.relation(relative)?);
+        }
+        Ok(if selectors.len() == 1 {
+            selectors.remove(0)
+        } else {
+            SelectorNode::Matches(selectors)
+        })
+    }
+
+    fn combinator(&mut self) -> Option<Combinator> {
+        let whitespace = self.skip_whitespace();
+        let combinator = match self.peek() {
+            Some('>') => Combinator::Child,
+            Some('~') => Combinator::Sibling,
+            Some('+') => Combinator::Adjacent,
+            Some(',' | ')') | None => return None,
+            Some(_) => return whitespace.then_some(Combinator::Descendant),
+        };
+        self.chars.next();
+        self.skip_whitespace();
+        Some(combinator)
+    }
+
+    fn relation(&mut self, relative: bool) -> Result<SelectorNode, String> {
+        self.skip_whitespace();
+        let mut left = if relative {
+            let combinator = match self.peek() {
+                Some('>' | '~' | '+') => self.combinator().unwrap_or(Combinator::Descendant),
+                _ => Combinator::Descendant,
+            };
+            let right = Box::new(self.compound()?);
+            SelectorNode::Relation { left: Box::new(SelectorNode::Scope), combinator, right }
+        } else {
+            self.compound()?
+        };
+        while let Some(combinator) = self.combinator() {
+            let right = Box::new(self.compound()?);
+            left = SelectorNode::Relation { left: Box::new(left), combinator, right };
+        }
+        Ok(left)
+    }
+
+    fn compound(&mut self) -> Result<SelectorNode, String> {
+        let mut selectors = vec![];
+        loop {
+            match self.peek() {
+                Some('*') => {
+                    self.chars.next();
+                    selectors.push(SelectorNode::Wildcard);
+                }
+                Some('[') => {
+                    self.chars.next();
+                    selectors.push(self.attribute()?);
+                }
+                Some(':') => {
+                    self.chars.next();
+                    selectors.push(self.pseudo()?);
+                }
+                Some(c) if c.is_alphabetic() => {
+                    selectors.push(SelectorNode::Type(self.identifier()?));
+                }
+                _ => break,
+            }
+        }
+        match selectors.len() {
+            0 => {
+                let offset = self.offset();
+                Err(match self.peek() {
+                    Some(c) => self.error(offset, &format!("unexpected `{c}`")),
+                    None => self.error(offset, "expected a selector"),
+                })
+            }
+            1 => Ok(selectors.remove(0)),
+            _ => Ok(SelectorNode::Compound(selectors)),
+        }
+    }
+
+    fn attribute(&mut self) -> Result<SelectorNode, String> {
+        self.skip_whitespace();
+        let mut path = vec![self.identifier()?];
+        while self.peek() == Some('.') {
+            self.chars.next();
+            path.push(self.identifier()?);
+        }
+        self.skip_whitespace();
+        let offset = self.offset();
+        let operator = match self.chars.next().map(|(_, c)| c) {
+            Some(']') => return Ok(SelectorNode::Attribute { path, test: None }),
+            Some('=') => Operator::Eq,
+            Some('!') if self.peek() == Some('=') => Operator::NotEq,
+            Some('<') => Operator::Lt,
+            Some('>') => Operator::Gt,
+            _ => return Err(self.error(offset, "expected `]` or an operator")),
+        };
+        let operator = match (operator, self.peek()) {
+            (Operator::NotEq, _) => {
+                self.chars.next();
+                operator
+            }
+            (Operator::Lt, Some('=')) => {
+                self.chars.next();
+                Operator::LtEq
+            }
+            (Operator::Gt, Some('=')) => {
+                self.chars.next();
+                Operator::GtEq
+            }
+            _ => operator,
+        };
+        self.skip_whitespace();
+        let value = self.attribute_value()?;
+        self.expect(']')?;
+        Ok(SelectorNode::Attribute { path, test: Some((operator, value)) })
+    }
+
+    fn attribute_value(&mut self) -> Result<AttributeValue, String> {
+        let offset = self.offset();
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.chars.next();
+                let mut value = String::new();
+                loop {
+                    match self.chars.next().map(|(_, c)| c) {
+                        Some(c) if c == quote => return Ok(AttributeValue::String(value)),
+                        Some('\\') => match self.chars.next().map(|(_, c)| c) {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some(c) => value.push(c),
+                            None => break,
+                        },
+                        Some(c) => value.push(c),
+                        None => break,
+                    }
+                }
+                Err(self.error(offset, "unterminated string"))
+            }
+            Some('/') => {
+                self.chars.next();
+                let mut pattern = String::new();
+                loop {
+                    match self.chars.next().map(|(_, c)| c) {
+                        Some('/') => break,
+                        Some('\\') => {
+                            pattern.push('\\');
+                            let Some((_, c)) = self.chars.next() else { break };
+                            pattern.push(c);
+                        }
+                        Some(c) => pattern.push(c),
+                        None => return Err(self.error(offset, "unterminated regular expression")),
+                    }
+                }
+                let mut flags = String::new();
+                while let Some(flag) = self.peek().filter(char::is_ascii_alphabetic) {
+                    self.chars.next();
+                    // `g`, `u`, `v`, `y` and `d` do not change whether a string matches
+                    if matches!(flag, 'i' | 'm' | 's') {
+                        flags.push(flag);
+                    }
+                }
+                let pattern =
+                    if flags.is_empty() { pattern } else { format!("(?{flags}){pattern}") };
+                Regex::new(&pattern).map(AttributeValue::Regex).map_err(|err| {
+                    self.error(offset, &format!("invalid regular expression: {err}"))
+                })
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let mut number = String::new();
+                while let Some(c) = self
+                    .peek()
+                    .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'))
+                {
+                    number.push(c);
+                    self.chars.next();
+                }
+                number
+                    .parse::<f64>()
+                    .map(AttributeValue::Number)
+                    .map_err(|_| self.error(offset, &format!("invalid number `{number}`")))
+            }
+            _ => {
+                let value = self.identifier()?;
+                if value == "type" && self.peek() == Some('(') {
+                    return Err(self.error(offset, "`type()` is not supported"));
+                }
+                Ok(AttributeValue::String(value))
+            }
+        }
+    }
+
+    fn pseudo(&mut self) -> Result<SelectorNode, String> {
+        let offset = self.offset();
+        let name = self.identifier()?;
+        let selector = match name.as_str() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                let selectors = match self.selectors(name == "has")? {
+                    SelectorNode::Matches(selectors) => selectors,
+                    selector => vec![selector],
+                };
+                self.expect(')')?;
+                match name.as_str() {
+                    "not" => SelectorNode::Not(selectors),
+                    "has" => SelectorNode::Has(selectors),
+                    _ => SelectorNode::Matches(selectors),
+                }
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let index_offset = self.offset();
+                let index = self
+                    .identifier()
+                    .ok()
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .filter(|index| *index > 0)
+                    .ok_or_else(|| self.error(index_offset, "expected a positive integer"))?;
+                self.expect(')')?;
+                SelectorNode::NthChild { index, from_end: name == "nth-last-child" }
+            }
+            "first-child" => SelectorNode::NthChild { index: 1, from_end: false },
+            "last-child" => SelectorNode::NthChild { index: 1, from_end: true },
+            "statement" => SelectorNode::Class(Class::Statement),
+            "expression" => SelectorNode::Class(Class::Expression),
+            "declaration" => SelectorNode::Class(Class::Declaration),
+            "function" => SelectorNode::Class(Class::Function),
+            "pattern" => SelectorNode::Class(Class::Pattern),
+            _ => return Err(self.error(offset, &format!("unsupported pseudo-class `:{name}`"))),
+        };
+        Ok(selector)
+    }
+}
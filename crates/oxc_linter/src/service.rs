@@ -1,26 +1,37 @@
 use std::{
+    borrow::Cow,
     collections::HashMap,
     ffi::OsStr,
     fs,
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, Condvar, Mutex},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex, OnceLock,
+    },
+    time::{Duration, Instant},
 };
 
 use dashmap::DashMap;
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashSet, FxHasher};
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::Parser;
-use oxc_resolver::Resolver;
+use oxc_resolver::{Resolution, ResolveError, Resolver};
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_syntax::module_record::ResolvedModule;
 
 use crate::{
+    cache::{CacheEntry, CachedDiagnostic, DEPENDENTS_RULES},
+    config::OxlintGlobs,
+    dead_code::{dynamic_imports, DeadCodeReport},
+    fixer::{FixResult, MAX_FIX_PASSES},
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    Fixer, LintCache, LintContext, Linter, Message,
 };
 
 pub struct LintServiceOptions {
@@ -32,6 +43,9 @@ pub struct LintServiceOptions {
 
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     pub tsconfig: Option<PathBuf>,
+
+    /// Reuse the results of the unchanged files from the previous runs, not used with `fix`
+    pub cache: Option<LintCache>,
 }
 
 #[derive(Clone)]
@@ -45,6 +59,14 @@ impl LintService {
         Self { runtime }
     }
 
+    /// Only build the module graph of the paths with [`Self::run`], without linting them,
+    /// for [`Self::dead_code`]. The import plugin must be enabled to resolve the imports.
+    pub fn module_graph(linter: Linter, options: LintServiceOptions) -> Self {
+        let mut runtime = Runtime::new(linter, options);
+        runtime.lint = false;
+        Self { runtime: Arc::new(runtime) }
+    }
+
     #[cfg(test)]
     pub(crate) fn from_linter(linter: Linter, options: LintServiceOptions) -> Self {
         let runtime = Arc::new(Runtime::new(linter, options));
@@ -55,17 +77,54 @@ impl LintService {
         &self.runtime.linter
     }
 
+    /// Number of fixes applied with [`LintOptions::fix`](crate::LintOptions::fix).
+    pub fn number_of_fixes(&self) -> usize {
+        self.runtime.number_of_fixes.load(Ordering::Relaxed)
+    }
+
+    /// Number of fixes not applied with [`LintOptions::fix`](crate::LintOptions::fix),
+    /// because they still overlap with other fixes after the last pass.
+    pub fn number_of_remaining_fixes(&self) -> usize {
+        self.runtime.number_of_remaining_fixes.load(Ordering::Relaxed)
+    }
+
     pub fn number_of_dependencies(&self) -> usize {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
 
+    /// Find the exports never imported from the `entries`, and the files unreachable from them,
+    /// in the module graph built by [`Self::run`].
+    ///
+    /// `entries` are matched against the paths relative to the current working directory.
+    pub fn dead_code(&self, entries: &OxlintGlobs) -> DeadCodeReport {
+        let records = self
+            .runtime
+            .module_map
+            .iter()
+            .filter_map(|entry| match entry.value() {
+                ModuleState::Resolved(module_record) => Some(Arc::clone(module_record)),
+                ModuleState::Ignored => None,
+            })
+            .collect();
+        DeadCodeReport::new(&self.runtime.cwd, &self.runtime.paths, records, entries)
+    }
+
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
         self.runtime
             .paths
             .iter()
             .par_bridge()
-            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+            .for_each_with(&self.runtime, |runtime, path| runtime.lint_path(path, tx_error));
+        if let Some(cache) = &self.runtime.cache {
+            if let Err(e) = cache.save() {
+                let error = OxcDiagnostic::warn(format!(
+                    "Failed to write cache {:?} with error \"{e}\"",
+                    cache.location()
+                ));
+                tx_error.send(Some((cache.location().to_path_buf(), vec![error.into()]))).unwrap();
+            }
+        }
         tx_error.send(None).unwrap();
     }
 
@@ -90,6 +149,7 @@ impl LintService {
                     source_text,
                     source_type,
                     check_syntax_errors,
+                    true,
                     tx_error,
                 )
             })
@@ -129,22 +189,49 @@ pub struct Runtime {
     paths: FxHashSet<Box<Path>>,
     linter: Linter,
     resolver: Option<Resolver>,
+    /// `false` to only build the module graph, see [`LintService::module_graph`].
+    lint: bool,
     module_map: ModuleMap,
     cache_state: CacheState,
+    cache: Option<LintCache>,
+    /// Hash of all the linted files, see [`DEPENDENTS_RULES`].
+    project_hash: OnceLock<u64>,
+    /// Number of fixes applied.
+    number_of_fixes: AtomicUsize,
+    /// Number of fixes not applied after the last pass.
+    number_of_remaining_fixes: AtomicUsize,
 }
 
 impl Runtime {
     fn new(linter: Linter, options: LintServiceOptions) -> Self {
-        let resolver = linter.options().import_plugin.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+        // Absolute like the paths of the resolved modules, so that both refer to the same modules.
+        let paths = options
+            .paths
+            .iter()
+            .map(|path| options.cwd.join(path).into_boxed_path())
+            .collect::<FxHashSet<_>>();
+        // The import plugin may be enabled by a nested configuration only.
+        linter.read_nested_configs(paths.iter().map(AsRef::as_ref));
+        let resolver = linter.import_plugin_enabled().then(|| {
+            let tsconfig = options.tsconfig.map_or_else(
+                || options.cwd.join("tsconfig.json"),
+                |tsconfig| options.cwd.join(tsconfig),
+            );
+            Self::get_resolver(Some(tsconfig))
         });
+        let cache = options.cache.filter(|_| linter.options().fix.is_empty());
         Self {
+            paths,
             cwd: options.cwd,
-            paths: options.paths.iter().cloned().collect(),
             linter,
             resolver,
+            lint: true,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            cache,
+            project_hash: OnceLock::new(),
+            number_of_fixes: AtomicUsize::new(0),
+            number_of_remaining_fixes: AtomicUsize::new(0),
         }
     }
 
@@ -160,7 +247,13 @@ impl Runtime {
 
         Resolver::new(ResolveOptions {
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            condition_names: vec!["module".into(), "require".into()],
+            condition_names: vec![
+                "node".into(),
+                "import".into(),
+                "module".into(),
+                "require".into(),
+            ],
+            builtin_modules: true,
             tsconfig,
             ..ResolveOptions::default()
         })
@@ -188,6 +281,154 @@ impl Runtime {
         })
     }
 
+    fn lint_path(&self, path: &Path, tx_error: &DiagnosticSender) {
+        if !self.report_cached(path, tx_error) {
+            self.process_path(path, tx_error);
+        }
+    }
+
+    /// Report the results of `path` from the cache, returns `false` if they are not cached.
+    fn report_cached(&self, path: &Path, tx_error: &DiagnosticSender) -> bool {
+        let Some(cache) = &self.cache else {
+            return false;
+        };
+        let Some(ext) = path.extension().and_then(OsStr::to_str) else {
+            return false;
+        };
+        let Some(Ok((_, source_text))) = Self::get_source_type_and_text(path, ext) else {
+            return false;
+        };
+        let Ok(resolved) = self.linter.resolve_config(path) else {
+            return false;
+        };
+        let content_hash = cache.insert_content(path, &source_text);
+        let is_resolved = |importer: &Path, specifier: &str| {
+            self.resolver.as_ref().is_some_and(|resolver| {
+                let resolution = resolver.resolve(importer.parent().unwrap(), specifier);
+                !matches!(resolved_module(&resolution), ResolvedModule::NotFound)
+            })
+        };
+        let Some(entry) =
+            cache.get(path, content_hash, resolved.hash, || self.project_hash(), is_resolved)
+        else {
+            return false;
+        };
+
+        // Already reported while being linted as a dependency of another file.
+        if !cache.claim(path) {
+            return true;
+        }
+        let sources = PartialLoader::parse(ext, &source_text).map_or_else(
+            || vec![source_text.as_str()],
+            |sources| sources.iter().map(|source| source.source_text).collect(),
+        );
+        for (source_text, diagnostics) in sources.into_iter().zip(&entry.diagnostics) {
+            if diagnostics.is_empty() {
+                continue;
+            }
+            let errors = diagnostics.iter().map(OxcDiagnostic::from).collect();
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+        true
+    }
+
+    /// Hash of the paths and contents of all the linted files.
+    fn project_hash(&self) -> u64 {
+        *self.project_hash.get_or_init(|| {
+            let cache = self.cache.as_ref().unwrap();
+            let mut paths = self.paths.iter().collect::<Vec<_>>();
+            paths.sort_unstable();
+            let mut hasher = FxHasher::default();
+            for path in paths {
+                path.hash(&mut hasher);
+                cache.content_hash(path).hash(&mut hasher);
+            }
+            hasher.finish()
+        })
+    }
+
+    /// Store the results of `path` in the cache, with the files it depends on
+    /// and the module specifiers which cannot be resolved.
+    fn store_cached(
+        &self,
+        path: &Path,
+        content_hash: u64,
+        mut dependencies: FxHashSet<PathBuf>,
+        unresolved: FxHashSet<(PathBuf, String)>,
+        diagnostics: Vec<Vec<CachedDiagnostic>>,
+    ) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        let Ok(resolved) = self.linter.resolve_config(path) else {
+            return;
+        };
+        let project_hash = resolved
+            .rules()
+            .iter()
+            .any(|rule| DEPENDENTS_RULES.contains(&rule.name()))
+            .then(|| self.project_hash());
+        dependencies.extend(resolved.rules().iter().flat_map(|rule| rule.read_files(path)));
+        let mut dependencies = dependencies
+            .into_iter()
+            .map(|dependency| {
+                let hash = cache.content_hash(&dependency);
+                (dependency, hash)
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_unstable();
+        let mut unresolved = unresolved.into_iter().collect::<Vec<_>>();
+        unresolved.sort_unstable();
+        let entry = CacheEntry {
+            content_hash,
+            config_hash: resolved.hash,
+            project_hash,
+            dependencies,
+            unresolved,
+            diagnostics,
+        };
+        cache.insert(path, entry);
+    }
+
+    /// Collect the paths of the files imported by `path` directly or indirectly,
+    /// and the module specifiers which cannot be resolved, with the path of the importing module.
+    fn collect_dependencies(
+        &self,
+        path: &Path,
+        dependencies: &mut FxHashSet<PathBuf>,
+        unresolved: &mut FxHashSet<(PathBuf, String)>,
+    ) {
+        let Some(module_state) = self.module_map.get(path) else {
+            return;
+        };
+        let ModuleState::Resolved(module_record) = module_state.value() else {
+            return;
+        };
+        let mut stack = vec![Arc::clone(module_record)];
+        while let Some(module_record) = stack.pop() {
+            // Including the files which are not modules, e.g. JSON files.
+            for resolved_module in &module_record.resolved_modules {
+                match resolved_module.value() {
+                    ResolvedModule::Path(path) => {
+                        dependencies.insert(path.clone());
+                    }
+                    ResolvedModule::NotFound => {
+                        let path = module_record.resolved_absolute_path.clone();
+                        unresolved.insert((path, resolved_module.key().to_string()));
+                    }
+                    ResolvedModule::Builtin => {}
+                }
+            }
+            for loaded_module in &module_record.loaded_modules {
+                if dependencies.insert(loaded_module.resolved_absolute_path.clone()) {
+                    stack.push(Arc::clone(loaded_module.value()));
+                }
+            }
+        }
+    }
+
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         if self.init_cache_state(path) {
             return;
@@ -207,11 +448,20 @@ impl Runtime {
             Ok(source_text) => source_text,
             Err(e) => {
                 self.ignore_path(path);
+                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
                 tx_error.send(Some((path.to_path_buf(), vec![e]))).unwrap();
                 return;
             }
         };
 
+        // `false` for the dependencies of the linted files,
+        // and for the files whose results are already reported from the cache.
+        let lint = self.lint
+            && self.paths.contains(path)
+            && self.cache.as_ref().map_or(true, |cache| cache.claim(path));
+        let content_hash =
+            self.cache.as_ref().map(|cache| cache.insert_content(path, &source_text));
+
         let sources = PartialLoader::parse(ext, &source_text);
         let is_processed_by_partial_loader = sources.is_some();
         let sources =
@@ -222,26 +472,90 @@ impl Runtime {
             return;
         }
 
+        let mut dependencies = FxHashSet::default();
+        let mut unresolved = FxHashSet::default();
+        let mut cached_diagnostics = vec![];
         for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
-
             // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+            let (source_text, errors) =
+                if !is_processed_by_partial_loader && !self.linter.options().fix.is_empty() {
+                    let (fixed_code, errors) =
+                        self.fix_source(path, source_text, source_type, lint, tx_error);
+                    if fixed_code != source_text {
+                        fs::write(path, fixed_code.as_bytes()).unwrap();
+                    }
+                    (Cow::Owned(fixed_code), errors)
+                } else {
+                    let allocator = Allocator::default();
+                    let messages = self.process_source(
+                        path,
+                        &allocator,
+                        source_text,
+                        source_type,
+                        true,
+                        lint,
+                        tx_error,
+                    );
+                    (Cow::Borrowed(source_text), messages.into_iter().map(|m| m.error).collect())
+                };
+
+            if content_hash.is_some() {
+                self.collect_dependencies(path, &mut dependencies, &mut unresolved);
+                cached_diagnostics.push(errors.iter().map(CachedDiagnostic::from).collect());
             }
 
-            if !messages.is_empty() {
+            if !errors.is_empty() {
                 self.ignore_path(path);
-                let errors = messages.into_iter().map(|m| m.error).collect();
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+                let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
             }
         }
+
+        if let (true, Some(content_hash)) = (lint, content_hash) {
+            self.store_cached(path, content_hash, dependencies, unresolved, cached_diagnostics);
+        }
+    }
+
+    /// Lint and fix `source_text` again until no fix applies, up to [`MAX_FIX_PASSES`] times,
+    /// so that the fixes skipped because they overlap with other fixes are applied by the next passes.
+    ///
+    /// Returns the fixed code and its diagnostics.
+    fn fix_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+        lint: bool,
+        tx_error: &DiagnosticSender,
+    ) -> (String, Vec<OxcDiagnostic>) {
+        let mut code = source_text.to_string();
+        for _ in 0..MAX_FIX_PASSES {
+            let allocator = Allocator::default();
+            let messages =
+                self.process_source(path, &allocator, &code, source_type, true, lint, tx_error);
+            let number_of_messages = messages.len();
+            let FixResult { fixed, fixed_code, messages } = Fixer::new(&code, messages).fix();
+            if !fixed {
+                let errors = self.count_remaining_fixes(messages);
+                return (code, errors);
+            }
+            self.number_of_fixes.fetch_add(number_of_messages - messages.len(), Ordering::Relaxed);
+            code = fixed_code.into_owned();
+        }
+
+        let allocator = Allocator::default();
+        let messages =
+            self.process_source(path, &allocator, &code, source_type, true, lint, tx_error);
+        let errors = self.count_remaining_fixes(messages);
+        (code, errors)
+    }
+
+    /// Count the fixes left after fixing, and return the diagnostics of `messages`.
+    fn count_remaining_fixes(&self, messages: Vec<Message>) -> Vec<OxcDiagnostic> {
+        let remaining_fixes = messages.iter().filter(|m| m.fix.is_some()).count();
+        self.number_of_remaining_fixes.fetch_add(remaining_fixes, Ordering::Relaxed);
+        messages.into_iter().map(|m| m.error).collect()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -252,13 +566,20 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
+        lint: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
+        let timings = self.linter.timings().filter(|_| lint);
+        let start = Instant::now();
         let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
+        let parse_time = start.elapsed();
 
         if !ret.errors.is_empty() {
+            if let Some(timings) = timings {
+                timings.add_file(path, parse_time, Duration::ZERO);
+            }
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
@@ -268,13 +589,15 @@ impl Runtime {
 
         // Build the module record to unblock other threads from waiting for too long.
         // The semantic model is not built at this stage.
+        let start = Instant::now();
         let semantic_builder = SemanticBuilder::new(source_text, source_type)
             .with_trivias(trivias)
             .with_check_syntax_error(check_syntax_errors)
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
+        let module_record_time = start.elapsed();
 
-        if self.linter.options().import_plugin {
+        if self.linter.import_plugin_enabled() {
             self.module_map.insert(
                 path.to_path_buf().into_boxed_path(),
                 ModuleState::Resolved(Arc::clone(&module_record)),
@@ -288,7 +611,10 @@ impl Runtime {
                 .keys()
                 .par_bridge()
                 .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                    let resolution = resolver.resolve(dir, specifier);
+                    let resolved_module = resolved_module(&resolution);
+                    module_record.resolved_modules.insert(specifier.clone(), resolved_module);
+                    resolution.ok().map(|r| (specifier, r))
                 })
                 .flatten()
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
@@ -308,6 +634,33 @@ impl Runtime {
                         .insert(specifier.clone(), Arc::clone(target_module_record));
                 });
 
+            // The `import()` calls are only followed to build the module graph for the dead
+            // code analysis, not when linting.
+            if !self.lint {
+                dynamic_imports(program)
+                    .into_iter()
+                    .par_bridge()
+                    .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
+                        resolver.resolve(dir, &specifier).ok().map(|r| (specifier, r))
+                    })
+                    .flatten()
+                    .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
+                        let path = resolution.path();
+                        self.process_path(path, tx_error);
+                        let Some(target_module_record_ref) = self.module_map.get(path) else {
+                            return;
+                        };
+                        if let ModuleState::Resolved(target_module_record) =
+                            target_module_record_ref.value()
+                        {
+                            module_record.dynamically_loaded_modules.insert(
+                                specifier.to_compact_str(),
+                                Arc::clone(target_module_record),
+                            );
+                        }
+                    });
+            }
+
             // The thread is blocked here until all dependent modules are resolved.
 
             // Resolve and append `star_export_bindings`
@@ -339,14 +692,18 @@ impl Runtime {
                     .value_mut()
                     .extend(remote_bindings);
             }
+        }
 
-            // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
-                return vec![];
-            }
+        // Stop if the current module is not marked for lint.
+        if !lint {
+            return vec![];
         }
 
+        let start = Instant::now();
         let semantic_ret = semantic_builder.build(program);
+        if let Some(timings) = timings {
+            timings.add_file(path, parse_time, module_record_time + start.elapsed());
+        }
 
         if !semantic_ret.errors.is_empty() {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -358,7 +715,7 @@ impl Runtime {
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
-        if !self.linter.options().import_plugin {
+        if !self.linter.import_plugin_enabled() {
             return false;
         }
 
@@ -413,9 +770,20 @@ impl Runtime {
     }
 
     fn ignore_path(&self, path: &Path) {
-        if self.linter.options().import_plugin {
+        if self.linter.import_plugin_enabled() {
             self.module_map.insert(path.to_path_buf().into_boxed_path(), ModuleState::Ignored);
             self.update_cache_state(path);
         }
     }
 }
+
+/// The module of the result of [`Resolver::resolve`].
+fn resolved_module(resolution: &Result<Resolution, ResolveError>) -> ResolvedModule {
+    match resolution {
+        Ok(resolution) => ResolvedModule::Path(resolution.path().to_path_buf()),
+        Err(ResolveError::Builtin(_)) => ResolvedModule::Builtin,
+        // Mapped to `false` by the `browser` field of `package.json`
+        Err(ResolveError::Ignored(path)) => ResolvedModule::Path(path.clone()),
+        Err(_) => ResolvedModule::NotFound,
+    }
+}
@@ -34,8 +34,9 @@ impl Default for RuleTable {
 impl RuleTable {
     pub fn new() -> Self {
         let default_rules = Linter::default()
-            .rules
-            .into_iter()
+            .state
+            .rules()
+            .iter()
             .map(|rule| rule.name())
             .collect::<FxHashSet<&str>>();
 
@@ -9,8 +9,8 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    rules::RULES, AllowWarnDeny, Fixer, LintOptions, LintService, LintServiceOptions, Linter,
-    OxlintConfig, RuleEnum, RuleWithSeverity,
+    rules::RULES, AllowWarnDeny, FixKind, Fixer, LintOptions, LintService, LintServiceOptions,
+    Linter, OxlintConfig, RuleEnum, RuleWithSeverity,
 };
 
 #[derive(Eq, PartialEq)]
@@ -88,6 +88,7 @@ impl<S: Into<String>> From<(S, S)> for ExpectFix {
 
 pub struct Tester {
     rule_name: &'static str,
+    plugin_name: Option<&'static str>,
     rule_path: PathBuf,
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
@@ -99,6 +100,10 @@ pub struct Tester {
     jsx_a11y_plugin: bool,
     nextjs_plugin: bool,
     react_perf_plugin: bool,
+    promise_plugin: bool,
+    node_plugin: bool,
+    vitest_plugin: bool,
+    type_aware: bool,
 }
 
 impl Tester {
@@ -114,6 +119,7 @@ impl Tester {
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
         Self {
             rule_name,
+            plugin_name: None,
             rule_path,
             expect_pass,
             expect_fail,
@@ -125,6 +131,10 @@ impl Tester {
             jsx_a11y_plugin: false,
             nextjs_plugin: false,
             react_perf_plugin: false,
+            promise_plugin: false,
+            node_plugin: false,
+            vitest_plugin: false,
+            type_aware: false,
         }
     }
 
@@ -165,6 +175,34 @@ impl Tester {
         self
     }
 
+    pub fn with_promise_plugin(mut self, yes: bool) -> Self {
+        self.promise_plugin = yes;
+        self
+    }
+
+    pub fn with_node_plugin(mut self, yes: bool) -> Self {
+        self.node_plugin = yes;
+        self
+    }
+
+    pub fn with_vitest_plugin(mut self, yes: bool) -> Self {
+        self.vitest_plugin = yes;
+        self
+    }
+
+    /// Test the rule of this plugin, for the rules whose name is shared by several plugins,
+    /// e.g. `no-process-exit` of `unicorn` and `node`. The snapshot is prefixed with the plugin.
+    pub fn with_plugin_name(mut self, plugin_name: &'static str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    /// Run the rule with the type information of [`SemanticTypeInfo`](crate::SemanticTypeInfo).
+    pub fn with_type_aware(mut self, yes: bool) -> Self {
+        self.type_aware = yes;
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -204,7 +242,10 @@ impl Tester {
     }
 
     pub fn snapshot(&self) {
-        let name = self.rule_name.replace('-', "_");
+        let name = match self.plugin_name {
+            Some(plugin_name) => format!("{plugin_name}_{}", self.rule_name.replace('-', "_")),
+            None => self.rule_name.replace('-', "_"),
+        };
         insta::with_settings!({ prepend_module_to_snapshot => false, }, {
             insta::assert_snapshot!(name.clone(), self.snapshot, &name);
         });
@@ -252,12 +293,16 @@ impl Tester {
         let allocator = Allocator::default();
         let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
         let options = LintOptions::default()
-            .with_fix(is_fix)
-            .with_import_plugin(self.import_plugin)
-            .with_jest_plugin(self.jest_plugin)
-            .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
-            .with_nextjs_plugin(self.nextjs_plugin)
-            .with_react_perf_plugin(self.react_perf_plugin);
+            .with_fix(if is_fix { FixKind::all() } else { FixKind::None })
+            .with_import_plugin(Some(self.import_plugin))
+            .with_jest_plugin(Some(self.jest_plugin))
+            .with_jsx_a11y_plugin(Some(self.jsx_a11y_plugin))
+            .with_nextjs_plugin(Some(self.nextjs_plugin))
+            .with_react_perf_plugin(Some(self.react_perf_plugin))
+            .with_promise_plugin(Some(self.promise_plugin))
+            .with_node_plugin(Some(self.node_plugin))
+            .with_vitest_plugin(Some(self.vitest_plugin))
+            .with_type_aware(self.type_aware);
         let eslint_config = eslint_config
             .as_ref()
             .map_or_else(OxlintConfig::default, |v| OxlintConfig::deserialize(v).unwrap());
@@ -276,7 +321,7 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options = LintServiceOptions { cwd, paths, tsconfig: None };
+        let options = LintServiceOptions { cwd, paths, tsconfig: None, cache: None };
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
@@ -312,7 +357,12 @@ impl Tester {
     fn find_rule(&self) -> &RuleEnum {
         RULES
             .iter()
-            .find(|rule| rule.name() == self.rule_name)
+            .find(|rule| {
+                rule.name() == self.rule_name
+                    && self
+                        .plugin_name
+                        .map_or(true, |plugin_name| rule.plugin_name() == plugin_name)
+            })
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
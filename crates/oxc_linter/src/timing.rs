@@ -0,0 +1,148 @@
+//! Time spent linting, collected when [`LintOptions::timing`](crate::LintOptions::timing) is enabled.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use rustc_hash::FxHashMap;
+use serde_json::{json, Value};
+
+use crate::rules::RULES;
+
+/// Number of rules shown by the [`fmt::Display`] table, the JSON output lists every rule.
+const DISPLAYED_RULES: usize = 10;
+
+/// Wall time of each rule across `run_once`, `run_on_symbol` and `run`,
+/// and parse and semantic time of each file.
+#[derive(Debug, Default)]
+pub struct LintTimings {
+    /// Keyed by [`RuleEnum::id`](crate::rules::RuleEnum::id).
+    rules: Mutex<FxHashMap<usize, Duration>>,
+    files: Mutex<Vec<FileTiming>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileTiming {
+    pub path: PathBuf,
+    pub parse: Duration,
+    pub semantic: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleTiming {
+    /// `plugin/rule-name`
+    pub rule_name: String,
+    pub time: Duration,
+}
+
+impl LintTimings {
+    /// Add the time spent by the rules of one file, keyed by rule id.
+    pub(crate) fn add_rules(&self, rules: impl IntoIterator<Item = (usize, Duration)>) {
+        let mut timings = self.rules.lock().unwrap();
+        for (id, time) in rules {
+            *timings.entry(id).or_default() += time;
+        }
+    }
+
+    pub(crate) fn add_file(&self, path: &Path, parse: Duration, semantic: Duration) {
+        let timing = FileTiming { path: path.to_path_buf(), parse, semantic };
+        self.files.lock().unwrap().push(timing);
+    }
+
+    /// Time spent by each rule, the slowest first.
+    ///
+    /// # Panics
+    ///
+    /// * When a thread panicked while adding timings
+    pub fn rules(&self) -> Vec<RuleTiming> {
+        let timings = self.rules.lock().unwrap();
+        let mut rules = RULES
+            .iter()
+            .filter_map(|rule| {
+                let time = *timings.get(&rule.id())?;
+                let rule_name = format!("{}/{}", rule.plugin_name(), rule.name());
+                Some(RuleTiming { rule_name, time })
+            })
+            .collect::<Vec<_>>();
+        rules.sort_by(|a, b| b.time.cmp(&a.time).then_with(|| a.rule_name.cmp(&b.rule_name)));
+        rules
+    }
+
+    /// Parse and semantic time of each file, in path order.
+    ///
+    /// # Panics
+    ///
+    /// * When a thread panicked while adding timings
+    pub fn files(&self) -> Vec<FileTiming> {
+        let mut files = self.files.lock().unwrap().clone();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files
+    }
+
+    pub fn to_json(&self) -> Value {
+        let rules = self.rules();
+        let total: Duration = rules.iter().map(|rule| rule.time).sum();
+        let files = self.files();
+        json!({
+            "rules": rules.iter().map(|rule| json!({
+                "rule": rule.rule_name,
+                "time": millis(rule.time),
+                "relative": relative(rule.time, total),
+            })).collect::<Vec<_>>(),
+            "parse": millis(files.iter().map(|file| file.parse).sum()),
+            "semantic": millis(files.iter().map(|file| file.semantic).sum()),
+            "files": files.iter().map(|file| json!({
+                "path": file.path.to_string_lossy(),
+                "parse": millis(file.parse),
+                "semantic": millis(file.semantic),
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+fn relative(time: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        0.0
+    } else {
+        time.as_secs_f64() * 100.0 / total.as_secs_f64()
+    }
+}
+
+/// A table similar to ESLint's `TIMING=1` output, with the slowest rules.
+impl fmt::Display for LintTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules = self.rules();
+        let total: Duration = rules.iter().map(|rule| rule.time).sum();
+        let displayed = &rules[..rules.len().min(DISPLAYED_RULES)];
+        let width = displayed.iter().map(|rule| rule.rule_name.len()).max().unwrap_or(0).max(4);
+
+        writeln!(f, "{:<width$} | {:>9} | {:>8}", "Rule", "Time (ms)", "Relative")?;
+        writeln!(f, ":{:-<w$}|{:->10}:|{:->8}:", "", "", "", w = width)?;
+        for rule in displayed {
+            writeln!(
+                f,
+                "{:<width$} | {:>9.3} | {:>7.1}%",
+                rule.rule_name,
+                millis(rule.time),
+                relative(rule.time, total)
+            )?;
+        }
+
+        let files = self.files();
+        writeln!(
+            f,
+            "Parse: {:.3} ms, semantic: {:.3} ms, rules: {:.3} ms in {} files",
+            millis(files.iter().map(|file| file.parse).sum()),
+            millis(files.iter().map(|file| file.semantic).sum()),
+            millis(total),
+            files.len()
+        )
+    }
+}
@@ -0,0 +1,438 @@
+//! Type information for the type-aware rules, enabled by `typeAware` in the configuration
+//! or by [`LintOptions::type_aware`](crate::LintOptions::type_aware).
+//!
+//! Rules read it from [`LintContext::type_info`], which is `None` when type-aware linting is disabled.
+//! The default provider, [`SemanticTypeInfo`], infers what it can from the current file only,
+//! and answers [`Thenable::Unknown`] otherwise. A provider backed by the TypeScript compiler
+//! can be given with [`Linter::with_type_info_provider`](crate::Linter::with_type_info_provider).
+
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, CallExpression, Class, ClassElement, Expression, Function,
+        IdentifierReference, NewExpression, ObjectExpression, ObjectPropertyKind, Statement,
+        TSType, TSTypeName, VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_semantic::Reference;
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    ast_util::{get_declaration_of_variable, get_symbol_id_of_variable, is_global_reference},
+    AstNode, LintContext,
+};
+
+/// Whether a value is a promise, or another object with a `then` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Thenable {
+    Yes,
+    No,
+    Unknown,
+}
+
+impl Thenable {
+    /// For a value which is either of two values.
+    #[must_use]
+    pub fn either(self, other: Self) -> Self {
+        if self == other {
+            self
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+pub trait TypeInfoProvider: Send + Sync {
+    /// Whether the value of `expr` is thenable, [`Thenable::Unknown`] when the provider cannot tell.
+    fn thenable<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> Thenable;
+
+    /// Whether the value of `expr` is known to be an array, `false` when the provider cannot tell.
+    fn is_array<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool;
+}
+
+/// Lightweight inference over the semantic model of the linted file.
+///
+/// Values are known from literals, `async` functions, `Promise` and `Array` constructors and static
+/// methods, type annotations and assertions, and the initializers of variables which are never
+/// reassigned.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SemanticTypeInfo;
+
+/// Maximum number of variables and calls followed from an expression.
+const MAX_DEPTH: u8 = 8;
+
+impl TypeInfoProvider for SemanticTypeInfo {
+    fn thenable<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> Thenable {
+        expression_thenable(expr, ctx, 0)
+    }
+
+    fn is_array<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        expression_is_array(expr, ctx, 0)
+    }
+}
+
+fn expression_thenable<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>, depth: u8) -> Thenable {
+    if depth > MAX_DEPTH {
+        return Thenable::Unknown;
+    }
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::PrivateInExpression(_)
+        | Expression::JSXElement(_)
+        | Expression::JSXFragment(_)
+        // promises adopt the state of thenables, the result of `await` is never a thenable
+        | Expression::AwaitExpression(_) => Thenable::No,
+        Expression::ParenthesizedExpression(expr) => {
+            expression_thenable(&expr.expression, ctx, depth)
+        }
+        Expression::TSSatisfiesExpression(expr) => expression_thenable(&expr.expression, ctx, depth),
+        Expression::TSNonNullExpression(expr) => expression_thenable(&expr.expression, ctx, depth),
+        Expression::TSInstantiationExpression(expr) => {
+            expression_thenable(&expr.expression, ctx, depth)
+        }
+        Expression::TSAsExpression(expr) => type_thenable(&expr.type_annotation),
+        Expression::TSTypeAssertion(expr) => type_thenable(&expr.type_annotation),
+        Expression::SequenceExpression(expr) => expr
+            .expressions
+            .last()
+            .map_or(Thenable::Unknown, |expr| expression_thenable(expr, ctx, depth)),
+        Expression::ConditionalExpression(expr) => expression_thenable(
+            &expr.consequent,
+            ctx,
+            depth,
+        )
+        .either(expression_thenable(&expr.alternate, ctx, depth)),
+        Expression::AssignmentExpression(expr) => match expr.operator {
+            AssignmentOperator::Assign => expression_thenable(&expr.right, ctx, depth),
+            operator if operator.is_logical() => Thenable::Unknown,
+            // arithmetic and bitwise assignments
+            _ => Thenable::No,
+        },
+        Expression::ObjectExpression(expr) => object_thenable(expr),
+        Expression::NewExpression(expr) => new_thenable(expr, ctx),
+        Expression::CallExpression(expr) => call_thenable(expr, ctx, depth + 1),
+        Expression::Identifier(ident) => identifier_thenable(ident, ctx, depth + 1),
+        _ => Thenable::Unknown,
+    }
+}
+
+fn type_thenable(ty: &TSType) -> Thenable {
+    match ty {
+        TSType::TSTypeReference(reference) => match &reference.type_name {
+            TSTypeName::IdentifierReference(ident)
+                if matches!(ident.name.as_str(), "Promise" | "PromiseLike") =>
+            {
+                Thenable::Yes
+            }
+            _ => Thenable::Unknown,
+        },
+        TSType::TSBigIntKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSNeverKeyword(_)
+        | TSType::TSNullKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSStringKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSUndefinedKeyword(_)
+        | TSType::TSVoidKeyword(_)
+        | TSType::TSArrayType(_)
+        | TSType::TSFunctionType(_)
+        | TSType::TSConstructorType(_)
+        | TSType::TSLiteralType(_)
+        | TSType::TSTemplateLiteralType(_)
+        | TSType::TSTupleType(_) => Thenable::No,
+        TSType::TSUnionType(union) => union
+            .types
+            .iter()
+            .map(type_thenable)
+            .reduce(Thenable::either)
+            .unwrap_or(Thenable::Unknown),
+        _ => Thenable::Unknown,
+    }
+}
+
+fn object_thenable(object: &ObjectExpression) -> Thenable {
+    for property in &object.properties {
+        let ObjectPropertyKind::ObjectProperty(property) = property else {
+            return Thenable::Unknown;
+        };
+        match property.key.static_name() {
+            Some(name) if name == "then" => return Thenable::Yes,
+            Some(_) => {}
+            None => return Thenable::Unknown,
+        }
+    }
+    Thenable::No
+}
+
+fn new_thenable<'a>(expr: &NewExpression<'a>, ctx: &LintContext<'a>) -> Thenable {
+    let Expression::Identifier(ident) = expr.callee.without_parenthesized() else {
+        return Thenable::Unknown;
+    };
+    if ident.name == "Promise" && is_global_reference(ident, ctx) {
+        return Thenable::Yes;
+    }
+    match get_declaration_of_variable(ident, ctx).map(AstNode::kind) {
+        Some(AstKind::Class(class)) => class_instance_thenable(class),
+        _ => Thenable::Unknown,
+    }
+}
+
+fn class_instance_thenable(class: &Class) -> Thenable {
+    if class.super_class.is_some() {
+        return Thenable::Unknown;
+    }
+    let has_then = class.body.body.iter().any(|element| match element {
+        ClassElement::MethodDefinition(method) => {
+            !method.r#static && method.key.static_name().is_some_and(|name| name == "then")
+        }
+        ClassElement::PropertyDefinition(property) => {
+            !property.r#static && property.key.static_name().is_some_and(|name| name == "then")
+        }
+        _ => false,
+    });
+    if has_then {
+        Thenable::Yes
+    } else {
+        Thenable::No
+    }
+}
+
+fn call_thenable<'a>(call: &CallExpression<'a>, ctx: &LintContext<'a>, depth: u8) -> Thenable {
+    let callee = call.callee.without_parenthesized();
+    if let Some(member) = callee.as_member_expression() {
+        let name = member.static_property_name();
+        if matches!(name, Some("then" | "catch" | "finally"))
+            && expression_thenable(member.object(), ctx, depth) == Thenable::Yes
+        {
+            return Thenable::Yes;
+        }
+        if let Expression::Identifier(object) = member.object().without_parenthesized() {
+            if object.name == "Promise"
+                && is_global_reference(object, ctx)
+                && matches!(
+                    name,
+                    Some("resolve" | "reject" | "all" | "allSettled" | "any" | "race")
+                )
+            {
+                return Thenable::Yes;
+            }
+        }
+        return Thenable::Unknown;
+    }
+    match callee {
+        Expression::FunctionExpression(function) => function_result_thenable(function),
+        Expression::ArrowFunctionExpression(arrow) => arrow_result_thenable(arrow, ctx, depth),
+        Expression::Identifier(ident) => {
+            match get_declaration_of_variable(ident, ctx).map(AstNode::kind) {
+                Some(AstKind::Function(function)) => function_result_thenable(function),
+                Some(AstKind::VariableDeclarator(declarator))
+                    if declarator.kind == VariableDeclarationKind::Const =>
+                {
+                    match declarator.init.as_ref().map(Expression::without_parenthesized) {
+                        Some(Expression::FunctionExpression(function)) => {
+                            function_result_thenable(function)
+                        }
+                        Some(Expression::ArrowFunctionExpression(arrow)) => {
+                            arrow_result_thenable(arrow, ctx, depth)
+                        }
+                        _ => Thenable::Unknown,
+                    }
+                }
+                _ => Thenable::Unknown,
+            }
+        }
+        _ => Thenable::Unknown,
+    }
+}
+
+fn function_result_thenable(function: &Function) -> Thenable {
+    if function.generator {
+        // generators and async generators return iterators
+        Thenable::No
+    } else if function.r#async {
+        Thenable::Yes
+    } else {
+        function
+            .return_type
+            .as_ref()
+            .map_or(Thenable::Unknown, |ty| type_thenable(&ty.type_annotation))
+    }
+}
+
+fn arrow_result_thenable<'a>(
+    arrow: &ArrowFunctionExpression<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Thenable {
+    if arrow.r#async {
+        return Thenable::Yes;
+    }
+    if let Some(ty) = &arrow.return_type {
+        return type_thenable(&ty.type_annotation);
+    }
+    match arrow.body.statements.first() {
+        Some(Statement::ExpressionStatement(stmt)) if arrow.expression => {
+            expression_thenable(&stmt.expression, ctx, depth)
+        }
+        _ => Thenable::Unknown,
+    }
+}
+
+fn identifier_thenable<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> Thenable {
+    if is_global_reference(ident, ctx) {
+        return match ident.name.as_str() {
+            "undefined" | "NaN" | "Infinity" => Thenable::No,
+            _ => Thenable::Unknown,
+        };
+    }
+    let Some(symbol_id) = get_symbol_id_of_variable(ident, ctx) else {
+        return Thenable::Unknown;
+    };
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::VariableDeclarator(declarator) => {
+            if let Some(ty) = &declarator.id.type_annotation {
+                return type_thenable(&ty.type_annotation);
+            }
+            if !declarator.id.kind.is_binding_identifier() {
+                return Thenable::Unknown;
+            }
+            let reassigned = declarator.kind != VariableDeclarationKind::Const
+                && ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write);
+            match &declarator.init {
+                Some(init) if !reassigned => expression_thenable(init, ctx, depth),
+                _ => Thenable::Unknown,
+            }
+        }
+        AstKind::FormalParameter(param) => param
+            .pattern
+            .type_annotation
+            .as_ref()
+            .map_or(Thenable::Unknown, |ty| type_thenable(&ty.type_annotation)),
+        AstKind::Function(_) | AstKind::Class(_) => Thenable::No,
+        _ => Thenable::Unknown,
+    }
+}
+
+/// The methods of arrays returning a new array.
+const ARRAY_RETURNING_METHODS: [&str; 10] = [
+    "concat",
+    "filter",
+    "flat",
+    "flatMap",
+    "map",
+    "slice",
+    "toReversed",
+    "toSorted",
+    "toSpliced",
+    "with",
+];
+
+fn expression_is_array<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>, depth: u8) -> bool {
+    if depth > MAX_DEPTH {
+        return false;
+    }
+    match expr {
+        Expression::ArrayExpression(_) => true,
+        Expression::ParenthesizedExpression(expr) => {
+            expression_is_array(&expr.expression, ctx, depth)
+        }
+        Expression::TSSatisfiesExpression(expr) => {
+            expression_is_array(&expr.expression, ctx, depth)
+        }
+        Expression::TSNonNullExpression(expr) => expression_is_array(&expr.expression, ctx, depth),
+        Expression::TSAsExpression(expr) => type_is_array(&expr.type_annotation),
+        Expression::TSTypeAssertion(expr) => type_is_array(&expr.type_annotation),
+        // `new Array(n)`
+        Expression::NewExpression(expr) => is_global_array(&expr.callee, ctx),
+        Expression::CallExpression(call) => {
+            let callee = call.callee.without_parenthesized();
+            // `Array(n)`
+            if is_global_array(callee, ctx) {
+                return true;
+            }
+            let Some(member) = callee.as_member_expression() else {
+                return false;
+            };
+            match member.static_property_name() {
+                // `Array.from(iterable)` and `Array.of(a, b)`
+                Some("from" | "of") => is_global_array(member.object(), ctx),
+                Some(name) if ARRAY_RETURNING_METHODS.contains(&name) => {
+                    expression_is_array(member.object(), ctx, depth + 1)
+                }
+                _ => false,
+            }
+        }
+        Expression::Identifier(ident) => identifier_is_array(ident, ctx, depth + 1),
+        _ => false,
+    }
+}
+
+fn is_global_array<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    matches!(expr.without_parenthesized(), Expression::Identifier(ident)
+        if ident.name == "Array" && is_global_reference(ident, ctx))
+}
+
+fn type_is_array(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSArrayType(_) | TSType::TSTupleType(_) => true,
+        TSType::TSTypeReference(reference) => matches!(&reference.type_name,
+            TSTypeName::IdentifierReference(ident)
+                if matches!(ident.name.as_str(), "Array" | "ReadonlyArray")),
+        // `readonly T[]`
+        TSType::TSTypeOperatorType(operator) => type_is_array(&operator.type_annotation),
+        _ => false,
+    }
+}
+
+fn identifier_is_array<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &LintContext<'a>,
+    depth: u8,
+) -> bool {
+    let Some(symbol_id) = get_symbol_id_of_variable(ident, ctx) else {
+        return false;
+    };
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::VariableDeclarator(declarator) => {
+            if let Some(ty) = &declarator.id.type_annotation {
+                return type_is_array(&ty.type_annotation);
+            }
+            if !declarator.id.kind.is_binding_identifier() {
+                return false;
+            }
+            let reassigned = declarator.kind != VariableDeclarationKind::Const
+                && ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write);
+            !reassigned
+                && declarator
+                    .init
+                    .as_ref()
+                    .is_some_and(|init| expression_is_array(init, ctx, depth))
+        }
+        AstKind::FormalParameter(param) => param
+            .pattern
+            .type_annotation
+            .as_ref()
+            .is_some_and(|ty| type_is_array(&ty.type_annotation)),
+        _ => false,
+    }
+}
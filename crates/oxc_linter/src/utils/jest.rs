@@ -27,11 +27,14 @@ const JEST_METHOD_NAMES: phf::Set<&'static str> = phf_set![
     "beforeEach",
     "describe",
     "expect",
+    "expectTypeOf",
     "fdescribe",
     "fit",
     "it",
     "jest",
+    "suite",
     "test",
+    "vi",
     "xdescribe",
     "xit",
     "xtest",
@@ -41,6 +44,8 @@ const JEST_METHOD_NAMES: phf::Set<&'static str> = phf_set![
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum JestFnKind {
     Expect,
+    /// Vitest `expectTypeOf`, e.g. `expectTypeOf(value).toBeString()`.
+    ExpectTypeOf,
     General(JestGeneralFnKind),
     Unknown,
 }
@@ -49,8 +54,13 @@ impl JestFnKind {
     pub fn from(name: &str) -> Self {
         match name {
             "expect" => Self::Expect,
-            "jest" => Self::General(JestGeneralFnKind::Jest),
-            "describe" | "fdescribe" | "xdescribe" => Self::General(JestGeneralFnKind::Describe),
+            "expectTypeOf" => Self::ExpectTypeOf,
+            // Vitest `vi` is the counterpart of `jest`, e.g. `vi.fn()`.
+            "jest" | "vi" => Self::General(JestGeneralFnKind::Jest),
+            // Vitest `suite` is an alias of `describe`.
+            "describe" | "fdescribe" | "xdescribe" | "suite" => {
+                Self::General(JestGeneralFnKind::Describe)
+            }
             "fit" | "it" | "test" | "xit" | "xtest" => Self::General(JestGeneralFnKind::Test),
             "beforeAll" | "beforeEach" | "afterAll" | "afterEach" => {
                 Self::General(JestGeneralFnKind::Hook)
@@ -76,6 +86,7 @@ pub enum JestGeneralFnKind {
 }
 
 /// <https://jestjs.io/docs/configuration#testmatch-arraystring>
+/// and <https://vitest.dev/config/#include>
 pub fn is_jest_file(ctx: &LintContext) -> bool {
     if ctx.file_path().components().any(|c| match c {
         std::path::Component::Normal(p) => p == std::ffi::OsStr::new("__tests__"),
@@ -85,9 +96,11 @@ pub fn is_jest_file(ctx: &LintContext) -> bool {
     }
 
     let file_path = ctx.file_path().to_string_lossy();
-    ["spec.js", "spec.jsx", "spec.ts", "spec.tsx", "test.js", "test.jsx", "test.ts", "test.tsx"]
-        .iter()
-        .any(|ext| file_path.ends_with(ext))
+    ["spec", "test"].iter().any(|suffix| {
+        ["js", "jsx", "ts", "tsx", "cjs", "mjs", "cts", "mts"]
+            .iter()
+            .any(|ext| file_path.ends_with(&format!("{suffix}.{ext}")))
+    })
 }
 
 pub fn is_type_of_jest_fn_call<'a>(
@@ -120,6 +133,8 @@ pub fn parse_general_jest_fn_call<'a>(
     None
 }
 
+/// Parse an `expect` call chain, the Vitest `expectTypeOf` chains are left out since their
+/// arguments and matchers differ, e.g. `expectTypeOf<string>().toBeString()`.
 pub fn parse_expect_jest_fn_call<'a>(
     call_expr: &'a CallExpression<'a>,
     possible_jest_node: &PossibleJestNode<'a, '_>,
@@ -128,14 +143,16 @@ pub fn parse_expect_jest_fn_call<'a>(
     let jest_fn_call = parse_jest_fn_call(call_expr, possible_jest_node, ctx)?;
 
     if let ParsedJestFnCallNew::ExpectFnCall(jest_fn_call) = jest_fn_call {
-        return Some(jest_fn_call);
+        if jest_fn_call.kind == JestFnKind::Expect {
+            return Some(jest_fn_call);
+        }
     }
     None
 }
 
 pub struct PossibleJestNode<'a, 'b> {
     pub node: &'b AstNode<'a>,
-    pub original: Option<&'a Atom<'a>>, // if this node is imported from 'jest/globals', this field will be Some(original_name), otherwise None
+    pub original: Option<&'a Atom<'a>>, // if this node is imported from '@jest/globals' or 'vitest', this field will be Some(original_name), otherwise None
 }
 
 /// Collect all possible Jest fn Call Expression,
@@ -151,6 +168,7 @@ pub fn collect_possible_jest_call_node<'a, 'b>(
     //     expect(1 + 2).toEqual(3);
     // });
     // ```
+    // The same goes for Vitest, e.g. `import { test as it, vi } from 'vitest'`.
     let mut reference_id_with_original_list = collect_ids_referenced_to_import(ctx);
     if JEST_METHOD_NAMES
         .iter()
@@ -207,7 +225,7 @@ fn collect_ids_referenced_to_import<'a, 'b>(
                 };
                 let name = ctx.symbols().get_name(symbol_id);
 
-                if import_decl.source.value == "@jest/globals" {
+                if matches!(import_decl.source.value.as_str(), "@jest/globals" | "vitest") {
                     let original = find_original_name(import_decl, name);
                     let mut ret = vec![];
                     for reference_id in reference_ids {
@@ -325,6 +343,10 @@ mod test {
         assert!(super::is_jest_file(&ctx));
 
         let path = Path::new("__tests__/foo/test.spec.js");
+        let ctx = LintContext::new(Box::from(path), Rc::clone(&semantic_ret));
+        assert!(super::is_jest_file(&ctx));
+
+        let path = Path::new("foo.test.mts");
         let ctx = LintContext::new(Box::from(path), semantic_ret);
         assert!(super::is_jest_file(&ctx));
     }
@@ -66,8 +66,9 @@ pub fn parse_jest_fn_call<'a>(
             members.push(member);
         }
 
-        if matches!(kind, JestFnKind::Expect) {
+        if matches!(kind, JestFnKind::Expect | JestFnKind::ExpectTypeOf) {
             let options = ExpectFnCallOptions {
+                kind,
                 call_expr,
                 members,
                 name,
@@ -99,7 +100,7 @@ pub fn parse_jest_fn_call<'a>(
 
         let mut call_chains = Vec::from([Cow::Borrowed(name)]);
         call_chains.extend(members.iter().filter_map(KnownMemberExpressionProperty::name));
-        if !is_valid_jest_call(&call_chains) {
+        if !is_valid_jest_call(&call_chains) && !is_valid_vitest_call(&call_chains) {
             return None;
         }
 
@@ -117,7 +118,7 @@ pub fn parse_jest_fn_call<'a>(
 fn parse_jest_expect_fn_call<'a>(
     options: ExpectFnCallOptions<'a, '_>,
 ) -> Option<ParsedJestFnCall<'a>> {
-    let ExpectFnCallOptions { call_expr, members, name, local, head, node, ctx } = options;
+    let ExpectFnCallOptions { kind, call_expr, members, name, local, head, node, ctx } = options;
     let (modifiers, matcher, mut expect_error) = match find_modifiers_and_matcher(&members) {
         Ok((modifier, matcher)) => (modifier, matcher, None),
         Err(e) => (vec![], None, Some(e)),
@@ -137,7 +138,7 @@ fn parse_jest_expect_fn_call<'a>(
     }
 
     return Some(ParsedJestFnCall::ExpectFnCall(ParsedExpectFnCall {
-        kind: JestFnKind::Expect,
+        kind,
         head,
         members,
         name: Cow::Borrowed(name),
@@ -237,7 +238,7 @@ fn parse_jest_jest_fn_call<'a>(
     name: &'a str,
     local: &'a str,
 ) -> Option<ParsedJestFnCall<'a>> {
-    if !name.to_ascii_lowercase().eq_ignore_ascii_case("jest") {
+    if !name.to_ascii_lowercase().eq_ignore_ascii_case("jest") && name != "vi" {
         return None;
     }
 
@@ -257,6 +258,7 @@ pub enum ExpectError {
 }
 
 pub struct ExpectFnCallOptions<'a, 'b> {
+    pub kind: JestFnKind,
     pub call_expr: &'a CallExpression<'a>,
     pub members: Vec<KnownMemberExpressionProperty<'a>>,
     pub name: &'a str,
@@ -285,6 +287,27 @@ fn is_valid_jest_call(members: &[Cow<str>]) -> bool {
         .is_ok()
 }
 
+/// Vitest allows the modifiers in any order, e.g. `test.concurrent.skip.each` or
+/// `describe.skip.shuffle`, so its chains are checked modifier by modifier instead of
+/// being listed in `VALID_JEST_FN_CALL_CHAINS`.
+fn is_valid_vitest_call(members: &[Cow<str>]) -> bool {
+    let Some((head, modifiers)) = members.split_first() else {
+        return false;
+    };
+    let valid_modifiers = match head.as_ref() {
+        "describe" | "suite" => VALID_VITEST_DESCRIBE_MODIFIERS.as_slice(),
+        "it" | "test" => VALID_VITEST_TEST_MODIFIERS.as_slice(),
+        _ => return false,
+    };
+    modifiers.iter().enumerate().all(|(index, modifier)| {
+        let is_last = index + 1 == modifiers.len();
+        valid_modifiers.contains(&modifier.as_ref())
+            // `each` and `for` end the chain, e.g. `test.each([])('title', fn)`
+            && (is_last || !matches!(modifier.as_ref(), "each" | "for"))
+            && !modifiers[..index].contains(modifier)
+    })
+}
+
 fn resolve_to_jest_fn<'a>(
     call_expr: &'a CallExpression<'a>,
     original: Option<&'a Atom<'a>>,
@@ -323,7 +346,6 @@ pub struct ParsedGeneralJestFnCall<'a> {
     pub kind: JestFnKind,
     pub members: Vec<KnownMemberExpressionProperty<'a>>,
     pub name: Cow<'a, str>,
-    #[allow(unused)]
     pub local: Cow<'a, str>,
 }
 
@@ -578,3 +600,19 @@ const VALID_JEST_FN_CALL_CHAINS: [[&str; 4]; 51] = [
     ["xtest", "each", "", ""],
     ["xtest", "failing", "", ""],
 ];
+
+const VALID_VITEST_DESCRIBE_MODIFIERS: [&str; 10] = [
+    "concurrent",
+    "each",
+    "for",
+    "only",
+    "runIf",
+    "sequential",
+    "shuffle",
+    "skip",
+    "skipIf",
+    "todo",
+];
+
+const VALID_VITEST_TEST_MODIFIERS: [&str; 10] =
+    ["concurrent", "each", "fails", "for", "only", "runIf", "sequential", "skip", "skipIf", "todo"];
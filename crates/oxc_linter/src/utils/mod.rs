@@ -1,11 +1,14 @@
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod node;
+mod promise;
 mod react;
 mod react_perf;
 mod tree_shaking;
 mod unicorn;
 
 pub use self::{
-    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
+    jest::*, jsdoc::*, nextjs::*, node::*, promise::*, react::*, react_perf::*, tree_shaking::*,
+    unicorn::*,
 };
@@ -0,0 +1,198 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use oxc_ast::{
+    ast::{Argument, BindingPatternKind, CallExpression, Expression},
+    AstKind,
+};
+use oxc_semantic::SymbolId;
+use rustc_hash::FxHashSet;
+
+use crate::{ast_util::is_global_require_call, LintContext};
+
+/// The module name without the `node:` protocol, e.g. `fs` of `node:fs`.
+pub fn strip_node_protocol(module_name: &str) -> &str {
+    module_name.strip_prefix("node:").unwrap_or(module_name)
+}
+
+/// The module name of a `require()` call, e.g. `fs` of `require('node:fs')`.
+pub fn get_required_module<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let Expression::CallExpression(call_expr) = expr.without_parenthesized() else {
+        return None;
+    };
+    get_require_call_module(call_expr, ctx)
+}
+
+/// See [`get_required_module`].
+pub fn get_require_call_module<'a>(
+    call_expr: &CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a str> {
+    if !is_global_require_call(call_expr, ctx) {
+        return None;
+    }
+    match &call_expr.arguments[0] {
+        Argument::StringLiteral(lit) => Some(strip_node_protocol(lit.value.as_str())),
+        Argument::TemplateLiteral(lit) => {
+            lit.quasi().map(|quasi| strip_node_protocol(quasi.as_str()))
+        }
+        _ => None,
+    }
+}
+
+/// The module which the whole symbol is bound to, e.g. `fs` of `import fs from 'fs'`,
+/// `import * as fs from 'node:fs'` or `const fs = require('fs')`.
+pub fn get_module_binding<'a>(symbol_id: SymbolId, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let declaration = ctx.symbols().get_declaration(symbol_id);
+    match ctx.nodes().kind(declaration) {
+        AstKind::VariableDeclarator(declarator) => {
+            if !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+                return None;
+            }
+            get_required_module(declarator.init.as_ref()?, ctx)
+        }
+        AstKind::ImportDefaultSpecifier(_) | AstKind::ImportNamespaceSpecifier(_) => {
+            let AstKind::ImportDeclaration(decl) = ctx.nodes().parent_kind(declaration)? else {
+                return None;
+            };
+            Some(strip_node_protocol(decl.source.value.as_str()))
+        }
+        _ => None,
+    }
+}
+
+/// Name of the package imported by a bare specifier, e.g. `@scope/name` for `@scope/name/sub`.
+pub fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with(['.', '/', '#']) || Path::new(specifier).is_absolute() {
+        return None;
+    }
+    let end = if specifier.starts_with('@') {
+        specifier.match_indices('/').nth(1).map(|(i, _)| i)
+    } else {
+        specifier.find('/')
+    };
+    Some(end.map_or(specifier, |end| &specifier[..end]))
+}
+
+/// Resolve the `.` and `..` components of the path without touching the file system.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The `package.json` files looked up to find the one closest to the file: those of its ancestor
+/// directories, up to the first one which exists.
+pub fn package_json_lookup(file_path: &Path) -> Vec<PathBuf> {
+    let mut package_jsons = vec![];
+    for dir in normalize_path(file_path).ancestors().skip(1) {
+        let package_json = dir.join("package.json");
+        let exists = package_json.is_file();
+        package_jsons.push(package_json);
+        if exists {
+            break;
+        }
+    }
+    package_jsons
+}
+
+/// The fields of a `package.json` used by the Node.js rules.
+#[derive(Debug)]
+pub struct NodePackage {
+    /// Directory of the `package.json`.
+    pub dir: PathBuf,
+    /// `"private": true`, the package is not published.
+    pub private: bool,
+    /// The `engines.node` version range, e.g. `>=18.0.0`.
+    pub engines_node: Option<String>,
+    pub dependencies: FxHashSet<String>,
+    pub dev_dependencies: FxHashSet<String>,
+    /// The `files` field, `None` if every file is published.
+    files: Option<GlobSet>,
+}
+
+impl NodePackage {
+    fn read(path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&text).ok()?;
+        let names = |key: &str| -> FxHashSet<String> {
+            json.get(key)
+                .and_then(serde_json::Value::as_object)
+                .map(|map| map.keys().cloned().collect())
+                .unwrap_or_default()
+        };
+        let files = json.get("files").and_then(serde_json::Value::as_array).and_then(|files| {
+            let mut builder = GlobSetBuilder::new();
+            for pattern in files.iter().filter_map(serde_json::Value::as_str) {
+                let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+                // A directory includes all of its files.
+                for glob in [pattern.to_string(), format!("{pattern}/**")] {
+                    builder.add(GlobBuilder::new(&glob).literal_separator(true).build().ok()?);
+                }
+            }
+            builder.build().ok()
+        });
+        Some(Self {
+            dir: path.parent()?.to_path_buf(),
+            private: json.get("private").and_then(serde_json::Value::as_bool).unwrap_or(false),
+            engines_node: json
+                .get("engines")
+                .and_then(|engines| engines.get("node"))
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string),
+            dependencies: names("dependencies"),
+            dev_dependencies: names("devDependencies"),
+            files,
+        })
+    }
+
+    /// Whether the file of the package is published, according to the `files` field.
+    /// The `package.json`, README and LICENSE files are always published, `node_modules` never is.
+    pub fn is_published(&self, path: &Path) -> bool {
+        let Ok(relative) = normalize_path(path).strip_prefix(&self.dir).map(Path::to_path_buf)
+        else {
+            return false;
+        };
+        if relative.components().any(|c| c == Component::Normal("node_modules".as_ref())) {
+            return false;
+        }
+        let Some(files) = &self.files else { return true };
+        let is_always_published = relative.components().count() == 1
+            && relative.file_stem().is_some_and(|stem| {
+                let stem = stem.to_string_lossy().to_ascii_lowercase();
+                matches!(stem.as_str(), "package" | "readme" | "license" | "licence")
+            });
+        is_always_published || files.is_match(&relative)
+    }
+}
+
+/// The `package.json` files read so far, shared by the clones of a rule.
+#[derive(Debug, Default, Clone)]
+pub struct NodePackages(Arc<DashMap<PathBuf, Option<Arc<NodePackage>>>>);
+
+impl NodePackages {
+    /// The `package.json` closest to the file, `None` if there is none or it cannot be read.
+    pub fn closest(&self, file_path: &Path) -> Option<Arc<NodePackage>> {
+        let package_json =
+            package_json_lookup(file_path).pop().filter(|package_json| package_json.is_file())?;
+        self.0
+            .entry(package_json.clone())
+            .or_insert_with(|| NodePackage::read(&package_json).map(Arc::new))
+            .clone()
+    }
+}
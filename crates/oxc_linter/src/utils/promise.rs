@@ -0,0 +1,75 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression, StaticMemberExpression},
+    AstKind,
+};
+use oxc_semantic::AstNode;
+use phf::phf_set;
+
+use crate::LintContext;
+
+pub const PROMISE_STATIC_METHODS: phf::Set<&'static str> =
+    phf_set!["all", "allSettled", "any", "race", "reject", "resolve", "withResolvers"];
+
+/// The `obj.method` callee of a call, e.g. `promise.then` of `promise.then(fn)`.
+pub fn get_method_callee<'a, 'b>(
+    call_expr: &'b CallExpression<'a>,
+) -> Option<&'b StaticMemberExpression<'a>> {
+    match call_expr.callee.without_parenthesized() {
+        Expression::StaticMemberExpression(member_expr) => Some(member_expr),
+        _ => None,
+    }
+}
+
+/// Whether the call is `obj.method(...)` for one of the given method names.
+pub fn is_method_call(call_expr: &CallExpression, names: &[&str]) -> bool {
+    get_method_callee(call_expr)
+        .is_some_and(|callee| names.contains(&callee.property.name.as_str()))
+}
+
+/// Whether the expression is known to create a promise, i.e. a `then`, `catch` or `finally` call,
+/// a method call on such a promise, or a static method of `Promise`, e.g. `Promise.all(...)`.
+///
+/// ref: <https://github.com/eslint-community/eslint-plugin-promise/blob/main/rules/lib/is-promise.js>
+pub fn is_promise(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::CallExpression(call_expr) => is_promise_call(call_expr),
+        _ => false,
+    }
+}
+
+/// See [`is_promise`].
+pub fn is_promise_call(call_expr: &CallExpression) -> bool {
+    let Some(callee) = get_method_callee(call_expr) else { return false };
+    let method = callee.property.name.as_str();
+    if matches!(method, "then" | "catch" | "finally") || is_promise(&callee.object) {
+        return true;
+    }
+    // `Promise.withResolvers()` returns an object, not a promise.
+    callee.object.is_specific_id("Promise")
+        && PROMISE_STATIC_METHODS.contains(method)
+        && method != "withResolvers"
+}
+
+/// Whether the node is a function passed as a callback to `.then()` or `.catch()`.
+///
+/// ref: <https://github.com/eslint-community/eslint-plugin-promise/blob/main/rules/lib/is-inside-promise.js>
+pub fn is_inside_promise<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return false;
+    }
+    get_callback_call(node, ctx)
+        .is_some_and(|call_expr| is_method_call(call_expr, &["then", "catch"]))
+}
+
+/// The call which the function is directly passed to as an argument, e.g. `foo(function() {})`.
+pub fn get_callback_call<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a CallExpression<'a>> {
+    let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+    let AstKind::Argument(_) = parents.next()?.kind() else { return None };
+    match parents.next()?.kind() {
+        AstKind::CallExpression(call_expr) => Some(call_expr),
+        _ => None,
+    }
+}
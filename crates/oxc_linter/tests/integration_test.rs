@@ -1,4 +1,4 @@
-use oxc_linter::{RuleCategory, RuleMeta};
+use oxc_linter::{FixKind, RuleCategory, RuleMeta};
 use oxc_macros::declare_oxc_lint_test;
 
 struct TestRule;
@@ -21,6 +21,15 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Dummy description3
+    TestRule3,
+    style,
+    fix | suggestion
+);
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -31,4 +40,8 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Kinds of fixes, none by default
+    assert_eq!(TestRule::FIX, FixKind::None);
+    assert_eq!(TestRule3::FIX, FixKind::SafeFix | FixKind::Suggestion);
 }
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::quote;
@@ -7,18 +9,21 @@ use syn::{
 };
 
 pub struct LintRuleMeta {
+    /// Name of the `RuleEnum` variant.
     name: syn::Ident,
+    /// Name of the rule struct, e.g. `NoProcessExit` of `unicorn::no_process_exit`.
+    struct_name: syn::Ident,
     path: syn::Path,
 }
 
 impl Parse for LintRuleMeta {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let path = input.parse::<syn::Path>()?;
-        let name = syn::parse_str(
+        let struct_name: syn::Ident = syn::parse_str(
             &path.segments.iter().last().unwrap().ident.to_string().to_case(Case::Pascal),
         )
         .unwrap();
-        Ok(Self { name, path })
+        Ok(Self { name: struct_name.clone(), struct_name, path })
     }
 }
 
@@ -28,8 +33,24 @@ pub struct AllLintRulesMeta {
 
 impl Parse for AllLintRulesMeta {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let rules =
+        let mut rules: Vec<LintRuleMeta> =
             input.parse_terminated(LintRuleMeta::parse, syn::Token![,])?.into_iter().collect();
+        // A rule of the same name as a rule declared before it in another plugin, e.g.
+        // `node::no_process_exit` after `unicorn::no_process_exit`, is named after its whole
+        // path, e.g. `NodeNoProcessExit`, the first one keeps its name.
+        let mut names = HashSet::new();
+        for rule in &mut rules {
+            if !names.insert(rule.struct_name.to_string()) {
+                let path = rule
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("_");
+                rule.name = syn::parse_str(&path.to_case(Case::Pascal)).unwrap();
+            }
+        }
         Ok(Self { rules })
     }
 }
@@ -37,7 +58,17 @@ impl Parse for AllLintRulesMeta {
 #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
 pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     let AllLintRulesMeta { rules } = metadata;
-    let use_stmts = rules.iter().map(|rule| &rule.path).collect::<Vec<_>>();
+    let use_stmts = rules
+        .iter()
+        .map(|rule| {
+            let LintRuleMeta { name, struct_name, path } = rule;
+            if name == struct_name {
+                quote! { #path::#struct_name }
+            } else {
+                quote! { #path::#struct_name as #name }
+            }
+        })
+        .collect::<Vec<_>>();
     let struct_names = rules.iter().map(|rule| &rule.name).collect::<Vec<_>>();
     let plugin_names = rules.iter().map(|node| {
         node.path
@@ -51,9 +82,9 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     let ids = rules.iter().enumerate().map(|(i, _)| i).collect::<Vec<_>>();
 
     let expanded = quote! {
-        #(pub use self::#use_stmts::#struct_names;)*
+        #(pub use self::#use_stmts;)*
 
-        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, AstNode};
+        use crate::{context::LintContext, fixer::FixKind, rule::{Rule, RuleCategory, RuleMeta}, AstNode};
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
@@ -81,6 +112,18 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn fix(&self) -> FixKind {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                }
+            }
+
+            pub fn ast_types(&self) -> Option<&'static [oxc_ast::AstType]> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::AST_TYPES),*
+                }
+            }
+
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::documentation()),*
@@ -93,6 +136,24 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn config_schema(&self, gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::config_schema(gen)),*
+                }
+            }
+
+            pub fn repeated_config(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::REPEATED_CONFIG),*
+                }
+            }
+
+            pub fn validate_config(&self, value: &serde_json::Value) -> Result<(), serde_json::Error> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_config(value)),*
+                }
+            }
+
             pub fn read_json(&self, value: serde_json::Value) -> Self {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
@@ -118,6 +179,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                     #(Self::#struct_names(rule) => rule.run_once(ctx)),*
                 }
             }
+
+            pub fn read_files(&self, file_path: &std::path::Path) -> Vec<std::path::PathBuf> {
+                match self {
+                    #(Self::#struct_names(rule) => rule.read_files(file_path)),*
+                }
+            }
         }
 
         impl std::hash::Hash for RuleEnum {
@@ -2,13 +2,22 @@ use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
+    bracketed,
     parse::{Parse, ParseStream},
-    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token,
+    punctuated::Punctuated,
+    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token, Type,
 };
 
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    /// Kinds of fixes, e.g. `fix`, `suggestion | dangerous_fix`.
+    fix: Vec<Ident>,
+    /// Type of the options of the rule, e.g. `config = NoConsoleConfig`,
+    /// or of each of its options with `config = [RestrictedSyntax]`.
+    config: Option<Type>,
+    /// AST types visited by `Rule::run`, e.g. `ast_types = [CallExpression, NewExpression]`.
+    ast_types: Option<Vec<Ident>>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -32,15 +41,54 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
+        let mut fix = vec![];
+        let mut config = None;
+        let mut ast_types = None;
+        while input.peek(Token!(,)) && input.peek2(Ident) {
+            input.parse::<Token!(,)>()?;
+            if input.peek2(Token!(=)) {
+                let key: Ident = input.parse()?;
+                input.parse::<Token!(=)>()?;
+                if key == "config" {
+                    config = Some(input.parse()?);
+                } else if key == "ast_types" {
+                    let content;
+                    bracketed!(content in input);
+                    let types = Punctuated::<Ident, Token!(,)>::parse_terminated(&content)?;
+                    ast_types = Some(types.into_iter().collect());
+                } else {
+                    return Err(Error::new_spanned(
+                        key,
+                        "unexpected key, expected `config` or `ast_types`",
+                    ));
+                }
+                continue;
+            }
+            fix.push(input.parse()?);
+            while input.peek(Token!(|)) {
+                input.parse::<Token!(|)>()?;
+                fix.push(input.parse()?);
+            }
+        }
+
         // Ignore the rest
         input.parse::<proc_macro2::TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self {
+            name: struct_name,
+            category,
+            fix,
+            config,
+            ast_types,
+            documentation,
+            used_in_test: false,
+        })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, config, ast_types, documentation, used_in_test } =
+        metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -52,9 +100,61 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
+    let fix_kinds = fix.iter().map(|kind| match kind.to_string().as_str() {
+        "fix" => quote! { FixKind::SafeFix },
+        "suggestion" => quote! { FixKind::Suggestion },
+        "dangerous_fix" => quote! { FixKind::DangerousFix },
+        _ => panic!("invalid fix kind, expected `fix`, `suggestion` or `dangerous_fix`"),
+    });
+    let fix = if fix.is_empty() {
+        None
+    } else {
+        Some(quote! { const FIX: FixKind = FixKind::None #(.union(#fix_kinds))*; })
+    };
+
+    let config = config.map(|config| match config {
+        Type::Slice(slice) => {
+            let config = slice.elem;
+            quote! {
+                const REPEATED_CONFIG: bool = true;
+
+                fn config_schema(gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                    Some(gen.subschema_for::<#config>())
+                }
+
+                fn validate_config(value: &serde_json::Value) -> Result<(), serde_json::Error> {
+                    value.as_array().map_or(Ok(()), |options| {
+                        options.iter().try_for_each(|options| {
+                            <#config as serde::Deserialize>::deserialize(options).map(|_| ())
+                        })
+                    })
+                }
+            }
+        }
+        config => quote! {
+            fn config_schema(gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                Some(gen.subschema_for::<#config>())
+            }
+
+            fn validate_config(value: &serde_json::Value) -> Result<(), serde_json::Error> {
+                value.get(0).map_or(Ok(()), |options| {
+                    <#config as serde::Deserialize>::deserialize(options).map(|_| ())
+                })
+            }
+        },
+    });
+
+    let ast_types = ast_types.map(|ast_types| {
+        quote! {
+            const AST_TYPES: Option<&'static [oxc_ast::AstType]> =
+                Some(&[#(oxc_ast::AstType::#ast_types),*]);
+        }
+    });
 
     let import_statement = if used_in_test {
         None
+    } else if fix.is_some() {
+        Some(quote! { use crate::{fixer::FixKind, rule::{RuleCategory, RuleMeta}}; })
     } else {
         Some(quote! { use crate::rule::{RuleCategory, RuleMeta}; })
     };
@@ -67,6 +167,12 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            #fix
+
+            #ast_types
+
+            #config
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
@@ -11,6 +11,22 @@ mod declare_oxc_lint;
 /// 1. The documentation
 /// 2. The lint's struct
 ///
+/// The struct is followed by the category of the lint, and optionally by the kinds of fixes
+/// it provides: `fix`, `suggestion` or `dangerous_fix`, combined with `|`,
+/// e.g. `NoDebugger, correctness, fix`.
+///
+/// Rules with options declare the type of their options, the first element after the severity
+/// in the configuration, with `config = Type`, e.g. `NoConsole, restriction, config = NoConsoleConfig`.
+/// The type must implement `serde::Deserialize` and `schemars::JsonSchema`, it is used to validate
+/// the configuration and to generate its JSON schema.
+/// Rules taking any number of options of the same type declare it with `config = [Type]`,
+/// e.g. `NoRestrictedSyntax, restriction, config = [RestrictedSyntax]`.
+///
+/// Rules list the AST types their `run` visits with `ast_types = [..]`,
+/// e.g. `NoDebugger, correctness, fix, ast_types = [DebuggerStatement]`, so that the linter calls `run`
+/// only for the nodes of these types. Rules without `run` declare `ast_types = []`,
+/// rules without `ast_types` run on every node.
+///
 /// # Example
 ///
 /// ```
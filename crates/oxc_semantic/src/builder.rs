@@ -262,13 +262,13 @@ impl<'a> SemanticBuilder<'a> {
         includes: SymbolFlags,
         excludes: SymbolFlags,
     ) -> SymbolId {
+        let includes = includes | self.current_symbol_flags;
         if let Some(symbol_id) = self.check_redeclaration(scope_id, span, name, excludes, true) {
             self.symbols.union_flag(symbol_id, includes);
             self.add_redeclare_variable(symbol_id, span);
             return symbol_id;
         }
 
-        let includes = includes | self.current_symbol_flags;
         let name = CompactStr::new(name);
         let symbol_id = self.symbols.create_symbol(span, name.clone(), includes, scope_id);
         self.symbols.add_declaration(self.current_node_id);
@@ -39,6 +39,18 @@ pub struct ModuleRecord {
     /// The list does not contain two different Records with the same `[[Specifier]]`.
     pub loaded_modules: DashMap<CompactStr, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
 
+    /// Resolutions of the `[[RequestedModules]]`, keyed by ModuleSpecifier.
+    ///
+    /// Only filled when the modules are resolved by the import plugin of the linter.
+    pub resolved_modules: DashMap<CompactStr, ResolvedModule, BuildHasherDefault<FxHasher>>,
+
+    /// The modules loaded by the `import()` calls with a string literal specifier, e.g.
+    /// `import('./lazy')`, keyed by specifier. These are not part of `[[RequestedModules]]`.
+    ///
+    /// Only filled when the module graph is built for the dead code analysis of the linter.
+    pub dynamically_loaded_modules:
+        DashMap<CompactStr, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of ImportEntry records derived from the code of this module
@@ -98,11 +110,20 @@ impl fmt::Debug for ModuleRecord {
             .reduce(|acc, key| format!("{acc}, {key}"))
             .unwrap_or_default();
         let loaded_modules = format!("{{ {loaded_modules} }}");
+        let dynamically_loaded_modules = self
+            .dynamically_loaded_modules
+            .iter()
+            .map(|entry| (entry.key().to_string()))
+            .reduce(|acc, key| format!("{acc}, {key}"))
+            .unwrap_or_default();
+        let dynamically_loaded_modules = format!("{{ {dynamically_loaded_modules} }}");
         f.debug_struct("ModuleRecord")
             .field("not_esm", &self.not_esm)
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("resolved_modules", &self.resolved_modules)
+            .field("dynamically_loaded_modules", &dynamically_loaded_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -116,6 +137,17 @@ impl fmt::Debug for ModuleRecord {
     }
 }
 
+/// Resolution of a requested module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedModule {
+    /// Resolved to a file, which is not necessarily a JavaScript module, e.g. a JSON file
+    Path(PathBuf),
+    /// A Node.js builtin module, e.g. `fs` or `node:fs`
+    Builtin,
+    /// Failed to resolve
+    NotFound,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NameSpan {
     name: CompactStr,
@@ -42,6 +42,20 @@ fn bugfix_features() -> &'static FxHashMap<String, Versions> {
     })
 }
 
+/// The minimum versions of the browsers and engines which support the feature of the plugin,
+/// e.g. `{ "node": "14.0.0", ... }` for `transform-nullish-coalescing-operator`.
+///
+/// With `bugfixes`, the versions which only need a bugfix transform are considered supported, like
+/// in [can_enable_plugin].
+pub fn plugin_versions(name: &str, bugfixes: bool) -> Option<&'static Versions> {
+    if bugfixes {
+        if let Some(versions) = bugfix_features().get(name) {
+            return Some(versions);
+        }
+    }
+    features().get(name)
+}
+
 pub fn can_enable_plugin(name: &str, targets: &Versions, bugfixes: bool) -> bool {
     let versions = if bugfixes {
         bugfix_features().get(name).unwrap_or_else(|| &features()[name])
@@ -1,3 +1,3 @@
 mod babel;
 
-pub use self::babel::can_enable_plugin;
+pub use self::babel::{can_enable_plugin, plugin_versions};
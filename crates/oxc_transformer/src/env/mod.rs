@@ -2,4 +2,8 @@ mod data;
 mod options;
 mod targets;
 
-pub use self::{data::can_enable_plugin, options::EnvOptions, targets::Versions};
+pub use self::{
+    data::{can_enable_plugin, plugin_versions},
+    options::EnvOptions,
+    targets::{version::Version, Versions},
+};
@@ -34,7 +34,7 @@ use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 
 pub use crate::{
     compiler_assumptions::CompilerAssumptions,
-    env::EnvOptions,
+    env::{plugin_versions, EnvOptions, Version},
     es2015::{ArrowFunctionsOptions, ES2015Options},
     options::BabelOptions,
     options::TransformOptions,
@@ -38,12 +38,15 @@ fn bench_linter(criterion: &mut Criterion) {
                 ];
                 let lint_options = LintOptions::default()
                     .with_filter(filter)
-                    .with_import_plugin(true)
-                    .with_jsdoc_plugin(true)
-                    .with_jest_plugin(true)
-                    .with_jsx_a11y_plugin(true)
-                    .with_nextjs_plugin(true)
-                    .with_react_perf_plugin(true);
+                    .with_import_plugin(Some(true))
+                    .with_jsdoc_plugin(Some(true))
+                    .with_jest_plugin(Some(true))
+                    .with_jsx_a11y_plugin(Some(true))
+                    .with_nextjs_plugin(Some(true))
+                    .with_react_perf_plugin(Some(true))
+                    .with_promise_plugin(Some(true))
+                    .with_node_plugin(Some(true))
+                    .with_vitest_plugin(Some(true));
                 let linter = Linter::from_options(lint_options).unwrap();
                 let semantic = Rc::new(semantic_ret.semantic);
                 b.iter(|| {